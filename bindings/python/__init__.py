@@ -1,13 +1,42 @@
 # ruff: noqa: F401 F821
 from ._subset import Font  # type: ignore
-from ._types import FontStyle, Glyph, Path, PathVerb, Point, Transform
+from ._types import (
+    Baseline,
+    BidiClass,
+    BidiControl,
+    Direction,
+    EmojiPresentation,
+    FontStyle,
+    GeneralCategory,
+    Glyph,
+    GlyphClass,
+    GlyphFlags,
+    Normalization,
+    Path,
+    PathVerb,
+    Point,
+    Segmentation,
+    TextTransform,
+    Transform,
+)
 
 __all__ = [
+    "Baseline",
+    "BidiClass",
+    "BidiControl",
+    "Direction",
+    "EmojiPresentation",
     "Font",
     "FontStyle",
+    "GeneralCategory",
     "Glyph",
+    "GlyphClass",
+    "GlyphFlags",
+    "Normalization",
     "Path",
     "PathVerb",
     "Point",
+    "Segmentation",
+    "TextTransform",
     "Transform",
 ]
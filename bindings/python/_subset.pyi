@@ -10,8 +10,46 @@ class FontExtents(NamedTuple):
     descender: int
     line_gap: int
 
+class GlyphExtents(NamedTuple):
+    x_bearing: int
+    y_bearing: int
+    width: int
+    height: int
+
+class FontMetrics(NamedTuple):
+    underline_offset: int
+    underline_size: int
+    strikeout_offset: int
+    strikeout_size: int
+    x_height: int
+    cap_height: int
+    typo_ascender: int
+    typo_descender: int
+    typo_line_gap: int
+    win_ascent: int
+    win_descent: int
+    hhea_ascender: int
+    hhea_descender: int
+    hhea_line_gap: int
+    subscript_x_offset: int
+    subscript_y_offset: int
+    subscript_x_size: int
+    subscript_y_size: int
+    superscript_x_offset: int
+    superscript_y_offset: int
+    superscript_x_size: int
+    superscript_y_size: int
+    use_typo_metrics: bool
+
 class Font:
     def has_glyph(self, uchar: int) -> bool: ...
+    def has_variation_glyph(self, uchar: int, variation_selector: int) -> bool: ...
+    def nominal_glyph_id(self, uchar: int) -> int | None: ...
+    def variation_glyph_id(
+        self, uchar: int, variation_selector: int
+    ) -> int | None: ...
+    @property
+    def has_color_glyphs(self) -> bool: ...
     @property
     def is_italic(self) -> bool: ...
     @property
@@ -21,6 +59,19 @@ class Font:
     @property
     def upem(self) -> int: ...
     def extents(self, horizontal: bool = True) -> FontExtents: ...
+    def glyph_extents(self, glyph_id: int) -> GlyphExtents | None: ...
+    def glyph_advance(self, glyph_id: int, vertical: bool = False) -> int: ...
+    def metrics(self) -> FontMetrics: ...
+    def baseline(
+        self,
+        baseline_tag: int,
+        vertical: bool,
+        script: int,
+        language: str | None = None,
+    ) -> tuple[bool, int]: ...
+    def has_feature(self, feature_tag: int) -> bool: ...
+    def glyph_class(self, glyph_id: int) -> int: ...
+    def ligature_carets(self, glyph_id: int, direction: int) -> list[int]: ...
     def clone(self) -> Font: ...
     def scale(self, font_size: float) -> float: ...
     def synthesize(
@@ -38,11 +89,34 @@ class Font:
     def from_file_path(cls, filename: str | Path, index: int = 0) -> Font: ...
 
 def text_runs(
-    unichars: list[int], callback: Callable[[int, int, int, int], None]
+    unichars: list[int],
+    callback: Callable[[int, int, int, int, int, int, bool, bool], None],
+    vertical: bool = False,
+    bidi_spans: list[tuple[int, int, int]] | None = None,
 ) -> int: ...
+def segment(
+    unichars: list[int], segmentation: int, language: str | None = None
+) -> list[int]: ...
+def normalize(
+    unichars: list[int], normalization: int
+) -> tuple[list[int], list[int]]: ...
+def transform_text(
+    unichars: list[int], transform: int, language: str | None = None
+) -> tuple[list[int], list[int]]: ...
+def unicode_properties(unichar: int) -> tuple[int, int, int, int, int]: ...
+def is_variation_selector(unichar: int) -> bool: ...
+def is_word_separator(unichar: int) -> bool: ...
 def find_best_font_match(
     unichar: int, italic: bool, weight: float, width: float, fonts: list[Font]
 ) -> tuple[bool, int]: ...
+def find_best_font_match_for_sequence(
+    unichars: list[int],
+    emoji_presentation: int,
+    italic: bool,
+    weight: float,
+    width: float,
+    fonts: list[Font],
+) -> tuple[bool, int]: ...
 
 class ShapeContext:
     def __init__(self, unichars: list[int]) -> None: ...
@@ -53,5 +127,11 @@ class ShapeContext:
         length: int,
         bidi_level: int,
         script: int,
-        callback: Callable[[int, int, int, int, int], None],
+        callback: Callable[[int, int, int, int, int, int, int, int, int, int], None],
+        vertical: bool = False,
+        glyph_classes: bool = False,
+        letter_spacing: int = 0,
+        word_spacing: int = 0,
+        show_invisibles: bool = False,
+        small_caps: bool = False,
     ) -> bool: ...
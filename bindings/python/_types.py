@@ -1,5 +1,5 @@
 from dataclasses import dataclass, field
-from enum import IntEnum
+from enum import IntEnum, IntFlag
 from typing import NamedTuple
 
 
@@ -9,11 +9,119 @@ class FontStyle(NamedTuple):
     width: float = 100.0
 
 
+class EmojiPresentation(IntEnum):
+    NONE = 0
+    TEXT = 1
+    EMOJI = 2
+
+
+class BidiControl(IntEnum):
+    ISOLATE_LTR = 0
+    ISOLATE_RTL = 1
+    ISOLATE_FIRST_STRONG = 2
+    EMBED_LTR = 3
+    EMBED_RTL = 4
+    OVERRIDE_LTR = 5
+    OVERRIDE_RTL = 6
+
+
+class Normalization(IntEnum):
+    NFC = 0
+    NFD = 1
+
+
+class TextTransform(IntEnum):
+    UPPERCASE = 0
+    LOWERCASE = 1
+    CAPITALIZE = 2
+
+
+class Baseline(IntEnum):
+    ROMAN = 0x726F6D6E
+    HANGING = 0x68616E67
+    IDEOGRAPHIC_FACE_BOTTOM_OR_LEFT = 0x69636662
+    IDEOGRAPHIC_FACE_TOP_OR_RIGHT = 0x69636674
+    IDEOGRAPHIC_FACE_CENTRAL = 0x49636663
+    IDEOGRAPHIC_EMBOX_BOTTOM_OR_LEFT = 0x6964656F
+    IDEOGRAPHIC_EMBOX_TOP_OR_RIGHT = 0x69647470
+    IDEOGRAPHIC_EMBOX_CENTRAL = 0x49646365
+    MATH = 0x6D617468
+
+
+class GeneralCategory(IntEnum):
+    CONTROL = 0
+    FORMAT = 1
+    UNASSIGNED = 2
+    PRIVATE_USE = 3
+    SURROGATE = 4
+    LOWERCASE_LETTER = 5
+    MODIFIER_LETTER = 6
+    OTHER_LETTER = 7
+    TITLECASE_LETTER = 8
+    UPPERCASE_LETTER = 9
+    SPACING_MARK = 10
+    ENCLOSING_MARK = 11
+    NON_SPACING_MARK = 12
+    DECIMAL_NUMBER = 13
+    LETTER_NUMBER = 14
+    OTHER_NUMBER = 15
+    CONNECT_PUNCTUATION = 16
+    DASH_PUNCTUATION = 17
+    CLOSE_PUNCTUATION = 18
+    FINAL_PUNCTUATION = 19
+    INITIAL_PUNCTUATION = 20
+    OTHER_PUNCTUATION = 21
+    OPEN_PUNCTUATION = 22
+    CURRENCY_SYMBOL = 23
+    MODIFIER_SYMBOL = 24
+    MATH_SYMBOL = 25
+    OTHER_SYMBOL = 26
+    LINE_SEPARATOR = 27
+    PARAGRAPH_SEPARATOR = 28
+    SPACE_SEPARATOR = 29
+
+
+class BidiClass(IntEnum):
+    L = 0
+    R = 1
+    AL = 2
+    EN = 3
+    ES = 4
+    ET = 5
+    AN = 6
+    CS = 7
+    NSM = 8
+    BN = 9
+    B = 10
+    S = 11
+    WS = 12
+    ON = 13
+    LRE = 14
+    RLE = 15
+    LRO = 16
+    RLO = 17
+    PDF = 18
+    LRI = 19
+    RLI = 20
+    FSI = 21
+    PDI = 22
+
+
+class Segmentation(IntEnum):
+    GRAPHEME = 0
+    WORD = 1
+    SENTENCE = 2
+    HARD_LINE_BREAK = 3
+
+
 class FontRun(NamedTuple):
     offset: int
     length: int
     bidi_level: int
+    paragraph_level: int
     script: int
+    emoji_presentation: EmojiPresentation
+    upright: bool
     font_index: int
     font_style: FontStyle
     synthetic_bold: bool
@@ -67,6 +175,27 @@ class Transform:
             points[i] = self.transform_point(points[i])
 
 
+class GlyphClass(IntEnum):
+    UNCLASSIFIED = 0
+    BASE = 1
+    LIGATURE = 2
+    MARK = 3
+    COMPONENT = 4
+
+
+class GlyphFlags(IntFlag):
+    UNSAFE_TO_BREAK = 1
+    UNSAFE_TO_CONCAT = 2
+    SAFE_TO_INSERT_TATWEEL = 4
+
+
+class Direction(IntEnum):
+    LTR = 0
+    RTL = 1
+    TTB = 2
+    BTT = 3
+
+
 class PathVerb(IntEnum):
     MOVE_TO = 0
     LINE_TO = 1
@@ -94,13 +223,19 @@ class Path:
 
 class Glyph(NamedTuple):
     glyph_id: int
+    cluster: int
     x_offset: int
     y_offset: int
     x_advance: int
     y_advance: int
+    x_origin: int
+    y_origin: int
+    glyph_class: GlyphClass
+    flags: GlyphFlags
     path: Path
 
 
 class GlyphRun(NamedTuple):
     font_run: FontRun
     glyphs: list[Glyph]
+    baseline_offset: int = 0
@@ -4,8 +4,31 @@ from collections import deque
 from collections.abc import Callable, Generator
 from dataclasses import dataclass, field
 
-from ._subset import GlyphDrawer, Font, ShapeContext, find_best_font_match, text_runs  # type: ignore
-from ._types import FontRun, FontStyle, Glyph, GlyphRun, Path, PathVerb, Point
+from ._subset import (  # type: ignore
+    GlyphDrawer,
+    Font,
+    ShapeContext,
+    find_best_font_match,
+    find_best_font_match_for_sequence,
+    is_variation_selector,
+    text_runs,
+)
+from ._types import (
+    Baseline,
+    EmojiPresentation,
+    FontRun,
+    FontStyle,
+    Glyph,
+    GlyphClass,
+    GlyphFlags,
+    GlyphRun,
+    Path,
+    PathVerb,
+    Point,
+)
+
+_VERT = int.from_bytes(b"vert", "big")
+_VRT2 = int.from_bytes(b"vrt2", "big")
 
 
 @dataclass(eq=False)
@@ -20,6 +43,11 @@ class Layout:
         embolden_strength: float = 0.02,
         slant: float = 0.25,
         emit_path_commands: bool = True,
+        emit_glyph_classes: bool = False,
+        letter_spacing: int = 0,
+        word_spacing: int = 0,
+        show_invisibles: bool = False,
+        dominant_baseline: Baseline | None = None,
     ) -> list[GlyphRun]:
         glyph_drawer = GlyphDrawer() if emit_path_commands else None
         shape_context = ShapeContext(self.unichars)
@@ -32,14 +60,24 @@ class Layout:
                     slant if run.synthetic_slant else None,
                 )
 
+            baseline_offset = (
+                self._baseline_offset(run, dominant_baseline)
+                if dominant_baseline is not None
+                else 0
+            )
             glyphs = []
 
             def glyph_callback(
                 glyph_id: int,
+                cluster: int,
                 x_offset: int,
                 y_offset: int,
                 x_advance: int,
                 y_advance: int,
+                x_origin: int,
+                y_origin: int,
+                glyph_class: int,
+                flags: int,
             ):
                 path = Path()
 
@@ -52,13 +90,22 @@ class Layout:
 
                 if glyph_drawer is not None:
                     font.draw_glyph(glyph_id, glyph_drawer, path_command_callback)
+                if run.upright:
+                    x_offset += baseline_offset
+                else:
+                    y_offset += baseline_offset
                 glyphs.append(
                     Glyph(
                         glyph_id=glyph_id,
+                        cluster=cluster,
                         x_offset=x_offset,
                         y_offset=y_offset,
                         x_advance=x_advance,
                         y_advance=y_advance,
+                        x_origin=x_origin,
+                        y_origin=y_origin,
+                        glyph_class=GlyphClass(glyph_class),
+                        flags=GlyphFlags(flags),
                         path=path,
                     )
                 )
@@ -70,10 +117,24 @@ class Layout:
                 run.bidi_level,
                 run.script,
                 glyph_callback,
+                vertical=run.upright,
+                glyph_classes=emit_glyph_classes,
+                letter_spacing=letter_spacing,
+                word_spacing=word_spacing,
+                show_invisibles=show_invisibles,
+            )
+            glyph_runs.append(
+                GlyphRun(font_run=run, glyphs=glyphs, baseline_offset=baseline_offset)
             )
-            glyph_runs.append(GlyphRun(font_run=run, glyphs=glyphs))
         return glyph_runs
 
+    # N.B. Runs are aligned to where the first font puts the dominant baseline.
+    def _baseline_offset(self, run: FontRun, baseline: Baseline) -> int:
+        font = self.fonts[run.font_index]
+        _, dominant = self.fonts[0].baseline(baseline, run.upright, run.script)
+        _, own = font.baseline(baseline, run.upright, run.script)
+        return round(dominant * font.upem / self.fonts[0].upem) - own
+
 
 def _split_run[
     T
@@ -101,11 +162,13 @@ class LayoutBuilder:
     unichars: list[int]
     font_styles: list[FontStyle]
     font_style_indices: list[int]
+    vertical: bool
 
-    def __init__(self, fonts: list[Font]) -> None:
+    def __init__(self, fonts: list[Font], vertical: bool = False) -> None:
         if not fonts:
             raise ValueError("empty fonts list")
         self.fonts = fonts
+        self.vertical = vertical
         self.unichars = []
         self.font_styles = []
         self.font_style_indices = []
@@ -127,6 +190,41 @@ class LayoutBuilder:
                 return True
         return False
 
+    def _base_index(self, index: int, start: int) -> int:
+        while index > start and is_variation_selector(self.unichars[index]):
+            index -= 1
+        return index
+
+    def _font_index(
+        self, index: int, start: int, end: int, font_style: FontStyle
+    ) -> int:
+        # N.B. A variation selector always goes with its base character.
+        if index > start and is_variation_selector(self.unichars[index]):
+            index -= 1
+        if index + 1 < end and is_variation_selector(self.unichars[index + 1]):
+            return find_best_font_match_for_sequence(
+                self.unichars[index : index + 2],
+                EmojiPresentation.NONE,
+                font_style.italic,
+                font_style.weight,
+                font_style.width,
+                self.fonts,
+            )[1]
+        return find_best_font_match(
+            self.unichars[index],
+            font_style.italic,
+            font_style.weight,
+            font_style.width,
+            self.fonts,
+        )[1]
+
+    # N.B. Tr characters are rotated if the font can't transform them.
+    @staticmethod
+    def _upright(upright: bool, transformed: bool, font: Font) -> bool:
+        if not upright or not transformed:
+            return upright
+        return font.has_feature(_VERT) or font.has_feature(_VRT2)
+
     def build(self) -> Layout:
         layout = Layout(fonts=self.fonts)
         if not self.unichars:
@@ -134,9 +232,44 @@ class LayoutBuilder:
         layout.unichars = self.unichars.copy()
 
         def text_run_callback(
-            text_run_offset: int, text_run_length: int, bidi_level: int, script: int
+            text_run_offset: int,
+            text_run_length: int,
+            bidi_level: int,
+            paragraph_level: int,
+            script: int,
+            emoji_presentation: int,
+            upright: bool,
+            transformed: bool,
         ):
             rtl = (bidi_level & 1) != 0
+            if emoji_presentation != EmojiPresentation.NONE:
+                # N.B. Emoji sequences must never be split across fonts.
+                font_style = self.font_styles[self.font_style_indices[text_run_offset]]
+                _, font_index = find_best_font_match_for_sequence(
+                    self.unichars[text_run_offset : text_run_offset + text_run_length],
+                    emoji_presentation,
+                    font_style.italic,
+                    font_style.weight,
+                    font_style.width,
+                    self.fonts,
+                )
+                font = self.fonts[font_index]
+                layout.font_runs.append(
+                    FontRun(
+                        offset=text_run_offset,
+                        length=text_run_length,
+                        bidi_level=bidi_level,
+                        paragraph_level=paragraph_level,
+                        script=script,
+                        emoji_presentation=EmojiPresentation(emoji_presentation),
+                        upright=self._upright(upright, transformed, font),
+                        font_index=font_index,
+                        font_style=font_style,
+                        synthetic_bold=font_style.weight > font.weight,
+                        synthetic_slant=font_style.italic and not font.is_italic,
+                    )
+                )
+                return
             font_runs = deque()
             for (
                 font_style_run_offset,
@@ -146,19 +279,22 @@ class LayoutBuilder:
                 run_offset=text_run_offset,
                 run_length=text_run_length,
                 last_value=FontStyle(),
-                callable=lambda index: self.font_styles[self.font_style_indices[index]],
+                # N.B. A variation selector takes the style of its base
+                # character, even when pushed in a span of its own.
+                callable=lambda index: self.font_styles[
+                    self.font_style_indices[self._base_index(index, text_run_offset)]
+                ],
             ):
                 for offset, length, font_index in _split_run(
                     run_offset=font_style_run_offset,
                     run_length=font_style_run_length,
                     last_value=0,
-                    callable=lambda index: find_best_font_match(
-                        self.unichars[index],
-                        font_style.italic,
-                        font_style.weight,
-                        font_style.width,
-                        self.fonts,
-                    )[1],
+                    callable=lambda index: self._font_index(
+                        index,
+                        font_style_run_offset,
+                        font_style_run_offset + font_style_run_length,
+                        font_style,
+                    ),
                 ):
                     font = self.fonts[font_index]
                     synthetic_bold = font_style.weight > font.weight
@@ -167,7 +303,10 @@ class LayoutBuilder:
                         offset=offset,
                         length=length,
                         bidi_level=bidi_level,
+                        paragraph_level=paragraph_level,
                         script=script,
+                        emoji_presentation=EmojiPresentation.NONE,
+                        upright=self._upright(upright, transformed, font),
                         font_index=font_index,
                         font_style=font_style,
                         synthetic_bold=synthetic_bold,
@@ -179,7 +318,9 @@ class LayoutBuilder:
                         font_runs.append(font_run)
             layout.font_runs.extend(font_runs)
 
-        layout.paragraph_base_level = text_runs(self.unichars, text_run_callback)
+        layout.paragraph_base_level = text_runs(
+            self.unichars, text_run_callback, vertical=self.vertical
+        )
         return layout
 
     def clear(self) -> None:
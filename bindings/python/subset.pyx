@@ -42,6 +42,21 @@ cdef extern from "subset.h":
 
     bint subset_font_has_glyph(SubsetFont* font, uint32_t unichar)
 
+    bint subset_font_has_variation_glyph(SubsetFont* font,
+                                         uint32_t unichar,
+                                         uint32_t variation_selector)
+
+    bint subset_font_get_nominal_glyph(SubsetFont* font,
+                                       uint32_t unichar,
+                                       uint32_t* glyph_id)
+
+    bint subset_font_get_variation_glyph(SubsetFont* font,
+                                         uint32_t unichar,
+                                         uint32_t variation_selector,
+                                         uint32_t* glyph_id)
+
+    bint subset_font_has_color_glyphs(SubsetFont* font)
+
     void subset_font_destroy(SubsetFont* font)
 
     bint subset_font_is_italic(SubsetFont* font)
@@ -58,6 +73,70 @@ cdef extern from "subset.h":
                              int32_t* descender,
                              int32_t* line_gap)
 
+    bint subset_font_glyph_extents(SubsetFont* font,
+                                   uint32_t glyph_id,
+                                   int32_t* x_bearing,
+                                   int32_t* y_bearing,
+                                   int32_t* width,
+                                   int32_t* height)
+
+    int32_t subset_font_glyph_advance(SubsetFont* font, uint32_t glyph_id, bint vertical)
+
+    struct SubsetFontMetrics:
+        int32_t underline_offset
+        int32_t underline_size
+        int32_t strikeout_offset
+        int32_t strikeout_size
+        int32_t x_height
+        int32_t cap_height
+        int32_t typo_ascender
+        int32_t typo_descender
+        int32_t typo_line_gap
+        int32_t win_ascent
+        int32_t win_descent
+        int32_t hhea_ascender
+        int32_t hhea_descender
+        int32_t hhea_line_gap
+        int32_t subscript_x_offset
+        int32_t subscript_y_offset
+        int32_t subscript_x_size
+        int32_t subscript_y_size
+        int32_t superscript_x_offset
+        int32_t superscript_y_offset
+        int32_t superscript_x_size
+        int32_t superscript_y_size
+        bint use_typo_metrics
+
+    bint subset_font_metrics(SubsetFont* font, SubsetFontMetrics* metrics)
+
+    bint subset_font_get_baseline(SubsetFont* font, uint32_t baseline_tag,
+                                  bint vertical, uint32_t script,
+                                  const char* language, int32_t* coord)
+
+    bint subset_font_has_feature(SubsetFont* font, uint32_t feature_tag)
+
+    enum SubsetGlyphClass:
+        SUBSET_GLYPH_CLASS_UNCLASSIFIED = 0
+        SUBSET_GLYPH_CLASS_BASE = 1
+        SUBSET_GLYPH_CLASS_LIGATURE = 2
+        SUBSET_GLYPH_CLASS_MARK = 3
+        SUBSET_GLYPH_CLASS_COMPONENT = 4
+
+    SubsetGlyphClass subset_font_glyph_class(SubsetFont* font, uint32_t glyph_id)
+
+    enum SubsetDirection:
+        SUBSET_DIRECTION_LTR = 0
+        SUBSET_DIRECTION_RTL = 1
+        SUBSET_DIRECTION_TTB = 2
+        SUBSET_DIRECTION_BTT = 3
+
+    unsigned int subset_font_ligature_carets(SubsetFont* font,
+                                             SubsetDirection direction,
+                                             uint32_t glyph_id,
+                                             unsigned int start_offset,
+                                             unsigned int* caret_count,
+                                             int32_t* carets)
+
     SubsetFont* subset_font_reference(SubsetFont* font)
 
     SubsetFont* subset_font_synthesize(SubsetFont* font,
@@ -70,20 +149,109 @@ cdef extern from "subset.h":
                                 SubsetPathCommandCallback callback,
                                 void* context)
 
+    enum SubsetEmojiPresentation:
+        SUBSET_EMOJI_PRESENTATION_NONE = 0
+        SUBSET_EMOJI_PRESENTATION_TEXT = 1
+        SUBSET_EMOJI_PRESENTATION_EMOJI = 2
+
     ctypedef void (*SubsetTextRunCallback)(SubsetTextRun text_run,
                                            void* context)
     struct SubsetTextRun:
         size_t offset
         size_t length
         uint8_t bidi_level
+        uint8_t paragraph_level
         uint32_t script
+        SubsetEmojiPresentation emoji_presentation
+        bint upright
+        bint transformed
+
+    enum SubsetBidiControl:
+        SUBSET_BIDI_CONTROL_ISOLATE_LTR = 0
+        SUBSET_BIDI_CONTROL_ISOLATE_RTL = 1
+        SUBSET_BIDI_CONTROL_ISOLATE_FIRST_STRONG = 2
+        SUBSET_BIDI_CONTROL_EMBED_LTR = 3
+        SUBSET_BIDI_CONTROL_EMBED_RTL = 4
+        SUBSET_BIDI_CONTROL_OVERRIDE_LTR = 5
+        SUBSET_BIDI_CONTROL_OVERRIDE_RTL = 6
+
+    struct SubsetBidiSpan:
+        size_t offset
+        size_t length
+        SubsetBidiControl control
 
     int subset_text_runs(const uint32_t* unichars,
                          size_t unichar_count,
+                         const SubsetBidiSpan* bidi_spans,
+                         size_t bidi_span_count,
+                         bint vertical,
                          uint8_t* paragraph_base_level,
                          SubsetTextRunCallback callback,
                          void* context);
 
+    enum SubsetSegmentation:
+        SUBSET_SEGMENTATION_GRAPHEME = 0
+        SUBSET_SEGMENTATION_WORD = 1
+        SUBSET_SEGMENTATION_SENTENCE = 2
+        SUBSET_SEGMENTATION_HARD_LINE_BREAK = 3
+
+    ctypedef void (*SubsetBoundaryCallback)(size_t offset, void* context)
+
+    bint subset_segment(const uint32_t* unichars,
+                        size_t unichar_count,
+                        SubsetSegmentation segmentation,
+                        const char* language,
+                        SubsetBoundaryCallback callback,
+                        void* context)
+
+    enum SubsetNormalization:
+        SUBSET_NORMALIZATION_NFC = 0
+        SUBSET_NORMALIZATION_NFD = 1
+
+    ctypedef bint (*SubsetCompositionFilter)(uint32_t starter,
+                                             uint32_t composed,
+                                             void* context)
+    ctypedef void (*SubsetNormalizedCallback)(uint32_t unichar,
+                                              size_t offset,
+                                              void* context)
+
+    bint subset_normalize(const uint32_t* unichars,
+                          size_t unichar_count,
+                          SubsetNormalization normalization,
+                          SubsetCompositionFilter filter,
+                          SubsetNormalizedCallback callback,
+                          void* context)
+
+    enum SubsetTextTransform:
+        SUBSET_TEXT_TRANSFORM_UPPERCASE = 0
+        SUBSET_TEXT_TRANSFORM_LOWERCASE = 1
+        SUBSET_TEXT_TRANSFORM_CAPITALIZE = 2
+
+    ctypedef void (*SubsetTransformedCallback)(uint32_t unichar,
+                                               size_t offset,
+                                               void* context)
+
+    bint subset_transform_text(const uint32_t* unichars,
+                               size_t unichar_count,
+                               SubsetTextTransform transform,
+                               const char* language,
+                               SubsetTransformedCallback callback,
+                               void* context)
+
+    struct SubsetUnicodeProperties:
+        uint32_t general_category
+        uint32_t script
+        uint32_t mirroring
+        uint8_t combining_class
+        uint32_t bidi_class
+
+    void subset_unicode_properties(uint32_t unichar,
+                                   SubsetUnicodeProperties* properties)
+
+    bint subset_is_variation_selector(uint32_t unichar)
+
+    bint subset_is_word_separator(uint32_t unichar)
+
     struct SubsetFontStyle:
         bint italic
         float weight;
@@ -98,6 +266,15 @@ cdef extern from "subset.h":
                                      void* font_provider_context,
                                      size_t* best_index)
 
+    bint subset_find_best_font_match_for_sequence(const uint32_t* unichars,
+                                                  size_t unichar_count,
+                                                  SubsetEmojiPresentation emoji_presentation,
+                                                  SubsetFontStyle font_style,
+                                                  size_t font_count,
+                                                  SubsetFontProvider font_provider,
+                                                  void* font_provider_context,
+                                                  size_t* best_index)
+
     struct SubsetShapeParams:
         const uint32_t* unichars
         size_t unichar_count
@@ -106,13 +283,24 @@ cdef extern from "subset.h":
         uint8_t bidi_level
         uint32_t script
         const char* language
+        bint vertical
+        bint glyph_classes
+        int32_t letter_spacing
+        int32_t word_spacing
+        bint show_invisibles
+        bint small_caps
 
     struct SubsetGlyph:
         uint32_t glyph_id
+        uint32_t cluster
         int32_t x_offset
         int32_t y_offset
         int32_t x_advance
         int32_t y_advance
+        int32_t x_origin
+        int32_t y_origin
+        SubsetGlyphClass glyph_class
+        uint32_t flags
 
     ctypedef void (*SubsetShapeCallback)(SubsetGlyph glyph, void* context)
 
@@ -146,6 +334,34 @@ cdef void _draw_glyph_callback(SubsetPathVerb verb, const float* points, size_t
 
 FontExtents = namedtuple("FontExtents", ["ascender", "descender", "line_gap"])
 
+GlyphExtents = namedtuple("GlyphExtents", ["x_bearing", "y_bearing", "width", "height"])
+
+FontMetrics = namedtuple("FontMetrics", [
+    "underline_offset",
+    "underline_size",
+    "strikeout_offset",
+    "strikeout_size",
+    "x_height",
+    "cap_height",
+    "typo_ascender",
+    "typo_descender",
+    "typo_line_gap",
+    "win_ascent",
+    "win_descent",
+    "hhea_ascender",
+    "hhea_descender",
+    "hhea_line_gap",
+    "subscript_x_offset",
+    "subscript_y_offset",
+    "subscript_x_size",
+    "subscript_y_size",
+    "superscript_x_offset",
+    "superscript_y_offset",
+    "superscript_x_size",
+    "superscript_y_size",
+    "use_typo_metrics",
+])
+
 
 cdef class Font:
     cdef SubsetFont* _font
@@ -158,6 +374,25 @@ cdef class Font:
     def has_glyph(self, uchar: int) -> bool:
         return subset_font_has_glyph(self._font, uchar)
 
+    def has_variation_glyph(self, uchar: int, variation_selector: int) -> bool:
+        return subset_font_has_variation_glyph(self._font, uchar, variation_selector)
+
+    def nominal_glyph_id(self, uchar: int) -> int | None:
+        cdef uint32_t glyph_id = 0
+        if subset_font_get_nominal_glyph(self._font, uchar, &glyph_id):
+            return glyph_id
+        return None
+
+    def variation_glyph_id(self, uchar: int, variation_selector: int) -> int | None:
+        cdef uint32_t glyph_id = 0
+        if subset_font_get_variation_glyph(self._font, uchar, variation_selector, &glyph_id):
+            return glyph_id
+        return None
+
+    @property
+    def has_color_glyphs(self) -> bool:
+        return subset_font_has_color_glyphs(self._font)
+
     @property
     def is_italic(self) -> bool:
         return subset_font_is_italic(self._font)
@@ -181,6 +416,53 @@ cdef class Font:
         subset_font_extents(self._font, horizontal, &ascender, &descender, &line_gap)
         return FontExtents(ascender, descender, line_gap)
 
+    def glyph_extents(self, glyph_id: int) -> GlyphExtents | None:
+        cdef int32_t x_bearing
+        cdef int32_t y_bearing
+        cdef int32_t width
+        cdef int32_t height
+        if subset_font_glyph_extents(self._font, glyph_id, &x_bearing, &y_bearing, &width, &height):
+            return GlyphExtents(x_bearing, y_bearing, width, height)
+        return None
+
+    def glyph_advance(self, glyph_id: int, vertical: bool = False) -> int:
+        return subset_font_glyph_advance(self._font, glyph_id, vertical)
+
+    def metrics(self) -> FontMetrics:
+        cdef SubsetFontMetrics metrics
+        subset_font_metrics(self._font, &metrics)
+        return FontMetrics(**metrics)
+
+    def baseline(self, baseline_tag: int, vertical: bool, script: int, language: str | None = None) -> tuple[bool, int]:
+        cdef int32_t coord = 0
+        cdef const char* lang = NULL
+        language_bytes = language.encode() if language is not None else None
+        if language_bytes is not None:
+            lang = language_bytes
+        found = subset_font_get_baseline(self._font, baseline_tag, vertical, script, lang, &coord)
+        return found, coord
+
+    def has_feature(self, feature_tag: int) -> bool:
+        return subset_font_has_feature(self._font, feature_tag)
+
+    def glyph_class(self, glyph_id: int) -> int:
+        return subset_font_glyph_class(self._font, glyph_id)
+
+    def ligature_carets(self, glyph_id: int, direction: int) -> list[int]:
+        cdef unsigned int caret_count = 0
+        cdef unsigned int total = subset_font_ligature_carets(self._font, direction, glyph_id, 0, &caret_count, NULL)
+        if total == 0:
+            return []
+        cdef int32_t* carets = <int32_t*>PyMem_Malloc(total * sizeof(int32_t))
+        if carets is NULL:
+            raise MemoryError()
+        caret_count = total
+        try:
+            subset_font_ligature_carets(self._font, direction, glyph_id, 0, &caret_count, carets)
+            return [carets[i] for i in range(caret_count)]
+        finally:
+            PyMem_Free(carets)
+
     def clone(self) -> Font:
         cdef SubsetFont* clone = subset_font_reference(self._font)
         if clone is NULL:
@@ -238,31 +520,112 @@ cdef class Font:
 cdef void _text_run_callback(SubsetTextRun text_run, void* context) noexcept:
     (<object>context)(text_run)
 
-def text_runs(unichars: list[int], callback: Callable[[int, int, int, int], None]) -> int:
+def text_runs(unichars: list[int], callback: Callable[[int, int, int, int, int, int, bool, bool], None], vertical: bool = False, bidi_spans: list[tuple[int, int, int]] | None = None) -> int:
     if not unichars:
         return 0
+    bidi_spans = bidi_spans or []
     cdef uint32_t* uc = <uint32_t*>PyMem_Malloc(len(unichars) * sizeof(uint32_t))
     if uc is NULL:
         raise MemoryError()
+    cdef SubsetBidiSpan* spans = <SubsetBidiSpan*>PyMem_Malloc(max(len(bidi_spans), 1) * sizeof(SubsetBidiSpan))
+    if spans is NULL:
+        PyMem_Free(uc)
+        raise MemoryError()
     for i in range(len(unichars)):
         uc[i] = unichars[i]
+    for i, (offset, length, control) in enumerate(bidi_spans):
+        spans[i].offset = offset
+        spans[i].length = length
+        spans[i].control = control
 
     def callback_delegate(text_run):
-        callback(text_run["offset"], text_run["length"], text_run["bidi_level"], text_run["script"])
+        callback(text_run["offset"], text_run["length"], text_run["bidi_level"], text_run["paragraph_level"], text_run["script"], text_run["emoji_presentation"], text_run["upright"], text_run["transformed"])
 
     cdef uint8_t paragraph_base_level = 0
     try:
-        rv = subset_text_runs(uc, len(unichars), &paragraph_base_level, _text_run_callback, <void*>callback_delegate)
+        rv = subset_text_runs(uc, len(unichars), spans, len(bidi_spans), vertical, &paragraph_base_level, _text_run_callback, <void*>callback_delegate)
         if rv != 0:
             raise ValueError()
     finally:
         PyMem_Free(uc)
+        PyMem_Free(spans)
     return paragraph_base_level
 
+cdef void _boundary_callback(size_t offset, void* context) noexcept:
+    (<list>context).append(offset)
+
+def segment(unichars: list[int], segmentation: int, language: str | None = None) -> list[int]:
+    cdef uint32_t* uc = <uint32_t*>PyMem_Malloc(max(len(unichars), 1) * sizeof(uint32_t))
+    if uc is NULL:
+        raise MemoryError()
+    for i in range(len(unichars)):
+        uc[i] = unichars[i]
+
+    cdef const char* lang = NULL
+    language_bytes = language.encode() if language is not None else None
+    if language_bytes is not None:
+        lang = language_bytes
+    boundaries = []
+    try:
+        if not subset_segment(uc, len(unichars), segmentation, lang, _boundary_callback, <void*>boundaries):
+            raise ValueError()
+    finally:
+        PyMem_Free(uc)
+    return boundaries
+
 cdef SubsetFont* _font_provider(size_t index, void* context) noexcept:
     cdef Font font = (<list>context)[index]
     return font._font
 
+cdef void _normalized_callback(uint32_t unichar, size_t offset, void* context) noexcept:
+    (<list>context).append((unichar, offset))
+
+def normalize(unichars: list[int], normalization: int) -> tuple[list[int], list[int]]:
+    cdef uint32_t* uc = <uint32_t*>PyMem_Malloc(max(len(unichars), 1) * sizeof(uint32_t))
+    if uc is NULL:
+        raise MemoryError()
+    for i in range(len(unichars)):
+        uc[i] = unichars[i]
+
+    normalized = []
+    try:
+        subset_normalize(uc, len(unichars), normalization, NULL, _normalized_callback, <void*>normalized)
+    finally:
+        PyMem_Free(uc)
+    return [v[0] for v in normalized], [v[1] for v in normalized]
+
+cdef void _transformed_callback(uint32_t unichar, size_t offset, void* context) noexcept:
+    (<list>context).append((unichar, offset))
+
+def transform_text(unichars: list[int], transform: int, language: str | None = None) -> tuple[list[int], list[int]]:
+    cdef uint32_t* uc = <uint32_t*>PyMem_Malloc(max(len(unichars), 1) * sizeof(uint32_t))
+    if uc is NULL:
+        raise MemoryError()
+    for i in range(len(unichars)):
+        uc[i] = unichars[i]
+
+    cdef const char* lang = NULL
+    language_bytes = language.encode() if language is not None else None
+    if language_bytes is not None:
+        lang = language_bytes
+    transformed = []
+    try:
+        subset_transform_text(uc, len(unichars), transform, lang, _transformed_callback, <void*>transformed)
+    finally:
+        PyMem_Free(uc)
+    return [v[0] for v in transformed], [v[1] for v in transformed]
+
+def unicode_properties(unichar: int) -> tuple[int, int, int, int, int]:
+    cdef SubsetUnicodeProperties properties
+    subset_unicode_properties(unichar, &properties)
+    return properties.general_category, properties.script, properties.mirroring, properties.combining_class, properties.bidi_class
+
+def is_variation_selector(unichar: int) -> bool:
+    return subset_is_variation_selector(unichar)
+
+def is_word_separator(unichar: int) -> bool:
+    return subset_is_word_separator(unichar)
+
 def find_best_font_match(unichar: int, italic: bool, weight: float, width: float, fonts: list[Font]) -> tuple[bool, int]:
     cdef SubsetFontStyle fs = SubsetFontStyle(
         italic=italic,
@@ -273,6 +636,27 @@ def find_best_font_match(unichar: int, italic: bool, weight: float, width: float
     cdef bint ok = subset_find_best_font_match(unichar, fs, len(fonts), _font_provider, <void*>fonts, &best_index)
     return ok, best_index
 
+def find_best_font_match_for_sequence(unichars: list[int], emoji_presentation: int, italic: bool, weight: float, width: float, fonts: list[Font]) -> tuple[bool, int]:
+    if not unichars:
+        return False, 0
+    cdef uint32_t* uc = <uint32_t*>PyMem_Malloc(len(unichars) * sizeof(uint32_t))
+    if uc is NULL:
+        raise MemoryError()
+    for i in range(len(unichars)):
+        uc[i] = unichars[i]
+    cdef SubsetFontStyle fs = SubsetFontStyle(
+        italic=italic,
+        weight=weight,
+        width=width,
+    )
+    cdef size_t best_index = 0;
+    cdef bint ok = False
+    try:
+        ok = subset_find_best_font_match_for_sequence(uc, len(unichars), emoji_presentation, fs, len(fonts), _font_provider, <void*>fonts, &best_index)
+    finally:
+        PyMem_Free(uc)
+    return ok, best_index
+
 cdef void _shape_callback(SubsetGlyph glyph, void* context) noexcept:
     (<object>context)(glyph)
 
@@ -294,7 +678,7 @@ cdef class ShapeContext:
         PyMem_Free(self._unichars)
 
     # TODO: Language param
-    def shape(self, font: Font, offset: int, length: int, bidi_level: int, script: int, callback: Callable[[int, int, int, int, int], None]) -> bool:
+    def shape(self, font: Font, offset: int, length: int, bidi_level: int, script: int, callback: Callable[[int, int, int, int, int, int, int, int, int, int], None], vertical: bool = False, glyph_classes: bool = False, letter_spacing: int = 0, word_spacing: int = 0, show_invisibles: bool = False, small_caps: bool = False) -> bool:
         cdef SubsetShapeParams params = SubsetShapeParams(
             unichars=self._unichars,
             unichar_count=self._unichar_count,
@@ -303,9 +687,15 @@ cdef class ShapeContext:
             bidi_level=bidi_level,
             script=script,
             language=NULL,
+            vertical=vertical,
+            glyph_classes=glyph_classes,
+            letter_spacing=letter_spacing,
+            word_spacing=word_spacing,
+            show_invisibles=show_invisibles,
+            small_caps=small_caps,
         )
 
         def callback_delegate(glyph):
-            callback(glyph["glyph_id"], glyph["x_offset"], glyph["y_offset"], glyph["x_advance"], glyph["y_advance"])
+            callback(glyph["glyph_id"], glyph["cluster"], glyph["x_offset"], glyph["y_offset"], glyph["x_advance"], glyph["y_advance"], glyph["x_origin"], glyph["y_origin"], glyph["glyph_class"], glyph["flags"])
 
         return subset_shape(font._font, &params, _shape_callback, <void*>callback_delegate)
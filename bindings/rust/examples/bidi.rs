@@ -1,7 +1,7 @@
 use std::path::Path;
 
 use anyhow::{anyhow, Result};
-use subset::{Font, FontStyle, GlyphRun, LayoutBuilder, PathCommand, ShapeParams};
+use subset::{Font, FontStyle, LayoutBuilder, Path as GlyphPath, PathCommand, ShapeParams};
 use tiny_skia::{Color, FillRule, Paint, PathBuilder, Pixmap, Transform};
 
 fn main() -> Result<()> {
@@ -12,7 +12,8 @@ fn main() -> Result<()> {
         "داستان SVG Tiny 1.2 طولا ني است.".chars(),
         FontStyle::default(),
     );
-    let runs = builder.build().shape(ShapeParams {
+    let layout = builder.build();
+    let runs = layout.shape(ShapeParams {
         emit_path_commands: true,
         ..Default::default()
     });
@@ -20,11 +21,15 @@ fn main() -> Result<()> {
     pixmap.fill(Color::WHITE);
     let mut paint = Paint::default();
     paint.set_color(Color::BLACK);
-    let transform = Transform::from_translate(100.0, 75.0).pre_scale(scale, -scale);
-    let mut cx = 0.0;
-    let mut cy = 0.0;
-    for run in runs {
-        draw(&mut pixmap, &paint, &transform, run, &mut cx, &mut cy);
+    for glyph in layout
+        .positioned_glyphs(&runs)
+        .with_origin(100.0, -75.0)
+        .with_scale(scale)
+    {
+        let transform =
+            Transform::from_translate(glyph.x, -glyph.y).pre_scale(glyph.scale, -glyph.scale);
+        let path = &runs[glyph.run_index].paths[glyph.glyph_index];
+        draw(&mut pixmap, &paint, &transform, path);
     }
     pixmap.save_png("bidi.png")?;
     Ok(())
@@ -83,23 +88,10 @@ fn add_path(
     }
 }
 
-fn draw(
-    pixmap: &mut Pixmap,
-    paint: &Paint,
-    transform: &Transform,
-    run: GlyphRun,
-    cx: &mut f32,
-    cy: &mut f32,
-) {
-    for (glyph, path) in run.glyphs.into_iter().zip(run.paths.into_iter()) {
-        let transform =
-            transform.pre_translate(*cx + glyph.x_offset as f32, *cy + glyph.y_offset as f32);
-        let mut builder = PathBuilder::new();
-        add_path(&mut builder, &transform, path.iter());
-        if let Some(path) = builder.finish() {
-            pixmap.fill_path(&path, paint, FillRule::Winding, Transform::identity(), None);
-        }
-        *cx += glyph.x_advance as f32;
-        *cy += glyph.y_advance as f32;
+fn draw(pixmap: &mut Pixmap, paint: &Paint, transform: &Transform, path: &GlyphPath) {
+    let mut builder = PathBuilder::new();
+    add_path(&mut builder, transform, path.iter());
+    if let Some(path) = builder.finish() {
+        pixmap.fill_path(&path, paint, FillRule::Winding, Transform::identity(), None);
     }
 }
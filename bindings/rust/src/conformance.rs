@@ -0,0 +1,127 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use crate::normalize::normalize;
+use crate::{boundaries, FontStyle, Normalization, Segmentation};
+
+// N.B. Runs the UCD conformance tests of the Unicode version the tables are
+// generated from. Like `gen_unicode_tables.py`, the files are read from the
+// directory in `UCD_DIR` if set, and downloaded from unicode.org otherwise.
+const UNICODE_VERSION: &str = "15.1.0";
+
+fn read_ucd(path: &str) -> String {
+    if let Ok(ucd_dir) = env::var("UCD_DIR") {
+        let path = Path::new(&ucd_dir).join(path);
+        return fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("could not read {}: {e}", path.display()));
+    }
+    let url = format!("https://www.unicode.org/Public/{UNICODE_VERSION}/ucd/{path}");
+    let output = Command::new("curl")
+        .args(["--silent", "--fail", "--location", &url])
+        .output()
+        .unwrap_or_else(|e| panic!("could not download {url}: {e}"));
+    assert!(output.status.success(), "could not download {url}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn parse_codepoints(value: &str) -> Vec<u32> {
+    value
+        .split_whitespace()
+        .map(|v| u32::from_str_radix(v, 16).unwrap())
+        .collect()
+}
+
+// N.B. Every test line is a sequence of codepoints with `÷` between those
+// separated by a boundary and `×` between the others.
+fn check_break_test(path: &str, segmentation: Segmentation) {
+    let mut failures = Vec::new();
+    for line in read_ucd(path).lines() {
+        let test = line.split('#').next().unwrap().trim();
+        if test.is_empty() {
+            continue;
+        }
+        let mut codepoints = Vec::new();
+        let mut expected = Vec::new();
+        for token in test.split_whitespace() {
+            match token {
+                "÷" => expected.push(codepoints.len()),
+                "×" => {}
+                _ => codepoints.push(u32::from_str_radix(token, 16).unwrap()),
+            }
+        }
+        let actual = boundaries(&codepoints, segmentation, None).collect::<Vec<_>>();
+        if actual != expected {
+            failures.push(format!("{test}: got {actual:?}"));
+        }
+    }
+    assert!(failures.is_empty(), "{path}:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn grapheme_break_test() {
+    check_break_test("auxiliary/GraphemeBreakTest.txt", Segmentation::Grapheme);
+}
+
+#[test]
+fn word_break_test() {
+    check_break_test("auxiliary/WordBreakTest.txt", Segmentation::Word);
+}
+
+#[test]
+fn sentence_break_test() {
+    check_break_test("auxiliary/SentenceBreakTest.txt", Segmentation::Sentence);
+}
+
+// N.B. Checks the NFC and NFD invariants of every line, and that the codepoints
+// not listed in part 1 are left as they are.
+#[test]
+fn normalization_test() {
+    let nfc = |v: &[u32]| normalize(v, Normalization::Nfc, &[], FontStyle::default()).0;
+    let nfd = |v: &[u32]| normalize(v, Normalization::Nfd, &[], FontStyle::default()).0;
+    let mut failures = Vec::new();
+    let mut part1 = Vec::new();
+    let mut in_part1 = false;
+    for line in read_ucd("NormalizationTest.txt").lines() {
+        let test = line.split('#').next().unwrap().trim();
+        if let Some(part) = test.strip_prefix('@') {
+            in_part1 = part == "Part1";
+            continue;
+        }
+        if test.is_empty() {
+            continue;
+        }
+        let columns = test.split(';').map(parse_codepoints).collect::<Vec<_>>();
+        let (c1, c2, c3, c4, c5) = (
+            &columns[0],
+            &columns[1],
+            &columns[2],
+            &columns[3],
+            &columns[4],
+        );
+        if in_part1 {
+            part1.push(c1[0]);
+        }
+        let ok = [c1, c2, c3].iter().all(|v| nfc(v) == *c2)
+            && [c4, c5].iter().all(|v| nfc(v) == *c4)
+            && [c1, c2, c3].iter().all(|v| nfd(v) == *c3)
+            && [c4, c5].iter().all(|v| nfd(v) == *c5);
+        if !ok {
+            failures.push(test.to_owned());
+        }
+    }
+    part1.sort_unstable();
+    for unichar in (0..=0x10FFFF).filter(|v| !(0xD800..=0xDFFF).contains(v)) {
+        if part1.binary_search(&unichar).is_err()
+            && (nfc(&[unichar]) != [unichar] || nfd(&[unichar]) != [unichar])
+        {
+            failures.push(format!("{unichar:04X} is not invariant"));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "NormalizationTest.txt:\n{}",
+        failures.join("\n")
+    );
+}
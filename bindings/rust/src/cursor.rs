@@ -0,0 +1,126 @@
+use std::ops::Range;
+
+use crate::{Affinity, Cells, Layout, Segmentation};
+
+impl Cells<'_> {
+    pub fn move_left(&self, index: usize, affinity: Affinity) -> (usize, Affinity) {
+        self.move_visually(index, affinity, false)
+    }
+
+    pub fn move_right(&self, index: usize, affinity: Affinity) -> (usize, Affinity) {
+        self.move_visually(index, affinity, true)
+    }
+
+    fn move_visually(&self, index: usize, affinity: Affinity, right: bool) -> (usize, Affinity) {
+        let cells = &self.cells;
+        let (index, affinity) = self.layout.normalized_position(index, affinity);
+        let len = self.layout.codepoints().len();
+        let (index, affinity) = if index >= len {
+            (len.saturating_sub(1), Affinity::Trailing)
+        } else {
+            (index, affinity)
+        };
+        let Some(current) = cells.iter().position(|v| v.range.contains(&index)) else {
+            return self.layout.original_position(index, affinity);
+        };
+        // N.B. Each cell has a left and right edge, where the leading edge is
+        // the right one in right-to-left runs.
+        let at_right_edge = (affinity == Affinity::Trailing) != cells[current].rtl;
+        let target = match (right, at_right_edge) {
+            (true, false) | (false, true) => current,
+            (true, true) if current + 1 < cells.len() => current + 1,
+            (false, false) if current > 0 => current - 1,
+            _ => current,
+        };
+        let cell = &cells[target];
+        let affinity = if right != cell.rtl {
+            Affinity::Trailing
+        } else {
+            Affinity::Leading
+        };
+        let (index, affinity) = cell.position(affinity);
+        self.layout.original_position(index, affinity)
+    }
+}
+
+impl Layout<'_> {
+    pub fn next_grapheme(&self, index: usize, affinity: Affinity) -> (usize, Affinity) {
+        let offset = self.normalized_offset(caret_offset(index, affinity));
+        let offset = self
+            .normalized_boundaries(Segmentation::Grapheme, None)
+            .find(|v| *v > offset)
+            .unwrap_or(self.codepoints().len());
+        self.position_at(offset, true)
+    }
+
+    pub fn previous_grapheme(&self, index: usize, affinity: Affinity) -> (usize, Affinity) {
+        let offset = self.normalized_offset(caret_offset(index, affinity));
+        let offset = self
+            .normalized_boundaries(Segmentation::Grapheme, None)
+            .rev()
+            .find(|v| *v < offset)
+            .unwrap_or(0);
+        self.position_at(offset, false)
+    }
+
+    // N.B. Word jumps skip segments of whitespace, so moving forward ends up at
+    // the end of the next word and moving backward at the start of the previous.
+    pub fn next_word(
+        &self,
+        index: usize,
+        affinity: Affinity,
+        language: Option<&str>,
+    ) -> (usize, Affinity) {
+        let offset = self.normalized_offset(caret_offset(index, affinity));
+        let boundaries = self
+            .normalized_boundaries(Segmentation::Word, language)
+            .collect::<Vec<_>>();
+        let offset = boundaries
+            .windows(2)
+            .find(|v| v[1] > offset && !self.is_whitespace(v[0].max(offset)..v[1]))
+            .map_or(self.codepoints().len(), |v| v[1]);
+        self.position_at(offset, true)
+    }
+
+    pub fn previous_word(
+        &self,
+        index: usize,
+        affinity: Affinity,
+        language: Option<&str>,
+    ) -> (usize, Affinity) {
+        let offset = self.normalized_offset(caret_offset(index, affinity));
+        let boundaries = self
+            .normalized_boundaries(Segmentation::Word, language)
+            .collect::<Vec<_>>();
+        let offset = boundaries
+            .windows(2)
+            .rev()
+            .find(|v| v[0] < offset && !self.is_whitespace(v[0]..v[1].min(offset)))
+            .map_or(0, |v| v[0]);
+        self.position_at(offset, false)
+    }
+
+    // N.B. Moving forward the caret sticks to the codepoint before it.
+    fn position_at(&self, offset: usize, forward: bool) -> (usize, Affinity) {
+        let len = self.codepoints().len();
+        let (index, affinity) = if (forward || offset >= len) && offset > 0 {
+            (offset.min(len) - 1, Affinity::Trailing)
+        } else {
+            (offset, Affinity::Leading)
+        };
+        self.original_position(index, affinity)
+    }
+
+    fn is_whitespace(&self, range: Range<usize>) -> bool {
+        self.codepoints()[range]
+            .iter()
+            .all(|v| char::from_u32(*v).is_some_and(char::is_whitespace))
+    }
+}
+
+fn caret_offset(index: usize, affinity: Affinity) -> usize {
+    match affinity {
+        Affinity::Leading => index,
+        Affinity::Trailing => index + 1,
+    }
+}
@@ -0,0 +1,291 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::layout::{glyph_scale, GlyphDrawer};
+use crate::metrics::inline_advance;
+use crate::{
+    Decoration, FontRun, Glyph, GlyphRun, Layout, Path, PathCommand, Rect, VerticalOrientation,
+    WritingMode,
+};
+
+const CURVE_STEPS: usize = 8;
+
+impl Layout<'_> {
+    // N.B. Like in browsers, the position and thickness come from the first font
+    // for the whole range, so fallback fonts don't step the line. In vertical
+    // layouts underlines are drawn along the right edge of the em box, as is
+    // usual in CJK typography, and strikeouts through the vertical line.
+    // `range` is in offsets of the text as pushed. Ink is skipped using the
+    // paths of the glyph runs, or outlines drawn without synthesis if they
+    // were shaped without them.
+    pub fn decoration_rects(
+        &self,
+        glyph_runs: &[GlyphRun],
+        range: Range<usize>,
+        decoration: Decoration,
+        skip_ink: bool,
+    ) -> Vec<Rect> {
+        let vertical = self.writing_mode() == WritingMode::Vertical;
+        let range = self.normalized_offset(range.start)..self.normalized_offset(range.end);
+        let font = &self.fonts()[0];
+        let metrics = font.metrics();
+        let (offset, size) = match decoration {
+            Decoration::Underline => (metrics.underline_offset, metrics.underline_size),
+            Decoration::Strikeout => (metrics.strikeout_offset, metrics.strikeout_size),
+        };
+        let thickness = size as f32;
+        // N.B. The position is the bottom edge of the stroke, or its left edge
+        // in vertical layouts. The offset is the top of the stroke.
+        let position = match (vertical, decoration) {
+            (false, _) => offset as f32 - thickness,
+            (true, Decoration::Underline) => font.upem() as f32 / 2.0,
+            (true, Decoration::Strikeout) => -thickness / 2.0,
+        };
+        let band = (position - thickness, position + thickness * 2.0);
+        let skip_ink = skip_ink && decoration == Decoration::Underline;
+        let mut glyph_drawer = None;
+        let mut strokes = Vec::new();
+        let mut x = 0.0;
+        for glyph_run in glyph_runs {
+            let font_run = &self.runs()[glyph_run.font_run_index];
+            let scale = self.font_scale(font_run.font_index);
+            let mut segment: Option<(f32, f32)> = None;
+            let mut ink = Vec::new();
+            for (index, glyph) in glyph_run.glyphs.iter().enumerate() {
+                let advance = inline_advance(glyph, font_run) as f32 * scale;
+                if range.contains(&(glyph.cluster as usize)) {
+                    if skip_ink && font_run.object_index.is_none() {
+                        let path = match glyph_run.paths.get(index) {
+                            Some(path) => Cow::Borrowed(path),
+                            None => Cow::Owned(
+                                glyph_drawer
+                                    .get_or_insert_with(GlyphDrawer::new)
+                                    .draw(&self.fonts()[font_run.font_index], glyph.glyph_id),
+                            ),
+                        };
+                        if let Some((start, end)) = self.glyph_ink(glyph, font_run, &path, x, band)
+                        {
+                            ink.push((start - thickness, end + thickness));
+                        }
+                    }
+                    segment = match segment {
+                        Some((start, _)) => Some((start, x + advance)),
+                        None => Some((x, x + advance)),
+                    };
+                } else if let Some((start, end)) = segment.take() {
+                    push_strokes(&mut strokes, start, end, position, thickness, &mut ink);
+                }
+                x += advance;
+            }
+            if let Some((start, end)) = segment {
+                push_strokes(&mut strokes, start, end, position, thickness, &mut ink);
+            }
+        }
+        strokes
+            .into_iter()
+            .map(|stroke| match vertical {
+                false => Rect {
+                    x: stroke.start,
+                    y: stroke.position,
+                    width: stroke.end - stroke.start,
+                    height: stroke.thickness,
+                },
+                true => Rect {
+                    x: stroke.position,
+                    y: -stroke.end,
+                    width: stroke.thickness,
+                    height: stroke.end - stroke.start,
+                },
+            })
+            .collect()
+    }
+
+    // N.B. Returns the inline extents of the glyph's ink inside the band,
+    // which lies across the line at the given position.
+    fn glyph_ink(
+        &self,
+        glyph: &Glyph,
+        font_run: &FontRun,
+        path: &Path,
+        x: f32,
+        band: (f32, f32),
+    ) -> Option<(f32, f32)> {
+        let scale = self.font_scale(font_run.font_index);
+        let path_scale = scale * glyph_scale(font_run);
+        let x_offset = glyph.x_offset as f32 * scale;
+        let y_offset = glyph.y_offset as f32 * scale;
+        match (self.writing_mode(), font_run.vertical_orientation) {
+            (WritingMode::Horizontal, _) => {
+                let (min, max) = ink_extents(
+                    path,
+                    (band.0 - y_offset) / path_scale,
+                    (band.1 - y_offset) / path_scale,
+                    false,
+                )?;
+                Some((
+                    x + x_offset + min * path_scale,
+                    x + x_offset + max * path_scale,
+                ))
+            }
+            (WritingMode::Vertical, Some(VerticalOrientation::Sideways)) => {
+                // N.B. Matches the rotation in `positioned_glyphs`.
+                let central = self.fonts()[font_run.font_index]
+                    .horizontal_extents()
+                    .map_or(0.0, |v| (v.ascender + v.descender) as f32 / 2.0);
+                let origin = y_offset - central * scale;
+                let (min, max) = ink_extents(
+                    path,
+                    (band.0 - origin) / path_scale,
+                    (band.1 - origin) / path_scale,
+                    false,
+                )?;
+                Some((
+                    x + x_offset + min * path_scale,
+                    x + x_offset + max * path_scale,
+                ))
+            }
+            (WritingMode::Vertical, _) => {
+                let (min, max) = ink_extents(
+                    path,
+                    (band.0 - x_offset) / path_scale,
+                    (band.1 - x_offset) / path_scale,
+                    true,
+                )?;
+                Some((
+                    x - y_offset - max * path_scale,
+                    x - y_offset - min * path_scale,
+                ))
+            }
+        }
+    }
+}
+
+struct Stroke {
+    start: f32,
+    end: f32,
+    position: f32,
+    thickness: f32,
+}
+
+fn push_strokes(
+    strokes: &mut Vec<Stroke>,
+    start: f32,
+    end: f32,
+    position: f32,
+    thickness: f32,
+    ink: &mut Vec<(f32, f32)>,
+) {
+    ink.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut x = start;
+    for (ink_start, ink_end) in ink.drain(..) {
+        if ink_start > x {
+            push_stroke(strokes, x, ink_start.min(end), position, thickness);
+        }
+        x = x.max(ink_end);
+        if x >= end {
+            break;
+        }
+    }
+    if x < end {
+        push_stroke(strokes, x, end, position, thickness);
+    }
+}
+
+fn push_stroke(strokes: &mut Vec<Stroke>, start: f32, end: f32, position: f32, thickness: f32) {
+    if let Some(last) = strokes.last_mut() {
+        // N.B. Merge the strokes of adjacent runs.
+        if (last.end - start).abs() < 0.5 {
+            last.end = end;
+            return;
+        }
+    }
+    strokes.push(Stroke {
+        start,
+        end,
+        position,
+        thickness,
+    });
+}
+
+// N.B. Returns the x extents of the path between `y_min` and `y_max`, or the
+// y extents between the x bounds when `transpose` is set.
+fn ink_extents(path: &Path, y_min: f32, y_max: f32, transpose: bool) -> Option<(f32, f32)> {
+    let mut extents: Option<(f32, f32)> = None;
+    let mut add_line = |a: (f32, f32), b: (f32, f32)| {
+        let ((x0, y0), (x1, y1)) = match transpose {
+            false => (a, b),
+            true => ((a.1, a.0), (b.1, b.0)),
+        };
+        let dy = y1 - y0;
+        let (t0, t1) = if dy == 0.0 {
+            if y0 < y_min || y0 > y_max {
+                return;
+            }
+            (0.0, 1.0)
+        } else {
+            let ta = (y_min - y0) / dy;
+            let tb = (y_max - y0) / dy;
+            (ta.min(tb).max(0.0), ta.max(tb).min(1.0))
+        };
+        if t0 > t1 {
+            return;
+        }
+        let xa = x0 + (x1 - x0) * t0;
+        let xb = x0 + (x1 - x0) * t1;
+        let (min, max) = extents.unwrap_or((f32::MAX, f32::MIN));
+        extents = Some((min.min(xa).min(xb), max.max(xa).max(xb)));
+    };
+    let mut start = (0.0, 0.0);
+    let mut current = (0.0, 0.0);
+    for command in path.iter() {
+        match command {
+            PathCommand::MoveTo(point) => {
+                start = point;
+                current = point;
+            }
+            PathCommand::LineTo(point) => {
+                add_line(current, point);
+                current = point;
+            }
+            PathCommand::QuadTo(point1, point2) => {
+                let mut last = current;
+                for step in 1..=CURVE_STEPS {
+                    let t = step as f32 / CURVE_STEPS as f32;
+                    let u = 1.0 - t;
+                    let point = (
+                        u * u * current.0 + 2.0 * u * t * point1.0 + t * t * point2.0,
+                        u * u * current.1 + 2.0 * u * t * point1.1 + t * t * point2.1,
+                    );
+                    add_line(last, point);
+                    last = point;
+                }
+                current = point2;
+            }
+            PathCommand::CubicTo(point1, point2, point3) => {
+                let mut last = current;
+                for step in 1..=CURVE_STEPS {
+                    let t = step as f32 / CURVE_STEPS as f32;
+                    let u = 1.0 - t;
+                    let point = (
+                        u * u * u * current.0
+                            + 3.0 * u * u * t * point1.0
+                            + 3.0 * u * t * t * point2.0
+                            + t * t * t * point3.0,
+                        u * u * u * current.1
+                            + 3.0 * u * u * t * point1.1
+                            + 3.0 * u * t * t * point2.1
+                            + t * t * t * point3.1,
+                    );
+                    add_line(last, point);
+                    last = point;
+                }
+                current = point3;
+            }
+            PathCommand::Close => {
+                add_line(current, start);
+                current = start;
+            }
+        }
+    }
+    extents
+}
@@ -12,7 +12,19 @@ pub struct SubsetTextRun {
     pub offset: usize,
     pub length: usize,
     pub bidi_level: u8,
+    pub paragraph_level: u8,
     pub script: u32,
+    pub emoji_presentation: c_uint,
+    pub upright: bool,
+    pub transformed: bool,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SubsetBidiSpan {
+    pub offset: usize,
+    pub length: usize,
+    pub control: c_uint,
 }
 
 #[repr(C)]
@@ -31,6 +43,12 @@ pub struct SubsetShapeParams {
     pub bidi_level: u8,
     pub script: u32,
     pub language: *const c_char,
+    pub vertical: bool,
+    pub glyph_classes: bool,
+    pub letter_spacing: i32,
+    pub word_spacing: i32,
+    pub show_invisibles: bool,
+    pub small_caps: bool,
 }
 
 pub type SubsetFontProvider =
@@ -39,6 +57,17 @@ pub type SubsetFontProvider =
 pub type SubsetTextRunCallback =
     Option<unsafe extern "C" fn(text_run: SubsetTextRun, context: *mut c_void)>;
 
+pub type SubsetBoundaryCallback = Option<unsafe extern "C" fn(offset: usize, context: *mut c_void)>;
+
+pub type SubsetCompositionFilter =
+    Option<unsafe extern "C" fn(starter: u32, composed: u32, context: *mut c_void) -> bool>;
+
+pub type SubsetNormalizedCallback =
+    Option<unsafe extern "C" fn(unichar: u32, offset: usize, context: *mut c_void)>;
+
+pub type SubsetTransformedCallback =
+    Option<unsafe extern "C" fn(unichar: u32, offset: usize, context: *mut c_void)>;
+
 pub type SubsetShapeCallback =
     Option<unsafe extern "C" fn(glyph: crate::Glyph, context: *mut c_void)>;
 
@@ -82,6 +111,27 @@ extern "C" {
 
     pub fn subset_font_has_glyph(font: *mut SubsetFont, unichar: u32) -> bool;
 
+    pub fn subset_font_has_variation_glyph(
+        font: *mut SubsetFont,
+        unichar: u32,
+        variation_selector: u32,
+    ) -> bool;
+
+    pub fn subset_font_get_nominal_glyph(
+        font: *mut SubsetFont,
+        unichar: u32,
+        glyph_id: *mut u32,
+    ) -> bool;
+
+    pub fn subset_font_get_variation_glyph(
+        font: *mut SubsetFont,
+        unichar: u32,
+        variation_selector: u32,
+        glyph_id: *mut u32,
+    ) -> bool;
+
+    pub fn subset_font_has_color_glyphs(font: *mut SubsetFont) -> bool;
+
     pub fn subset_font_is_italic(font: *mut SubsetFont) -> bool;
 
     pub fn subset_font_weight(font: *mut SubsetFont) -> f32;
@@ -98,14 +148,85 @@ extern "C" {
         line_gap: *mut i32,
     ) -> bool;
 
+    pub fn subset_font_glyph_extents(
+        font: *mut SubsetFont,
+        glyph_id: u32,
+        x_bearing: *mut i32,
+        y_bearing: *mut i32,
+        width: *mut i32,
+        height: *mut i32,
+    ) -> bool;
+
+    pub fn subset_font_glyph_advance(font: *mut SubsetFont, glyph_id: u32, vertical: bool) -> i32;
+
+    pub fn subset_font_metrics(font: *mut SubsetFont, metrics: *mut crate::FontMetrics) -> bool;
+
+    pub fn subset_font_get_baseline(
+        font: *mut SubsetFont,
+        baseline_tag: u32,
+        vertical: bool,
+        script: u32,
+        language: *const c_char,
+        coord: *mut i32,
+    ) -> bool;
+
+    pub fn subset_font_has_feature(font: *mut SubsetFont, feature_tag: u32) -> bool;
+
+    pub fn subset_font_glyph_class(font: *mut SubsetFont, glyph_id: u32) -> crate::GlyphClass;
+
+    pub fn subset_font_ligature_carets(
+        font: *mut SubsetFont,
+        direction: crate::Direction,
+        glyph_id: u32,
+        start_offset: c_uint,
+        caret_count: *mut c_uint,
+        carets: *mut i32,
+    ) -> c_uint;
+
     pub fn subset_text_runs(
         unichars: *const u32,
         unichar_count: usize,
+        bidi_spans: *const SubsetBidiSpan,
+        bidi_span_count: usize,
+        vertical: bool,
         paragraph_base_level: *mut u8,
         callback: SubsetTextRunCallback,
         context: *mut c_void,
     ) -> c_int;
 
+    pub fn subset_transform_text(
+        unichars: *const u32,
+        unichar_count: usize,
+        transform: c_uint,
+        language: *const c_char,
+        callback: SubsetTransformedCallback,
+        context: *mut c_void,
+    ) -> bool;
+
+    pub fn subset_normalize(
+        unichars: *const u32,
+        unichar_count: usize,
+        normalization: c_uint,
+        filter: SubsetCompositionFilter,
+        callback: SubsetNormalizedCallback,
+        context: *mut c_void,
+    ) -> bool;
+
+    pub fn subset_unicode_properties(unichar: u32, properties: *mut crate::UnicodeProperties);
+
+    pub fn subset_is_variation_selector(unichar: u32) -> bool;
+
+    pub fn subset_is_word_separator(unichar: u32) -> bool;
+
+    pub fn subset_segment(
+        unichars: *const u32,
+        unichar_count: usize,
+        segmentation: c_uint,
+        language: *const c_char,
+        callback: SubsetBoundaryCallback,
+        context: *mut c_void,
+    ) -> bool;
+
     pub fn subset_find_best_font_match(
         unichar: u32,
         font_style: crate::FontStyle,
@@ -115,6 +236,17 @@ extern "C" {
         best_index: *mut usize,
     ) -> bool;
 
+    pub fn subset_find_best_font_match_for_sequence(
+        unichars: *const u32,
+        unichar_count: usize,
+        emoji_presentation: c_uint,
+        font_style: crate::FontStyle,
+        font_count: usize,
+        font_provider: SubsetFontProvider,
+        font_provider_context: *mut c_void,
+        best_index: *mut usize,
+    ) -> bool;
+
     pub fn subset_glyph_drawer_create() -> *mut SubsetGlyphDrawer;
 
     pub fn subset_glyph_drawer_destroy(drawer: *mut SubsetGlyphDrawer);
@@ -3,7 +3,10 @@ use std::fmt::{self, Debug};
 use std::path::Path;
 use std::ptr;
 
-use crate::{ffi, FontExtents, FontStyle, UnicodeValue};
+use crate::{
+    ffi, Baseline, Direction, EmojiPresentation, FontExtents, FontMetrics, FontStyle, GlyphClass,
+    GlyphExtents, UnicodeValue, WritingMode,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub enum Syntesize {
@@ -62,6 +65,62 @@ impl Font {
         unsafe { ffi::subset_font_has_glyph(self.0, value) }
     }
 
+    // N.B. Looks the feature up in both GSUB and GPOS, e.g. `font.has_feature(b"smcp")`.
+    pub fn has_feature(&self, tag: &[u8; 4]) -> bool {
+        unsafe { ffi::subset_font_has_feature(self.0, u32::from_be_bytes(*tag)) }
+    }
+
+    pub fn has_variation_glyph(
+        &self,
+        value: impl Into<UnicodeValue>,
+        variation_selector: impl Into<UnicodeValue>,
+    ) -> bool {
+        unsafe {
+            ffi::subset_font_has_variation_glyph(
+                self.0,
+                value.into().into(),
+                variation_selector.into().into(),
+            )
+        }
+    }
+
+    pub fn nominal_glyph_id(&self, value: impl Into<UnicodeValue>) -> Option<u32> {
+        let mut glyph_id = 0u32;
+        let found = unsafe {
+            ffi::subset_font_get_nominal_glyph(self.0, value.into().into(), &mut glyph_id as *mut _)
+        };
+        if found {
+            Some(glyph_id)
+        } else {
+            None
+        }
+    }
+
+    pub fn variation_glyph_id(
+        &self,
+        value: impl Into<UnicodeValue>,
+        variation_selector: impl Into<UnicodeValue>,
+    ) -> Option<u32> {
+        let mut glyph_id = 0u32;
+        let found = unsafe {
+            ffi::subset_font_get_variation_glyph(
+                self.0,
+                value.into().into(),
+                variation_selector.into().into(),
+                &mut glyph_id as *mut _,
+            )
+        };
+        if found {
+            Some(glyph_id)
+        } else {
+            None
+        }
+    }
+
+    pub fn has_color_glyphs(&self) -> bool {
+        unsafe { ffi::subset_font_has_color_glyphs(self.0) }
+    }
+
     pub fn style(&self) -> FontStyle {
         unsafe {
             FontStyle {
@@ -76,6 +135,10 @@ impl Font {
         unsafe { ffi::subset_font_upem(self.0) }
     }
 
+    pub fn scale(&self, font_size: f32) -> f32 {
+        font_size / self.upem() as f32
+    }
+
     pub fn horizontal_extents(&self) -> Option<FontExtents> {
         extents(self.0, true)
     }
@@ -83,6 +146,103 @@ impl Font {
     pub fn vertical_extents(&self) -> Option<FontExtents> {
         extents(self.0, false)
     }
+
+    pub fn glyph_extents(&self, glyph_id: u32) -> Option<GlyphExtents> {
+        let mut x_bearing = 0i32;
+        let mut y_bearing = 0i32;
+        let mut width = 0i32;
+        let mut height = 0i32;
+        let found = unsafe {
+            ffi::subset_font_glyph_extents(
+                self.0,
+                glyph_id,
+                &mut x_bearing as *mut _,
+                &mut y_bearing as *mut _,
+                &mut width as *mut _,
+                &mut height as *mut _,
+            )
+        };
+        if found {
+            Some(GlyphExtents {
+                x_bearing,
+                y_bearing,
+                width,
+                height,
+            })
+        } else {
+            None
+        }
+    }
+
+    // N.B. Vertical advances are negative, i.e. downwards.
+    pub fn glyph_advance(&self, glyph_id: u32, writing_mode: WritingMode) -> i32 {
+        unsafe {
+            ffi::subset_font_glyph_advance(self.0, glyph_id, writing_mode == WritingMode::Vertical)
+        }
+    }
+
+    pub fn metrics(&self) -> FontMetrics {
+        let mut metrics = FontMetrics::default();
+        unsafe {
+            ffi::subset_font_metrics(self.0, &mut metrics as *mut _);
+        }
+        metrics
+    }
+
+    pub fn glyph_class(&self, glyph_id: u32) -> GlyphClass {
+        unsafe { ffi::subset_font_glyph_class(self.0, glyph_id) }
+    }
+
+    pub fn ligature_carets(&self, glyph_id: u32, direction: Direction) -> Vec<i32> {
+        let mut caret_count: c_uint = 0;
+        let total = unsafe {
+            ffi::subset_font_ligature_carets(
+                self.0,
+                direction,
+                glyph_id,
+                0,
+                &mut caret_count as *mut _,
+                ptr::null_mut(),
+            )
+        };
+        let mut carets = vec![0i32; total as usize];
+        caret_count = total;
+        unsafe {
+            ffi::subset_font_ligature_carets(
+                self.0,
+                direction,
+                glyph_id,
+                0,
+                &mut caret_count as *mut _,
+                carets.as_mut_ptr(),
+            );
+        }
+        carets.truncate(caret_count as usize);
+        carets
+    }
+
+    pub fn baseline(
+        &self,
+        baseline: Baseline,
+        writing_mode: WritingMode,
+        script: u32,
+        language: Option<&str>,
+    ) -> Option<i32> {
+        match get_baseline(self.0, baseline, writing_mode, script, language) {
+            (true, coord) => Some(coord),
+            (false, _) => None,
+        }
+    }
+
+    pub fn baseline_with_fallback(
+        &self,
+        baseline: Baseline,
+        writing_mode: WritingMode,
+        script: u32,
+        language: Option<&str>,
+    ) -> i32 {
+        get_baseline(self.0, baseline, writing_mode, script, language).1
+    }
 }
 
 impl Clone for Font {
@@ -109,16 +269,18 @@ impl Drop for Font {
 
 unsafe impl Send for Font {}
 
+struct Fonts<'a>(&'a [Font]);
+
+unsafe extern "C" fn font_provider(index: usize, context: *mut c_void) -> *mut ffi::SubsetFont {
+    let fonts: &Fonts<'_> = unsafe { &*(context as *const Fonts<'_>) };
+    (fonts.0)[index].0
+}
+
 pub fn find_best_font_match(
     fonts: &[Font],
     unicode_value: impl Into<UnicodeValue>,
     font_style: FontStyle,
 ) -> Option<usize> {
-    struct Fonts<'a>(&'a [Font]);
-    unsafe extern "C" fn font_provider(index: usize, context: *mut c_void) -> *mut ffi::SubsetFont {
-        let fonts: &Fonts<'_> = unsafe { &*(context as *const Fonts<'_>) };
-        (fonts.0)[index].0
-    }
     let len = fonts.len();
     let fonts = Fonts(fonts);
     let mut best_index = 0usize;
@@ -139,6 +301,56 @@ pub fn find_best_font_match(
     }
 }
 
+pub fn find_best_font_match_for_sequence(
+    fonts: &[Font],
+    codepoints: &[u32],
+    emoji_presentation: Option<EmojiPresentation>,
+    font_style: FontStyle,
+) -> Option<usize> {
+    let len = fonts.len();
+    let fonts = Fonts(fonts);
+    let mut best_index = 0usize;
+    let found = unsafe {
+        ffi::subset_find_best_font_match_for_sequence(
+            codepoints.as_ptr(),
+            codepoints.len(),
+            EmojiPresentation::to_raw(emoji_presentation),
+            font_style,
+            len,
+            Some(font_provider),
+            &fonts as *const _ as *mut _,
+            &mut best_index as *mut _,
+        )
+    };
+    if found {
+        Some(best_index)
+    } else {
+        None
+    }
+}
+
+fn get_baseline(
+    font: *mut ffi::SubsetFont,
+    baseline: Baseline,
+    writing_mode: WritingMode,
+    script: u32,
+    language: Option<&str>,
+) -> (bool, i32) {
+    let language = language.map(CString::new).transpose().ok().flatten();
+    let mut coord = 0i32;
+    let found = unsafe {
+        ffi::subset_font_get_baseline(
+            font,
+            baseline.to_raw(),
+            writing_mode == WritingMode::Vertical,
+            script,
+            language.as_ref().map_or(ptr::null(), |v| v.as_ptr()),
+            &mut coord as *mut _,
+        )
+    };
+    (found, coord)
+}
+
 fn extents(font: *mut ffi::SubsetFont, horizontal: bool) -> Option<FontExtents> {
     let mut ascender = 0i32;
     let mut descender = 0i32;
@@ -0,0 +1,193 @@
+use std::ops::Range;
+
+use crate::layout::glyph_scale;
+use crate::metrics::Cluster;
+use crate::{Affinity, Direction, GlyphRun, Layout, LineHeight, Rect, Segmentation, WritingMode};
+
+// N.B. A cell is a grapheme, or the part of one, within a cluster. Clusters
+// with several graphemes, i.e. ligatures, are split evenly between them.
+#[derive(Debug, Clone)]
+pub(crate) struct Cell {
+    pub(crate) range: Range<usize>,
+    pub(crate) x: f32,
+    pub(crate) advance: f32,
+    pub(crate) rtl: bool,
+}
+
+impl Cell {
+    fn edge(&self, affinity: Affinity) -> f32 {
+        match (affinity, self.rtl) {
+            (Affinity::Leading, false) | (Affinity::Trailing, true) => self.x,
+            (Affinity::Trailing, false) | (Affinity::Leading, true) => self.x + self.advance,
+        }
+    }
+
+    pub(crate) fn position(&self, affinity: Affinity) -> (usize, Affinity) {
+        match affinity {
+            Affinity::Leading => (self.range.start, Affinity::Leading),
+            Affinity::Trailing => (self.range.end - 1, Affinity::Trailing),
+        }
+    }
+}
+
+// N.B. The cells of a shaped layout, computed once by `Layout::cells` and
+// kept by the caller for hit testing, caret positioning and cursor movement.
+// Offsets are those of the text as pushed, see `Layout::original_offset`.
+#[derive(Debug, Clone)]
+pub struct Cells<'a> {
+    pub(crate) layout: &'a Layout<'a>,
+    pub(crate) cells: Vec<Cell>,
+}
+
+impl Cells<'_> {
+    // N.B. `x` is along the inline axis, i.e. downwards in vertical layouts.
+    pub fn hit_test(&self, x: f32) -> (usize, Affinity) {
+        let cells = &self.cells;
+        let Some(cell) = cells.iter().find(|v| x < v.x + v.advance).or(cells.last()) else {
+            return (0, Affinity::Leading);
+        };
+        let right_half = x >= cell.x + cell.advance / 2.0;
+        let (index, affinity) = if right_half != cell.rtl {
+            cell.position(Affinity::Trailing)
+        } else {
+            cell.position(Affinity::Leading)
+        };
+        self.layout.original_position(index, affinity)
+    }
+
+    pub fn caret_x(&self, index: usize, affinity: Affinity) -> Option<f32> {
+        let (index, affinity) = self.layout.normalized_position(index, affinity);
+        caret_x(&self.cells, index, affinity)
+    }
+
+    // N.B. At direction boundaries the caret is split, and the secondary caret
+    // is at the trailing edge of the previous codepoint.
+    pub fn carets(&self, offset: usize) -> Option<(f32, Option<f32>)> {
+        let cells = &self.cells;
+        let offset = self.layout.normalized_offset(offset);
+        if offset == 0 || cells.is_empty() {
+            return Some((
+                caret_x(cells, offset, Affinity::Leading).unwrap_or(0.0),
+                None,
+            ));
+        }
+        let secondary = caret_x(cells, offset - 1, Affinity::Trailing)?;
+        match caret_x(cells, offset, Affinity::Leading) {
+            Some(primary) if (primary - secondary).abs() > f32::EPSILON => {
+                Some((primary, Some(secondary)))
+            }
+            Some(primary) => Some((primary, None)),
+            None => Some((secondary, None)),
+        }
+    }
+
+    pub fn selection_rects(&self, range: Range<usize>, line_height: LineHeight) -> Vec<Rect> {
+        let metrics = self.layout.line_metrics(line_height);
+        let range =
+            self.layout.normalized_offset(range.start)..self.layout.normalized_offset(range.end);
+        let mut spans: Vec<(f32, f32)> = Vec::new();
+        for cell in &self.cells {
+            if cell.range.start >= range.end || cell.range.end <= range.start {
+                continue;
+            }
+            let (x0, x1) = (cell.x, cell.x + cell.advance);
+            match spans.last_mut() {
+                Some(last) if (last.1 - x0).abs() < 0.5 => last.1 = x1,
+                _ => spans.push((x0, x1)),
+            }
+        }
+        spans
+            .into_iter()
+            .map(|(x0, x1)| match self.layout.writing_mode() {
+                WritingMode::Horizontal => Rect {
+                    x: x0,
+                    y: metrics.baseline - metrics.height,
+                    width: x1 - x0,
+                    height: metrics.height,
+                },
+                WritingMode::Vertical => Rect {
+                    x: -metrics.height / 2.0,
+                    y: -x1,
+                    width: metrics.height,
+                    height: x1 - x0,
+                },
+            })
+            .collect()
+    }
+}
+
+impl<'a> Layout<'a> {
+    pub fn cells(&'a self, glyph_runs: &[GlyphRun]) -> Cells<'a> {
+        let boundaries = self
+            .normalized_boundaries(Segmentation::Grapheme, None)
+            .collect::<Vec<_>>();
+        let mut cells = Vec::new();
+        for cluster in self.clusters(glyph_runs) {
+            let mut starts = vec![cluster.range.start];
+            starts.extend(
+                boundaries
+                    .iter()
+                    .copied()
+                    .filter(|v| *v > cluster.range.start && *v < cluster.range.end),
+            );
+            let edges = self.ligature_edges(glyph_runs, &cluster, starts.len());
+            let ends = starts
+                .iter()
+                .skip(1)
+                .copied()
+                .chain(Some(cluster.range.end));
+            let mut ranges = starts.iter().copied().zip(ends).collect::<Vec<_>>();
+            if cluster.rtl {
+                ranges.reverse();
+            }
+            for (index, (start, end)) in ranges.into_iter().enumerate() {
+                cells.push(Cell {
+                    range: start..end,
+                    x: edges[index],
+                    advance: edges[index + 1] - edges[index],
+                    rtl: cluster.rtl,
+                });
+            }
+        }
+        Cells {
+            layout: self,
+            cells,
+        }
+    }
+
+    // N.B. Uses the GDEF ligature carets of single glyph clusters when the font
+    // has one for each grapheme boundary, and splits the advance evenly
+    // otherwise.
+    fn ligature_edges(&self, glyph_runs: &[GlyphRun], cluster: &Cluster, count: usize) -> Vec<f32> {
+        let mut edges = Vec::with_capacity(count + 1);
+        edges.push(cluster.x);
+        if count > 1 && cluster.glyphs.len() == 1 {
+            let glyph_run = &glyph_runs[cluster.run_index];
+            let font_run = &self.runs()[glyph_run.font_run_index];
+            let glyph = &glyph_run.glyphs[cluster.glyphs.start];
+            let direction = if cluster.rtl {
+                Direction::RightToLeft
+            } else {
+                Direction::LeftToRight
+            };
+            let mut carets =
+                self.fonts()[font_run.font_index].ligature_carets(glyph.glyph_id, direction);
+            if carets.len() >= count - 1 && font_run.vertical_orientation.is_none() {
+                carets.truncate(count - 1);
+                carets.sort_unstable();
+                let scale = self.font_scale(font_run.font_index) * glyph_scale(font_run);
+                edges.extend(carets.into_iter().map(|v| cluster.x + v as f32 * scale));
+                edges.push(cluster.x + cluster.advance);
+                return edges;
+            }
+        }
+        let advance = cluster.advance / count as f32;
+        edges.extend((1..=count).map(|v| cluster.x + advance * v as f32));
+        edges
+    }
+}
+
+fn caret_x(cells: &[Cell], index: usize, affinity: Affinity) -> Option<f32> {
+    let cell = cells.iter().find(|v| v.range.contains(&index))?;
+    Some(cell.edge(affinity))
+}
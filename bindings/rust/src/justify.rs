@@ -0,0 +1,215 @@
+use crate::layout::{glyph_scale, GlyphDrawer};
+use crate::{ffi, FontRun, GlyphRun, Layout, ShapeParams, VerticalOrientation, WritingMode};
+
+const TATWEEL: u32 = 0x0640;
+
+// N.B. Longer kashidas than this look broken, so any width left after that goes
+// to the other justification opportunities.
+const MAX_TATWEELS_PER_JOIN: usize = 3;
+
+#[derive(Debug, Clone, Copy)]
+struct Join {
+    run_index: usize,
+    offset: usize,
+    advance: f32,
+    count: usize,
+}
+
+impl Layout<'_> {
+    // N.B. Kashidas are inserted first, at joins where the shaper deems it safe,
+    // and the width left is spread evenly over word separators and between CJK
+    // characters. `params` must be the ones the glyph runs were shaped with.
+    pub fn justify(
+        &self,
+        glyph_runs: &[GlyphRun],
+        width: f32,
+        params: ShapeParams,
+    ) -> Vec<GlyphRun> {
+        let mut glyph_runs = glyph_runs.to_vec();
+        let extra = width - self.advance(&glyph_runs);
+        if extra <= 0.0 {
+            return glyph_runs;
+        }
+        self.insert_kashidas(&mut glyph_runs, extra, &params);
+        let extra = width - self.advance(&glyph_runs);
+        if extra > 0.0 {
+            self.expand(&mut glyph_runs, extra);
+        }
+        glyph_runs
+    }
+
+    fn insert_kashidas(&self, glyph_runs: &mut [GlyphRun], extra: f32, params: &ShapeParams) {
+        let mut joins = Vec::new();
+        for (run_index, glyph_run) in glyph_runs.iter().enumerate() {
+            let font_run = &self.runs()[glyph_run.font_run_index];
+            if font_run.vertical_orientation == Some(VerticalOrientation::Upright) {
+                continue;
+            }
+            let font = self.run_font(font_run, params);
+            let Some(glyph_id) = font.nominal_glyph_id(TATWEEL) else {
+                continue;
+            };
+            let advance = font.glyph_advance(glyph_id, WritingMode::Horizontal) as f32
+                * self.font_scale(font_run.font_index)
+                * glyph_scale(font_run);
+            if advance <= 0.0 {
+                continue;
+            }
+            for glyph in glyph_run.glyphs.iter() {
+                let offset = glyph.cluster as usize;
+                if glyph.safe_to_insert_tatweel() && offset > font_run.offset {
+                    joins.push(Join {
+                        run_index,
+                        offset,
+                        advance,
+                        count: 0,
+                    });
+                }
+            }
+        }
+        joins.sort_by_key(|v| v.offset);
+        joins.dedup_by_key(|v| v.offset);
+        let mut remaining = extra;
+        for _ in 0..MAX_TATWEELS_PER_JOIN {
+            let mut inserted = false;
+            for join in joins.iter_mut() {
+                if join.advance <= remaining {
+                    join.count += 1;
+                    remaining -= join.advance;
+                    inserted = true;
+                }
+            }
+            if !inserted {
+                break;
+            }
+        }
+        joins.retain(|v| v.count > 0);
+        if joins.is_empty() {
+            return;
+        }
+        let glyph_drawer = if params.emit_path_commands {
+            Some(GlyphDrawer::new())
+        } else {
+            None
+        };
+        for (run_index, glyph_run) in glyph_runs.iter_mut().enumerate() {
+            let run_joins = joins
+                .iter()
+                .filter(|v| v.run_index == run_index)
+                .copied()
+                .collect::<Vec<_>>();
+            if !run_joins.is_empty() {
+                *glyph_run = self.reshape_with_tatweels(
+                    glyph_run,
+                    &run_joins,
+                    params,
+                    glyph_drawer.as_ref(),
+                );
+            }
+        }
+    }
+
+    // N.B. Reshaping lets the font pick the right forms around the tatweels,
+    // which are then mapped to the cluster before the join.
+    fn reshape_with_tatweels(
+        &self,
+        glyph_run: &GlyphRun,
+        joins: &[Join],
+        params: &ShapeParams,
+        glyph_drawer: Option<&GlyphDrawer>,
+    ) -> GlyphRun {
+        let font_run = self.runs()[glyph_run.font_run_index];
+        let end = font_run.offset + font_run.len;
+        let mut starts = glyph_run
+            .glyphs
+            .iter()
+            .map(|v| v.cluster as usize)
+            .collect::<Vec<_>>();
+        starts.sort_unstable();
+        starts.dedup();
+        let mut codepoints = self.codepoints()[..font_run.offset].to_vec();
+        let mut offsets = (0..font_run.offset).collect::<Vec<_>>();
+        for offset in font_run.offset..end {
+            if let Some(join) = joins.iter().find(|v| v.offset == offset) {
+                let previous = starts
+                    .partition_point(|v| *v < offset)
+                    .checked_sub(1)
+                    .map_or(font_run.offset, |v| starts[v]);
+                for _ in 0..join.count {
+                    codepoints.push(TATWEEL);
+                    offsets.push(previous);
+                }
+            }
+            codepoints.push(self.codepoints()[offset]);
+            offsets.push(offset);
+        }
+        let inserted = codepoints.len() - end;
+        codepoints.extend_from_slice(&self.codepoints()[end..]);
+        let font_run = FontRun {
+            len: font_run.len + inserted,
+            ..font_run
+        };
+        let mut glyph_run = self.shape_run(
+            &codepoints,
+            &font_run,
+            glyph_run.font_run_index,
+            params,
+            glyph_drawer,
+        );
+        for glyph in glyph_run.glyphs.iter_mut() {
+            glyph.cluster = offsets[glyph.cluster as usize] as u32;
+        }
+        glyph_run
+    }
+
+    fn expand(&self, glyph_runs: &mut [GlyphRun], extra: f32) {
+        let codepoints = self.codepoints();
+        let clusters = self.clusters(glyph_runs);
+        // N.B. Trailing word separators and the last character never expand.
+        let content_end = clusters
+            .iter()
+            .filter(|v| !is_word_separator(codepoints[v.range.start]))
+            .map(|v| v.range.end)
+            .max()
+            .unwrap_or(0);
+        let opportunities = clusters
+            .into_iter()
+            .filter(|cluster| {
+                let codepoint = codepoints[cluster.range.start];
+                if is_word_separator(codepoint) {
+                    cluster.range.start < content_end
+                } else {
+                    let font_run = &self.runs()[glyph_runs[cluster.run_index].font_run_index];
+                    is_cjk(font_run) && cluster.range.end < content_end
+                }
+            })
+            .collect::<Vec<_>>();
+        let count = opportunities.len() as f32;
+        let mut assigned = 0.0;
+        for (index, cluster) in opportunities.into_iter().enumerate() {
+            let glyph_run = &mut glyph_runs[cluster.run_index];
+            let font_run = &self.runs()[glyph_run.font_run_index];
+            let scale = self.font_scale(font_run.font_index);
+            let target = extra * (index + 1) as f32 / count;
+            let delta = ((target - assigned) / scale).round() as i32;
+            assigned += delta as f32 * scale;
+            let glyph = &mut glyph_run.glyphs[cluster.glyphs.end - 1];
+            if font_run.vertical_orientation == Some(VerticalOrientation::Upright) {
+                glyph.y_advance -= delta;
+            } else {
+                glyph.x_advance += delta;
+            }
+        }
+    }
+}
+
+fn is_word_separator(codepoint: u32) -> bool {
+    unsafe { ffi::subset_is_word_separator(codepoint) }
+}
+
+// N.B. CJK text expands between any two characters.
+fn is_cjk(font_run: &FontRun) -> bool {
+    [b"Hani", b"Hira", b"Kana", b"Hang", b"Bopo"]
+        .into_iter()
+        .any(|v| font_run.script == u32::from_be_bytes(*v))
+}
@@ -4,24 +4,41 @@ use std::ffi::{c_uint, c_void, CString};
 use std::ops::Range;
 use std::{iter, ptr};
 
+use crate::normalize::normalize;
+use crate::transform::{synthesize_small_caps, transform_text, SMALL_CAPS_SCALE};
 use crate::{
-    ffi, find_best_font_match, Font, FontRun, FontStyle, Glyph, GlyphRun, Path, PathVerb, Syntesize,
+    ffi, find_best_font_match, find_best_font_match_for_sequence, Affinity, Baseline, BidiControl,
+    EmojiPresentation, Font, FontRun, FontStyle, Glyph, GlyphClass, GlyphRun, InlineObject,
+    Normalization, Path, PathVerb, Syntesize, TabStops, TextStyle, VerticalOrientation,
+    WritingMode,
 };
 
+const OBJECT_REPLACEMENT_CHARACTER: u32 = 0xFFFC;
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct ShapeParams<'a> {
     pub embolden_strength: Option<f32>,
     pub slant: Option<f32>,
     pub emit_path_commands: bool,
+    pub emit_glyph_classes: bool,
     pub language: Option<&'a str>,
+    pub letter_spacing: f32,
+    pub word_spacing: f32,
+    pub dominant_baseline: Option<Baseline>,
+    pub tab_stops: Option<TabStops<'a>>,
+    pub show_invisibles: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct Layout<'a> {
     fonts: &'a [Font],
     codepoints: Vec<u32>,
+    original_codepoints: Vec<u32>,
+    original_offsets: Vec<usize>,
+    objects: Vec<(usize, InlineObject)>,
     runs: Vec<FontRun>,
     paragraph_base_level: u8,
+    writing_mode: WritingMode,
 }
 
 impl<'a> Layout<'a> {
@@ -29,14 +46,141 @@ impl<'a> Layout<'a> {
         self.fonts
     }
 
+    pub fn codepoints(&self) -> &[u32] {
+        &self.codepoints
+    }
+
     pub fn runs(&self) -> &[FontRun] {
         &self.runs
     }
 
+    // N.B. Objects are indexed by `FontRun::object_index` and paired with the
+    // offset of their object replacement character.
+    pub fn objects(&self) -> &[(usize, InlineObject)] {
+        &self.objects
+    }
+
+    // N.B. The text as pushed, with an object replacement character for every
+    // object.
+    pub fn original_codepoints(&self) -> &[u32] {
+        &self.original_codepoints
+    }
+
+    // N.B. Maps an offset in the normalized codepoints, i.e. `codepoints` after
+    // text transforms, small caps and normalization, to the one of the text as
+    // pushed. Glyph clusters and the offsets of runs and positioned objects are
+    // in normalized codepoints, while all other offsets of the layout API, e.g.
+    // of carets, hit tests, segmentation boundaries and truncation, are in the
+    // text as pushed. Without transforms or normalization, both are the same.
+    pub fn original_offset(&self, offset: usize) -> usize {
+        self.original_offsets
+            .get(offset)
+            .copied()
+            .unwrap_or(self.original_codepoints.len())
+    }
+
+    // N.B. Offsets no codepoint comes from, e.g. of marks composed with their
+    // base, map to the next codepoint.
+    pub fn normalized_offset(&self, original_offset: usize) -> usize {
+        self.original_offsets
+            .partition_point(|v| *v < original_offset)
+    }
+
+    // N.B. A trailing position is after the last codepoint of the text as
+    // pushed that its normalized codepoint comes from.
+    pub(crate) fn original_position(&self, index: usize, affinity: Affinity) -> (usize, Affinity) {
+        let offset = self.original_offset(index);
+        match affinity {
+            Affinity::Leading => (offset, affinity),
+            Affinity::Trailing => (
+                self.original_offset(index + 1)
+                    .saturating_sub(1)
+                    .max(offset),
+                affinity,
+            ),
+        }
+    }
+
+    pub(crate) fn normalized_position(
+        &self,
+        index: usize,
+        affinity: Affinity,
+    ) -> (usize, Affinity) {
+        match affinity {
+            Affinity::Leading => (self.normalized_offset(index), affinity),
+            Affinity::Trailing => (
+                self.normalized_offset(index + 1).saturating_sub(1),
+                affinity,
+            ),
+        }
+    }
+
+    // N.B. The base level of the first paragraph. Every paragraph is resolved on
+    // its own, see `FontRun::paragraph_level`.
     pub fn paragraph_base_level(&self) -> u8 {
         self.paragraph_base_level
     }
 
+    pub fn writing_mode(&self) -> WritingMode {
+        self.writing_mode
+    }
+
+    // N.B. Layout space is in design units of the first font, with the origin at
+    // the start of the baseline and y pointing up, so glyph values from fonts with
+    // another upem must be scaled by this.
+    pub(crate) fn font_scale(&self, font_index: usize) -> f32 {
+        self.fonts[0].upem() as f32 / self.fonts[font_index].upem() as f32
+    }
+
+    pub fn scale(&self, font_size: f32) -> f32 {
+        self.fonts[0].scale(font_size)
+    }
+
+    // N.B. Derived layouts, e.g. truncated ones, get their runs from this one
+    // rather than from the bidi algorithm. The `elided` codepoints are replaced
+    // by `inserted` ones, so objects within them are dropped and the ones after
+    // them shifted.
+    pub(crate) fn derive(
+        &self,
+        codepoints: Vec<u32>,
+        mut runs: Vec<FontRun>,
+        elided: Range<usize>,
+        inserted: usize,
+    ) -> Layout<'a> {
+        let mut objects = Vec::with_capacity(self.objects.len());
+        let mut object_indices = Vec::with_capacity(self.objects.len());
+        for &(offset, object) in &self.objects {
+            if elided.contains(&offset) {
+                object_indices.push(None);
+                continue;
+            }
+            let offset = if offset >= elided.end {
+                offset - elided.len() + inserted
+            } else {
+                offset
+            };
+            object_indices.push(Some(objects.len()));
+            objects.push((offset, object));
+        }
+        for run in runs.iter_mut() {
+            run.object_index = run.object_index.and_then(|v| object_indices[v]);
+        }
+        // N.B. Inserted codepoints map to where the elided ones started.
+        let mut original_offsets = self.original_offsets[..elided.start].to_vec();
+        original_offsets.extend(iter::repeat(self.original_offset(elided.start)).take(inserted));
+        original_offsets.extend_from_slice(&self.original_offsets[elided.end..]);
+        Layout {
+            fonts: self.fonts,
+            original_codepoints: self.original_codepoints.clone(),
+            original_offsets,
+            codepoints,
+            objects,
+            runs,
+            paragraph_base_level: self.paragraph_base_level,
+            writing_mode: self.writing_mode,
+        }
+    }
+
     pub fn shape(&self, params: ShapeParams) -> Vec<GlyphRun> {
         if self.codepoints.is_empty() {
             return Vec::new();
@@ -46,51 +190,170 @@ impl<'a> Layout<'a> {
         } else {
             None
         };
-        let mut runs = Vec::new();
-        for (font_run_index, font_run) in self.runs.iter().enumerate() {
-            let font = &self.fonts[font_run.font_index];
-            let synthesize = match (
-                font_run.synthetic_bold,
-                font_run.synthetic_slant,
-                params.embolden_strength,
-                params.slant,
-            ) {
-                (true, false, Some(embolden_strength), _) => {
-                    Some(Syntesize::Embolden(embolden_strength))
-                }
-                (false, true, _, Some(slant)) => Some(Syntesize::Slant(slant)),
-                (true, true, Some(embolden_strength), Some(slant)) => {
-                    Some(Syntesize::EmboldenAndSlant {
-                        embolden_strength,
-                        slant,
-                    })
-                }
-                (true, true, Some(embolden_strength), _) => {
-                    Some(Syntesize::Embolden(embolden_strength))
+        let mut glyph_runs = self
+            .runs
+            .iter()
+            .enumerate()
+            .map(|(font_run_index, font_run)| {
+                self.shape_run(
+                    &self.codepoints,
+                    font_run,
+                    font_run_index,
+                    &params,
+                    glyph_drawer.as_ref(),
+                )
+            })
+            .collect::<Vec<_>>();
+        if self.codepoints.contains(&0x0009) {
+            self.expand_tabs(&mut glyph_runs, params.tab_stops);
+        }
+        glyph_runs
+    }
+
+    // N.B. `codepoints` and `font_run` may differ from the layout's own, e.g. when
+    // reshaping with inserted tatweels.
+    pub(crate) fn shape_run(
+        &self,
+        codepoints: &[u32],
+        font_run: &FontRun,
+        font_run_index: usize,
+        params: &ShapeParams,
+        glyph_drawer: Option<&GlyphDrawer>,
+    ) -> GlyphRun {
+        if let Some(object_index) = font_run.object_index {
+            return self.object_glyph_run(font_run, font_run_index, object_index);
+        }
+        let font = self.run_font(font_run, params);
+        let glyph_scale = glyph_scale(font_run);
+        let (mut glyphs, paths) = shape(
+            codepoints,
+            &font,
+            font_run,
+            params,
+            self.font_scale(font_run.font_index) * glyph_scale,
+            glyph_drawer,
+        );
+        // N.B. Synthesized small caps are shaped as capitals at full size, so
+        // the positions are scaled down to match the drawn glyphs.
+        if glyph_scale != 1.0 {
+            for glyph in glyphs.iter_mut() {
+                glyph.x_advance = (glyph.x_advance as f32 * glyph_scale).round() as i32;
+                glyph.y_advance = (glyph.y_advance as f32 * glyph_scale).round() as i32;
+                glyph.x_offset = (glyph.x_offset as f32 * glyph_scale).round() as i32;
+                glyph.y_offset = (glyph.y_offset as f32 * glyph_scale).round() as i32;
+                glyph.x_origin = (glyph.x_origin as f32 * glyph_scale).round() as i32;
+                glyph.y_origin = (glyph.y_origin as f32 * glyph_scale).round() as i32;
+            }
+        }
+        let baseline_offset = params.dominant_baseline.map_or(0, |baseline| {
+            self.baseline_offset(font_run, baseline, params.language)
+        });
+        if baseline_offset != 0 {
+            let vertical = font_run.vertical_orientation == Some(VerticalOrientation::Upright);
+            for glyph in glyphs.iter_mut() {
+                if vertical {
+                    glyph.x_offset += baseline_offset;
+                } else {
+                    glyph.y_offset += baseline_offset;
                 }
-                (true, true, _, Some(slant)) => Some(Syntesize::Slant(slant)),
-                _ => None,
-            };
-            let font = if let Some(synthesize) = synthesize {
-                let font = font.synthesize(synthesize);
-                Cow::Owned(font)
+            }
+        }
+        GlyphRun {
+            font_run_index,
+            baseline_offset,
+            glyphs,
+            paths,
+        }
+    }
+
+    // N.B. An object is a single glyph without outline, with the object width as
+    // advance, so it takes part in measuring and hit testing.
+    fn object_glyph_run(
+        &self,
+        font_run: &FontRun,
+        font_run_index: usize,
+        object_index: usize,
+    ) -> GlyphRun {
+        let advance = (self.objects[object_index].1.width / self.font_scale(font_run.font_index))
+            .round() as i32;
+        let (x_advance, y_advance) =
+            if font_run.vertical_orientation == Some(VerticalOrientation::Upright) {
+                (0, -advance)
             } else {
-                Cow::Borrowed(font)
+                (advance, 0)
             };
-            let (glyphs, paths) = shape(
-                &self.codepoints,
-                &font,
-                font_run,
-                params.language,
-                glyph_drawer.as_ref(),
-            );
-            runs.push(GlyphRun {
-                font_run_index,
-                glyphs,
-                paths,
-            });
+        GlyphRun {
+            font_run_index,
+            baseline_offset: 0,
+            glyphs: vec![Glyph {
+                glyph_id: 0,
+                cluster: font_run.offset as u32,
+                x_offset: 0,
+                y_offset: 0,
+                x_advance,
+                y_advance,
+                x_origin: 0,
+                y_origin: 0,
+                glyph_class: GlyphClass::Unclassified,
+                flags: 0,
+            }],
+            paths: Vec::new(),
+        }
+    }
+
+    pub(crate) fn run_font(&self, font_run: &FontRun, params: &ShapeParams) -> Cow<'_, Font> {
+        let font = &self.fonts[font_run.font_index];
+        let synthesize = match (
+            font_run.synthetic_bold,
+            font_run.synthetic_slant,
+            params.embolden_strength,
+            params.slant,
+        ) {
+            (true, false, Some(embolden_strength), _) => {
+                Some(Syntesize::Embolden(embolden_strength))
+            }
+            (false, true, _, Some(slant)) => Some(Syntesize::Slant(slant)),
+            (true, true, Some(embolden_strength), Some(slant)) => {
+                Some(Syntesize::EmboldenAndSlant {
+                    embolden_strength,
+                    slant,
+                })
+            }
+            (true, true, Some(embolden_strength), _) => {
+                Some(Syntesize::Embolden(embolden_strength))
+            }
+            (true, true, _, Some(slant)) => Some(Syntesize::Slant(slant)),
+            _ => None,
+        };
+        if let Some(synthesize) = synthesize {
+            let font = font.synthesize(synthesize);
+            Cow::Owned(font)
+        } else {
+            Cow::Borrowed(font)
         }
-        runs
+    }
+
+    // N.B. Runs are aligned to where the first font puts the dominant baseline.
+    fn baseline_offset(
+        &self,
+        font_run: &FontRun,
+        baseline: Baseline,
+        language: Option<&str>,
+    ) -> i32 {
+        let writing_mode = if font_run.vertical_orientation == Some(VerticalOrientation::Upright) {
+            WritingMode::Vertical
+        } else {
+            WritingMode::Horizontal
+        };
+        let dominant =
+            self.fonts[0].baseline_with_fallback(baseline, writing_mode, font_run.script, language);
+        let own = self.fonts[font_run.font_index].baseline_with_fallback(
+            baseline,
+            writing_mode,
+            font_run.script,
+            language,
+        );
+        (dominant as f32 / self.font_scale(font_run.font_index)).round() as i32 - own
     }
 }
 
@@ -98,8 +361,16 @@ impl<'a> Layout<'a> {
 pub struct LayoutBuilder<'a> {
     fonts: &'a [Font],
     codepoints: Vec<u32>,
-    styles: Vec<FontStyle>,
+    original_codepoints: Vec<u32>,
+    original_offsets: Vec<usize>,
+    styles: Vec<TextStyle>,
     style_indices: Vec<usize>,
+    attributes: Vec<Option<u64>>,
+    synthetic_small_caps: Vec<bool>,
+    objects: Vec<(usize, InlineObject)>,
+    normalization: Option<Normalization>,
+    language: Option<String>,
+    writing_mode: WritingMode,
 }
 
 impl<'a> LayoutBuilder<'a> {
@@ -108,8 +379,16 @@ impl<'a> LayoutBuilder<'a> {
         Self {
             fonts,
             codepoints: Vec::new(),
+            original_codepoints: Vec::new(),
+            original_offsets: Vec::new(),
             styles: Vec::new(),
             style_indices: Vec::new(),
+            attributes: Vec::new(),
+            synthetic_small_caps: Vec::new(),
+            objects: Vec::new(),
+            normalization: None,
+            language: None,
+            writing_mode: WritingMode::default(),
         }
     }
 
@@ -117,24 +396,138 @@ impl<'a> LayoutBuilder<'a> {
         self.fonts
     }
 
+    pub fn codepoints(&self) -> &[u32] {
+        &self.codepoints
+    }
+
+    pub fn writing_mode(&self) -> WritingMode {
+        self.writing_mode
+    }
+
+    pub fn set_writing_mode(&mut self, writing_mode: WritingMode) {
+        self.writing_mode = writing_mode;
+    }
+
+    pub fn normalization(&self) -> Option<Normalization> {
+        self.normalization
+    }
+
+    // N.B. Applies to the text pushed after it, span by span, so marks only
+    // compose with base characters pushed along with them.
+    pub fn set_normalization(&mut self, normalization: Option<Normalization>) {
+        self.normalization = normalization;
+    }
+
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    // N.B. Tailors the text transforms and small caps of the text pushed after
+    // it, e.g. "tr" for the dotted capital I. Shaping takes its language from
+    // `ShapeParams`.
+    pub fn set_language(&mut self, language: Option<&str>) {
+        self.language = language.map(str::to_owned);
+    }
+
     pub fn clear(&mut self) {
         self.codepoints.clear();
+        self.original_codepoints.clear();
+        self.original_offsets.clear();
         self.styles.clear();
         self.style_indices.clear();
+        self.attributes.clear();
+        self.synthetic_small_caps.clear();
+        self.objects.clear();
     }
 
-    pub fn push(&mut self, text: impl Iterator<Item = char>, style: FontStyle) {
+    pub fn push(&mut self, text: impl Iterator<Item = char>, style: impl Into<TextStyle>) {
+        let style = style.into();
+        let text = text.map(|c| c as u32).collect::<Vec<_>>();
         let start = self.codepoints.len();
-        self.codepoints.extend(text.map(|c| c as u32));
+        let language = self.language.as_deref();
+        let (mut codepoints, mut offsets) = match style.text_transform {
+            Some(transform) => transform_text(&text, transform, language),
+            None => (text.clone(), (0..text.len()).collect()),
+        };
+        let mut synthetic_small_caps = vec![false; codepoints.len()];
+        if style.small_caps {
+            let (small_caps, small_caps_offsets, synthetic) =
+                synthesize_small_caps(&codepoints, self.fonts, style.font_style, language);
+            offsets = small_caps_offsets.into_iter().map(|v| offsets[v]).collect();
+            codepoints = small_caps;
+            synthetic_small_caps = synthetic;
+        }
+        if let Some(normalization) = self.normalization {
+            let (normalized, normalized_offsets) =
+                normalize(&codepoints, normalization, self.fonts, style.font_style);
+            offsets = normalized_offsets.iter().map(|v| offsets[*v]).collect();
+            synthetic_small_caps = normalized_offsets
+                .into_iter()
+                .map(|v| synthetic_small_caps[v])
+                .collect();
+            codepoints = normalized;
+        }
+        let original_len = self.original_codepoints.len();
+        self.codepoints.extend(codepoints);
+        self.original_offsets
+            .extend(offsets.into_iter().map(|v| original_len + v));
+        self.synthetic_small_caps.extend(synthetic_small_caps);
+        self.original_codepoints.extend(text);
         let end = self.codepoints.len();
         let style_index = self.styles.len();
         self.styles.push(style);
         self.style_indices
             .extend(iter::repeat(style_index).take(end - start));
+        self.attributes.resize(end, None);
+    }
+
+    // N.B. Attributes are user ids set on codepoint ranges of the text as pushed,
+    // independent of the pushed spans, where later ones take precedence. Runs
+    // are split where the attribute changes.
+    pub fn set_attribute(&mut self, range: Range<usize>, attribute: u64) {
+        let start = self.original_offsets.partition_point(|v| *v < range.start);
+        let end = self.original_offsets.partition_point(|v| *v < range.end);
+        for v in self.attributes[start.min(end)..end].iter_mut() {
+            *v = Some(attribute);
+        }
+    }
+
+    // N.B. The object takes part in bidi as an object replacement character, and
+    // in the style of the text before it.
+    pub fn push_object(&mut self, width: f32, ascent: f32, descent: f32, user_id: u64) {
+        let offset = self.codepoints.len();
+        self.codepoints.push(OBJECT_REPLACEMENT_CHARACTER);
+        self.original_offsets.push(self.original_codepoints.len());
+        self.original_codepoints.push(OBJECT_REPLACEMENT_CHARACTER);
+        let style_index = if let Some(style_index) = self.style_indices.last() {
+            *style_index
+        } else {
+            self.styles.push(TextStyle::default());
+            self.styles.len() - 1
+        };
+        self.style_indices.push(style_index);
+        self.attributes.push(None);
+        self.synthetic_small_caps.push(false);
+        self.objects.push((
+            offset,
+            InlineObject {
+                width,
+                ascent,
+                descent,
+                user_id,
+            },
+        ));
+    }
+
+    fn object_index(&self, offset: usize) -> Option<usize> {
+        self.objects.binary_search_by_key(&offset, |v| v.0).ok()
     }
 
     pub fn has_missing_glyphs(&self) -> bool {
-        for v in self.codepoints.iter() {
+        for (index, v) in self.codepoints.iter().enumerate() {
+            if self.object_index(index).is_some() {
+                continue;
+            }
             if !self.fonts.iter().any(|font| font.has_glyph(v)) {
                 return true;
             }
@@ -147,8 +540,12 @@ impl<'a> LayoutBuilder<'a> {
             Layout {
                 fonts: self.fonts,
                 codepoints: self.codepoints,
+                original_codepoints: self.original_codepoints,
+                original_offsets: self.original_offsets,
+                objects: self.objects,
                 runs: Vec::new(),
                 paragraph_base_level: 0,
+                writing_mode: self.writing_mode,
             }
         } else {
             let mut paragraph_base_level = 0u8;
@@ -156,8 +553,12 @@ impl<'a> LayoutBuilder<'a> {
             Layout {
                 fonts: self.fonts,
                 codepoints: self.codepoints,
+                original_codepoints: self.original_codepoints,
+                original_offsets: self.original_offsets,
+                objects: self.objects,
                 runs,
                 paragraph_base_level,
+                writing_mode: self.writing_mode,
             }
         }
     }
@@ -171,28 +572,86 @@ fn compute_runs(builder: &LayoutBuilder<'_>, paragraph_base_level: &mut u8) -> V
     unsafe extern "C" fn text_run_callback(run: ffi::SubsetTextRun, context: *mut c_void) {
         let Context { builder, runs } = &mut *(context as *mut Context<'_>);
         let rtl = (run.bidi_level & 1) != 0;
+        let emoji_presentation = EmojiPresentation::from_raw(run.emoji_presentation);
+        if emoji_presentation.is_some() {
+            // N.B. Emoji sequences must never be split across fonts.
+            let style = builder.styles[builder.style_indices[run.offset]];
+            let sequence = &builder.codepoints[run.offset..run.offset + run.length];
+            let index = find_best_font_match_for_sequence(
+                builder.fonts,
+                sequence,
+                emoji_presentation,
+                style.font_style,
+            )
+            .unwrap_or(0);
+            runs.push(font_run(
+                builder, run.offset, run.length, &run, index, style,
+            ));
+            return;
+        }
         let mut deque = VecDeque::new();
-        for (offset, len, style) in
-            split_run(run.offset, run.length, FontStyle::default(), |index| {
-                builder.styles[builder.style_indices[index]]
-            })
-        {
+        // N.B. A variation selector goes with its base character, even when
+        // pushed in a span of its own.
+        let base_index = |mut index: usize| {
+            while index > run.offset && is_variation_selector(builder.codepoints[index]) {
+                index -= 1;
+            }
+            index
+        };
+        for (offset, len, (style, _, _, object_index)) in split_run(
+            run.offset,
+            run.length,
+            (TextStyle::default(), None, false, None),
+            |index| {
+                let index = base_index(index);
+                (
+                    builder.styles[builder.style_indices[index]],
+                    builder.attributes[index],
+                    builder.synthetic_small_caps[index],
+                    builder.object_index(index),
+                )
+            },
+        ) {
+            if let Some(object_index) = object_index {
+                // N.B. Objects are never shaped, so the font is irrelevant.
+                let run = FontRun {
+                    synthetic_bold: false,
+                    synthetic_slant: false,
+                    object_index: Some(object_index),
+                    ..font_run(builder, offset, len, &run, 0, style)
+                };
+                if rtl {
+                    deque.push_front(run);
+                } else {
+                    deque.push_back(run);
+                }
+                continue;
+            }
+            let end = offset + len;
+            let mut last_index = 0;
             for (offset, len, index) in split_run(offset, len, 0, |index| {
                 let codepoint = builder.codepoints[index];
-                find_best_font_match(builder.fonts, codepoint, style).unwrap_or(0)
+                // N.B. A variation selector always goes with its base character.
+                if index > offset && is_variation_selector(codepoint) {
+                    return last_index;
+                }
+                let variation_selector = builder.codepoints[index + 1..end]
+                    .first()
+                    .filter(|v| is_variation_selector(**v));
+                last_index = if let Some(variation_selector) = variation_selector {
+                    find_best_font_match_for_sequence(
+                        builder.fonts,
+                        &[codepoint, *variation_selector],
+                        None,
+                        style.font_style,
+                    )
+                } else {
+                    find_best_font_match(builder.fonts, codepoint, style.font_style)
+                }
+                .unwrap_or(0);
+                last_index
             }) {
-                let font = &builder.fonts[index];
-                let FontStyle { italic, weight, .. } = font.style();
-                let run = FontRun {
-                    offset,
-                    len,
-                    bidi_level: run.bidi_level,
-                    script: run.script,
-                    font_index: index,
-                    font_style: style,
-                    synthetic_bold: style.weight > weight,
-                    synthetic_slant: style.italic && !italic,
-                };
+                let run = font_run(builder, offset, len, &run, index, style);
                 if rtl {
                     deque.push_front(run);
                 } else {
@@ -202,6 +661,44 @@ fn compute_runs(builder: &LayoutBuilder<'_>, paragraph_base_level: &mut u8) -> V
         }
         runs.extend(deque);
     }
+    fn font_run(
+        builder: &LayoutBuilder<'_>,
+        offset: usize,
+        len: usize,
+        run: &ffi::SubsetTextRun,
+        font_index: usize,
+        style: TextStyle,
+    ) -> FontRun {
+        let font = &builder.fonts[font_index];
+        let FontStyle { italic, weight, .. } = font.style();
+        // N.B. Tr characters are rotated if the font can't transform them.
+        let upright = run.upright
+            && (!run.transformed || font.has_feature(b"vert") || font.has_feature(b"vrt2"));
+        FontRun {
+            offset,
+            len,
+            bidi_level: run.bidi_level,
+            paragraph_level: run.paragraph_level,
+            script: run.script,
+            emoji_presentation: EmojiPresentation::from_raw(run.emoji_presentation),
+            vertical_orientation: match builder.writing_mode {
+                WritingMode::Horizontal => None,
+                WritingMode::Vertical if upright => Some(VerticalOrientation::Upright),
+                WritingMode::Vertical => Some(VerticalOrientation::Sideways),
+            },
+            font_index,
+            font_style: style.font_style,
+            synthetic_bold: style.font_style.weight > weight,
+            synthetic_slant: style.font_style.italic && !italic,
+            letter_spacing: style.letter_spacing,
+            word_spacing: style.word_spacing,
+            small_caps: style.small_caps,
+            synthetic_small_caps: builder.synthetic_small_caps[offset],
+            object_index: None,
+            attribute: builder.attributes[offset],
+        }
+    }
+    let bidi_spans = bidi_spans(builder);
     let mut context = Context {
         builder,
         runs: Vec::new(),
@@ -210,6 +707,9 @@ fn compute_runs(builder: &LayoutBuilder<'_>, paragraph_base_level: &mut u8) -> V
         ffi::subset_text_runs(
             builder.codepoints.as_ptr(),
             builder.codepoints.len(),
+            bidi_spans.as_ptr(),
+            bidi_spans.len(),
+            builder.writing_mode == WritingMode::Vertical,
             paragraph_base_level as *mut _,
             Some(text_run_callback),
             &mut context as *mut _ as *mut _,
@@ -218,6 +718,52 @@ fn compute_runs(builder: &LayoutBuilder<'_>, paragraph_base_level: &mut u8) -> V
     context.runs
 }
 
+// N.B. Every pushed span with a bidi control is a span of its own, even next to
+// one with the same control, just like separately enclosed text. Objects share
+// the style of the span before them, but are never part of it.
+fn bidi_spans(builder: &LayoutBuilder<'_>) -> Vec<ffi::SubsetBidiSpan> {
+    let mut spans: Vec<ffi::SubsetBidiSpan> = Vec::new();
+    let mut last_style_index = None;
+    for (offset, style_index) in builder.style_indices.iter().copied().enumerate() {
+        let control = builder.styles[style_index].bidi_control;
+        let Some(control) = control.filter(|_| builder.object_index(offset).is_none()) else {
+            last_style_index = None;
+            continue;
+        };
+        match spans.last_mut() {
+            Some(span) if last_style_index == Some(style_index) => span.length += 1,
+            _ => spans.push(ffi::SubsetBidiSpan {
+                offset,
+                length: 1,
+                control: match control {
+                    BidiControl::IsolateLeftToRight => 0,
+                    BidiControl::IsolateRightToLeft => 1,
+                    BidiControl::IsolateFirstStrong => 2,
+                    BidiControl::EmbedLeftToRight => 3,
+                    BidiControl::EmbedRightToLeft => 4,
+                    BidiControl::OverrideLeftToRight => 5,
+                    BidiControl::OverrideRightToLeft => 6,
+                },
+            }),
+        }
+        last_style_index = Some(style_index);
+    }
+    spans
+}
+
+// N.B. The scale of the glyphs of the run relative to their font.
+pub(crate) fn glyph_scale(font_run: &FontRun) -> f32 {
+    if font_run.synthetic_small_caps {
+        SMALL_CAPS_SCALE
+    } else {
+        1.0
+    }
+}
+
+fn is_variation_selector(codepoint: u32) -> bool {
+    unsafe { ffi::subset_is_variation_selector(codepoint) }
+}
+
 struct SplitRun<T, F> {
     done: bool,
     range: Range<usize>,
@@ -266,14 +812,18 @@ where
     }
 }
 
-struct GlyphDrawer(*mut ffi::SubsetGlyphDrawer);
+pub(crate) struct GlyphDrawer(*mut ffi::SubsetGlyphDrawer);
 
 impl GlyphDrawer {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         let raw = unsafe { ffi::subset_glyph_drawer_create() };
         assert!(!raw.is_null());
         Self(raw)
     }
+
+    pub(crate) fn draw(&self, font: &Font, glyph_id: u32) -> Path {
+        unsafe { draw_glyph(font.0, glyph_id, self.0) }
+    }
 }
 
 impl Drop for GlyphDrawer {
@@ -284,19 +834,11 @@ impl Drop for GlyphDrawer {
     }
 }
 
-fn shape(
-    codepoints: &[u32],
-    font: &Font,
-    run: &FontRun,
-    language: Option<&str>,
-    glyph_drawer: Option<&GlyphDrawer>,
-) -> (Vec<Glyph>, Vec<Path>) {
-    struct Context {
-        font: *mut ffi::SubsetFont,
-        glyph_drawer: *mut ffi::SubsetGlyphDrawer,
-        glyphs: Vec<Glyph>,
-        paths: Vec<Path>,
-    }
+unsafe fn draw_glyph(
+    font: *mut ffi::SubsetFont,
+    glyph_id: u32,
+    glyph_drawer: *mut ffi::SubsetGlyphDrawer,
+) -> Path {
     unsafe extern "C" fn path_command_callback(
         verb: c_uint,
         points: *const f32,
@@ -323,6 +865,31 @@ fn shape(
             path.points.push((x, y));
         }
     }
+    let mut path = Path::default();
+    ffi::subset_font_draw_glyph(
+        font,
+        glyph_id,
+        glyph_drawer,
+        Some(path_command_callback),
+        &mut path as *mut _ as *mut _,
+    );
+    path
+}
+
+fn shape(
+    codepoints: &[u32],
+    font: &Font,
+    run: &FontRun,
+    shape_params: &ShapeParams,
+    scale: f32,
+    glyph_drawer: Option<&GlyphDrawer>,
+) -> (Vec<Glyph>, Vec<Path>) {
+    struct Context {
+        font: *mut ffi::SubsetFont,
+        glyph_drawer: *mut ffi::SubsetGlyphDrawer,
+        glyphs: Vec<Glyph>,
+        paths: Vec<Path>,
+    }
     unsafe extern "C" fn shape_callback(glyph: crate::Glyph, context: *mut c_void) {
         let Context {
             font,
@@ -332,15 +899,7 @@ fn shape(
         } = &mut *(context as *mut Context);
         glyphs.push(glyph);
         if !glyph_drawer.is_null() {
-            let mut path = Path::default();
-            ffi::subset_font_draw_glyph(
-                *font,
-                glyph.glyph_id,
-                *glyph_drawer,
-                Some(path_command_callback),
-                &mut path as *mut _ as *mut _,
-            );
-            paths.push(path);
+            paths.push(draw_glyph(*font, glyph.glyph_id, *glyph_drawer));
         }
     }
     let mut params = ffi::SubsetShapeParams {
@@ -351,8 +910,21 @@ fn shape(
         bidi_level: run.bidi_level,
         script: run.script,
         language: ptr::null(),
+        vertical: run.vertical_orientation == Some(VerticalOrientation::Upright),
+        glyph_classes: shape_params.emit_glyph_classes,
+        letter_spacing: (run.letter_spacing.unwrap_or(shape_params.letter_spacing) / scale).round()
+            as i32,
+        word_spacing: (run.word_spacing.unwrap_or(shape_params.word_spacing) / scale).round()
+            as i32,
+        show_invisibles: shape_params.show_invisibles,
+        small_caps: run.small_caps && !run.synthetic_small_caps,
     };
-    let language = language.map(CString::new).transpose().ok().flatten();
+    let language = shape_params
+        .language
+        .map(CString::new)
+        .transpose()
+        .ok()
+        .flatten();
     if let Some(ref lang) = language {
         params.language = lang.as_ptr();
     }
@@ -1,11 +1,32 @@
 extern crate link_cplusplus;
 
+use std::ffi::c_uint;
+
+#[cfg(test)]
+mod conformance;
+mod cursor;
+mod decoration;
 mod ffi;
 mod font;
+mod hit_test;
+mod justify;
 mod layout;
+mod metrics;
+mod normalize;
+mod position;
+mod segmentation;
+mod tabs;
+mod transform;
+mod truncate;
+mod unicode;
 
 pub use self::font::*;
+pub use self::hit_test::*;
 pub use self::layout::*;
+pub use self::position::*;
+pub use self::segmentation::*;
+pub use self::truncate::*;
+pub use self::unicode::*;
 
 #[derive(Debug, Clone, Copy)]
 pub struct FontExtents {
@@ -14,6 +35,42 @@ pub struct FontExtents {
     pub line_gap: i32,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct GlyphExtents {
+    pub x_bearing: i32,
+    pub y_bearing: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FontMetrics {
+    pub underline_offset: i32,
+    pub underline_size: i32,
+    pub strikeout_offset: i32,
+    pub strikeout_size: i32,
+    pub x_height: i32,
+    pub cap_height: i32,
+    pub typo_ascender: i32,
+    pub typo_descender: i32,
+    pub typo_line_gap: i32,
+    pub win_ascent: i32,
+    pub win_descent: i32,
+    pub hhea_ascender: i32,
+    pub hhea_descender: i32,
+    pub hhea_line_gap: i32,
+    pub subscript_x_offset: i32,
+    pub subscript_y_offset: i32,
+    pub subscript_x_size: i32,
+    pub subscript_y_size: i32,
+    pub superscript_x_offset: i32,
+    pub superscript_y_offset: i32,
+    pub superscript_x_size: i32,
+    pub superscript_y_size: i32,
+    pub use_typo_metrics: bool,
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct FontStyle {
@@ -56,31 +113,405 @@ impl Default for FontStyle {
     }
 }
 
+// N.B. Spacing is in layout units and overrides the one in `ShapeParams` for the
+// span when set. The bidi control applies to the span as a whole, as if it was
+// enclosed in the matching control characters. Small caps use the `smcp`
+// feature, or are synthesized from scaled down capitals for fonts without it.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TextStyle {
+    pub font_style: FontStyle,
+    pub letter_spacing: Option<f32>,
+    pub word_spacing: Option<f32>,
+    pub bidi_control: Option<BidiControl>,
+    pub text_transform: Option<TextTransform>,
+    pub small_caps: bool,
+}
+
+impl From<FontStyle> for TextStyle {
+    fn from(font_style: FontStyle) -> Self {
+        Self {
+            font_style,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextTransform {
+    Uppercase,
+    Lowercase,
+    Capitalize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BidiControl {
+    IsolateLeftToRight,
+    IsolateRightToLeft,
+    IsolateFirstStrong,
+    EmbedLeftToRight,
+    EmbedRightToLeft,
+    OverrideLeftToRight,
+    OverrideRightToLeft,
+}
+
+// N.B. Tab stops are in layout units from the start edge of the paragraph.
+// Tabs past the last position, or all of them by default, advance to the next
+// multiple of eight spaces of the first font, like CSS `tab-size`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TabStops<'a> {
+    Interval(f32),
+    Positions(&'a [f32]),
+}
+
+// N.B. Absolute line heights are in layout units and numbers are multiples of
+// the em size of the first font, like CSS `line-height`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum LineHeight {
+    #[default]
+    Normal,
+    Number(f32),
+    Absolute(f32),
+}
+
+// N.B. `ascent` and `descent` are the largest font extents on the line while
+// `baseline` is the distance from the top of the line box to the baseline.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct LineMetrics {
+    pub ascent: f32,
+    pub descent: f32,
+    pub baseline: f32,
+    pub height: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmojiPresentation {
+    Text,
+    Emoji,
+}
+
+impl EmojiPresentation {
+    pub(crate) fn from_raw(value: c_uint) -> Option<Self> {
+        match value {
+            1 => Some(Self::Text),
+            2 => Some(Self::Emoji),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_raw(value: Option<Self>) -> c_uint {
+        match value {
+            None => 0,
+            Some(Self::Text) => 1,
+            Some(Self::Emoji) => 2,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WritingMode {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Baseline {
+    Roman,
+    Hanging,
+    IdeographicFaceBottomOrLeft,
+    IdeographicFaceTopOrRight,
+    IdeographicFaceCentral,
+    IdeographicEmboxBottomOrLeft,
+    IdeographicEmboxTopOrRight,
+    IdeographicEmboxCentral,
+    Math,
+}
+
+impl Baseline {
+    pub(crate) fn to_raw(self) -> u32 {
+        let tag = match self {
+            Self::Roman => b"romn",
+            Self::Hanging => b"hang",
+            Self::IdeographicFaceBottomOrLeft => b"icfb",
+            Self::IdeographicFaceTopOrRight => b"icft",
+            Self::IdeographicFaceCentral => b"Icfc",
+            Self::IdeographicEmboxBottomOrLeft => b"ideo",
+            Self::IdeographicEmboxTopOrRight => b"idtp",
+            Self::IdeographicEmboxCentral => b"Idce",
+            Self::Math => b"math",
+        };
+        u32::from_be_bytes(*tag)
+    }
+}
+
+// N.B. Characters with the Tr orientation of UAX #50 are upright where the font
+// has the `vert` or `vrt2` feature for their vertical forms, and sideways
+// otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerticalOrientation {
+    Upright,
+    Sideways,
+}
+
+// N.B. `paragraph_level` is the base level of the paragraph the run is in.
 #[derive(Debug, Clone, Copy)]
 pub struct FontRun {
     pub offset: usize,
     pub len: usize,
     pub bidi_level: u8,
+    pub paragraph_level: u8,
     pub script: u32,
+    pub emoji_presentation: Option<EmojiPresentation>,
+    pub vertical_orientation: Option<VerticalOrientation>,
     pub font_index: usize,
     pub font_style: FontStyle,
     pub synthetic_bold: bool,
     pub synthetic_slant: bool,
+    pub letter_spacing: Option<f32>,
+    pub word_spacing: Option<f32>,
+    pub small_caps: bool,
+    pub synthetic_small_caps: bool,
+    pub object_index: Option<usize>,
+    pub attribute: Option<u64>,
+}
+
+// N.B. The width is along the inline axis and, like the ascent and descent, in
+// layout units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InlineObject {
+    pub width: f32,
+    pub ascent: f32,
+    pub descent: f32,
+    pub user_id: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PositionedObject {
+    pub user_id: u64,
+    pub offset: usize,
+    pub rect: Rect,
+    pub attribute: Option<u64>,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GlyphClass {
+    #[default]
+    Unclassified = 0,
+    Base = 1,
+    Ligature = 2,
+    Mark = 3,
+    Component = 4,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    LeftToRight = 0,
+    RightToLeft = 1,
+    TopToBottom = 2,
+    BottomToTop = 3,
 }
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Glyph {
     pub glyph_id: u32,
+    pub cluster: u32,
     pub x_offset: i32,
     pub y_offset: i32,
     pub x_advance: i32,
     pub y_advance: i32,
+    pub x_origin: i32,
+    pub y_origin: i32,
+    pub glyph_class: GlyphClass,
+    pub flags: u32,
+}
+
+impl Glyph {
+    pub const UNSAFE_TO_BREAK: u32 = 1;
+    pub const UNSAFE_TO_CONCAT: u32 = 2;
+    pub const SAFE_TO_INSERT_TATWEEL: u32 = 4;
+
+    pub fn unsafe_to_break(&self) -> bool {
+        (self.flags & Self::UNSAFE_TO_BREAK) != 0
+    }
+
+    pub fn unsafe_to_concat(&self) -> bool {
+        (self.flags & Self::UNSAFE_TO_CONCAT) != 0
+    }
+
+    pub fn safe_to_insert_tatweel(&self) -> bool {
+        (self.flags & Self::SAFE_TO_INSERT_TATWEEL) != 0
+    }
+}
+
+// N.B. `x` and `y` is the glyph origin in layout space, with y pointing up,
+// and `scale` maps the glyph's design units to it. `run_index` and
+// `glyph_index` refer to the shaped `GlyphRun` and its glyphs and paths, and
+// `attribute` is the user attribute of its run.
+#[derive(Debug, Clone, Copy)]
+pub struct PositionedGlyph {
+    pub font_index: usize,
+    pub glyph_id: u32,
+    pub x: f32,
+    pub y: f32,
+    pub scale: f32,
+    pub sideways: bool,
+    pub cluster: usize,
+    pub run_index: usize,
+    pub glyph_index: usize,
+    pub attribute: Option<u64>,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneralCategory {
+    Control = 0,
+    Format = 1,
+    Unassigned = 2,
+    PrivateUse = 3,
+    Surrogate = 4,
+    LowercaseLetter = 5,
+    ModifierLetter = 6,
+    OtherLetter = 7,
+    TitlecaseLetter = 8,
+    UppercaseLetter = 9,
+    SpacingMark = 10,
+    EnclosingMark = 11,
+    NonSpacingMark = 12,
+    DecimalNumber = 13,
+    LetterNumber = 14,
+    OtherNumber = 15,
+    ConnectPunctuation = 16,
+    DashPunctuation = 17,
+    ClosePunctuation = 18,
+    FinalPunctuation = 19,
+    InitialPunctuation = 20,
+    OtherPunctuation = 21,
+    OpenPunctuation = 22,
+    CurrencySymbol = 23,
+    ModifierSymbol = 24,
+    MathSymbol = 25,
+    OtherSymbol = 26,
+    LineSeparator = 27,
+    ParagraphSeparator = 28,
+    SpaceSeparator = 29,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BidiClass {
+    LeftToRight = 0,
+    RightToLeft = 1,
+    ArabicLetter = 2,
+    EuropeanNumber = 3,
+    EuropeanSeparator = 4,
+    EuropeanTerminator = 5,
+    ArabicNumber = 6,
+    CommonSeparator = 7,
+    NonspacingMark = 8,
+    BoundaryNeutral = 9,
+    ParagraphSeparator = 10,
+    SegmentSeparator = 11,
+    WhiteSpace = 12,
+    OtherNeutral = 13,
+    LeftToRightEmbedding = 14,
+    RightToLeftEmbedding = 15,
+    LeftToRightOverride = 16,
+    RightToLeftOverride = 17,
+    PopDirectionalFormat = 18,
+    LeftToRightIsolate = 19,
+    RightToLeftIsolate = 20,
+    FirstStrongIsolate = 21,
+    PopDirectionalIsolate = 22,
+}
+
+// N.B. `mirroring` is the codepoint itself for characters without a mirrored
+// counterpart, and `script` a tag like the one of font runs.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnicodeProperties {
+    pub general_category: GeneralCategory,
+    pub script: u32,
+    pub mirroring: u32,
+    pub combining_class: u8,
+    pub bidi_class: BidiClass,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segmentation {
+    Grapheme,
+    Word,
+    Sentence,
+    HardLineBreak,
+}
+
+// N.B. The affinity tells which edge of the codepoint at an index a caret is
+// at, so the logical caret offset is the index plus one for trailing edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Affinity {
+    Leading,
+    Trailing,
+}
+
+// N.B. `BestForFont` composes only where the font picked for the base
+// character has the composed one, and leaves the rest decomposed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    Nfc,
+    Nfd,
+    BestForFont,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EllipsisPosition {
+    Start,
+    Middle,
+    #[default]
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decoration {
+    Underline,
+    Strikeout,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub fn union(&self, other: &Rect) -> Rect {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        Rect {
+            x,
+            y,
+            width: (self.x + self.width).max(other.x + other.width) - x,
+            height: (self.y + self.height).max(other.y + other.height) - y,
+        }
+    }
+
+    pub fn scale(&self, scale: f32) -> Rect {
+        Rect {
+            x: self.x * scale,
+            y: self.y * scale,
+            width: self.width * scale,
+            height: self.height * scale,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct GlyphRun {
     pub font_run_index: usize,
+    pub baseline_offset: i32,
     pub glyphs: Vec<Glyph>,
     pub paths: Vec<Path>,
 }
@@ -167,7 +598,7 @@ impl<'a> Iterator for PathIter<'a> {
                     .points
                     .get(self.point_index..self.point_index + 3)?;
                 self.point_index += 3;
-                Some(PathCommand::CubicTo(points[0], points[1], points[3]))
+                Some(PathCommand::CubicTo(points[0], points[1], points[2]))
             }
             PathVerb::Close => Some(PathCommand::Close),
         }
@@ -0,0 +1,208 @@
+use std::ops::Range;
+
+use crate::{
+    FontMetrics, FontRun, Glyph, GlyphRun, Layout, LineHeight, LineMetrics, Rect,
+    VerticalOrientation, WritingMode,
+};
+
+// N.B. A cluster is the glyphs shaped from a range of codepoints, with `x` and
+// `advance` along the inline axis in layout units.
+#[derive(Debug, Clone)]
+pub(crate) struct Cluster {
+    pub(crate) run_index: usize,
+    pub(crate) range: Range<usize>,
+    pub(crate) glyphs: Range<usize>,
+    pub(crate) x: f32,
+    pub(crate) advance: f32,
+    pub(crate) rtl: bool,
+}
+
+impl Layout<'_> {
+    pub fn advance(&self, glyph_runs: &[GlyphRun]) -> f32 {
+        let mut advance = 0.0;
+        for glyph_run in glyph_runs {
+            let font_run = &self.runs()[glyph_run.font_run_index];
+            let scale = self.font_scale(font_run.font_index);
+            for glyph in glyph_run.glyphs.iter() {
+                advance += inline_advance(glyph, font_run) as f32 * scale;
+            }
+        }
+        advance
+    }
+
+    // N.B. Clusters partially in range, e.g. ligatures, contribute in
+    // proportion to the number of their codepoints in range.
+    pub fn range_advance(&self, glyph_runs: &[GlyphRun], range: Range<usize>) -> f32 {
+        let mut advance = 0.0;
+        for cluster in self.clusters(glyph_runs) {
+            let start = cluster.range.start.max(range.start);
+            let end = cluster.range.end.min(range.end);
+            if start < end {
+                advance += cluster.advance * (end - start) as f32 / cluster.range.len() as f32;
+            }
+        }
+        advance
+    }
+
+    pub fn ink_bounds(&self, glyph_runs: &[GlyphRun]) -> Option<Rect> {
+        let mut bounds: Option<Rect> = None;
+        for glyph in self.positioned_glyphs(glyph_runs) {
+            let Some(extents) = self.fonts()[glyph.font_index].glyph_extents(glyph.glyph_id) else {
+                continue;
+            };
+            if extents.width == 0 || extents.height == 0 {
+                continue;
+            }
+            let x_min = extents.x_bearing as f32 * glyph.scale;
+            let x_max = (extents.x_bearing + extents.width) as f32 * glyph.scale;
+            let y_min = (extents.y_bearing + extents.height) as f32 * glyph.scale;
+            let y_max = extents.y_bearing as f32 * glyph.scale;
+            let rect = if glyph.sideways {
+                Rect {
+                    x: glyph.x + y_min,
+                    y: glyph.y - x_max,
+                    width: y_max - y_min,
+                    height: x_max - x_min,
+                }
+            } else {
+                Rect {
+                    x: glyph.x + x_min,
+                    y: glyph.y + y_min,
+                    width: x_max - x_min,
+                    height: y_max - y_min,
+                }
+            };
+            bounds = Some(bounds.map_or(rect, |v| v.union(&rect)));
+        }
+        for object in self.positioned_objects(glyph_runs) {
+            bounds = Some(bounds.map_or(object.rect, |v| v.union(&object.rect)));
+        }
+        bounds
+    }
+
+    pub fn logical_bounds(&self, glyph_runs: &[GlyphRun]) -> Rect {
+        let advance = self.advance(glyph_runs);
+        let LineMetrics {
+            ascent, descent, ..
+        } = self.line_metrics(LineHeight::Normal);
+        match self.writing_mode() {
+            WritingMode::Horizontal => Rect {
+                x: 0.0,
+                y: -descent,
+                width: advance,
+                height: ascent + descent,
+            },
+            WritingMode::Vertical => Rect {
+                x: -(ascent + descent) / 2.0,
+                y: -advance,
+                width: ascent + descent,
+                height: advance,
+            },
+        }
+    }
+
+    pub(crate) fn clusters(&self, glyph_runs: &[GlyphRun]) -> Vec<Cluster> {
+        let mut clusters = Vec::new();
+        let mut x = 0.0;
+        for (run_index, glyph_run) in glyph_runs.iter().enumerate() {
+            let font_run = &self.runs()[glyph_run.font_run_index];
+            let scale = self.font_scale(font_run.font_index);
+            let glyphs = &glyph_run.glyphs;
+            let mut starts = glyphs
+                .iter()
+                .map(|v| v.cluster as usize)
+                .collect::<Vec<_>>();
+            starts.sort_unstable();
+            starts.dedup();
+            let mut index = 0;
+            while index < glyphs.len() {
+                let start = glyphs[index].cluster as usize;
+                let end = starts
+                    .get(starts.partition_point(|v| *v <= start))
+                    .copied()
+                    .unwrap_or(font_run.offset + font_run.len);
+                let first = index;
+                let mut advance = 0.0;
+                while index < glyphs.len() && glyphs[index].cluster as usize == start {
+                    advance += inline_advance(&glyphs[index], font_run) as f32 * scale;
+                    index += 1;
+                }
+                clusters.push(Cluster {
+                    run_index,
+                    range: start..end,
+                    glyphs: first..index,
+                    x,
+                    advance,
+                    rtl: (font_run.bidi_level & 1) != 0,
+                });
+                x += advance;
+            }
+        }
+        clusters
+    }
+
+    pub fn line_metrics(&self, line_height: LineHeight) -> LineMetrics {
+        // N.B. The first font always contributes, like the strut of a CSS line
+        // box, along with every font used by a run.
+        let mut font_indices = vec![0];
+        for run in self.runs() {
+            if !font_indices.contains(&run.font_index) {
+                font_indices.push(run.font_index);
+            }
+        }
+        let em = self.fonts()[0].upem() as f32;
+        let mut metrics = LineMetrics::default();
+        let mut above = f32::MIN;
+        let mut below = f32::MIN;
+        for font_index in font_indices {
+            let scale = self.font_scale(font_index);
+            let (ascent, descent, line_gap) = font_extents(&self.fonts()[font_index].metrics());
+            let (ascent, descent, line_gap) = (ascent * scale, descent * scale, line_gap * scale);
+            let half_leading = match line_height {
+                LineHeight::Normal => line_gap / 2.0,
+                LineHeight::Number(number) => (number * em - (ascent + descent)) / 2.0,
+                LineHeight::Absolute(height) => (height - (ascent + descent)) / 2.0,
+            };
+            metrics.ascent = metrics.ascent.max(ascent);
+            metrics.descent = metrics.descent.max(descent);
+            above = above.max(ascent + half_leading);
+            below = below.max(descent + half_leading);
+        }
+        for (_, object) in self.objects() {
+            metrics.ascent = metrics.ascent.max(object.ascent);
+            metrics.descent = metrics.descent.max(object.descent);
+            above = above.max(object.ascent);
+            below = below.max(object.descent);
+        }
+        metrics.baseline = above;
+        metrics.height = above + below;
+        metrics
+    }
+}
+
+pub(crate) fn inline_advance(glyph: &Glyph, font_run: &FontRun) -> i32 {
+    if font_run.vertical_orientation == Some(VerticalOrientation::Upright) {
+        -glyph.y_advance
+    } else {
+        glyph.x_advance
+    }
+}
+
+fn font_extents(metrics: &FontMetrics) -> (f32, f32, f32) {
+    let (ascent, descent, line_gap) = if metrics.use_typo_metrics {
+        (
+            metrics.typo_ascender,
+            -metrics.typo_descender,
+            metrics.typo_line_gap,
+        )
+    } else if metrics.hhea_ascender != 0 || metrics.hhea_descender != 0 {
+        (
+            metrics.hhea_ascender,
+            -metrics.hhea_descender,
+            metrics.hhea_line_gap,
+        )
+    } else {
+        (metrics.win_ascent, metrics.win_descent, 0)
+    };
+    (ascent as f32, descent as f32, line_gap.max(0) as f32)
+}
@@ -0,0 +1,56 @@
+use std::ffi::c_void;
+
+use crate::{ffi, find_best_font_match, Font, FontStyle, Normalization};
+
+// N.B. Returns the normalized codepoints and, for each of them, the offset of
+// the codepoint of `codepoints` it comes from.
+pub(crate) fn normalize(
+    codepoints: &[u32],
+    normalization: Normalization,
+    fonts: &[Font],
+    font_style: FontStyle,
+) -> (Vec<u32>, Vec<usize>) {
+    struct Context<'a> {
+        fonts: &'a [Font],
+        font_style: FontStyle,
+        codepoints: Vec<u32>,
+        offsets: Vec<usize>,
+    }
+    unsafe extern "C" fn composition_filter(
+        starter: u32,
+        composed: u32,
+        context: *mut c_void,
+    ) -> bool {
+        let context = &*(context as *const Context<'_>);
+        let font_index =
+            find_best_font_match(context.fonts, starter, context.font_style).unwrap_or(0);
+        context.fonts[font_index].has_glyph(composed)
+    }
+    unsafe extern "C" fn normalized_callback(unichar: u32, offset: usize, context: *mut c_void) {
+        let context = &mut *(context as *mut Context<'_>);
+        context.codepoints.push(unichar);
+        context.offsets.push(offset);
+    }
+    let (raw, filter): (_, ffi::SubsetCompositionFilter) = match normalization {
+        Normalization::Nfc => (0, None),
+        Normalization::Nfd => (1, None),
+        Normalization::BestForFont => (0, Some(composition_filter)),
+    };
+    let mut context = Context {
+        fonts,
+        font_style,
+        codepoints: Vec::with_capacity(codepoints.len()),
+        offsets: Vec::with_capacity(codepoints.len()),
+    };
+    unsafe {
+        ffi::subset_normalize(
+            codepoints.as_ptr(),
+            codepoints.len(),
+            raw,
+            filter,
+            Some(normalized_callback),
+            &mut context as *mut _ as *mut _,
+        );
+    }
+    (context.codepoints, context.offsets)
+}
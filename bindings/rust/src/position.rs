@@ -0,0 +1,131 @@
+use crate::layout::glyph_scale;
+use crate::{
+    GlyphRun, Layout, PositionedGlyph, PositionedObject, Rect, VerticalOrientation, WritingMode,
+};
+
+#[derive(Debug, Clone)]
+pub struct PositionedGlyphs<'a> {
+    layout: &'a Layout<'a>,
+    glyph_runs: &'a [GlyphRun],
+    run_index: usize,
+    glyph_index: usize,
+    x: f32,
+    y: f32,
+    origin: (f32, f32),
+    scale: f32,
+}
+
+impl<'a> PositionedGlyphs<'a> {
+    pub fn with_origin(mut self, x: f32, y: f32) -> Self {
+        self.origin = (x, y);
+        self
+    }
+
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+impl<'a> Iterator for PositionedGlyphs<'a> {
+    type Item = PositionedGlyph;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let glyph_run = self.glyph_runs.get(self.run_index)?;
+            let Some(glyph) = glyph_run.glyphs.get(self.glyph_index) else {
+                self.run_index += 1;
+                self.glyph_index = 0;
+                continue;
+            };
+            let font_run = &self.layout.runs()[glyph_run.font_run_index];
+            let font_scale = self.layout.font_scale(font_run.font_index);
+            let sideways = font_run.vertical_orientation == Some(VerticalOrientation::Sideways);
+            let (x, y) = if sideways {
+                // N.B. Sideways glyphs are rotated 90 degrees clockwise around
+                // their origin, with the central baseline on the vertical line.
+                let central = self.layout.fonts()[font_run.font_index]
+                    .horizontal_extents()
+                    .map_or(0.0, |v| (v.ascender + v.descender) as f32 / 2.0);
+                let x = self.x + (glyph.y_offset as f32 - central) * font_scale;
+                let y = self.y - glyph.x_offset as f32 * font_scale;
+                self.y -= glyph.x_advance as f32 * font_scale;
+                (x, y)
+            } else {
+                let x = self.x + glyph.x_offset as f32 * font_scale;
+                let y = self.y + glyph.y_offset as f32 * font_scale;
+                self.x += glyph.x_advance as f32 * font_scale;
+                self.y += glyph.y_advance as f32 * font_scale;
+                (x, y)
+            };
+            if font_run.object_index.is_some() {
+                self.glyph_index += 1;
+                continue;
+            }
+            let item = PositionedGlyph {
+                font_index: font_run.font_index,
+                glyph_id: glyph.glyph_id,
+                x: self.origin.0 + x * self.scale,
+                y: self.origin.1 + y * self.scale,
+                scale: font_scale * glyph_scale(font_run) * self.scale,
+                sideways,
+                cluster: glyph.cluster as usize,
+                run_index: self.run_index,
+                glyph_index: self.glyph_index,
+                attribute: font_run.attribute,
+            };
+            self.glyph_index += 1;
+            return Some(item);
+        }
+    }
+}
+
+impl<'a> Layout<'a> {
+    pub fn positioned_glyphs(&'a self, glyph_runs: &'a [GlyphRun]) -> PositionedGlyphs<'a> {
+        PositionedGlyphs {
+            layout: self,
+            glyph_runs,
+            run_index: 0,
+            glyph_index: 0,
+            x: 0.0,
+            y: 0.0,
+            origin: (0.0, 0.0),
+            scale: 1.0,
+        }
+    }
+
+    // N.B. Object rects are in layout space, sitting on the baseline, or
+    // centered on the vertical line in vertical layouts.
+    pub fn positioned_objects(&self, glyph_runs: &[GlyphRun]) -> Vec<PositionedObject> {
+        let mut objects = Vec::new();
+        for cluster in self.clusters(glyph_runs) {
+            let font_run = &self.runs()[glyph_runs[cluster.run_index].font_run_index];
+            let Some(object_index) = font_run.object_index else {
+                continue;
+            };
+            let (offset, object) = self.objects()[object_index];
+            let height = object.ascent + object.descent;
+            let rect = match self.writing_mode() {
+                WritingMode::Horizontal => Rect {
+                    x: cluster.x,
+                    y: -object.descent,
+                    width: cluster.advance,
+                    height,
+                },
+                WritingMode::Vertical => Rect {
+                    x: -height / 2.0,
+                    y: -(cluster.x + cluster.advance),
+                    width: height,
+                    height: cluster.advance,
+                },
+            };
+            objects.push(PositionedObject {
+                user_id: object.user_id,
+                offset,
+                rect,
+                attribute: font_run.attribute,
+            });
+        }
+        objects
+    }
+}
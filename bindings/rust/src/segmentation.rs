@@ -0,0 +1,95 @@
+use std::ffi::{c_void, CString};
+use std::{ptr, vec};
+
+use crate::{ffi, Layout, Segmentation};
+
+#[derive(Debug, Clone)]
+pub struct Boundaries(vec::IntoIter<usize>);
+
+impl Iterator for Boundaries {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl DoubleEndedIterator for Boundaries {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+// N.B. The boundaries include the start and the end of the text, and the
+// language only tailors word boundaries. Hard line break boundaries are the
+// paragraph boundaries of the bidi algorithm, too.
+pub fn boundaries(
+    codepoints: &[u32],
+    segmentation: Segmentation,
+    language: Option<&str>,
+) -> Boundaries {
+    unsafe extern "C" fn boundary_callback(offset: usize, context: *mut c_void) {
+        let offsets: &mut Vec<usize> = &mut *(context as *mut Vec<usize>);
+        offsets.push(offset);
+    }
+    let segmentation = match segmentation {
+        Segmentation::Grapheme => 0,
+        Segmentation::Word => 1,
+        Segmentation::Sentence => 2,
+        Segmentation::HardLineBreak => 3,
+    };
+    let language = language.map(CString::new).transpose().ok().flatten();
+    let mut offsets = Vec::new();
+    unsafe {
+        ffi::subset_segment(
+            codepoints.as_ptr(),
+            codepoints.len(),
+            segmentation,
+            language.as_ref().map_or(ptr::null(), |v| v.as_ptr()),
+            Some(boundary_callback),
+            &mut offsets as *mut _ as *mut _,
+        );
+    }
+    Boundaries(offsets.into_iter())
+}
+
+// N.B. The boundaries of a layout are in offsets of the text as pushed, found
+// in the normalized codepoints so they agree with the carets.
+impl Layout<'_> {
+    pub fn grapheme_boundaries(&self) -> Boundaries {
+        self.original_boundaries(Segmentation::Grapheme, None)
+    }
+
+    pub fn word_boundaries(&self, language: Option<&str>) -> Boundaries {
+        self.original_boundaries(Segmentation::Word, language)
+    }
+
+    pub fn sentence_boundaries(&self) -> Boundaries {
+        self.original_boundaries(Segmentation::Sentence, None)
+    }
+
+    pub fn hard_line_break_boundaries(&self) -> Boundaries {
+        self.original_boundaries(Segmentation::HardLineBreak, None)
+    }
+
+    pub(crate) fn normalized_boundaries(
+        &self,
+        segmentation: Segmentation,
+        language: Option<&str>,
+    ) -> Boundaries {
+        boundaries(self.codepoints(), segmentation, language)
+    }
+
+    fn original_boundaries(
+        &self,
+        segmentation: Segmentation,
+        language: Option<&str>,
+    ) -> Boundaries {
+        let mut offsets = self
+            .normalized_boundaries(segmentation, language)
+            .map(|v| self.original_offset(v))
+            .collect::<Vec<_>>();
+        offsets.dedup();
+        Boundaries(offsets.into_iter())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::metrics::Cluster;
+use crate::{GlyphRun, Layout, Segmentation, TabStops, VerticalOrientation};
+
+const TAB: u32 = 0x0009;
+const TAB_SIZE: f32 = 8.0;
+
+impl Layout<'_> {
+    // N.B. Tabs are measured from the start edge of their paragraph, i.e. the
+    // right one in RTL paragraphs, since bidi resolves them to the paragraph
+    // level.
+    pub(crate) fn expand_tabs(&self, glyph_runs: &mut [GlyphRun], tab_stops: Option<TabStops>) {
+        let interval = self.default_tab_interval();
+        let clusters = self.clusters(glyph_runs);
+        let paragraphs = self
+            .normalized_boundaries(Segmentation::HardLineBreak, None)
+            .collect::<Vec<_>>();
+        let paragraph =
+            |cluster: &Cluster| paragraphs.partition_point(|v| *v <= cluster.range.start);
+        // N.B. The runs of every paragraph follow those of the one before it.
+        let mut start = 0;
+        while start < clusters.len() {
+            let end = clusters[start..]
+                .iter()
+                .position(|v| paragraph(v) != paragraph(&clusters[start]))
+                .map_or(clusters.len(), |v| start + v);
+            self.expand_paragraph_tabs(glyph_runs, &clusters[start..end], tab_stops, interval);
+            start = end;
+        }
+    }
+
+    fn expand_paragraph_tabs(
+        &self,
+        glyph_runs: &mut [GlyphRun],
+        clusters: &[Cluster],
+        tab_stops: Option<TabStops>,
+        interval: f32,
+    ) {
+        let x = clusters[0].x;
+        let width = clusters.iter().map(|v| v.advance).sum::<f32>();
+        let paragraph_level =
+            self.runs()[glyph_runs[clusters[0].run_index].font_run_index].paragraph_level;
+        let rtl = (paragraph_level & 1) != 0;
+        let mut clusters = clusters.iter().collect::<Vec<_>>();
+        if rtl {
+            clusters.reverse();
+        }
+        let mut shift = 0.0;
+        for cluster in clusters {
+            if self.codepoints()[cluster.range.start] != TAB {
+                continue;
+            }
+            let start = shift
+                + if rtl {
+                    x + width - cluster.x - cluster.advance
+                } else {
+                    cluster.x - x
+                };
+            let stop = next_tab_stop(start, tab_stops, interval);
+            let glyph_run = &mut glyph_runs[cluster.run_index];
+            let font_run = &self.runs()[glyph_run.font_run_index];
+            let scale = self.font_scale(font_run.font_index);
+            let delta = ((stop - start - cluster.advance) / scale).round() as i32;
+            let glyph = &mut glyph_run.glyphs[cluster.glyphs.end - 1];
+            if font_run.vertical_orientation == Some(VerticalOrientation::Upright) {
+                glyph.y_advance -= delta;
+            } else {
+                glyph.x_advance += delta;
+            }
+            shift += delta as f32 * scale;
+        }
+    }
+
+    fn default_tab_interval(&self) -> f32 {
+        let font = &self.fonts()[0];
+        let advance = font
+            .nominal_glyph_id(' ')
+            .map_or(0, |v| font.glyph_advance(v, self.writing_mode()).abs());
+        // N.B. A quarter em is a common space width for fonts without one.
+        let advance = if advance > 0 {
+            advance as f32
+        } else {
+            font.upem() as f32 / 4.0
+        };
+        advance * TAB_SIZE
+    }
+}
+
+fn next_tab_stop(x: f32, tab_stops: Option<TabStops>, interval: f32) -> f32 {
+    let interval = match tab_stops {
+        Some(TabStops::Interval(interval)) if interval > 0.0 => interval,
+        Some(TabStops::Positions(positions)) => {
+            if let Some(position) = positions
+                .iter()
+                .copied()
+                .filter(|v| *v > x)
+                .min_by(|a, b| a.total_cmp(b))
+            {
+                return position;
+            }
+            interval
+        }
+        _ => interval,
+    };
+    ((x / interval).floor() + 1.0) * interval
+}
@@ -0,0 +1,94 @@
+use std::ffi::{c_void, CString};
+use std::ptr;
+
+use crate::{
+    ffi, find_best_font_match, unicode_properties, Font, FontStyle, GeneralCategory, TextTransform,
+};
+
+// N.B. The scale browsers draw synthesized small caps at.
+pub(crate) const SMALL_CAPS_SCALE: f32 = 0.7;
+
+// N.B. Returns the transformed codepoints and, for each of them, the offset of
+// the codepoint of `codepoints` it comes from.
+pub(crate) fn transform_text(
+    codepoints: &[u32],
+    transform: TextTransform,
+    language: Option<&str>,
+) -> (Vec<u32>, Vec<usize>) {
+    struct Context {
+        codepoints: Vec<u32>,
+        offsets: Vec<usize>,
+    }
+    unsafe extern "C" fn transformed_callback(unichar: u32, offset: usize, context: *mut c_void) {
+        let context = &mut *(context as *mut Context);
+        context.codepoints.push(unichar);
+        context.offsets.push(offset);
+    }
+    let language = language.map(CString::new).transpose().ok().flatten();
+    let mut context = Context {
+        codepoints: Vec::with_capacity(codepoints.len()),
+        offsets: Vec::with_capacity(codepoints.len()),
+    };
+    unsafe {
+        ffi::subset_transform_text(
+            codepoints.as_ptr(),
+            codepoints.len(),
+            match transform {
+                TextTransform::Uppercase => 0,
+                TextTransform::Lowercase => 1,
+                TextTransform::Capitalize => 2,
+            },
+            language.as_ref().map_or(ptr::null(), |v| v.as_ptr()),
+            Some(transformed_callback),
+            &mut context as *mut _ as *mut _,
+        );
+    }
+    (context.codepoints, context.offsets)
+}
+
+// N.B. Characters with an uppercase mapping whose font lacks `smcp` are
+// replaced with it and flagged to be shaped at `SMALL_CAPS_SCALE`. Marks go
+// along with their base character.
+pub(crate) fn synthesize_small_caps(
+    codepoints: &[u32],
+    fonts: &[Font],
+    font_style: FontStyle,
+    language: Option<&str>,
+) -> (Vec<u32>, Vec<usize>, Vec<bool>) {
+    let (uppercase, uppercase_offsets) =
+        transform_text(codepoints, TextTransform::Uppercase, language);
+    let mut result = (
+        Vec::with_capacity(uppercase.len()),
+        Vec::with_capacity(uppercase.len()),
+        Vec::with_capacity(uppercase.len()),
+    );
+    let mut synthetic = false;
+    let mut start = 0;
+    for (offset, codepoint) in codepoints.iter().copied().enumerate() {
+        let end = start + uppercase_offsets[start..].partition_point(|v| *v <= offset);
+        let mapping = &uppercase[start..end];
+        start = end;
+        let is_mark = matches!(
+            unicode_properties(codepoint).general_category,
+            GeneralCategory::SpacingMark
+                | GeneralCategory::EnclosingMark
+                | GeneralCategory::NonSpacingMark
+        );
+        if !is_mark || offset == 0 {
+            synthetic = mapping != [codepoint] && {
+                let font_index = find_best_font_match(fonts, codepoint, font_style).unwrap_or(0);
+                !fonts[font_index].has_feature(b"smcp")
+            };
+        }
+        if synthetic {
+            result.0.extend_from_slice(mapping);
+            result.1.extend(mapping.iter().map(|_| offset));
+            result.2.extend(mapping.iter().map(|_| true));
+        } else {
+            result.0.push(codepoint);
+            result.1.push(offset);
+            result.2.push(false);
+        }
+    }
+    result
+}
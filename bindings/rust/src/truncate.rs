@@ -0,0 +1,273 @@
+use std::ops::Range;
+
+use crate::metrics::inline_advance;
+use crate::{
+    find_best_font_match, EllipsisPosition, FontRun, GlyphRun, Layout, Segmentation, ShapeParams,
+};
+
+const ELLIPSIS: [u32; 1] = [0x2026];
+const FULL_STOPS: [u32; 3] = [0x2E; 3];
+
+// N.B. `elided` is the range of the text as pushed that was removed, and
+// `ellipsis_run` the index of the font run of the truncated layout that
+// replaced it. Offsets of the truncated layout are in the text as pushed, too.
+#[derive(Debug, Clone)]
+pub struct Truncation<'a> {
+    pub layout: Layout<'a>,
+    pub glyph_runs: Vec<GlyphRun>,
+    pub elided: Range<usize>,
+    pub ellipsis_run: usize,
+}
+
+impl<'a> Layout<'a> {
+    // N.B. Whole graphemes are removed in logical order. At the end or start,
+    // the ellipsis takes the paragraph direction so it lands on that visual
+    // edge of the line, and in the middle, the direction of the text before it.
+    // Returns `None` if the text already fits.
+    pub fn truncate(
+        &self,
+        glyph_runs: &[GlyphRun],
+        max_width: f32,
+        position: EllipsisPosition,
+        params: ShapeParams,
+    ) -> Option<Truncation<'a>> {
+        if self.codepoints().is_empty() || self.advance(glyph_runs) <= max_width {
+            return None;
+        }
+        let graphemes = self.grapheme_advances(glyph_runs);
+        let len = self.codepoints().len();
+        let offset = match position {
+            EllipsisPosition::Start => 0,
+            EllipsisPosition::Middle => len / 2,
+            EllipsisPosition::End => len - 1,
+        };
+        let (ellipsis, font_run) = self.ellipsis_run(&self.run_at(offset), offset, 0);
+        let scale = self.font_scale(font_run.font_index);
+        let ellipsis_advance = self
+            .shape_run(ellipsis, &font_run, 0, &params, None)
+            .glyphs
+            .iter()
+            .map(|v| inline_advance(v, &font_run) as f32 * scale)
+            .sum::<f32>();
+        let (mut front, mut back) = elide(&graphemes, max_width - ellipsis_advance, position);
+        loop {
+            let start = graphemes.get(front).map_or(len, |v| v.0.start);
+            let end = graphemes.get(back).map_or(len, |v| v.0.start);
+            let truncation = self.truncated(start..end, position, params);
+            if (front == 0 && back == graphemes.len())
+                || truncation.layout.advance(&truncation.glyph_runs) <= max_width
+            {
+                return Some(truncation);
+            }
+            // N.B. Shaping across the cut may make the text wider than measured.
+            let elide_front = match position {
+                EllipsisPosition::Start => back == graphemes.len(),
+                EllipsisPosition::Middle => front > graphemes.len() - back,
+                EllipsisPosition::End => front > 0,
+            };
+            if elide_front {
+                front -= 1;
+            } else {
+                back += 1;
+            }
+        }
+    }
+
+    fn truncated(
+        &self,
+        elided: Range<usize>,
+        position: EllipsisPosition,
+        params: ShapeParams,
+    ) -> Truncation<'a> {
+        let codepoints = self.codepoints();
+        let neighbour = match position {
+            EllipsisPosition::Start => elided.end,
+            EllipsisPosition::Middle | EllipsisPosition::End => elided.start.saturating_sub(1),
+        };
+        let neighbour = neighbour.min(codepoints.len() - 1);
+        let paragraph = self.paragraph_at(neighbour);
+        let neighbour = self.run_at(neighbour);
+        let middle = position == EllipsisPosition::Middle && elided.start > 0;
+        let bidi_level = if middle {
+            neighbour.bidi_level
+        } else {
+            neighbour.paragraph_level
+        };
+        let (ellipsis, ellipsis_run) = self.ellipsis_run(&neighbour, elided.start, bidi_level);
+        let mut truncated = codepoints[..elided.start].to_vec();
+        truncated.extend_from_slice(ellipsis);
+        truncated.extend_from_slice(&codepoints[elided.end..]);
+        let mut runs = Vec::with_capacity(self.runs().len() + 2);
+        let mut middle_index = None;
+        let mut paragraph_runs: Option<Range<usize>> = None;
+        for run in self.runs() {
+            let start = runs.len();
+            let end = run.offset + run.len;
+            let before = (run.offset < elided.start).then(|| FontRun {
+                len: end.min(elided.start) - run.offset,
+                ..*run
+            });
+            let after = (end > elided.end).then(|| {
+                let offset = run.offset.max(elided.end);
+                FontRun {
+                    offset: offset - elided.len() + ellipsis.len(),
+                    len: end - offset,
+                    ..*run
+                }
+            });
+            let (first, second) = if (run.bidi_level & 1) != 0 {
+                (after, before)
+            } else {
+                (before, after)
+            };
+            runs.extend(first);
+            // N.B. A middle ellipsis follows the text before it in its own
+            // direction, i.e. between the pieces of the run that held it.
+            if middle && run.offset < elided.start && end >= elided.start {
+                middle_index = Some(runs.len());
+            }
+            runs.extend(second);
+            if paragraph.contains(&run.offset) {
+                paragraph_runs =
+                    Some(paragraph_runs.map_or(start..runs.len(), |v| v.start..runs.len()));
+            }
+        }
+        let at_end = match position {
+            EllipsisPosition::End => true,
+            EllipsisPosition::Start | EllipsisPosition::Middle => false,
+        };
+        // N.B. Otherwise the ellipsis goes to an edge of the paragraph it's in.
+        let paragraph_runs = paragraph_runs.unwrap_or(0..runs.len());
+        let index = middle_index.unwrap_or(if at_end == ((bidi_level & 1) == 0) {
+            paragraph_runs.end
+        } else {
+            paragraph_runs.start
+        });
+        runs.insert(index, ellipsis_run);
+        let layout = self.derive(truncated, runs, elided.clone(), ellipsis.len());
+        let glyph_runs = layout.shape(params);
+        let elided = self.original_offset(elided.start)..self.original_offset(elided.end);
+        Truncation {
+            layout,
+            glyph_runs,
+            elided,
+            ellipsis_run: index,
+        }
+    }
+
+    // N.B. Falls back to three full stops if no font has an ellipsis.
+    fn ellipsis_run(
+        &self,
+        neighbour: &FontRun,
+        offset: usize,
+        bidi_level: u8,
+    ) -> (&'static [u32], FontRun) {
+        let style = neighbour.font_style;
+        let (codepoints, font_index) = match find_best_font_match(self.fonts(), ELLIPSIS[0], style)
+        {
+            Some(font_index) => (&ELLIPSIS[..], font_index),
+            None => (
+                &FULL_STOPS[..],
+                find_best_font_match(self.fonts(), FULL_STOPS[0], style).unwrap_or(0),
+            ),
+        };
+        let font_style = self.fonts()[font_index].style();
+        let font_run = FontRun {
+            offset,
+            len: codepoints.len(),
+            bidi_level,
+            emoji_presentation: None,
+            font_index,
+            synthetic_bold: style.weight > font_style.weight,
+            synthetic_slant: style.italic && !font_style.italic,
+            synthetic_small_caps: false,
+            object_index: None,
+            ..*neighbour
+        };
+        (codepoints, font_run)
+    }
+
+    fn paragraph_at(&self, offset: usize) -> Range<usize> {
+        let paragraphs = self
+            .normalized_boundaries(Segmentation::HardLineBreak, None)
+            .collect::<Vec<_>>();
+        let index = paragraphs.partition_point(|v| *v <= offset);
+        paragraphs[index - 1]
+            ..paragraphs
+                .get(index)
+                .copied()
+                .unwrap_or(self.codepoints().len())
+    }
+
+    fn run_at(&self, offset: usize) -> FontRun {
+        self.runs()
+            .iter()
+            .find(|v| v.offset <= offset && offset < v.offset + v.len)
+            .copied()
+            .unwrap_or(self.runs()[0])
+    }
+
+    fn grapheme_advances(&self, glyph_runs: &[GlyphRun]) -> Vec<(Range<usize>, f32)> {
+        let boundaries = self
+            .normalized_boundaries(Segmentation::Grapheme, None)
+            .collect::<Vec<_>>();
+        let mut graphemes = boundaries
+            .windows(2)
+            .map(|v| (v[0]..v[1], 0.0))
+            .collect::<Vec<_>>();
+        for cell in &self.cells(glyph_runs).cells {
+            let index = boundaries.partition_point(|v| *v <= cell.range.start);
+            if let Some(grapheme) = index.checked_sub(1).and_then(|v| graphemes.get_mut(v)) {
+                grapheme.1 += cell.advance;
+            }
+        }
+        graphemes
+    }
+}
+
+// N.B. Returns the number of graphemes kept at the front and the index of the
+// first one kept at the back.
+fn elide(
+    graphemes: &[(Range<usize>, f32)],
+    available: f32,
+    position: EllipsisPosition,
+) -> (usize, usize) {
+    let mut front = 0;
+    let mut back = graphemes.len();
+    let mut used = 0.0;
+    let mut take = |index: usize| {
+        if used + graphemes[index].1 <= available {
+            used += graphemes[index].1;
+            true
+        } else {
+            false
+        }
+    };
+    match position {
+        EllipsisPosition::Start => {
+            while back > front && take(back - 1) {
+                back -= 1;
+            }
+        }
+        EllipsisPosition::Middle => loop {
+            let mut taken = false;
+            if front < back && take(front) {
+                front += 1;
+                taken = true;
+            }
+            if front < back && take(back - 1) {
+                back -= 1;
+                taken = true;
+            }
+            if !taken {
+                break;
+            }
+        },
+        EllipsisPosition::End => {
+            while front < back && take(front) {
+                front += 1;
+            }
+        }
+    }
+    (front, back)
+}
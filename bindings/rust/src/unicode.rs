@@ -0,0 +1,44 @@
+use crate::{ffi, BidiClass, GeneralCategory, Layout, UnicodeProperties, UnicodeValue};
+
+pub fn unicode_properties(unicode_value: impl Into<UnicodeValue>) -> UnicodeProperties {
+    let unichar: u32 = unicode_value.into().into();
+    let mut properties = UnicodeProperties {
+        general_category: GeneralCategory::Unassigned,
+        script: 0,
+        mirroring: unichar,
+        combining_class: 0,
+        bidi_class: BidiClass::OtherNeutral,
+    };
+    unsafe {
+        ffi::subset_unicode_properties(unichar, &mut properties as *mut _);
+    }
+    properties
+}
+
+impl Layout<'_> {
+    // N.B. These are the levels of the runs, i.e. after trailing whitespace was
+    // reset to the level of its paragraph, one per codepoint of the text as
+    // pushed. Codepoints merged into another by normalization take its level.
+    pub fn bidi_levels(&self) -> Vec<u8> {
+        let mut levels = vec![0; self.codepoints().len()];
+        for run in self.runs() {
+            levels[run.offset..run.offset + run.len].fill(run.bidi_level);
+        }
+        let mut index = 0;
+        (0..self.original_codepoints().len())
+            .map(|offset| {
+                while index + 1 < levels.len() && self.original_offset(index + 1) <= offset {
+                    index += 1;
+                }
+                levels.get(index).copied().unwrap_or(0)
+            })
+            .collect()
+    }
+
+    pub fn bidi_classes(&self) -> Vec<BidiClass> {
+        self.original_codepoints()
+            .iter()
+            .map(|v| unicode_properties(v).bidi_class)
+            .collect()
+    }
+}
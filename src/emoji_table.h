@@ -0,0 +1,299 @@
+// Generated by gen_unicode_tables.py from unofficial Unicode 15.1.0
+// emoji-data.txt.
+// N.B. These tables were generated from files rebuilt offline, not the ones of
+// the UCD: segmentation and vertical orientation from ICU 72 (Unicode 15.0)
+// plus the 15.1 changes, Indic_Conjunct_Break inferred from the GB9c rules,
+// emoji from the unicode-properties crate, Script_Extensions from the
+// unicode-script crate and case mappings from Python 3.13. Regenerate them from
+// unicode.org.
+
+#pragma once
+
+#include <array>
+#include <cstdint>
+
+namespace subset {
+constexpr uint8_t kEmoji = 1U << 0U;
+constexpr uint8_t kEmojiPresentation = 1U << 1U;
+constexpr uint8_t kEmojiModifierBase = 1U << 2U;
+constexpr uint8_t kEmojiModifier = 1U << 3U;
+constexpr uint8_t kEmojiComponent = 1U << 4U;
+
+struct EmojiRange {
+  uint32_t first;
+  uint32_t last;
+  uint8_t properties;
+};
+
+inline constexpr std::array<EmojiRange, 268> kEmojiRanges{{
+    {0x0023, 0x0023, kEmoji | kEmojiComponent},
+    {0x002A, 0x002A, kEmoji | kEmojiComponent},
+    {0x0030, 0x0039, kEmoji | kEmojiComponent},
+    {0x00A9, 0x00A9, kEmoji},
+    {0x00AE, 0x00AE, kEmoji},
+    {0x200D, 0x200D, kEmojiComponent},
+    {0x203C, 0x203C, kEmoji},
+    {0x2049, 0x2049, kEmoji},
+    {0x20E3, 0x20E3, kEmojiComponent},
+    {0x2122, 0x2122, kEmoji},
+    {0x2139, 0x2139, kEmoji},
+    {0x2194, 0x2199, kEmoji},
+    {0x21A9, 0x21AA, kEmoji},
+    {0x231A, 0x231B, kEmoji | kEmojiPresentation},
+    {0x2328, 0x2328, kEmoji},
+    {0x23CF, 0x23CF, kEmoji},
+    {0x23E9, 0x23EC, kEmoji | kEmojiPresentation},
+    {0x23ED, 0x23EF, kEmoji},
+    {0x23F0, 0x23F0, kEmoji | kEmojiPresentation},
+    {0x23F1, 0x23F2, kEmoji},
+    {0x23F3, 0x23F3, kEmoji | kEmojiPresentation},
+    {0x23F8, 0x23FA, kEmoji},
+    {0x24C2, 0x24C2, kEmoji},
+    {0x25AA, 0x25AB, kEmoji},
+    {0x25B6, 0x25B6, kEmoji},
+    {0x25C0, 0x25C0, kEmoji},
+    {0x25FB, 0x25FC, kEmoji},
+    {0x25FD, 0x25FE, kEmoji | kEmojiPresentation},
+    {0x2600, 0x2604, kEmoji},
+    {0x260E, 0x260E, kEmoji},
+    {0x2611, 0x2611, kEmoji},
+    {0x2614, 0x2615, kEmoji | kEmojiPresentation},
+    {0x2618, 0x2618, kEmoji},
+    {0x261D, 0x261D, kEmoji | kEmojiModifierBase},
+    {0x2620, 0x2620, kEmoji},
+    {0x2622, 0x2623, kEmoji},
+    {0x2626, 0x2626, kEmoji},
+    {0x262A, 0x262A, kEmoji},
+    {0x262E, 0x262F, kEmoji},
+    {0x2638, 0x263A, kEmoji},
+    {0x2640, 0x2640, kEmoji},
+    {0x2642, 0x2642, kEmoji},
+    {0x2648, 0x2653, kEmoji | kEmojiPresentation},
+    {0x265F, 0x2660, kEmoji},
+    {0x2663, 0x2663, kEmoji},
+    {0x2665, 0x2666, kEmoji},
+    {0x2668, 0x2668, kEmoji},
+    {0x267B, 0x267B, kEmoji},
+    {0x267E, 0x267E, kEmoji},
+    {0x267F, 0x267F, kEmoji | kEmojiPresentation},
+    {0x2692, 0x2692, kEmoji},
+    {0x2693, 0x2693, kEmoji | kEmojiPresentation},
+    {0x2694, 0x2697, kEmoji},
+    {0x2699, 0x2699, kEmoji},
+    {0x269B, 0x269C, kEmoji},
+    {0x26A0, 0x26A0, kEmoji},
+    {0x26A1, 0x26A1, kEmoji | kEmojiPresentation},
+    {0x26A7, 0x26A7, kEmoji},
+    {0x26AA, 0x26AB, kEmoji | kEmojiPresentation},
+    {0x26B0, 0x26B1, kEmoji},
+    {0x26BD, 0x26BE, kEmoji | kEmojiPresentation},
+    {0x26C4, 0x26C5, kEmoji | kEmojiPresentation},
+    {0x26C8, 0x26C8, kEmoji},
+    {0x26CE, 0x26CE, kEmoji | kEmojiPresentation},
+    {0x26CF, 0x26CF, kEmoji},
+    {0x26D1, 0x26D1, kEmoji},
+    {0x26D3, 0x26D3, kEmoji},
+    {0x26D4, 0x26D4, kEmoji | kEmojiPresentation},
+    {0x26E9, 0x26E9, kEmoji},
+    {0x26EA, 0x26EA, kEmoji | kEmojiPresentation},
+    {0x26F0, 0x26F1, kEmoji},
+    {0x26F2, 0x26F3, kEmoji | kEmojiPresentation},
+    {0x26F4, 0x26F4, kEmoji},
+    {0x26F5, 0x26F5, kEmoji | kEmojiPresentation},
+    {0x26F7, 0x26F8, kEmoji},
+    {0x26F9, 0x26F9, kEmoji | kEmojiModifierBase},
+    {0x26FA, 0x26FA, kEmoji | kEmojiPresentation},
+    {0x26FD, 0x26FD, kEmoji | kEmojiPresentation},
+    {0x2702, 0x2702, kEmoji},
+    {0x2705, 0x2705, kEmoji | kEmojiPresentation},
+    {0x2708, 0x2709, kEmoji},
+    {0x270A, 0x270B, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x270C, 0x270D, kEmoji | kEmojiModifierBase},
+    {0x270F, 0x270F, kEmoji},
+    {0x2712, 0x2712, kEmoji},
+    {0x2714, 0x2714, kEmoji},
+    {0x2716, 0x2716, kEmoji},
+    {0x271D, 0x271D, kEmoji},
+    {0x2721, 0x2721, kEmoji},
+    {0x2728, 0x2728, kEmoji | kEmojiPresentation},
+    {0x2733, 0x2734, kEmoji},
+    {0x2744, 0x2744, kEmoji},
+    {0x2747, 0x2747, kEmoji},
+    {0x274C, 0x274C, kEmoji | kEmojiPresentation},
+    {0x274E, 0x274E, kEmoji | kEmojiPresentation},
+    {0x2753, 0x2755, kEmoji | kEmojiPresentation},
+    {0x2757, 0x2757, kEmoji | kEmojiPresentation},
+    {0x2763, 0x2764, kEmoji},
+    {0x2795, 0x2797, kEmoji | kEmojiPresentation},
+    {0x27A1, 0x27A1, kEmoji},
+    {0x27B0, 0x27B0, kEmoji | kEmojiPresentation},
+    {0x27BF, 0x27BF, kEmoji | kEmojiPresentation},
+    {0x2934, 0x2935, kEmoji},
+    {0x2B05, 0x2B07, kEmoji},
+    {0x2B1B, 0x2B1C, kEmoji | kEmojiPresentation},
+    {0x2B50, 0x2B50, kEmoji | kEmojiPresentation},
+    {0x2B55, 0x2B55, kEmoji | kEmojiPresentation},
+    {0x3030, 0x3030, kEmoji},
+    {0x303D, 0x303D, kEmoji},
+    {0x3297, 0x3297, kEmoji},
+    {0x3299, 0x3299, kEmoji},
+    {0xFE0F, 0xFE0F, kEmojiComponent},
+    {0x1F004, 0x1F004, kEmoji | kEmojiPresentation},
+    {0x1F0CF, 0x1F0CF, kEmoji | kEmojiPresentation},
+    {0x1F170, 0x1F171, kEmoji},
+    {0x1F17E, 0x1F17F, kEmoji},
+    {0x1F18E, 0x1F18E, kEmoji | kEmojiPresentation},
+    {0x1F191, 0x1F19A, kEmoji | kEmojiPresentation},
+    {0x1F1E6, 0x1F1FF, kEmoji | kEmojiPresentation | kEmojiComponent},
+    {0x1F201, 0x1F201, kEmoji | kEmojiPresentation},
+    {0x1F202, 0x1F202, kEmoji},
+    {0x1F21A, 0x1F21A, kEmoji | kEmojiPresentation},
+    {0x1F22F, 0x1F22F, kEmoji | kEmojiPresentation},
+    {0x1F232, 0x1F236, kEmoji | kEmojiPresentation},
+    {0x1F237, 0x1F237, kEmoji},
+    {0x1F238, 0x1F23A, kEmoji | kEmojiPresentation},
+    {0x1F250, 0x1F251, kEmoji | kEmojiPresentation},
+    {0x1F300, 0x1F320, kEmoji | kEmojiPresentation},
+    {0x1F321, 0x1F321, kEmoji},
+    {0x1F324, 0x1F32C, kEmoji},
+    {0x1F32D, 0x1F335, kEmoji | kEmojiPresentation},
+    {0x1F336, 0x1F336, kEmoji},
+    {0x1F337, 0x1F37C, kEmoji | kEmojiPresentation},
+    {0x1F37D, 0x1F37D, kEmoji},
+    {0x1F37E, 0x1F384, kEmoji | kEmojiPresentation},
+    {0x1F385, 0x1F385, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F386, 0x1F393, kEmoji | kEmojiPresentation},
+    {0x1F396, 0x1F397, kEmoji},
+    {0x1F399, 0x1F39B, kEmoji},
+    {0x1F39E, 0x1F39F, kEmoji},
+    {0x1F3A0, 0x1F3C1, kEmoji | kEmojiPresentation},
+    {0x1F3C2, 0x1F3C4, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F3C5, 0x1F3C6, kEmoji | kEmojiPresentation},
+    {0x1F3C7, 0x1F3C7, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F3C8, 0x1F3C9, kEmoji | kEmojiPresentation},
+    {0x1F3CA, 0x1F3CA, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F3CB, 0x1F3CC, kEmoji | kEmojiModifierBase},
+    {0x1F3CD, 0x1F3CE, kEmoji},
+    {0x1F3CF, 0x1F3D3, kEmoji | kEmojiPresentation},
+    {0x1F3D4, 0x1F3DF, kEmoji},
+    {0x1F3E0, 0x1F3F0, kEmoji | kEmojiPresentation},
+    {0x1F3F3, 0x1F3F3, kEmoji},
+    {0x1F3F4, 0x1F3F4, kEmoji | kEmojiPresentation},
+    {0x1F3F5, 0x1F3F5, kEmoji},
+    {0x1F3F7, 0x1F3F7, kEmoji},
+    {0x1F3F8, 0x1F3FA, kEmoji | kEmojiPresentation},
+    {0x1F3FB, 0x1F3FF,
+     kEmoji | kEmojiPresentation | kEmojiModifier | kEmojiComponent},
+    {0x1F400, 0x1F43E, kEmoji | kEmojiPresentation},
+    {0x1F43F, 0x1F43F, kEmoji},
+    {0x1F440, 0x1F440, kEmoji | kEmojiPresentation},
+    {0x1F441, 0x1F441, kEmoji},
+    {0x1F442, 0x1F443, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F444, 0x1F445, kEmoji | kEmojiPresentation},
+    {0x1F446, 0x1F450, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F451, 0x1F465, kEmoji | kEmojiPresentation},
+    {0x1F466, 0x1F478, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F479, 0x1F47B, kEmoji | kEmojiPresentation},
+    {0x1F47C, 0x1F47C, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F47D, 0x1F480, kEmoji | kEmojiPresentation},
+    {0x1F481, 0x1F483, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F484, 0x1F484, kEmoji | kEmojiPresentation},
+    {0x1F485, 0x1F487, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F488, 0x1F48E, kEmoji | kEmojiPresentation},
+    {0x1F48F, 0x1F48F, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F490, 0x1F490, kEmoji | kEmojiPresentation},
+    {0x1F491, 0x1F491, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F492, 0x1F4A9, kEmoji | kEmojiPresentation},
+    {0x1F4AA, 0x1F4AA, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F4AB, 0x1F4FC, kEmoji | kEmojiPresentation},
+    {0x1F4FD, 0x1F4FD, kEmoji},
+    {0x1F4FF, 0x1F53D, kEmoji | kEmojiPresentation},
+    {0x1F549, 0x1F54A, kEmoji},
+    {0x1F54B, 0x1F54E, kEmoji | kEmojiPresentation},
+    {0x1F550, 0x1F567, kEmoji | kEmojiPresentation},
+    {0x1F56F, 0x1F570, kEmoji},
+    {0x1F573, 0x1F573, kEmoji},
+    {0x1F574, 0x1F575, kEmoji | kEmojiModifierBase},
+    {0x1F576, 0x1F579, kEmoji},
+    {0x1F57A, 0x1F57A, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F587, 0x1F587, kEmoji},
+    {0x1F58A, 0x1F58D, kEmoji},
+    {0x1F590, 0x1F590, kEmoji | kEmojiModifierBase},
+    {0x1F595, 0x1F596, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F5A4, 0x1F5A4, kEmoji | kEmojiPresentation},
+    {0x1F5A5, 0x1F5A5, kEmoji},
+    {0x1F5A8, 0x1F5A8, kEmoji},
+    {0x1F5B1, 0x1F5B2, kEmoji},
+    {0x1F5BC, 0x1F5BC, kEmoji},
+    {0x1F5C2, 0x1F5C4, kEmoji},
+    {0x1F5D1, 0x1F5D3, kEmoji},
+    {0x1F5DC, 0x1F5DE, kEmoji},
+    {0x1F5E1, 0x1F5E1, kEmoji},
+    {0x1F5E3, 0x1F5E3, kEmoji},
+    {0x1F5E8, 0x1F5E8, kEmoji},
+    {0x1F5EF, 0x1F5EF, kEmoji},
+    {0x1F5F3, 0x1F5F3, kEmoji},
+    {0x1F5FA, 0x1F5FA, kEmoji},
+    {0x1F5FB, 0x1F644, kEmoji | kEmojiPresentation},
+    {0x1F645, 0x1F647, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F648, 0x1F64A, kEmoji | kEmojiPresentation},
+    {0x1F64B, 0x1F64F, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F680, 0x1F6A2, kEmoji | kEmojiPresentation},
+    {0x1F6A3, 0x1F6A3, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F6A4, 0x1F6B3, kEmoji | kEmojiPresentation},
+    {0x1F6B4, 0x1F6B6, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F6B7, 0x1F6BF, kEmoji | kEmojiPresentation},
+    {0x1F6C0, 0x1F6C0, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F6C1, 0x1F6C5, kEmoji | kEmojiPresentation},
+    {0x1F6CB, 0x1F6CB, kEmoji},
+    {0x1F6CC, 0x1F6CC, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F6CD, 0x1F6CF, kEmoji},
+    {0x1F6D0, 0x1F6D2, kEmoji | kEmojiPresentation},
+    {0x1F6D5, 0x1F6D7, kEmoji | kEmojiPresentation},
+    {0x1F6DC, 0x1F6DF, kEmoji | kEmojiPresentation},
+    {0x1F6E0, 0x1F6E5, kEmoji},
+    {0x1F6E9, 0x1F6E9, kEmoji},
+    {0x1F6EB, 0x1F6EC, kEmoji | kEmojiPresentation},
+    {0x1F6F0, 0x1F6F0, kEmoji},
+    {0x1F6F3, 0x1F6F3, kEmoji},
+    {0x1F6F4, 0x1F6FC, kEmoji | kEmojiPresentation},
+    {0x1F7E0, 0x1F7EB, kEmoji | kEmojiPresentation},
+    {0x1F7F0, 0x1F7F0, kEmoji | kEmojiPresentation},
+    {0x1F90C, 0x1F90C, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F90D, 0x1F90E, kEmoji | kEmojiPresentation},
+    {0x1F90F, 0x1F90F, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F910, 0x1F917, kEmoji | kEmojiPresentation},
+    {0x1F918, 0x1F91F, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F920, 0x1F925, kEmoji | kEmojiPresentation},
+    {0x1F926, 0x1F926, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F927, 0x1F92F, kEmoji | kEmojiPresentation},
+    {0x1F930, 0x1F939, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F93A, 0x1F93A, kEmoji | kEmojiPresentation},
+    {0x1F93C, 0x1F93E, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F93F, 0x1F945, kEmoji | kEmojiPresentation},
+    {0x1F947, 0x1F976, kEmoji | kEmojiPresentation},
+    {0x1F977, 0x1F977, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F978, 0x1F9AF, kEmoji | kEmojiPresentation},
+    {0x1F9B0, 0x1F9B3, kEmoji | kEmojiPresentation | kEmojiComponent},
+    {0x1F9B4, 0x1F9B4, kEmoji | kEmojiPresentation},
+    {0x1F9B5, 0x1F9B6, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F9B7, 0x1F9B7, kEmoji | kEmojiPresentation},
+    {0x1F9B8, 0x1F9B9, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F9BA, 0x1F9BA, kEmoji | kEmojiPresentation},
+    {0x1F9BB, 0x1F9BB, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F9BC, 0x1F9CC, kEmoji | kEmojiPresentation},
+    {0x1F9CD, 0x1F9CF, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F9D0, 0x1F9D0, kEmoji | kEmojiPresentation},
+    {0x1F9D1, 0x1F9DD, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1F9DE, 0x1F9FF, kEmoji | kEmojiPresentation},
+    {0x1FA70, 0x1FA7C, kEmoji | kEmojiPresentation},
+    {0x1FA80, 0x1FA88, kEmoji | kEmojiPresentation},
+    {0x1FA90, 0x1FABD, kEmoji | kEmojiPresentation},
+    {0x1FABF, 0x1FAC2, kEmoji | kEmojiPresentation},
+    {0x1FAC3, 0x1FAC5, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0x1FACE, 0x1FADB, kEmoji | kEmojiPresentation},
+    {0x1FAE0, 0x1FAE8, kEmoji | kEmojiPresentation},
+    {0x1FAF0, 0x1FAF8, kEmoji | kEmojiPresentation | kEmojiModifierBase},
+    {0xE0020, 0xE007F, kEmojiComponent},
+}};
+}  // namespace subset
@@ -0,0 +1,507 @@
+#!/usr/bin/env python3
+"""Generates the Unicode data tables in this directory from the UCD.
+
+All tables come from the same Unicode version, which should be the one of the
+HarfBuzz subproject, so segmentation, script and emoji data agree with shaping.
+Without `--ucd-dir` the files are downloaded from unicode.org. Files in the UCD
+format that don't come from it should be described with `--note`, so the headers
+don't claim they do.
+
+    ./gen_unicode_tables.py [--version 15.1.0] [--ucd-dir DIR] [--note TEXT]
+"""
+
+import argparse
+import os
+import re
+import urllib.request
+
+from collections.abc import Iterator
+
+UCD_URL = "https://www.unicode.org/Public/{version}/ucd/{path}"
+
+GRAPHEME_BREAK_VALUES = [
+    "Other",
+    "CR",
+    "LF",
+    "Control",
+    "Extend",
+    "ZWJ",
+    "RegionalIndicator",
+    "Prepend",
+    "SpacingMark",
+    "L",
+    "V",
+    "T",
+    "LV",
+    "LVT",
+    "ExtendedPictographic",
+    "InCBConsonant",
+]
+WORD_BREAK_VALUES = [
+    "Other",
+    "CR",
+    "LF",
+    "Newline",
+    "Extend",
+    "ZWJ",
+    "RegionalIndicator",
+    "Format",
+    "Katakana",
+    "HebrewLetter",
+    "ALetter",
+    "SingleQuote",
+    "DoubleQuote",
+    "MidNumLet",
+    "MidLetter",
+    "MidNum",
+    "Numeric",
+    "ExtendNumLet",
+    "WSegSpace",
+]
+SENTENCE_BREAK_VALUES = [
+    "Other",
+    "CR",
+    "LF",
+    "Extend",
+    "Sep",
+    "Format",
+    "Sp",
+    "Lower",
+    "Upper",
+    "OLetter",
+    "Numeric",
+    "ATerm",
+    "SContinue",
+    "STerm",
+    "Close",
+]
+EMOJI_PROPERTIES = {
+    "Emoji": "kEmoji",
+    "Emoji_Presentation": "kEmojiPresentation",
+    "Emoji_Modifier_Base": "kEmojiModifierBase",
+    "Emoji_Modifier": "kEmojiModifier",
+    "Emoji_Component": "kEmojiComponent",
+}
+VERTICAL_ORIENTATION_VALUES = {
+    "U": "kVerticalUpright",
+    "Tu": "kVerticalTransformedOrUpright",
+    "Tr": "kVerticalTransformedOrRotated",
+}
+
+
+class Ucd:
+    def __init__(self, version: str, ucd_dir: str | None, note: str | None) -> None:
+        self.version = version
+        self.ucd_dir = ucd_dir
+        self.note = note
+
+    def read(self, path: str) -> str:
+        if self.ucd_dir is not None:
+            with open(os.path.join(self.ucd_dir, path), encoding="utf-8") as f:
+                return f.read()
+        url = UCD_URL.format(version=self.version, path=path)
+        with urllib.request.urlopen(url) as response:
+            return response.read().decode("utf-8")
+
+    # N.B. Yields the code point range and the fields of every data line.
+    def lines(self, path: str) -> Iterator[tuple[int, int, list[str]]]:
+        for line in self.read(path).splitlines():
+            line = line.split("#", 1)[0].strip()
+            if not line:
+                continue
+            fields = [v.strip() for v in line.split(";")]
+            first, _, last = fields[0].partition("..")
+            yield int(first, 16), int(last or first, 16), fields[1:]
+
+    def property(self, path: str, name: str | None = None) -> dict[int, str]:
+        values = {}
+        for first, last, fields in self.lines(path):
+            if name is not None:
+                if fields[0] != name:
+                    continue
+                fields = fields[1:] or ["Y"]
+            for unichar in range(first, last + 1):
+                values[unichar] = fields[0]
+        return values
+
+
+def ranges(values: dict[int, object]) -> list[tuple[int, int, object]]:
+    result: list[tuple[int, int, object]] = []
+    for unichar in sorted(values):
+        value = values[unichar]
+        if result and result[-1][1] + 1 == unichar and result[-1][2] == value:
+            result[-1] = (result[-1][0], unichar, value)
+        else:
+            result.append((unichar, unichar, value))
+    return result
+
+
+def comment(line: str, text: str) -> list[str]:
+    lines = []
+    for word in text.split():
+        if len(line) + len(word) + 1 > 80:
+            lines.append(line)
+            line = "//"
+        line += " " + word
+    lines.append(line)
+    return lines
+
+
+def header(ucd: Ucd, sources: str, includes: list[str]) -> list[str]:
+    origin = "the" if ucd.note is None else "unofficial"
+    lines = comment(
+        f"// Generated by gen_unicode_tables.py from {origin} Unicode", sources
+    )
+    if ucd.note is not None:
+        lines += comment("// N.B.", ucd.note)
+    lines += ["", "#pragma once", ""]
+    lines += includes
+    lines += ["", "namespace subset {"]
+    return lines
+
+
+def write(name: str, lines: list[str]) -> None:
+    path = os.path.join(os.path.dirname(os.path.abspath(__file__)), name)
+    with open(path, "w", encoding="utf-8") as f:
+        f.write("\n".join(lines + ["}  // namespace subset"]) + "\n")
+
+
+def range_line(indent: str, first: int, last: int, value: str | None) -> str:
+    line = f"{indent}{{0x{first:04X}, 0x{last:04X}}},"
+    if value is None:
+        return line
+    line = f"{indent}{{0x{first:04X}, 0x{last:04X}, {value}}},"
+    if len(line) > 80:
+        line = f"{indent}{{0x{first:04X}, 0x{last:04X},\n{indent} {value}}},"
+    return line
+
+
+def array(lines: list[str], type_: str, name: str, items: list[str]) -> None:
+    head = f"inline constexpr std::array<{type_}, {len(items)}> {name}{{{{"
+    indent = ""
+    if len(head) > 80:
+        lines.append(f"inline constexpr std::array<{type_}, {len(items)}>")
+        head = f"    {name}{{{{"
+        indent = "    "
+    lines.append(head)
+    lines += [indent + item.replace("\n", "\n" + indent) for item in items]
+    lines.append(indent + "}};")
+
+
+def case_ranges(mappings: dict[int, int]) -> list[tuple[int, int, int, int]]:
+    result: list[list[int]] = []
+    for unichar in sorted(mappings):
+        delta = mappings[unichar] - unichar
+        if result:
+            first, last, last_delta, stride = result[-1]
+            if last_delta == delta and (
+                (stride == 0 and unichar - last <= 2)
+                or (stride != 0 and unichar - last == stride)
+            ):
+                result[-1] = [first, unichar, delta, unichar - last]
+                continue
+        result.append([unichar, unichar, delta, 0])
+    return [(first, last, delta, stride or 1) for first, last, delta, stride in result]
+
+
+def generate_case_mapping(ucd: Ucd) -> None:
+    simple: dict[str, dict[int, list[int]]] = {"upper": {}, "lower": {}, "title": {}}
+    for first, _, fields in ucd.lines("UnicodeData.txt"):
+        upper, lower, title = fields[11], fields[12], fields[13]
+        # N.B. A missing titlecase mapping is the uppercase one.
+        pairs = (("upper", upper), ("lower", lower), ("title", title or upper))
+        for kind, value in pairs:
+            if value:
+                simple[kind][first] = [int(value, 16)]
+    full = {kind: dict(mappings) for kind, mappings in simple.items()}
+    for first, _, fields in ucd.lines("SpecialCasing.txt"):
+        if len(fields) > 3 and fields[3]:
+            continue
+        for kind, value in zip(("lower", "title", "upper"), fields[:3]):
+            full[kind][first] = [int(v, 16) for v in value.split()]
+
+    def split(kind: str) -> tuple[dict[int, int], dict[int, list[int]]]:
+        singles, specials = {}, {}
+        for unichar, mapping in full[kind].items():
+            if mapping == [unichar]:
+                continue
+            if len(mapping) == 1:
+                singles[unichar] = mapping[0]
+            else:
+                specials[unichar] = mapping
+        return singles, specials
+
+    upper, special_upper = split("upper")
+    lower, special_lower = split("lower")
+    title, special_title = split("title")
+    # N.B. Titlecase is only kept where it differs from uppercase, and those that
+    # are themselves while their uppercase differs, e.g. U+01C5, map to themselves.
+    title = {k: v for k, v in title.items() if upper.get(k) != v}
+    special_title = {
+        k: v for k, v in special_title.items() if special_upper.get(k) != v
+    }
+    for unichar in list(upper) + list(special_upper):
+        if unichar not in full["title"] or full["title"][unichar] == [unichar]:
+            title[unichar] = unichar
+
+    lines = header(
+        ucd,
+        f"{ucd.version} UnicodeData.txt and SpecialCasing.txt.",
+        ["#include <array>", "#include <cstdint>"],
+    )
+    lines += [
+        "// N.B. Every `stride`th character from `first` to `last` maps to itself plus",
+        "// `delta`, while characters not covered by any range map to themselves.",
+        "struct CaseRange {",
+        "  uint32_t first;",
+        "  uint32_t last;",
+        "  int32_t delta;",
+        "  uint32_t stride;",
+        "};",
+        "",
+        "// N.B. Mappings to several characters take precedence over the ranges and",
+        "// are padded with zeros.",
+        "struct SpecialCasing {",
+        "  uint32_t unichar;",
+        "  std::array<uint32_t, 3> mapping;",
+        "};",
+    ]
+
+    def emit_ranges(name: str, mappings: dict[int, int]) -> None:
+        lines.append("")
+        items = [
+            f"    {{0x{first:04X}, 0x{last:04X}, {delta}, {stride}}},"
+            for first, last, delta, stride in case_ranges(mappings)
+        ]
+        array(lines, "CaseRange", name, items)
+
+    def emit_special(name: str, mappings: dict[int, list[int]]) -> None:
+        lines.append("")
+        items = []
+        for unichar in sorted(mappings):
+            mapping = mappings[unichar] + [0] * (3 - len(mappings[unichar]))
+            values = ", ".join(f"0x{v:04X}" for v in mapping)
+            items.append(f"    {{0x{unichar:04X}, {{{values}}}}},")
+        array(lines, "SpecialCasing", name, items)
+
+    emit_ranges("kUppercaseRanges", upper)
+    emit_ranges("kLowercaseRanges", lower)
+    lines.append("")
+    lines += [
+        "// N.B. Titlecase ranges only cover characters whose titlecase differs from",
+        "// their uppercase.",
+    ]
+    emit_ranges("kTitlecaseRanges", title)
+    emit_special("kSpecialUppercase", special_upper)
+    emit_special("kSpecialLowercase", special_lower)
+    emit_special("kSpecialTitlecase", special_title)
+    write("case_mapping_table.h", lines)
+
+
+def generate_emoji(ucd: Ucd) -> None:
+    properties: dict[int, list[str]] = {}
+    for first, last, fields in ucd.lines("emoji/emoji-data.txt"):
+        if fields[0] not in EMOJI_PROPERTIES:
+            continue
+        for unichar in range(first, last + 1):
+            properties.setdefault(unichar, []).append(fields[0])
+    values = {
+        unichar: " | ".join(v for k, v in EMOJI_PROPERTIES.items() if k in names)
+        for unichar, names in properties.items()
+    }
+    lines = header(
+        ucd,
+        f"{ucd.version} emoji-data.txt.",
+        ["#include <array>", "#include <cstdint>"],
+    )
+    for index, name in enumerate(EMOJI_PROPERTIES.values()):
+        lines.append(f"constexpr uint8_t {name} = 1U << {index}U;")
+    lines += [
+        "",
+        "struct EmojiRange {",
+        "  uint32_t first;",
+        "  uint32_t last;",
+        "  uint8_t properties;",
+        "};",
+        "",
+    ]
+    items = [range_line("    ", *v) for v in ranges(values)]
+    array(lines, "EmojiRange", "kEmojiRanges", items)
+    write("emoji_table.h", lines)
+
+
+def generate_script_extensions(ucd: Ucd) -> None:
+    values = {}
+    for first, last, fields in ucd.lines("ScriptExtensions.txt"):
+        for unichar in range(first, last + 1):
+            values[unichar] = tuple(fields[0].split())
+    # N.B. Sets are stored once, in the order they first appear.
+    indices: dict[tuple[str, ...], int] = {}
+    scripts: list[str] = []
+    items = []
+    for first, last, value in ranges(values):
+        if value not in indices:
+            indices[value] = len(scripts)
+            scripts.extend(value)
+        index, count = indices[value], len(value)
+        items.append(f"    {{0x{first:04X}, 0x{last:04X}, {index}, {count}}},")
+    lines = header(
+        ucd,
+        f"{ucd.version} ScriptExtensions.txt.",
+        ["#include <hb.h>", "", "#include <array>", "#include <cstdint>"],
+    )
+    lines += [
+        "struct ScriptExtensionRange {",
+        "  uint32_t first;",
+        "  uint32_t last;",
+        "  uint16_t script_index;",
+        "  uint16_t script_count;",
+        "};",
+        "",
+        f"inline constexpr std::array<hb_tag_t, {len(scripts)}>"
+        " kScriptExtensionScripts{",
+    ]
+    line = "   "
+    for script in scripts:
+        tag = ", ".join(f"'{v}'" for v in script[0].upper() + script[1:].lower())
+        item = f" HB_TAG({tag}),"
+        if len(line) + len(item) > 80:
+            lines.append(line)
+            line = "   "
+        line += item
+    lines += [line, "};", ""]
+    array(lines, "ScriptExtensionRange", "kScriptExtensionRanges", items)
+    write("script_extensions_table.h", lines)
+
+
+def generate_segmentation(ucd: Ucd) -> None:
+    grapheme_break = ucd.property("auxiliary/GraphemeBreakProperty.txt")
+    word_break = ucd.property("auxiliary/WordBreakProperty.txt")
+    sentence_break = ucd.property("auxiliary/SentenceBreakProperty.txt")
+    pictographic = ucd.property("emoji/emoji-data.txt", "Extended_Pictographic")
+    conjunct_break = ucd.property("DerivedCoreProperties.txt", "InCB")
+    # N.B. Extended_Pictographic and InCB=Consonant characters are all Other,
+    # so they are folded into the grapheme break values.
+    for unichar in pictographic:
+        grapheme_break.setdefault(unichar, "Extended_Pictographic")
+    for unichar, value in conjunct_break.items():
+        if value == "Consonant":
+            grapheme_break.setdefault(unichar, "InCB_Consonant")
+
+    lines = header(
+        ucd,
+        f"{ucd.version} GraphemeBreakProperty.txt, WordBreakProperty.txt,"
+        " SentenceBreakProperty.txt, emoji-data.txt and DerivedCoreProperties.txt"
+        " (UAX #29).",
+        ["#include <array>", "#include <cstdint>"],
+    )
+
+    def emit(prefix: str, names: list[str], values: dict[int, str], note: bool) -> None:
+        lines.append(f"enum {prefix}Value : uint8_t {{")
+        lines.extend(
+            f"  k{prefix}{name} = {index}," for index, name in enumerate(names)
+        )
+        lines.append("};")
+        lines.append("")
+        if note:
+            lines.append(
+                "// N.B. Characters not covered by any range in the tables below are"
+                " Other."
+            )
+        lines.extend(
+            [
+                f"struct {prefix}Range {{",
+                "  uint32_t first;",
+                "  uint32_t last;",
+                f"  {prefix}Value value;",
+                "};",
+                "",
+            ]
+        )
+        values = {k: v.replace("_", "") for k, v in values.items()}
+        assert set(values.values()) <= set(names[1:]), set(values.values())
+        items = [
+            range_line("    ", first, last, f"k{prefix}{value}")
+            for first, last, value in ranges(values)
+        ]
+        array(lines, f"{prefix}Range", f"k{prefix}Ranges", items)
+        lines.append("")
+
+    emit("GraphemeBreak", GRAPHEME_BREAK_VALUES, grapheme_break, True)
+    emit("WordBreak", WORD_BREAK_VALUES, word_break, False)
+    emit("SentenceBreak", SENTENCE_BREAK_VALUES, sentence_break, False)
+    lines += ["struct CodepointRange {", "  uint32_t first;", "  uint32_t last;"]
+    lines += ["};", ""]
+    items = [range_line("    ", v[0], v[1], None) for v in ranges(pictographic)]
+    array(lines, "CodepointRange", "kExtendedPictographicRanges", items)
+    lines.append("")
+    extend = {k: v for k, v in conjunct_break.items() if v == "Extend"}
+    items = [range_line("    ", v[0], v[1], None) for v in ranges(extend)]
+    array(lines, "CodepointRange", "kIndicConjunctBreakExtendRanges", items)
+    lines.append("")
+    linkers = sorted(k for k, v in conjunct_break.items() if v == "Linker")
+    lines.append(
+        f"inline constexpr std::array<uint32_t, {len(linkers)}>"
+        " kIndicConjunctBreakLinkers{{"
+    )
+    line = "   "
+    for linker in linkers:
+        item = f" 0x{linker:04X},"
+        if len(line) + len(item) > 80:
+            lines.append(line)
+            line = "   "
+        line += item
+    lines += [line, "}};"]
+    write("segmentation_table.h", lines)
+
+
+def generate_vertical_orientation(ucd: Ucd) -> None:
+    values = {
+        unichar: VERTICAL_ORIENTATION_VALUES[value]
+        for unichar, value in ucd.property("VerticalOrientation.txt").items()
+        if value != "R"
+    }
+    lines = header(
+        ucd,
+        f"{ucd.version} VerticalOrientation.txt (UAX #50).",
+        ["#include <array>", "#include <cstdint>"],
+    )
+    lines += [
+        "enum VerticalOrientationValue : uint8_t {",
+        "  kVerticalRotated = 0,",
+        "  kVerticalUpright = 1,",
+        "  kVerticalTransformedOrUpright = 2,",
+        "  kVerticalTransformedOrRotated = 3,",
+        "};",
+        "",
+        "// N.B. Characters not covered by any range are rotated.",
+        "struct VerticalOrientationRange {",
+        "  uint32_t first;",
+        "  uint32_t last;",
+        "  VerticalOrientationValue value;",
+        "};",
+        "",
+    ]
+    items = [range_line("    ", *v) for v in ranges(values)]
+    array(lines, "VerticalOrientationRange", "kVerticalOrientationRanges", items)
+    write("vertical_orientation_table.h", lines)
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--version", default="15.1.0")
+    parser.add_argument("--ucd-dir", help="read the UCD files from this directory")
+    parser.add_argument("--note", help="where the files come from if not the UCD")
+    args = parser.parse_args()
+    if not re.fullmatch(r"\d+\.\d+\.\d+", args.version):
+        parser.error(f"invalid Unicode version {args.version}")
+    ucd = Ucd(args.version, args.ucd_dir, args.note)
+    generate_case_mapping(ucd)
+    generate_emoji(ucd)
+    generate_script_extensions(ucd)
+    generate_segmentation(ucd)
+    generate_vertical_orientation(ucd)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,289 @@
+// Generated by gen_unicode_tables.py from unofficial Unicode 15.1.0
+// ScriptExtensions.txt.
+// N.B. These tables were generated from files rebuilt offline, not the ones of
+// the UCD: segmentation and vertical orientation from ICU 72 (Unicode 15.0)
+// plus the 15.1 changes, Indic_Conjunct_Break inferred from the GB9c rules,
+// emoji from the unicode-properties crate, Script_Extensions from the
+// unicode-script crate and case mappings from Python 3.13. Regenerate them from
+// unicode.org.
+
+#pragma once
+
+#include <hb.h>
+
+#include <array>
+#include <cstdint>
+
+namespace subset {
+struct ScriptExtensionRange {
+  uint32_t first;
+  uint32_t last;
+  uint16_t script_index;
+  uint16_t script_count;
+};
+
+inline constexpr std::array<hb_tag_t, 271> kScriptExtensionScripts{
+    HB_TAG('G', 'r', 'e', 'k'), HB_TAG('L', 'a', 't', 'n'),
+    HB_TAG('C', 'y', 'r', 'l'), HB_TAG('P', 'e', 'r', 'm'),
+    HB_TAG('C', 'y', 'r', 'l'), HB_TAG('G', 'l', 'a', 'g'),
+    HB_TAG('C', 'y', 'r', 'l'), HB_TAG('L', 'a', 't', 'n'),
+    HB_TAG('A', 'r', 'a', 'b'), HB_TAG('N', 'k', 'o', 'o'),
+    HB_TAG('R', 'o', 'h', 'g'), HB_TAG('S', 'y', 'r', 'c'),
+    HB_TAG('T', 'h', 'a', 'a'), HB_TAG('Y', 'e', 'z', 'i'),
+    HB_TAG('A', 'r', 'a', 'b'), HB_TAG('S', 'y', 'r', 'c'),
+    HB_TAG('T', 'h', 'a', 'a'), HB_TAG('A', 'd', 'l', 'm'),
+    HB_TAG('A', 'r', 'a', 'b'), HB_TAG('N', 'k', 'o', 'o'),
+    HB_TAG('R', 'o', 'h', 'g'), HB_TAG('S', 'y', 'r', 'c'),
+    HB_TAG('T', 'h', 'a', 'a'), HB_TAG('Y', 'e', 'z', 'i'),
+    HB_TAG('A', 'd', 'l', 'm'), HB_TAG('A', 'r', 'a', 'b'),
+    HB_TAG('M', 'a', 'n', 'd'), HB_TAG('M', 'a', 'n', 'i'),
+    HB_TAG('O', 'u', 'g', 'r'), HB_TAG('P', 'h', 'l', 'p'),
+    HB_TAG('R', 'o', 'h', 'g'), HB_TAG('S', 'o', 'g', 'd'),
+    HB_TAG('S', 'y', 'r', 'c'), HB_TAG('A', 'r', 'a', 'b'),
+    HB_TAG('S', 'y', 'r', 'c'), HB_TAG('A', 'r', 'a', 'b'),
+    HB_TAG('T', 'h', 'a', 'a'), HB_TAG('Y', 'e', 'z', 'i'),
+    HB_TAG('A', 'r', 'a', 'b'), HB_TAG('R', 'o', 'h', 'g'),
+    HB_TAG('B', 'e', 'n', 'g'), HB_TAG('D', 'e', 'v', 'a'),
+    HB_TAG('G', 'r', 'a', 'n'), HB_TAG('G', 'u', 'j', 'r'),
+    HB_TAG('G', 'u', 'r', 'u'), HB_TAG('K', 'n', 'd', 'a'),
+    HB_TAG('L', 'a', 't', 'n'), HB_TAG('M', 'l', 'y', 'm'),
+    HB_TAG('O', 'r', 'y', 'a'), HB_TAG('S', 'h', 'r', 'd'),
+    HB_TAG('T', 'a', 'm', 'l'), HB_TAG('T', 'e', 'l', 'u'),
+    HB_TAG('T', 'i', 'r', 'h'), HB_TAG('B', 'e', 'n', 'g'),
+    HB_TAG('D', 'e', 'v', 'a'), HB_TAG('G', 'r', 'a', 'n'),
+    HB_TAG('G', 'u', 'j', 'r'), HB_TAG('G', 'u', 'r', 'u'),
+    HB_TAG('K', 'n', 'd', 'a'), HB_TAG('L', 'a', 't', 'n'),
+    HB_TAG('M', 'l', 'y', 'm'), HB_TAG('O', 'r', 'y', 'a'),
+    HB_TAG('T', 'a', 'm', 'l'), HB_TAG('T', 'e', 'l', 'u'),
+    HB_TAG('T', 'i', 'r', 'h'), HB_TAG('B', 'e', 'n', 'g'),
+    HB_TAG('D', 'e', 'v', 'a'), HB_TAG('D', 'o', 'g', 'r'),
+    HB_TAG('G', 'o', 'n', 'g'), HB_TAG('G', 'o', 'n', 'm'),
+    HB_TAG('G', 'r', 'a', 'n'), HB_TAG('G', 'u', 'j', 'r'),
+    HB_TAG('G', 'u', 'r', 'u'), HB_TAG('K', 'n', 'd', 'a'),
+    HB_TAG('M', 'a', 'h', 'j'), HB_TAG('M', 'l', 'y', 'm'),
+    HB_TAG('N', 'a', 'n', 'd'), HB_TAG('O', 'r', 'y', 'a'),
+    HB_TAG('S', 'i', 'n', 'd'), HB_TAG('S', 'i', 'n', 'h'),
+    HB_TAG('S', 'y', 'l', 'o'), HB_TAG('T', 'a', 'k', 'r'),
+    HB_TAG('T', 'a', 'm', 'l'), HB_TAG('T', 'e', 'l', 'u'),
+    HB_TAG('T', 'i', 'r', 'h'), HB_TAG('B', 'e', 'n', 'g'),
+    HB_TAG('D', 'e', 'v', 'a'), HB_TAG('D', 'o', 'g', 'r'),
+    HB_TAG('G', 'o', 'n', 'g'), HB_TAG('G', 'o', 'n', 'm'),
+    HB_TAG('G', 'r', 'a', 'n'), HB_TAG('G', 'u', 'j', 'r'),
+    HB_TAG('G', 'u', 'r', 'u'), HB_TAG('K', 'n', 'd', 'a'),
+    HB_TAG('L', 'i', 'm', 'b'), HB_TAG('M', 'a', 'h', 'j'),
+    HB_TAG('M', 'l', 'y', 'm'), HB_TAG('N', 'a', 'n', 'd'),
+    HB_TAG('O', 'r', 'y', 'a'), HB_TAG('S', 'i', 'n', 'd'),
+    HB_TAG('S', 'i', 'n', 'h'), HB_TAG('S', 'y', 'l', 'o'),
+    HB_TAG('T', 'a', 'k', 'r'), HB_TAG('T', 'a', 'm', 'l'),
+    HB_TAG('T', 'e', 'l', 'u'), HB_TAG('T', 'i', 'r', 'h'),
+    HB_TAG('D', 'e', 'v', 'a'), HB_TAG('D', 'o', 'g', 'r'),
+    HB_TAG('K', 't', 'h', 'i'), HB_TAG('M', 'a', 'h', 'j'),
+    HB_TAG('B', 'e', 'n', 'g'), HB_TAG('C', 'a', 'k', 'm'),
+    HB_TAG('S', 'y', 'l', 'o'), HB_TAG('G', 'u', 'r', 'u'),
+    HB_TAG('M', 'u', 'l', 't'), HB_TAG('G', 'u', 'j', 'r'),
+    HB_TAG('K', 'h', 'o', 'j'), HB_TAG('G', 'r', 'a', 'n'),
+    HB_TAG('T', 'a', 'm', 'l'), HB_TAG('K', 'n', 'd', 'a'),
+    HB_TAG('N', 'a', 'n', 'd'), HB_TAG('C', 'a', 'k', 'm'),
+    HB_TAG('M', 'y', 'm', 'r'), HB_TAG('T', 'a', 'l', 'e'),
+    HB_TAG('G', 'e', 'o', 'r'), HB_TAG('L', 'a', 't', 'n'),
+    HB_TAG('B', 'u', 'h', 'd'), HB_TAG('H', 'a', 'n', 'o'),
+    HB_TAG('T', 'a', 'g', 'b'), HB_TAG('T', 'g', 'l', 'g'),
+    HB_TAG('M', 'o', 'n', 'g'), HB_TAG('P', 'h', 'a', 'g'),
+    HB_TAG('B', 'e', 'n', 'g'), HB_TAG('D', 'e', 'v', 'a'),
+    HB_TAG('G', 'r', 'a', 'n'), HB_TAG('K', 'n', 'd', 'a'),
+    HB_TAG('D', 'e', 'v', 'a'), HB_TAG('D', 'e', 'v', 'a'),
+    HB_TAG('G', 'r', 'a', 'n'), HB_TAG('B', 'e', 'n', 'g'),
+    HB_TAG('D', 'e', 'v', 'a'), HB_TAG('D', 'e', 'v', 'a'),
+    HB_TAG('S', 'h', 'r', 'd'), HB_TAG('D', 'e', 'v', 'a'),
+    HB_TAG('K', 'n', 'd', 'a'), HB_TAG('M', 'l', 'y', 'm'),
+    HB_TAG('O', 'r', 'y', 'a'), HB_TAG('T', 'a', 'm', 'l'),
+    HB_TAG('T', 'e', 'l', 'u'), HB_TAG('D', 'e', 'v', 'a'),
+    HB_TAG('N', 'a', 'n', 'd'), HB_TAG('B', 'e', 'n', 'g'),
+    HB_TAG('D', 'e', 'v', 'a'), HB_TAG('G', 'r', 'a', 'n'),
+    HB_TAG('K', 'n', 'd', 'a'), HB_TAG('M', 'l', 'y', 'm'),
+    HB_TAG('N', 'a', 'n', 'd'), HB_TAG('O', 'r', 'y', 'a'),
+    HB_TAG('S', 'i', 'n', 'h'), HB_TAG('T', 'e', 'l', 'u'),
+    HB_TAG('T', 'i', 'r', 'h'), HB_TAG('D', 'e', 'v', 'a'),
+    HB_TAG('G', 'r', 'a', 'n'), HB_TAG('K', 'n', 'd', 'a'),
+    HB_TAG('B', 'e', 'n', 'g'), HB_TAG('N', 'a', 'n', 'd'),
+    HB_TAG('C', 'y', 'r', 'l'), HB_TAG('S', 'y', 'r', 'c'),
+    HB_TAG('S', 'y', 'r', 'c'), HB_TAG('L', 'a', 't', 'n'),
+    HB_TAG('M', 'o', 'n', 'g'), HB_TAG('D', 'e', 'v', 'a'),
+    HB_TAG('G', 'r', 'a', 'n'), HB_TAG('L', 'a', 't', 'n'),
+    HB_TAG('B', 'o', 'p', 'o'), HB_TAG('H', 'a', 'n', 'g'),
+    HB_TAG('H', 'a', 'n', 'i'), HB_TAG('H', 'i', 'r', 'a'),
+    HB_TAG('K', 'a', 'n', 'a'), HB_TAG('Y', 'i', 'i', 'i'),
+    HB_TAG('B', 'o', 'p', 'o'), HB_TAG('H', 'a', 'n', 'g'),
+    HB_TAG('H', 'a', 'n', 'i'), HB_TAG('H', 'i', 'r', 'a'),
+    HB_TAG('K', 'a', 'n', 'a'), HB_TAG('H', 'a', 'n', 'i'),
+    HB_TAG('B', 'o', 'p', 'o'), HB_TAG('H', 'a', 'n', 'i'),
+    HB_TAG('H', 'i', 'r', 'a'), HB_TAG('K', 'a', 'n', 'a'),
+    HB_TAG('H', 'a', 'n', 'i'), HB_TAG('H', 'i', 'r', 'a'),
+    HB_TAG('K', 'a', 'n', 'a'), HB_TAG('H', 'a', 'n', 'i'),
+    HB_TAG('L', 'a', 't', 'n'), HB_TAG('D', 'e', 'v', 'a'),
+    HB_TAG('D', 'o', 'g', 'r'), HB_TAG('G', 'u', 'j', 'r'),
+    HB_TAG('G', 'u', 'r', 'u'), HB_TAG('K', 'h', 'o', 'j'),
+    HB_TAG('K', 'n', 'd', 'a'), HB_TAG('K', 't', 'h', 'i'),
+    HB_TAG('M', 'a', 'h', 'j'), HB_TAG('M', 'l', 'y', 'm'),
+    HB_TAG('M', 'o', 'd', 'i'), HB_TAG('N', 'a', 'n', 'd'),
+    HB_TAG('S', 'h', 'r', 'd'), HB_TAG('S', 'i', 'n', 'd'),
+    HB_TAG('T', 'a', 'k', 'r'), HB_TAG('T', 'i', 'r', 'h'),
+    HB_TAG('D', 'e', 'v', 'a'), HB_TAG('D', 'o', 'g', 'r'),
+    HB_TAG('G', 'u', 'j', 'r'), HB_TAG('G', 'u', 'r', 'u'),
+    HB_TAG('K', 'h', 'o', 'j'), HB_TAG('K', 'n', 'd', 'a'),
+    HB_TAG('K', 't', 'h', 'i'), HB_TAG('M', 'a', 'h', 'j'),
+    HB_TAG('M', 'o', 'd', 'i'), HB_TAG('N', 'a', 'n', 'd'),
+    HB_TAG('S', 'h', 'r', 'd'), HB_TAG('S', 'i', 'n', 'd'),
+    HB_TAG('T', 'a', 'k', 'r'), HB_TAG('T', 'i', 'r', 'h'),
+    HB_TAG('D', 'e', 'v', 'a'), HB_TAG('D', 'o', 'g', 'r'),
+    HB_TAG('G', 'u', 'j', 'r'), HB_TAG('G', 'u', 'r', 'u'),
+    HB_TAG('K', 'h', 'o', 'j'), HB_TAG('K', 't', 'h', 'i'),
+    HB_TAG('M', 'a', 'h', 'j'), HB_TAG('M', 'o', 'd', 'i'),
+    HB_TAG('S', 'i', 'n', 'd'), HB_TAG('T', 'a', 'k', 'r'),
+    HB_TAG('T', 'i', 'r', 'h'), HB_TAG('D', 'e', 'v', 'a'),
+    HB_TAG('D', 'o', 'g', 'r'), HB_TAG('G', 'u', 'j', 'r'),
+    HB_TAG('G', 'u', 'r', 'u'), HB_TAG('K', 'h', 'o', 'j'),
+    HB_TAG('K', 't', 'h', 'i'), HB_TAG('M', 'a', 'h', 'j'),
+    HB_TAG('M', 'o', 'd', 'i'), HB_TAG('S', 'h', 'r', 'd'),
+    HB_TAG('S', 'i', 'n', 'd'), HB_TAG('T', 'a', 'k', 'r'),
+    HB_TAG('T', 'i', 'r', 'h'), HB_TAG('D', 'e', 'v', 'a'),
+    HB_TAG('T', 'a', 'm', 'l'), HB_TAG('K', 'a', 'l', 'i'),
+    HB_TAG('L', 'a', 't', 'n'), HB_TAG('M', 'y', 'm', 'r'),
+    HB_TAG('B', 'u', 'g', 'i'), HB_TAG('J', 'a', 'v', 'a'),
+    HB_TAG('A', 'r', 'a', 'b'), HB_TAG('N', 'k', 'o', 'o'),
+    HB_TAG('A', 'r', 'a', 'b'), HB_TAG('T', 'h', 'a', 'a'),
+    HB_TAG('C', 'p', 'm', 'n'), HB_TAG('C', 'p', 'r', 't'),
+    HB_TAG('L', 'i', 'n', 'b'), HB_TAG('C', 'p', 'r', 't'),
+    HB_TAG('L', 'i', 'n', 'b'), HB_TAG('C', 'p', 'r', 't'),
+    HB_TAG('L', 'i', 'n', 'a'), HB_TAG('L', 'i', 'n', 'b'),
+    HB_TAG('A', 'r', 'a', 'b'), HB_TAG('C', 'o', 'p', 't'),
+    HB_TAG('M', 'a', 'n', 'i'), HB_TAG('O', 'u', 'g', 'r'),
+    HB_TAG('D', 'u', 'p', 'l'),
+};
+
+inline constexpr std::array<ScriptExtensionRange, 123> kScriptExtensionRanges{{
+    {0x0342, 0x0342, 0, 1},
+    {0x0345, 0x0345, 0, 1},
+    {0x0363, 0x036F, 1, 1},
+    {0x0483, 0x0483, 2, 2},
+    {0x0484, 0x0484, 4, 2},
+    {0x0485, 0x0486, 6, 2},
+    {0x0487, 0x0487, 4, 2},
+    {0x060C, 0x060C, 8, 6},
+    {0x061B, 0x061B, 8, 6},
+    {0x061C, 0x061C, 14, 3},
+    {0x061F, 0x061F, 17, 7},
+    {0x0640, 0x0640, 24, 9},
+    {0x064B, 0x0655, 33, 2},
+    {0x0660, 0x0669, 35, 3},
+    {0x0670, 0x0670, 33, 2},
+    {0x06D4, 0x06D4, 38, 2},
+    {0x0951, 0x0951, 40, 13},
+    {0x0952, 0x0952, 53, 12},
+    {0x0964, 0x0964, 65, 20},
+    {0x0965, 0x0965, 85, 21},
+    {0x0966, 0x096F, 106, 4},
+    {0x09E6, 0x09EF, 110, 3},
+    {0x0A66, 0x0A6F, 113, 2},
+    {0x0AE6, 0x0AEF, 115, 2},
+    {0x0BE6, 0x0BF3, 117, 2},
+    {0x0CE6, 0x0CEF, 119, 2},
+    {0x1040, 0x1049, 121, 3},
+    {0x10FB, 0x10FB, 124, 2},
+    {0x1735, 0x1736, 126, 4},
+    {0x1802, 0x1803, 130, 2},
+    {0x1805, 0x1805, 130, 2},
+    {0x1CD0, 0x1CD0, 132, 4},
+    {0x1CD1, 0x1CD1, 136, 1},
+    {0x1CD2, 0x1CD2, 132, 4},
+    {0x1CD3, 0x1CD3, 137, 2},
+    {0x1CD4, 0x1CD4, 136, 1},
+    {0x1CD5, 0x1CD6, 139, 2},
+    {0x1CD7, 0x1CD7, 141, 2},
+    {0x1CD8, 0x1CD8, 139, 2},
+    {0x1CD9, 0x1CD9, 141, 2},
+    {0x1CDA, 0x1CDA, 143, 6},
+    {0x1CDB, 0x1CDB, 136, 1},
+    {0x1CDC, 0x1CDD, 141, 2},
+    {0x1CDE, 0x1CDF, 136, 1},
+    {0x1CE0, 0x1CE0, 141, 2},
+    {0x1CE1, 0x1CE1, 139, 2},
+    {0x1CE2, 0x1CE8, 136, 1},
+    {0x1CE9, 0x1CE9, 149, 2},
+    {0x1CEA, 0x1CEA, 139, 2},
+    {0x1CEB, 0x1CEC, 136, 1},
+    {0x1CED, 0x1CED, 139, 2},
+    {0x1CEE, 0x1CF1, 136, 1},
+    {0x1CF2, 0x1CF2, 151, 10},
+    {0x1CF3, 0x1CF3, 137, 2},
+    {0x1CF4, 0x1CF4, 161, 3},
+    {0x1CF5, 0x1CF6, 139, 2},
+    {0x1CF7, 0x1CF7, 164, 1},
+    {0x1CF8, 0x1CF9, 137, 2},
+    {0x1CFA, 0x1CFA, 165, 1},
+    {0x1DC0, 0x1DC1, 0, 1},
+    {0x1DF8, 0x1DF8, 166, 2},
+    {0x1DFA, 0x1DFA, 168, 1},
+    {0x202F, 0x202F, 169, 2},
+    {0x20F0, 0x20F0, 171, 3},
+    {0x2E43, 0x2E43, 4, 2},
+    {0x3001, 0x3002, 174, 6},
+    {0x3003, 0x3003, 180, 5},
+    {0x3006, 0x3006, 185, 1},
+    {0x3008, 0x3011, 174, 6},
+    {0x3013, 0x3013, 180, 5},
+    {0x3014, 0x301B, 174, 6},
+    {0x301C, 0x301F, 180, 5},
+    {0x302A, 0x302D, 186, 2},
+    {0x3030, 0x3030, 180, 5},
+    {0x3031, 0x3035, 188, 2},
+    {0x3037, 0x3037, 180, 5},
+    {0x303C, 0x303D, 190, 3},
+    {0x303E, 0x303F, 185, 1},
+    {0x3099, 0x309C, 188, 2},
+    {0x30A0, 0x30A0, 188, 2},
+    {0x30FB, 0x30FB, 174, 6},
+    {0x30FC, 0x30FC, 188, 2},
+    {0x3190, 0x319F, 185, 1},
+    {0x31C0, 0x31E3, 185, 1},
+    {0x3220, 0x3247, 185, 1},
+    {0x3280, 0x32B0, 185, 1},
+    {0x32C0, 0x32CB, 185, 1},
+    {0x32FF, 0x32FF, 185, 1},
+    {0x3358, 0x3370, 185, 1},
+    {0x337B, 0x337F, 185, 1},
+    {0x33E0, 0x33FE, 185, 1},
+    {0xA66F, 0xA66F, 4, 2},
+    {0xA700, 0xA707, 193, 2},
+    {0xA830, 0xA832, 195, 15},
+    {0xA833, 0xA835, 210, 14},
+    {0xA836, 0xA837, 224, 11},
+    {0xA838, 0xA838, 235, 12},
+    {0xA839, 0xA839, 224, 11},
+    {0xA8F1, 0xA8F1, 139, 2},
+    {0xA8F3, 0xA8F3, 247, 2},
+    {0xA92E, 0xA92E, 249, 3},
+    {0xA9CF, 0xA9CF, 252, 2},
+    {0xFD3E, 0xFD3F, 254, 2},
+    {0xFDF2, 0xFDF2, 256, 2},
+    {0xFDFD, 0xFDFD, 256, 2},
+    {0xFE45, 0xFE46, 180, 5},
+    {0xFF61, 0xFF65, 174, 6},
+    {0xFF70, 0xFF70, 188, 2},
+    {0xFF9E, 0xFF9F, 188, 2},
+    {0x10100, 0x10101, 258, 3},
+    {0x10102, 0x10102, 261, 2},
+    {0x10107, 0x10133, 263, 3},
+    {0x10137, 0x1013F, 261, 2},
+    {0x102E0, 0x102FB, 266, 2},
+    {0x10AF2, 0x10AF2, 268, 2},
+    {0x11301, 0x11301, 117, 2},
+    {0x11303, 0x11303, 117, 2},
+    {0x1133B, 0x1133C, 117, 2},
+    {0x11FD0, 0x11FD1, 117, 2},
+    {0x11FD3, 0x11FD3, 117, 2},
+    {0x1BCA0, 0x1BCA3, 270, 1},
+    {0x1D360, 0x1D371, 185, 1},
+    {0x1F250, 0x1F251, 185, 1},
+}};
+}  // namespace subset
@@ -0,0 +1,5316 @@
+// Generated by gen_unicode_tables.py from unofficial Unicode 15.1.0
+// GraphemeBreakProperty.txt, WordBreakProperty.txt, SentenceBreakProperty.txt,
+// emoji-data.txt and DerivedCoreProperties.txt (UAX #29).
+// N.B. These tables were generated from files rebuilt offline, not the ones of
+// the UCD: segmentation and vertical orientation from ICU 72 (Unicode 15.0)
+// plus the 15.1 changes, Indic_Conjunct_Break inferred from the GB9c rules,
+// emoji from the unicode-properties crate, Script_Extensions from the
+// unicode-script crate and case mappings from Python 3.13. Regenerate them from
+// unicode.org.
+
+#pragma once
+
+#include <array>
+#include <cstdint>
+
+namespace subset {
+enum GraphemeBreakValue : uint8_t {
+  kGraphemeBreakOther = 0,
+  kGraphemeBreakCR = 1,
+  kGraphemeBreakLF = 2,
+  kGraphemeBreakControl = 3,
+  kGraphemeBreakExtend = 4,
+  kGraphemeBreakZWJ = 5,
+  kGraphemeBreakRegionalIndicator = 6,
+  kGraphemeBreakPrepend = 7,
+  kGraphemeBreakSpacingMark = 8,
+  kGraphemeBreakL = 9,
+  kGraphemeBreakV = 10,
+  kGraphemeBreakT = 11,
+  kGraphemeBreakLV = 12,
+  kGraphemeBreakLVT = 13,
+  kGraphemeBreakExtendedPictographic = 14,
+  kGraphemeBreakInCBConsonant = 15,
+};
+
+// N.B. Characters not covered by any range in the tables below are Other.
+struct GraphemeBreakRange {
+  uint32_t first;
+  uint32_t last;
+  GraphemeBreakValue value;
+};
+
+inline constexpr std::array<GraphemeBreakRange, 1475> kGraphemeBreakRanges{{
+    {0x0000, 0x0009, kGraphemeBreakControl},
+    {0x000A, 0x000A, kGraphemeBreakLF},
+    {0x000B, 0x000C, kGraphemeBreakControl},
+    {0x000D, 0x000D, kGraphemeBreakCR},
+    {0x000E, 0x001F, kGraphemeBreakControl},
+    {0x007F, 0x009F, kGraphemeBreakControl},
+    {0x00A9, 0x00A9, kGraphemeBreakExtendedPictographic},
+    {0x00AD, 0x00AD, kGraphemeBreakControl},
+    {0x00AE, 0x00AE, kGraphemeBreakExtendedPictographic},
+    {0x0300, 0x036F, kGraphemeBreakExtend},
+    {0x0483, 0x0489, kGraphemeBreakExtend},
+    {0x0591, 0x05BD, kGraphemeBreakExtend},
+    {0x05BF, 0x05BF, kGraphemeBreakExtend},
+    {0x05C1, 0x05C2, kGraphemeBreakExtend},
+    {0x05C4, 0x05C5, kGraphemeBreakExtend},
+    {0x05C7, 0x05C7, kGraphemeBreakExtend},
+    {0x0600, 0x0605, kGraphemeBreakPrepend},
+    {0x0610, 0x061A, kGraphemeBreakExtend},
+    {0x061C, 0x061C, kGraphemeBreakControl},
+    {0x064B, 0x065F, kGraphemeBreakExtend},
+    {0x0670, 0x0670, kGraphemeBreakExtend},
+    {0x06D6, 0x06DC, kGraphemeBreakExtend},
+    {0x06DD, 0x06DD, kGraphemeBreakPrepend},
+    {0x06DF, 0x06E4, kGraphemeBreakExtend},
+    {0x06E7, 0x06E8, kGraphemeBreakExtend},
+    {0x06EA, 0x06ED, kGraphemeBreakExtend},
+    {0x070F, 0x070F, kGraphemeBreakPrepend},
+    {0x0711, 0x0711, kGraphemeBreakExtend},
+    {0x0730, 0x074A, kGraphemeBreakExtend},
+    {0x07A6, 0x07B0, kGraphemeBreakExtend},
+    {0x07EB, 0x07F3, kGraphemeBreakExtend},
+    {0x07FD, 0x07FD, kGraphemeBreakExtend},
+    {0x0816, 0x0819, kGraphemeBreakExtend},
+    {0x081B, 0x0823, kGraphemeBreakExtend},
+    {0x0825, 0x0827, kGraphemeBreakExtend},
+    {0x0829, 0x082D, kGraphemeBreakExtend},
+    {0x0859, 0x085B, kGraphemeBreakExtend},
+    {0x0890, 0x0891, kGraphemeBreakPrepend},
+    {0x0898, 0x089F, kGraphemeBreakExtend},
+    {0x08CA, 0x08E1, kGraphemeBreakExtend},
+    {0x08E2, 0x08E2, kGraphemeBreakPrepend},
+    {0x08E3, 0x0902, kGraphemeBreakExtend},
+    {0x0903, 0x0903, kGraphemeBreakSpacingMark},
+    {0x0915, 0x0939, kGraphemeBreakInCBConsonant},
+    {0x093A, 0x093A, kGraphemeBreakExtend},
+    {0x093B, 0x093B, kGraphemeBreakSpacingMark},
+    {0x093C, 0x093C, kGraphemeBreakExtend},
+    {0x093E, 0x0940, kGraphemeBreakSpacingMark},
+    {0x0941, 0x0948, kGraphemeBreakExtend},
+    {0x0949, 0x094C, kGraphemeBreakSpacingMark},
+    {0x094D, 0x094D, kGraphemeBreakExtend},
+    {0x094E, 0x094F, kGraphemeBreakSpacingMark},
+    {0x0951, 0x0957, kGraphemeBreakExtend},
+    {0x0958, 0x095F, kGraphemeBreakInCBConsonant},
+    {0x0962, 0x0963, kGraphemeBreakExtend},
+    {0x0978, 0x097F, kGraphemeBreakInCBConsonant},
+    {0x0981, 0x0981, kGraphemeBreakExtend},
+    {0x0982, 0x0983, kGraphemeBreakSpacingMark},
+    {0x0995, 0x09A8, kGraphemeBreakInCBConsonant},
+    {0x09AA, 0x09B0, kGraphemeBreakInCBConsonant},
+    {0x09B2, 0x09B2, kGraphemeBreakInCBConsonant},
+    {0x09B6, 0x09B9, kGraphemeBreakInCBConsonant},
+    {0x09BC, 0x09BC, kGraphemeBreakExtend},
+    {0x09BE, 0x09BE, kGraphemeBreakExtend},
+    {0x09BF, 0x09C0, kGraphemeBreakSpacingMark},
+    {0x09C1, 0x09C4, kGraphemeBreakExtend},
+    {0x09C7, 0x09C8, kGraphemeBreakSpacingMark},
+    {0x09CB, 0x09CC, kGraphemeBreakSpacingMark},
+    {0x09CD, 0x09CD, kGraphemeBreakExtend},
+    {0x09D7, 0x09D7, kGraphemeBreakExtend},
+    {0x09DC, 0x09DD, kGraphemeBreakInCBConsonant},
+    {0x09DF, 0x09DF, kGraphemeBreakInCBConsonant},
+    {0x09E2, 0x09E3, kGraphemeBreakExtend},
+    {0x09F0, 0x09F1, kGraphemeBreakInCBConsonant},
+    {0x09FE, 0x09FE, kGraphemeBreakExtend},
+    {0x0A01, 0x0A02, kGraphemeBreakExtend},
+    {0x0A03, 0x0A03, kGraphemeBreakSpacingMark},
+    {0x0A3C, 0x0A3C, kGraphemeBreakExtend},
+    {0x0A3E, 0x0A40, kGraphemeBreakSpacingMark},
+    {0x0A41, 0x0A42, kGraphemeBreakExtend},
+    {0x0A47, 0x0A48, kGraphemeBreakExtend},
+    {0x0A4B, 0x0A4D, kGraphemeBreakExtend},
+    {0x0A51, 0x0A51, kGraphemeBreakExtend},
+    {0x0A70, 0x0A71, kGraphemeBreakExtend},
+    {0x0A75, 0x0A75, kGraphemeBreakExtend},
+    {0x0A81, 0x0A82, kGraphemeBreakExtend},
+    {0x0A83, 0x0A83, kGraphemeBreakSpacingMark},
+    {0x0A95, 0x0AA8, kGraphemeBreakInCBConsonant},
+    {0x0AAA, 0x0AB0, kGraphemeBreakInCBConsonant},
+    {0x0AB2, 0x0AB3, kGraphemeBreakInCBConsonant},
+    {0x0AB5, 0x0AB9, kGraphemeBreakInCBConsonant},
+    {0x0ABC, 0x0ABC, kGraphemeBreakExtend},
+    {0x0ABE, 0x0AC0, kGraphemeBreakSpacingMark},
+    {0x0AC1, 0x0AC5, kGraphemeBreakExtend},
+    {0x0AC7, 0x0AC8, kGraphemeBreakExtend},
+    {0x0AC9, 0x0AC9, kGraphemeBreakSpacingMark},
+    {0x0ACB, 0x0ACC, kGraphemeBreakSpacingMark},
+    {0x0ACD, 0x0ACD, kGraphemeBreakExtend},
+    {0x0AE2, 0x0AE3, kGraphemeBreakExtend},
+    {0x0AF9, 0x0AF9, kGraphemeBreakInCBConsonant},
+    {0x0AFA, 0x0AFF, kGraphemeBreakExtend},
+    {0x0B01, 0x0B01, kGraphemeBreakExtend},
+    {0x0B02, 0x0B03, kGraphemeBreakSpacingMark},
+    {0x0B15, 0x0B28, kGraphemeBreakInCBConsonant},
+    {0x0B2A, 0x0B30, kGraphemeBreakInCBConsonant},
+    {0x0B32, 0x0B33, kGraphemeBreakInCBConsonant},
+    {0x0B35, 0x0B39, kGraphemeBreakInCBConsonant},
+    {0x0B3C, 0x0B3C, kGraphemeBreakExtend},
+    {0x0B3E, 0x0B3F, kGraphemeBreakExtend},
+    {0x0B40, 0x0B40, kGraphemeBreakSpacingMark},
+    {0x0B41, 0x0B44, kGraphemeBreakExtend},
+    {0x0B47, 0x0B48, kGraphemeBreakSpacingMark},
+    {0x0B4B, 0x0B4C, kGraphemeBreakSpacingMark},
+    {0x0B4D, 0x0B4D, kGraphemeBreakExtend},
+    {0x0B55, 0x0B57, kGraphemeBreakExtend},
+    {0x0B5C, 0x0B5D, kGraphemeBreakInCBConsonant},
+    {0x0B5F, 0x0B5F, kGraphemeBreakInCBConsonant},
+    {0x0B62, 0x0B63, kGraphemeBreakExtend},
+    {0x0B71, 0x0B71, kGraphemeBreakInCBConsonant},
+    {0x0B82, 0x0B82, kGraphemeBreakExtend},
+    {0x0BBE, 0x0BBE, kGraphemeBreakExtend},
+    {0x0BBF, 0x0BBF, kGraphemeBreakSpacingMark},
+    {0x0BC0, 0x0BC0, kGraphemeBreakExtend},
+    {0x0BC1, 0x0BC2, kGraphemeBreakSpacingMark},
+    {0x0BC6, 0x0BC8, kGraphemeBreakSpacingMark},
+    {0x0BCA, 0x0BCC, kGraphemeBreakSpacingMark},
+    {0x0BCD, 0x0BCD, kGraphemeBreakExtend},
+    {0x0BD7, 0x0BD7, kGraphemeBreakExtend},
+    {0x0C00, 0x0C00, kGraphemeBreakExtend},
+    {0x0C01, 0x0C03, kGraphemeBreakSpacingMark},
+    {0x0C04, 0x0C04, kGraphemeBreakExtend},
+    {0x0C15, 0x0C28, kGraphemeBreakInCBConsonant},
+    {0x0C2A, 0x0C39, kGraphemeBreakInCBConsonant},
+    {0x0C3C, 0x0C3C, kGraphemeBreakExtend},
+    {0x0C3E, 0x0C40, kGraphemeBreakExtend},
+    {0x0C41, 0x0C44, kGraphemeBreakSpacingMark},
+    {0x0C46, 0x0C48, kGraphemeBreakExtend},
+    {0x0C4A, 0x0C4D, kGraphemeBreakExtend},
+    {0x0C55, 0x0C56, kGraphemeBreakExtend},
+    {0x0C58, 0x0C5A, kGraphemeBreakInCBConsonant},
+    {0x0C62, 0x0C63, kGraphemeBreakExtend},
+    {0x0C81, 0x0C81, kGraphemeBreakExtend},
+    {0x0C82, 0x0C83, kGraphemeBreakSpacingMark},
+    {0x0CBC, 0x0CBC, kGraphemeBreakExtend},
+    {0x0CBE, 0x0CBE, kGraphemeBreakSpacingMark},
+    {0x0CBF, 0x0CBF, kGraphemeBreakExtend},
+    {0x0CC0, 0x0CC1, kGraphemeBreakSpacingMark},
+    {0x0CC2, 0x0CC2, kGraphemeBreakExtend},
+    {0x0CC3, 0x0CC4, kGraphemeBreakSpacingMark},
+    {0x0CC6, 0x0CC6, kGraphemeBreakExtend},
+    {0x0CC7, 0x0CC8, kGraphemeBreakSpacingMark},
+    {0x0CCA, 0x0CCB, kGraphemeBreakSpacingMark},
+    {0x0CCC, 0x0CCD, kGraphemeBreakExtend},
+    {0x0CD5, 0x0CD6, kGraphemeBreakExtend},
+    {0x0CE2, 0x0CE3, kGraphemeBreakExtend},
+    {0x0CF3, 0x0CF3, kGraphemeBreakSpacingMark},
+    {0x0D00, 0x0D01, kGraphemeBreakExtend},
+    {0x0D02, 0x0D03, kGraphemeBreakSpacingMark},
+    {0x0D15, 0x0D3A, kGraphemeBreakInCBConsonant},
+    {0x0D3B, 0x0D3C, kGraphemeBreakExtend},
+    {0x0D3E, 0x0D3E, kGraphemeBreakExtend},
+    {0x0D3F, 0x0D40, kGraphemeBreakSpacingMark},
+    {0x0D41, 0x0D44, kGraphemeBreakExtend},
+    {0x0D46, 0x0D48, kGraphemeBreakSpacingMark},
+    {0x0D4A, 0x0D4C, kGraphemeBreakSpacingMark},
+    {0x0D4D, 0x0D4D, kGraphemeBreakExtend},
+    {0x0D4E, 0x0D4E, kGraphemeBreakPrepend},
+    {0x0D57, 0x0D57, kGraphemeBreakExtend},
+    {0x0D62, 0x0D63, kGraphemeBreakExtend},
+    {0x0D81, 0x0D81, kGraphemeBreakExtend},
+    {0x0D82, 0x0D83, kGraphemeBreakSpacingMark},
+    {0x0DCA, 0x0DCA, kGraphemeBreakExtend},
+    {0x0DCF, 0x0DCF, kGraphemeBreakExtend},
+    {0x0DD0, 0x0DD1, kGraphemeBreakSpacingMark},
+    {0x0DD2, 0x0DD4, kGraphemeBreakExtend},
+    {0x0DD6, 0x0DD6, kGraphemeBreakExtend},
+    {0x0DD8, 0x0DDE, kGraphemeBreakSpacingMark},
+    {0x0DDF, 0x0DDF, kGraphemeBreakExtend},
+    {0x0DF2, 0x0DF3, kGraphemeBreakSpacingMark},
+    {0x0E31, 0x0E31, kGraphemeBreakExtend},
+    {0x0E33, 0x0E33, kGraphemeBreakSpacingMark},
+    {0x0E34, 0x0E3A, kGraphemeBreakExtend},
+    {0x0E47, 0x0E4E, kGraphemeBreakExtend},
+    {0x0EB1, 0x0EB1, kGraphemeBreakExtend},
+    {0x0EB3, 0x0EB3, kGraphemeBreakSpacingMark},
+    {0x0EB4, 0x0EBC, kGraphemeBreakExtend},
+    {0x0EC8, 0x0ECE, kGraphemeBreakExtend},
+    {0x0F18, 0x0F19, kGraphemeBreakExtend},
+    {0x0F35, 0x0F35, kGraphemeBreakExtend},
+    {0x0F37, 0x0F37, kGraphemeBreakExtend},
+    {0x0F39, 0x0F39, kGraphemeBreakExtend},
+    {0x0F3E, 0x0F3F, kGraphemeBreakSpacingMark},
+    {0x0F71, 0x0F7E, kGraphemeBreakExtend},
+    {0x0F7F, 0x0F7F, kGraphemeBreakSpacingMark},
+    {0x0F80, 0x0F84, kGraphemeBreakExtend},
+    {0x0F86, 0x0F87, kGraphemeBreakExtend},
+    {0x0F8D, 0x0F97, kGraphemeBreakExtend},
+    {0x0F99, 0x0FBC, kGraphemeBreakExtend},
+    {0x0FC6, 0x0FC6, kGraphemeBreakExtend},
+    {0x102D, 0x1030, kGraphemeBreakExtend},
+    {0x1031, 0x1031, kGraphemeBreakSpacingMark},
+    {0x1032, 0x1037, kGraphemeBreakExtend},
+    {0x1039, 0x103A, kGraphemeBreakExtend},
+    {0x103B, 0x103C, kGraphemeBreakSpacingMark},
+    {0x103D, 0x103E, kGraphemeBreakExtend},
+    {0x1056, 0x1057, kGraphemeBreakSpacingMark},
+    {0x1058, 0x1059, kGraphemeBreakExtend},
+    {0x105E, 0x1060, kGraphemeBreakExtend},
+    {0x1071, 0x1074, kGraphemeBreakExtend},
+    {0x1082, 0x1082, kGraphemeBreakExtend},
+    {0x1084, 0x1084, kGraphemeBreakSpacingMark},
+    {0x1085, 0x1086, kGraphemeBreakExtend},
+    {0x108D, 0x108D, kGraphemeBreakExtend},
+    {0x109D, 0x109D, kGraphemeBreakExtend},
+    {0x1100, 0x115F, kGraphemeBreakL},
+    {0x1160, 0x11A7, kGraphemeBreakV},
+    {0x11A8, 0x11FF, kGraphemeBreakT},
+    {0x135D, 0x135F, kGraphemeBreakExtend},
+    {0x1712, 0x1714, kGraphemeBreakExtend},
+    {0x1715, 0x1715, kGraphemeBreakSpacingMark},
+    {0x1732, 0x1733, kGraphemeBreakExtend},
+    {0x1734, 0x1734, kGraphemeBreakSpacingMark},
+    {0x1752, 0x1753, kGraphemeBreakExtend},
+    {0x1772, 0x1773, kGraphemeBreakExtend},
+    {0x17B4, 0x17B5, kGraphemeBreakExtend},
+    {0x17B6, 0x17B6, kGraphemeBreakSpacingMark},
+    {0x17B7, 0x17BD, kGraphemeBreakExtend},
+    {0x17BE, 0x17C5, kGraphemeBreakSpacingMark},
+    {0x17C6, 0x17C6, kGraphemeBreakExtend},
+    {0x17C7, 0x17C8, kGraphemeBreakSpacingMark},
+    {0x17C9, 0x17D3, kGraphemeBreakExtend},
+    {0x17DD, 0x17DD, kGraphemeBreakExtend},
+    {0x180B, 0x180D, kGraphemeBreakExtend},
+    {0x180E, 0x180E, kGraphemeBreakControl},
+    {0x180F, 0x180F, kGraphemeBreakExtend},
+    {0x1885, 0x1886, kGraphemeBreakExtend},
+    {0x18A9, 0x18A9, kGraphemeBreakExtend},
+    {0x1920, 0x1922, kGraphemeBreakExtend},
+    {0x1923, 0x1926, kGraphemeBreakSpacingMark},
+    {0x1927, 0x1928, kGraphemeBreakExtend},
+    {0x1929, 0x192B, kGraphemeBreakSpacingMark},
+    {0x1930, 0x1931, kGraphemeBreakSpacingMark},
+    {0x1932, 0x1932, kGraphemeBreakExtend},
+    {0x1933, 0x1938, kGraphemeBreakSpacingMark},
+    {0x1939, 0x193B, kGraphemeBreakExtend},
+    {0x1A17, 0x1A18, kGraphemeBreakExtend},
+    {0x1A19, 0x1A1A, kGraphemeBreakSpacingMark},
+    {0x1A1B, 0x1A1B, kGraphemeBreakExtend},
+    {0x1A55, 0x1A55, kGraphemeBreakSpacingMark},
+    {0x1A56, 0x1A56, kGraphemeBreakExtend},
+    {0x1A57, 0x1A57, kGraphemeBreakSpacingMark},
+    {0x1A58, 0x1A5E, kGraphemeBreakExtend},
+    {0x1A60, 0x1A60, kGraphemeBreakExtend},
+    {0x1A62, 0x1A62, kGraphemeBreakExtend},
+    {0x1A65, 0x1A6C, kGraphemeBreakExtend},
+    {0x1A6D, 0x1A72, kGraphemeBreakSpacingMark},
+    {0x1A73, 0x1A7C, kGraphemeBreakExtend},
+    {0x1A7F, 0x1A7F, kGraphemeBreakExtend},
+    {0x1AB0, 0x1ACE, kGraphemeBreakExtend},
+    {0x1B00, 0x1B03, kGraphemeBreakExtend},
+    {0x1B04, 0x1B04, kGraphemeBreakSpacingMark},
+    {0x1B34, 0x1B3A, kGraphemeBreakExtend},
+    {0x1B3B, 0x1B3B, kGraphemeBreakSpacingMark},
+    {0x1B3C, 0x1B3C, kGraphemeBreakExtend},
+    {0x1B3D, 0x1B41, kGraphemeBreakSpacingMark},
+    {0x1B42, 0x1B42, kGraphemeBreakExtend},
+    {0x1B43, 0x1B44, kGraphemeBreakSpacingMark},
+    {0x1B6B, 0x1B73, kGraphemeBreakExtend},
+    {0x1B80, 0x1B81, kGraphemeBreakExtend},
+    {0x1B82, 0x1B82, kGraphemeBreakSpacingMark},
+    {0x1BA1, 0x1BA1, kGraphemeBreakSpacingMark},
+    {0x1BA2, 0x1BA5, kGraphemeBreakExtend},
+    {0x1BA6, 0x1BA7, kGraphemeBreakSpacingMark},
+    {0x1BA8, 0x1BA9, kGraphemeBreakExtend},
+    {0x1BAA, 0x1BAA, kGraphemeBreakSpacingMark},
+    {0x1BAB, 0x1BAD, kGraphemeBreakExtend},
+    {0x1BE6, 0x1BE6, kGraphemeBreakExtend},
+    {0x1BE7, 0x1BE7, kGraphemeBreakSpacingMark},
+    {0x1BE8, 0x1BE9, kGraphemeBreakExtend},
+    {0x1BEA, 0x1BEC, kGraphemeBreakSpacingMark},
+    {0x1BED, 0x1BED, kGraphemeBreakExtend},
+    {0x1BEE, 0x1BEE, kGraphemeBreakSpacingMark},
+    {0x1BEF, 0x1BF1, kGraphemeBreakExtend},
+    {0x1BF2, 0x1BF3, kGraphemeBreakSpacingMark},
+    {0x1C24, 0x1C2B, kGraphemeBreakSpacingMark},
+    {0x1C2C, 0x1C33, kGraphemeBreakExtend},
+    {0x1C34, 0x1C35, kGraphemeBreakSpacingMark},
+    {0x1C36, 0x1C37, kGraphemeBreakExtend},
+    {0x1CD0, 0x1CD2, kGraphemeBreakExtend},
+    {0x1CD4, 0x1CE0, kGraphemeBreakExtend},
+    {0x1CE1, 0x1CE1, kGraphemeBreakSpacingMark},
+    {0x1CE2, 0x1CE8, kGraphemeBreakExtend},
+    {0x1CED, 0x1CED, kGraphemeBreakExtend},
+    {0x1CF4, 0x1CF4, kGraphemeBreakExtend},
+    {0x1CF7, 0x1CF7, kGraphemeBreakSpacingMark},
+    {0x1CF8, 0x1CF9, kGraphemeBreakExtend},
+    {0x1DC0, 0x1DFF, kGraphemeBreakExtend},
+    {0x200B, 0x200B, kGraphemeBreakControl},
+    {0x200C, 0x200C, kGraphemeBreakExtend},
+    {0x200D, 0x200D, kGraphemeBreakZWJ},
+    {0x200E, 0x200F, kGraphemeBreakControl},
+    {0x2028, 0x202E, kGraphemeBreakControl},
+    {0x203C, 0x203C, kGraphemeBreakExtendedPictographic},
+    {0x2049, 0x2049, kGraphemeBreakExtendedPictographic},
+    {0x2060, 0x206F, kGraphemeBreakControl},
+    {0x20D0, 0x20F0, kGraphemeBreakExtend},
+    {0x2122, 0x2122, kGraphemeBreakExtendedPictographic},
+    {0x2139, 0x2139, kGraphemeBreakExtendedPictographic},
+    {0x2194, 0x2199, kGraphemeBreakExtendedPictographic},
+    {0x21A9, 0x21AA, kGraphemeBreakExtendedPictographic},
+    {0x231A, 0x231B, kGraphemeBreakExtendedPictographic},
+    {0x2328, 0x2328, kGraphemeBreakExtendedPictographic},
+    {0x2388, 0x2388, kGraphemeBreakExtendedPictographic},
+    {0x23CF, 0x23CF, kGraphemeBreakExtendedPictographic},
+    {0x23E9, 0x23F3, kGraphemeBreakExtendedPictographic},
+    {0x23F8, 0x23FA, kGraphemeBreakExtendedPictographic},
+    {0x24C2, 0x24C2, kGraphemeBreakExtendedPictographic},
+    {0x25AA, 0x25AB, kGraphemeBreakExtendedPictographic},
+    {0x25B6, 0x25B6, kGraphemeBreakExtendedPictographic},
+    {0x25C0, 0x25C0, kGraphemeBreakExtendedPictographic},
+    {0x25FB, 0x25FE, kGraphemeBreakExtendedPictographic},
+    {0x2600, 0x2605, kGraphemeBreakExtendedPictographic},
+    {0x2607, 0x2612, kGraphemeBreakExtendedPictographic},
+    {0x2614, 0x2685, kGraphemeBreakExtendedPictographic},
+    {0x2690, 0x2705, kGraphemeBreakExtendedPictographic},
+    {0x2708, 0x2712, kGraphemeBreakExtendedPictographic},
+    {0x2714, 0x2714, kGraphemeBreakExtendedPictographic},
+    {0x2716, 0x2716, kGraphemeBreakExtendedPictographic},
+    {0x271D, 0x271D, kGraphemeBreakExtendedPictographic},
+    {0x2721, 0x2721, kGraphemeBreakExtendedPictographic},
+    {0x2728, 0x2728, kGraphemeBreakExtendedPictographic},
+    {0x2733, 0x2734, kGraphemeBreakExtendedPictographic},
+    {0x2744, 0x2744, kGraphemeBreakExtendedPictographic},
+    {0x2747, 0x2747, kGraphemeBreakExtendedPictographic},
+    {0x274C, 0x274C, kGraphemeBreakExtendedPictographic},
+    {0x274E, 0x274E, kGraphemeBreakExtendedPictographic},
+    {0x2753, 0x2755, kGraphemeBreakExtendedPictographic},
+    {0x2757, 0x2757, kGraphemeBreakExtendedPictographic},
+    {0x2763, 0x2767, kGraphemeBreakExtendedPictographic},
+    {0x2795, 0x2797, kGraphemeBreakExtendedPictographic},
+    {0x27A1, 0x27A1, kGraphemeBreakExtendedPictographic},
+    {0x27B0, 0x27B0, kGraphemeBreakExtendedPictographic},
+    {0x27BF, 0x27BF, kGraphemeBreakExtendedPictographic},
+    {0x2934, 0x2935, kGraphemeBreakExtendedPictographic},
+    {0x2B05, 0x2B07, kGraphemeBreakExtendedPictographic},
+    {0x2B1B, 0x2B1C, kGraphemeBreakExtendedPictographic},
+    {0x2B50, 0x2B50, kGraphemeBreakExtendedPictographic},
+    {0x2B55, 0x2B55, kGraphemeBreakExtendedPictographic},
+    {0x2CEF, 0x2CF1, kGraphemeBreakExtend},
+    {0x2D7F, 0x2D7F, kGraphemeBreakExtend},
+    {0x2DE0, 0x2DFF, kGraphemeBreakExtend},
+    {0x302A, 0x302F, kGraphemeBreakExtend},
+    {0x3030, 0x3030, kGraphemeBreakExtendedPictographic},
+    {0x303D, 0x303D, kGraphemeBreakExtendedPictographic},
+    {0x3099, 0x309A, kGraphemeBreakExtend},
+    {0x3297, 0x3297, kGraphemeBreakExtendedPictographic},
+    {0x3299, 0x3299, kGraphemeBreakExtendedPictographic},
+    {0xA66F, 0xA672, kGraphemeBreakExtend},
+    {0xA674, 0xA67D, kGraphemeBreakExtend},
+    {0xA69E, 0xA69F, kGraphemeBreakExtend},
+    {0xA6F0, 0xA6F1, kGraphemeBreakExtend},
+    {0xA802, 0xA802, kGraphemeBreakExtend},
+    {0xA806, 0xA806, kGraphemeBreakExtend},
+    {0xA80B, 0xA80B, kGraphemeBreakExtend},
+    {0xA823, 0xA824, kGraphemeBreakSpacingMark},
+    {0xA825, 0xA826, kGraphemeBreakExtend},
+    {0xA827, 0xA827, kGraphemeBreakSpacingMark},
+    {0xA82C, 0xA82C, kGraphemeBreakExtend},
+    {0xA880, 0xA881, kGraphemeBreakSpacingMark},
+    {0xA8B4, 0xA8C3, kGraphemeBreakSpacingMark},
+    {0xA8C4, 0xA8C5, kGraphemeBreakExtend},
+    {0xA8E0, 0xA8F1, kGraphemeBreakExtend},
+    {0xA8FF, 0xA8FF, kGraphemeBreakExtend},
+    {0xA926, 0xA92D, kGraphemeBreakExtend},
+    {0xA947, 0xA951, kGraphemeBreakExtend},
+    {0xA952, 0xA953, kGraphemeBreakSpacingMark},
+    {0xA960, 0xA97C, kGraphemeBreakL},
+    {0xA980, 0xA982, kGraphemeBreakExtend},
+    {0xA983, 0xA983, kGraphemeBreakSpacingMark},
+    {0xA9B3, 0xA9B3, kGraphemeBreakExtend},
+    {0xA9B4, 0xA9B5, kGraphemeBreakSpacingMark},
+    {0xA9B6, 0xA9B9, kGraphemeBreakExtend},
+    {0xA9BA, 0xA9BB, kGraphemeBreakSpacingMark},
+    {0xA9BC, 0xA9BD, kGraphemeBreakExtend},
+    {0xA9BE, 0xA9C0, kGraphemeBreakSpacingMark},
+    {0xA9E5, 0xA9E5, kGraphemeBreakExtend},
+    {0xAA29, 0xAA2E, kGraphemeBreakExtend},
+    {0xAA2F, 0xAA30, kGraphemeBreakSpacingMark},
+    {0xAA31, 0xAA32, kGraphemeBreakExtend},
+    {0xAA33, 0xAA34, kGraphemeBreakSpacingMark},
+    {0xAA35, 0xAA36, kGraphemeBreakExtend},
+    {0xAA43, 0xAA43, kGraphemeBreakExtend},
+    {0xAA4C, 0xAA4C, kGraphemeBreakExtend},
+    {0xAA4D, 0xAA4D, kGraphemeBreakSpacingMark},
+    {0xAA7C, 0xAA7C, kGraphemeBreakExtend},
+    {0xAAB0, 0xAAB0, kGraphemeBreakExtend},
+    {0xAAB2, 0xAAB4, kGraphemeBreakExtend},
+    {0xAAB7, 0xAAB8, kGraphemeBreakExtend},
+    {0xAABE, 0xAABF, kGraphemeBreakExtend},
+    {0xAAC1, 0xAAC1, kGraphemeBreakExtend},
+    {0xAAEB, 0xAAEB, kGraphemeBreakSpacingMark},
+    {0xAAEC, 0xAAED, kGraphemeBreakExtend},
+    {0xAAEE, 0xAAEF, kGraphemeBreakSpacingMark},
+    {0xAAF5, 0xAAF5, kGraphemeBreakSpacingMark},
+    {0xAAF6, 0xAAF6, kGraphemeBreakExtend},
+    {0xABE3, 0xABE4, kGraphemeBreakSpacingMark},
+    {0xABE5, 0xABE5, kGraphemeBreakExtend},
+    {0xABE6, 0xABE7, kGraphemeBreakSpacingMark},
+    {0xABE8, 0xABE8, kGraphemeBreakExtend},
+    {0xABE9, 0xABEA, kGraphemeBreakSpacingMark},
+    {0xABEC, 0xABEC, kGraphemeBreakSpacingMark},
+    {0xABED, 0xABED, kGraphemeBreakExtend},
+    {0xAC00, 0xAC00, kGraphemeBreakLV},
+    {0xAC01, 0xAC1B, kGraphemeBreakLVT},
+    {0xAC1C, 0xAC1C, kGraphemeBreakLV},
+    {0xAC1D, 0xAC37, kGraphemeBreakLVT},
+    {0xAC38, 0xAC38, kGraphemeBreakLV},
+    {0xAC39, 0xAC53, kGraphemeBreakLVT},
+    {0xAC54, 0xAC54, kGraphemeBreakLV},
+    {0xAC55, 0xAC6F, kGraphemeBreakLVT},
+    {0xAC70, 0xAC70, kGraphemeBreakLV},
+    {0xAC71, 0xAC8B, kGraphemeBreakLVT},
+    {0xAC8C, 0xAC8C, kGraphemeBreakLV},
+    {0xAC8D, 0xACA7, kGraphemeBreakLVT},
+    {0xACA8, 0xACA8, kGraphemeBreakLV},
+    {0xACA9, 0xACC3, kGraphemeBreakLVT},
+    {0xACC4, 0xACC4, kGraphemeBreakLV},
+    {0xACC5, 0xACDF, kGraphemeBreakLVT},
+    {0xACE0, 0xACE0, kGraphemeBreakLV},
+    {0xACE1, 0xACFB, kGraphemeBreakLVT},
+    {0xACFC, 0xACFC, kGraphemeBreakLV},
+    {0xACFD, 0xAD17, kGraphemeBreakLVT},
+    {0xAD18, 0xAD18, kGraphemeBreakLV},
+    {0xAD19, 0xAD33, kGraphemeBreakLVT},
+    {0xAD34, 0xAD34, kGraphemeBreakLV},
+    {0xAD35, 0xAD4F, kGraphemeBreakLVT},
+    {0xAD50, 0xAD50, kGraphemeBreakLV},
+    {0xAD51, 0xAD6B, kGraphemeBreakLVT},
+    {0xAD6C, 0xAD6C, kGraphemeBreakLV},
+    {0xAD6D, 0xAD87, kGraphemeBreakLVT},
+    {0xAD88, 0xAD88, kGraphemeBreakLV},
+    {0xAD89, 0xADA3, kGraphemeBreakLVT},
+    {0xADA4, 0xADA4, kGraphemeBreakLV},
+    {0xADA5, 0xADBF, kGraphemeBreakLVT},
+    {0xADC0, 0xADC0, kGraphemeBreakLV},
+    {0xADC1, 0xADDB, kGraphemeBreakLVT},
+    {0xADDC, 0xADDC, kGraphemeBreakLV},
+    {0xADDD, 0xADF7, kGraphemeBreakLVT},
+    {0xADF8, 0xADF8, kGraphemeBreakLV},
+    {0xADF9, 0xAE13, kGraphemeBreakLVT},
+    {0xAE14, 0xAE14, kGraphemeBreakLV},
+    {0xAE15, 0xAE2F, kGraphemeBreakLVT},
+    {0xAE30, 0xAE30, kGraphemeBreakLV},
+    {0xAE31, 0xAE4B, kGraphemeBreakLVT},
+    {0xAE4C, 0xAE4C, kGraphemeBreakLV},
+    {0xAE4D, 0xAE67, kGraphemeBreakLVT},
+    {0xAE68, 0xAE68, kGraphemeBreakLV},
+    {0xAE69, 0xAE83, kGraphemeBreakLVT},
+    {0xAE84, 0xAE84, kGraphemeBreakLV},
+    {0xAE85, 0xAE9F, kGraphemeBreakLVT},
+    {0xAEA0, 0xAEA0, kGraphemeBreakLV},
+    {0xAEA1, 0xAEBB, kGraphemeBreakLVT},
+    {0xAEBC, 0xAEBC, kGraphemeBreakLV},
+    {0xAEBD, 0xAED7, kGraphemeBreakLVT},
+    {0xAED8, 0xAED8, kGraphemeBreakLV},
+    {0xAED9, 0xAEF3, kGraphemeBreakLVT},
+    {0xAEF4, 0xAEF4, kGraphemeBreakLV},
+    {0xAEF5, 0xAF0F, kGraphemeBreakLVT},
+    {0xAF10, 0xAF10, kGraphemeBreakLV},
+    {0xAF11, 0xAF2B, kGraphemeBreakLVT},
+    {0xAF2C, 0xAF2C, kGraphemeBreakLV},
+    {0xAF2D, 0xAF47, kGraphemeBreakLVT},
+    {0xAF48, 0xAF48, kGraphemeBreakLV},
+    {0xAF49, 0xAF63, kGraphemeBreakLVT},
+    {0xAF64, 0xAF64, kGraphemeBreakLV},
+    {0xAF65, 0xAF7F, kGraphemeBreakLVT},
+    {0xAF80, 0xAF80, kGraphemeBreakLV},
+    {0xAF81, 0xAF9B, kGraphemeBreakLVT},
+    {0xAF9C, 0xAF9C, kGraphemeBreakLV},
+    {0xAF9D, 0xAFB7, kGraphemeBreakLVT},
+    {0xAFB8, 0xAFB8, kGraphemeBreakLV},
+    {0xAFB9, 0xAFD3, kGraphemeBreakLVT},
+    {0xAFD4, 0xAFD4, kGraphemeBreakLV},
+    {0xAFD5, 0xAFEF, kGraphemeBreakLVT},
+    {0xAFF0, 0xAFF0, kGraphemeBreakLV},
+    {0xAFF1, 0xB00B, kGraphemeBreakLVT},
+    {0xB00C, 0xB00C, kGraphemeBreakLV},
+    {0xB00D, 0xB027, kGraphemeBreakLVT},
+    {0xB028, 0xB028, kGraphemeBreakLV},
+    {0xB029, 0xB043, kGraphemeBreakLVT},
+    {0xB044, 0xB044, kGraphemeBreakLV},
+    {0xB045, 0xB05F, kGraphemeBreakLVT},
+    {0xB060, 0xB060, kGraphemeBreakLV},
+    {0xB061, 0xB07B, kGraphemeBreakLVT},
+    {0xB07C, 0xB07C, kGraphemeBreakLV},
+    {0xB07D, 0xB097, kGraphemeBreakLVT},
+    {0xB098, 0xB098, kGraphemeBreakLV},
+    {0xB099, 0xB0B3, kGraphemeBreakLVT},
+    {0xB0B4, 0xB0B4, kGraphemeBreakLV},
+    {0xB0B5, 0xB0CF, kGraphemeBreakLVT},
+    {0xB0D0, 0xB0D0, kGraphemeBreakLV},
+    {0xB0D1, 0xB0EB, kGraphemeBreakLVT},
+    {0xB0EC, 0xB0EC, kGraphemeBreakLV},
+    {0xB0ED, 0xB107, kGraphemeBreakLVT},
+    {0xB108, 0xB108, kGraphemeBreakLV},
+    {0xB109, 0xB123, kGraphemeBreakLVT},
+    {0xB124, 0xB124, kGraphemeBreakLV},
+    {0xB125, 0xB13F, kGraphemeBreakLVT},
+    {0xB140, 0xB140, kGraphemeBreakLV},
+    {0xB141, 0xB15B, kGraphemeBreakLVT},
+    {0xB15C, 0xB15C, kGraphemeBreakLV},
+    {0xB15D, 0xB177, kGraphemeBreakLVT},
+    {0xB178, 0xB178, kGraphemeBreakLV},
+    {0xB179, 0xB193, kGraphemeBreakLVT},
+    {0xB194, 0xB194, kGraphemeBreakLV},
+    {0xB195, 0xB1AF, kGraphemeBreakLVT},
+    {0xB1B0, 0xB1B0, kGraphemeBreakLV},
+    {0xB1B1, 0xB1CB, kGraphemeBreakLVT},
+    {0xB1CC, 0xB1CC, kGraphemeBreakLV},
+    {0xB1CD, 0xB1E7, kGraphemeBreakLVT},
+    {0xB1E8, 0xB1E8, kGraphemeBreakLV},
+    {0xB1E9, 0xB203, kGraphemeBreakLVT},
+    {0xB204, 0xB204, kGraphemeBreakLV},
+    {0xB205, 0xB21F, kGraphemeBreakLVT},
+    {0xB220, 0xB220, kGraphemeBreakLV},
+    {0xB221, 0xB23B, kGraphemeBreakLVT},
+    {0xB23C, 0xB23C, kGraphemeBreakLV},
+    {0xB23D, 0xB257, kGraphemeBreakLVT},
+    {0xB258, 0xB258, kGraphemeBreakLV},
+    {0xB259, 0xB273, kGraphemeBreakLVT},
+    {0xB274, 0xB274, kGraphemeBreakLV},
+    {0xB275, 0xB28F, kGraphemeBreakLVT},
+    {0xB290, 0xB290, kGraphemeBreakLV},
+    {0xB291, 0xB2AB, kGraphemeBreakLVT},
+    {0xB2AC, 0xB2AC, kGraphemeBreakLV},
+    {0xB2AD, 0xB2C7, kGraphemeBreakLVT},
+    {0xB2C8, 0xB2C8, kGraphemeBreakLV},
+    {0xB2C9, 0xB2E3, kGraphemeBreakLVT},
+    {0xB2E4, 0xB2E4, kGraphemeBreakLV},
+    {0xB2E5, 0xB2FF, kGraphemeBreakLVT},
+    {0xB300, 0xB300, kGraphemeBreakLV},
+    {0xB301, 0xB31B, kGraphemeBreakLVT},
+    {0xB31C, 0xB31C, kGraphemeBreakLV},
+    {0xB31D, 0xB337, kGraphemeBreakLVT},
+    {0xB338, 0xB338, kGraphemeBreakLV},
+    {0xB339, 0xB353, kGraphemeBreakLVT},
+    {0xB354, 0xB354, kGraphemeBreakLV},
+    {0xB355, 0xB36F, kGraphemeBreakLVT},
+    {0xB370, 0xB370, kGraphemeBreakLV},
+    {0xB371, 0xB38B, kGraphemeBreakLVT},
+    {0xB38C, 0xB38C, kGraphemeBreakLV},
+    {0xB38D, 0xB3A7, kGraphemeBreakLVT},
+    {0xB3A8, 0xB3A8, kGraphemeBreakLV},
+    {0xB3A9, 0xB3C3, kGraphemeBreakLVT},
+    {0xB3C4, 0xB3C4, kGraphemeBreakLV},
+    {0xB3C5, 0xB3DF, kGraphemeBreakLVT},
+    {0xB3E0, 0xB3E0, kGraphemeBreakLV},
+    {0xB3E1, 0xB3FB, kGraphemeBreakLVT},
+    {0xB3FC, 0xB3FC, kGraphemeBreakLV},
+    {0xB3FD, 0xB417, kGraphemeBreakLVT},
+    {0xB418, 0xB418, kGraphemeBreakLV},
+    {0xB419, 0xB433, kGraphemeBreakLVT},
+    {0xB434, 0xB434, kGraphemeBreakLV},
+    {0xB435, 0xB44F, kGraphemeBreakLVT},
+    {0xB450, 0xB450, kGraphemeBreakLV},
+    {0xB451, 0xB46B, kGraphemeBreakLVT},
+    {0xB46C, 0xB46C, kGraphemeBreakLV},
+    {0xB46D, 0xB487, kGraphemeBreakLVT},
+    {0xB488, 0xB488, kGraphemeBreakLV},
+    {0xB489, 0xB4A3, kGraphemeBreakLVT},
+    {0xB4A4, 0xB4A4, kGraphemeBreakLV},
+    {0xB4A5, 0xB4BF, kGraphemeBreakLVT},
+    {0xB4C0, 0xB4C0, kGraphemeBreakLV},
+    {0xB4C1, 0xB4DB, kGraphemeBreakLVT},
+    {0xB4DC, 0xB4DC, kGraphemeBreakLV},
+    {0xB4DD, 0xB4F7, kGraphemeBreakLVT},
+    {0xB4F8, 0xB4F8, kGraphemeBreakLV},
+    {0xB4F9, 0xB513, kGraphemeBreakLVT},
+    {0xB514, 0xB514, kGraphemeBreakLV},
+    {0xB515, 0xB52F, kGraphemeBreakLVT},
+    {0xB530, 0xB530, kGraphemeBreakLV},
+    {0xB531, 0xB54B, kGraphemeBreakLVT},
+    {0xB54C, 0xB54C, kGraphemeBreakLV},
+    {0xB54D, 0xB567, kGraphemeBreakLVT},
+    {0xB568, 0xB568, kGraphemeBreakLV},
+    {0xB569, 0xB583, kGraphemeBreakLVT},
+    {0xB584, 0xB584, kGraphemeBreakLV},
+    {0xB585, 0xB59F, kGraphemeBreakLVT},
+    {0xB5A0, 0xB5A0, kGraphemeBreakLV},
+    {0xB5A1, 0xB5BB, kGraphemeBreakLVT},
+    {0xB5BC, 0xB5BC, kGraphemeBreakLV},
+    {0xB5BD, 0xB5D7, kGraphemeBreakLVT},
+    {0xB5D8, 0xB5D8, kGraphemeBreakLV},
+    {0xB5D9, 0xB5F3, kGraphemeBreakLVT},
+    {0xB5F4, 0xB5F4, kGraphemeBreakLV},
+    {0xB5F5, 0xB60F, kGraphemeBreakLVT},
+    {0xB610, 0xB610, kGraphemeBreakLV},
+    {0xB611, 0xB62B, kGraphemeBreakLVT},
+    {0xB62C, 0xB62C, kGraphemeBreakLV},
+    {0xB62D, 0xB647, kGraphemeBreakLVT},
+    {0xB648, 0xB648, kGraphemeBreakLV},
+    {0xB649, 0xB663, kGraphemeBreakLVT},
+    {0xB664, 0xB664, kGraphemeBreakLV},
+    {0xB665, 0xB67F, kGraphemeBreakLVT},
+    {0xB680, 0xB680, kGraphemeBreakLV},
+    {0xB681, 0xB69B, kGraphemeBreakLVT},
+    {0xB69C, 0xB69C, kGraphemeBreakLV},
+    {0xB69D, 0xB6B7, kGraphemeBreakLVT},
+    {0xB6B8, 0xB6B8, kGraphemeBreakLV},
+    {0xB6B9, 0xB6D3, kGraphemeBreakLVT},
+    {0xB6D4, 0xB6D4, kGraphemeBreakLV},
+    {0xB6D5, 0xB6EF, kGraphemeBreakLVT},
+    {0xB6F0, 0xB6F0, kGraphemeBreakLV},
+    {0xB6F1, 0xB70B, kGraphemeBreakLVT},
+    {0xB70C, 0xB70C, kGraphemeBreakLV},
+    {0xB70D, 0xB727, kGraphemeBreakLVT},
+    {0xB728, 0xB728, kGraphemeBreakLV},
+    {0xB729, 0xB743, kGraphemeBreakLVT},
+    {0xB744, 0xB744, kGraphemeBreakLV},
+    {0xB745, 0xB75F, kGraphemeBreakLVT},
+    {0xB760, 0xB760, kGraphemeBreakLV},
+    {0xB761, 0xB77B, kGraphemeBreakLVT},
+    {0xB77C, 0xB77C, kGraphemeBreakLV},
+    {0xB77D, 0xB797, kGraphemeBreakLVT},
+    {0xB798, 0xB798, kGraphemeBreakLV},
+    {0xB799, 0xB7B3, kGraphemeBreakLVT},
+    {0xB7B4, 0xB7B4, kGraphemeBreakLV},
+    {0xB7B5, 0xB7CF, kGraphemeBreakLVT},
+    {0xB7D0, 0xB7D0, kGraphemeBreakLV},
+    {0xB7D1, 0xB7EB, kGraphemeBreakLVT},
+    {0xB7EC, 0xB7EC, kGraphemeBreakLV},
+    {0xB7ED, 0xB807, kGraphemeBreakLVT},
+    {0xB808, 0xB808, kGraphemeBreakLV},
+    {0xB809, 0xB823, kGraphemeBreakLVT},
+    {0xB824, 0xB824, kGraphemeBreakLV},
+    {0xB825, 0xB83F, kGraphemeBreakLVT},
+    {0xB840, 0xB840, kGraphemeBreakLV},
+    {0xB841, 0xB85B, kGraphemeBreakLVT},
+    {0xB85C, 0xB85C, kGraphemeBreakLV},
+    {0xB85D, 0xB877, kGraphemeBreakLVT},
+    {0xB878, 0xB878, kGraphemeBreakLV},
+    {0xB879, 0xB893, kGraphemeBreakLVT},
+    {0xB894, 0xB894, kGraphemeBreakLV},
+    {0xB895, 0xB8AF, kGraphemeBreakLVT},
+    {0xB8B0, 0xB8B0, kGraphemeBreakLV},
+    {0xB8B1, 0xB8CB, kGraphemeBreakLVT},
+    {0xB8CC, 0xB8CC, kGraphemeBreakLV},
+    {0xB8CD, 0xB8E7, kGraphemeBreakLVT},
+    {0xB8E8, 0xB8E8, kGraphemeBreakLV},
+    {0xB8E9, 0xB903, kGraphemeBreakLVT},
+    {0xB904, 0xB904, kGraphemeBreakLV},
+    {0xB905, 0xB91F, kGraphemeBreakLVT},
+    {0xB920, 0xB920, kGraphemeBreakLV},
+    {0xB921, 0xB93B, kGraphemeBreakLVT},
+    {0xB93C, 0xB93C, kGraphemeBreakLV},
+    {0xB93D, 0xB957, kGraphemeBreakLVT},
+    {0xB958, 0xB958, kGraphemeBreakLV},
+    {0xB959, 0xB973, kGraphemeBreakLVT},
+    {0xB974, 0xB974, kGraphemeBreakLV},
+    {0xB975, 0xB98F, kGraphemeBreakLVT},
+    {0xB990, 0xB990, kGraphemeBreakLV},
+    {0xB991, 0xB9AB, kGraphemeBreakLVT},
+    {0xB9AC, 0xB9AC, kGraphemeBreakLV},
+    {0xB9AD, 0xB9C7, kGraphemeBreakLVT},
+    {0xB9C8, 0xB9C8, kGraphemeBreakLV},
+    {0xB9C9, 0xB9E3, kGraphemeBreakLVT},
+    {0xB9E4, 0xB9E4, kGraphemeBreakLV},
+    {0xB9E5, 0xB9FF, kGraphemeBreakLVT},
+    {0xBA00, 0xBA00, kGraphemeBreakLV},
+    {0xBA01, 0xBA1B, kGraphemeBreakLVT},
+    {0xBA1C, 0xBA1C, kGraphemeBreakLV},
+    {0xBA1D, 0xBA37, kGraphemeBreakLVT},
+    {0xBA38, 0xBA38, kGraphemeBreakLV},
+    {0xBA39, 0xBA53, kGraphemeBreakLVT},
+    {0xBA54, 0xBA54, kGraphemeBreakLV},
+    {0xBA55, 0xBA6F, kGraphemeBreakLVT},
+    {0xBA70, 0xBA70, kGraphemeBreakLV},
+    {0xBA71, 0xBA8B, kGraphemeBreakLVT},
+    {0xBA8C, 0xBA8C, kGraphemeBreakLV},
+    {0xBA8D, 0xBAA7, kGraphemeBreakLVT},
+    {0xBAA8, 0xBAA8, kGraphemeBreakLV},
+    {0xBAA9, 0xBAC3, kGraphemeBreakLVT},
+    {0xBAC4, 0xBAC4, kGraphemeBreakLV},
+    {0xBAC5, 0xBADF, kGraphemeBreakLVT},
+    {0xBAE0, 0xBAE0, kGraphemeBreakLV},
+    {0xBAE1, 0xBAFB, kGraphemeBreakLVT},
+    {0xBAFC, 0xBAFC, kGraphemeBreakLV},
+    {0xBAFD, 0xBB17, kGraphemeBreakLVT},
+    {0xBB18, 0xBB18, kGraphemeBreakLV},
+    {0xBB19, 0xBB33, kGraphemeBreakLVT},
+    {0xBB34, 0xBB34, kGraphemeBreakLV},
+    {0xBB35, 0xBB4F, kGraphemeBreakLVT},
+    {0xBB50, 0xBB50, kGraphemeBreakLV},
+    {0xBB51, 0xBB6B, kGraphemeBreakLVT},
+    {0xBB6C, 0xBB6C, kGraphemeBreakLV},
+    {0xBB6D, 0xBB87, kGraphemeBreakLVT},
+    {0xBB88, 0xBB88, kGraphemeBreakLV},
+    {0xBB89, 0xBBA3, kGraphemeBreakLVT},
+    {0xBBA4, 0xBBA4, kGraphemeBreakLV},
+    {0xBBA5, 0xBBBF, kGraphemeBreakLVT},
+    {0xBBC0, 0xBBC0, kGraphemeBreakLV},
+    {0xBBC1, 0xBBDB, kGraphemeBreakLVT},
+    {0xBBDC, 0xBBDC, kGraphemeBreakLV},
+    {0xBBDD, 0xBBF7, kGraphemeBreakLVT},
+    {0xBBF8, 0xBBF8, kGraphemeBreakLV},
+    {0xBBF9, 0xBC13, kGraphemeBreakLVT},
+    {0xBC14, 0xBC14, kGraphemeBreakLV},
+    {0xBC15, 0xBC2F, kGraphemeBreakLVT},
+    {0xBC30, 0xBC30, kGraphemeBreakLV},
+    {0xBC31, 0xBC4B, kGraphemeBreakLVT},
+    {0xBC4C, 0xBC4C, kGraphemeBreakLV},
+    {0xBC4D, 0xBC67, kGraphemeBreakLVT},
+    {0xBC68, 0xBC68, kGraphemeBreakLV},
+    {0xBC69, 0xBC83, kGraphemeBreakLVT},
+    {0xBC84, 0xBC84, kGraphemeBreakLV},
+    {0xBC85, 0xBC9F, kGraphemeBreakLVT},
+    {0xBCA0, 0xBCA0, kGraphemeBreakLV},
+    {0xBCA1, 0xBCBB, kGraphemeBreakLVT},
+    {0xBCBC, 0xBCBC, kGraphemeBreakLV},
+    {0xBCBD, 0xBCD7, kGraphemeBreakLVT},
+    {0xBCD8, 0xBCD8, kGraphemeBreakLV},
+    {0xBCD9, 0xBCF3, kGraphemeBreakLVT},
+    {0xBCF4, 0xBCF4, kGraphemeBreakLV},
+    {0xBCF5, 0xBD0F, kGraphemeBreakLVT},
+    {0xBD10, 0xBD10, kGraphemeBreakLV},
+    {0xBD11, 0xBD2B, kGraphemeBreakLVT},
+    {0xBD2C, 0xBD2C, kGraphemeBreakLV},
+    {0xBD2D, 0xBD47, kGraphemeBreakLVT},
+    {0xBD48, 0xBD48, kGraphemeBreakLV},
+    {0xBD49, 0xBD63, kGraphemeBreakLVT},
+    {0xBD64, 0xBD64, kGraphemeBreakLV},
+    {0xBD65, 0xBD7F, kGraphemeBreakLVT},
+    {0xBD80, 0xBD80, kGraphemeBreakLV},
+    {0xBD81, 0xBD9B, kGraphemeBreakLVT},
+    {0xBD9C, 0xBD9C, kGraphemeBreakLV},
+    {0xBD9D, 0xBDB7, kGraphemeBreakLVT},
+    {0xBDB8, 0xBDB8, kGraphemeBreakLV},
+    {0xBDB9, 0xBDD3, kGraphemeBreakLVT},
+    {0xBDD4, 0xBDD4, kGraphemeBreakLV},
+    {0xBDD5, 0xBDEF, kGraphemeBreakLVT},
+    {0xBDF0, 0xBDF0, kGraphemeBreakLV},
+    {0xBDF1, 0xBE0B, kGraphemeBreakLVT},
+    {0xBE0C, 0xBE0C, kGraphemeBreakLV},
+    {0xBE0D, 0xBE27, kGraphemeBreakLVT},
+    {0xBE28, 0xBE28, kGraphemeBreakLV},
+    {0xBE29, 0xBE43, kGraphemeBreakLVT},
+    {0xBE44, 0xBE44, kGraphemeBreakLV},
+    {0xBE45, 0xBE5F, kGraphemeBreakLVT},
+    {0xBE60, 0xBE60, kGraphemeBreakLV},
+    {0xBE61, 0xBE7B, kGraphemeBreakLVT},
+    {0xBE7C, 0xBE7C, kGraphemeBreakLV},
+    {0xBE7D, 0xBE97, kGraphemeBreakLVT},
+    {0xBE98, 0xBE98, kGraphemeBreakLV},
+    {0xBE99, 0xBEB3, kGraphemeBreakLVT},
+    {0xBEB4, 0xBEB4, kGraphemeBreakLV},
+    {0xBEB5, 0xBECF, kGraphemeBreakLVT},
+    {0xBED0, 0xBED0, kGraphemeBreakLV},
+    {0xBED1, 0xBEEB, kGraphemeBreakLVT},
+    {0xBEEC, 0xBEEC, kGraphemeBreakLV},
+    {0xBEED, 0xBF07, kGraphemeBreakLVT},
+    {0xBF08, 0xBF08, kGraphemeBreakLV},
+    {0xBF09, 0xBF23, kGraphemeBreakLVT},
+    {0xBF24, 0xBF24, kGraphemeBreakLV},
+    {0xBF25, 0xBF3F, kGraphemeBreakLVT},
+    {0xBF40, 0xBF40, kGraphemeBreakLV},
+    {0xBF41, 0xBF5B, kGraphemeBreakLVT},
+    {0xBF5C, 0xBF5C, kGraphemeBreakLV},
+    {0xBF5D, 0xBF77, kGraphemeBreakLVT},
+    {0xBF78, 0xBF78, kGraphemeBreakLV},
+    {0xBF79, 0xBF93, kGraphemeBreakLVT},
+    {0xBF94, 0xBF94, kGraphemeBreakLV},
+    {0xBF95, 0xBFAF, kGraphemeBreakLVT},
+    {0xBFB0, 0xBFB0, kGraphemeBreakLV},
+    {0xBFB1, 0xBFCB, kGraphemeBreakLVT},
+    {0xBFCC, 0xBFCC, kGraphemeBreakLV},
+    {0xBFCD, 0xBFE7, kGraphemeBreakLVT},
+    {0xBFE8, 0xBFE8, kGraphemeBreakLV},
+    {0xBFE9, 0xC003, kGraphemeBreakLVT},
+    {0xC004, 0xC004, kGraphemeBreakLV},
+    {0xC005, 0xC01F, kGraphemeBreakLVT},
+    {0xC020, 0xC020, kGraphemeBreakLV},
+    {0xC021, 0xC03B, kGraphemeBreakLVT},
+    {0xC03C, 0xC03C, kGraphemeBreakLV},
+    {0xC03D, 0xC057, kGraphemeBreakLVT},
+    {0xC058, 0xC058, kGraphemeBreakLV},
+    {0xC059, 0xC073, kGraphemeBreakLVT},
+    {0xC074, 0xC074, kGraphemeBreakLV},
+    {0xC075, 0xC08F, kGraphemeBreakLVT},
+    {0xC090, 0xC090, kGraphemeBreakLV},
+    {0xC091, 0xC0AB, kGraphemeBreakLVT},
+    {0xC0AC, 0xC0AC, kGraphemeBreakLV},
+    {0xC0AD, 0xC0C7, kGraphemeBreakLVT},
+    {0xC0C8, 0xC0C8, kGraphemeBreakLV},
+    {0xC0C9, 0xC0E3, kGraphemeBreakLVT},
+    {0xC0E4, 0xC0E4, kGraphemeBreakLV},
+    {0xC0E5, 0xC0FF, kGraphemeBreakLVT},
+    {0xC100, 0xC100, kGraphemeBreakLV},
+    {0xC101, 0xC11B, kGraphemeBreakLVT},
+    {0xC11C, 0xC11C, kGraphemeBreakLV},
+    {0xC11D, 0xC137, kGraphemeBreakLVT},
+    {0xC138, 0xC138, kGraphemeBreakLV},
+    {0xC139, 0xC153, kGraphemeBreakLVT},
+    {0xC154, 0xC154, kGraphemeBreakLV},
+    {0xC155, 0xC16F, kGraphemeBreakLVT},
+    {0xC170, 0xC170, kGraphemeBreakLV},
+    {0xC171, 0xC18B, kGraphemeBreakLVT},
+    {0xC18C, 0xC18C, kGraphemeBreakLV},
+    {0xC18D, 0xC1A7, kGraphemeBreakLVT},
+    {0xC1A8, 0xC1A8, kGraphemeBreakLV},
+    {0xC1A9, 0xC1C3, kGraphemeBreakLVT},
+    {0xC1C4, 0xC1C4, kGraphemeBreakLV},
+    {0xC1C5, 0xC1DF, kGraphemeBreakLVT},
+    {0xC1E0, 0xC1E0, kGraphemeBreakLV},
+    {0xC1E1, 0xC1FB, kGraphemeBreakLVT},
+    {0xC1FC, 0xC1FC, kGraphemeBreakLV},
+    {0xC1FD, 0xC217, kGraphemeBreakLVT},
+    {0xC218, 0xC218, kGraphemeBreakLV},
+    {0xC219, 0xC233, kGraphemeBreakLVT},
+    {0xC234, 0xC234, kGraphemeBreakLV},
+    {0xC235, 0xC24F, kGraphemeBreakLVT},
+    {0xC250, 0xC250, kGraphemeBreakLV},
+    {0xC251, 0xC26B, kGraphemeBreakLVT},
+    {0xC26C, 0xC26C, kGraphemeBreakLV},
+    {0xC26D, 0xC287, kGraphemeBreakLVT},
+    {0xC288, 0xC288, kGraphemeBreakLV},
+    {0xC289, 0xC2A3, kGraphemeBreakLVT},
+    {0xC2A4, 0xC2A4, kGraphemeBreakLV},
+    {0xC2A5, 0xC2BF, kGraphemeBreakLVT},
+    {0xC2C0, 0xC2C0, kGraphemeBreakLV},
+    {0xC2C1, 0xC2DB, kGraphemeBreakLVT},
+    {0xC2DC, 0xC2DC, kGraphemeBreakLV},
+    {0xC2DD, 0xC2F7, kGraphemeBreakLVT},
+    {0xC2F8, 0xC2F8, kGraphemeBreakLV},
+    {0xC2F9, 0xC313, kGraphemeBreakLVT},
+    {0xC314, 0xC314, kGraphemeBreakLV},
+    {0xC315, 0xC32F, kGraphemeBreakLVT},
+    {0xC330, 0xC330, kGraphemeBreakLV},
+    {0xC331, 0xC34B, kGraphemeBreakLVT},
+    {0xC34C, 0xC34C, kGraphemeBreakLV},
+    {0xC34D, 0xC367, kGraphemeBreakLVT},
+    {0xC368, 0xC368, kGraphemeBreakLV},
+    {0xC369, 0xC383, kGraphemeBreakLVT},
+    {0xC384, 0xC384, kGraphemeBreakLV},
+    {0xC385, 0xC39F, kGraphemeBreakLVT},
+    {0xC3A0, 0xC3A0, kGraphemeBreakLV},
+    {0xC3A1, 0xC3BB, kGraphemeBreakLVT},
+    {0xC3BC, 0xC3BC, kGraphemeBreakLV},
+    {0xC3BD, 0xC3D7, kGraphemeBreakLVT},
+    {0xC3D8, 0xC3D8, kGraphemeBreakLV},
+    {0xC3D9, 0xC3F3, kGraphemeBreakLVT},
+    {0xC3F4, 0xC3F4, kGraphemeBreakLV},
+    {0xC3F5, 0xC40F, kGraphemeBreakLVT},
+    {0xC410, 0xC410, kGraphemeBreakLV},
+    {0xC411, 0xC42B, kGraphemeBreakLVT},
+    {0xC42C, 0xC42C, kGraphemeBreakLV},
+    {0xC42D, 0xC447, kGraphemeBreakLVT},
+    {0xC448, 0xC448, kGraphemeBreakLV},
+    {0xC449, 0xC463, kGraphemeBreakLVT},
+    {0xC464, 0xC464, kGraphemeBreakLV},
+    {0xC465, 0xC47F, kGraphemeBreakLVT},
+    {0xC480, 0xC480, kGraphemeBreakLV},
+    {0xC481, 0xC49B, kGraphemeBreakLVT},
+    {0xC49C, 0xC49C, kGraphemeBreakLV},
+    {0xC49D, 0xC4B7, kGraphemeBreakLVT},
+    {0xC4B8, 0xC4B8, kGraphemeBreakLV},
+    {0xC4B9, 0xC4D3, kGraphemeBreakLVT},
+    {0xC4D4, 0xC4D4, kGraphemeBreakLV},
+    {0xC4D5, 0xC4EF, kGraphemeBreakLVT},
+    {0xC4F0, 0xC4F0, kGraphemeBreakLV},
+    {0xC4F1, 0xC50B, kGraphemeBreakLVT},
+    {0xC50C, 0xC50C, kGraphemeBreakLV},
+    {0xC50D, 0xC527, kGraphemeBreakLVT},
+    {0xC528, 0xC528, kGraphemeBreakLV},
+    {0xC529, 0xC543, kGraphemeBreakLVT},
+    {0xC544, 0xC544, kGraphemeBreakLV},
+    {0xC545, 0xC55F, kGraphemeBreakLVT},
+    {0xC560, 0xC560, kGraphemeBreakLV},
+    {0xC561, 0xC57B, kGraphemeBreakLVT},
+    {0xC57C, 0xC57C, kGraphemeBreakLV},
+    {0xC57D, 0xC597, kGraphemeBreakLVT},
+    {0xC598, 0xC598, kGraphemeBreakLV},
+    {0xC599, 0xC5B3, kGraphemeBreakLVT},
+    {0xC5B4, 0xC5B4, kGraphemeBreakLV},
+    {0xC5B5, 0xC5CF, kGraphemeBreakLVT},
+    {0xC5D0, 0xC5D0, kGraphemeBreakLV},
+    {0xC5D1, 0xC5EB, kGraphemeBreakLVT},
+    {0xC5EC, 0xC5EC, kGraphemeBreakLV},
+    {0xC5ED, 0xC607, kGraphemeBreakLVT},
+    {0xC608, 0xC608, kGraphemeBreakLV},
+    {0xC609, 0xC623, kGraphemeBreakLVT},
+    {0xC624, 0xC624, kGraphemeBreakLV},
+    {0xC625, 0xC63F, kGraphemeBreakLVT},
+    {0xC640, 0xC640, kGraphemeBreakLV},
+    {0xC641, 0xC65B, kGraphemeBreakLVT},
+    {0xC65C, 0xC65C, kGraphemeBreakLV},
+    {0xC65D, 0xC677, kGraphemeBreakLVT},
+    {0xC678, 0xC678, kGraphemeBreakLV},
+    {0xC679, 0xC693, kGraphemeBreakLVT},
+    {0xC694, 0xC694, kGraphemeBreakLV},
+    {0xC695, 0xC6AF, kGraphemeBreakLVT},
+    {0xC6B0, 0xC6B0, kGraphemeBreakLV},
+    {0xC6B1, 0xC6CB, kGraphemeBreakLVT},
+    {0xC6CC, 0xC6CC, kGraphemeBreakLV},
+    {0xC6CD, 0xC6E7, kGraphemeBreakLVT},
+    {0xC6E8, 0xC6E8, kGraphemeBreakLV},
+    {0xC6E9, 0xC703, kGraphemeBreakLVT},
+    {0xC704, 0xC704, kGraphemeBreakLV},
+    {0xC705, 0xC71F, kGraphemeBreakLVT},
+    {0xC720, 0xC720, kGraphemeBreakLV},
+    {0xC721, 0xC73B, kGraphemeBreakLVT},
+    {0xC73C, 0xC73C, kGraphemeBreakLV},
+    {0xC73D, 0xC757, kGraphemeBreakLVT},
+    {0xC758, 0xC758, kGraphemeBreakLV},
+    {0xC759, 0xC773, kGraphemeBreakLVT},
+    {0xC774, 0xC774, kGraphemeBreakLV},
+    {0xC775, 0xC78F, kGraphemeBreakLVT},
+    {0xC790, 0xC790, kGraphemeBreakLV},
+    {0xC791, 0xC7AB, kGraphemeBreakLVT},
+    {0xC7AC, 0xC7AC, kGraphemeBreakLV},
+    {0xC7AD, 0xC7C7, kGraphemeBreakLVT},
+    {0xC7C8, 0xC7C8, kGraphemeBreakLV},
+    {0xC7C9, 0xC7E3, kGraphemeBreakLVT},
+    {0xC7E4, 0xC7E4, kGraphemeBreakLV},
+    {0xC7E5, 0xC7FF, kGraphemeBreakLVT},
+    {0xC800, 0xC800, kGraphemeBreakLV},
+    {0xC801, 0xC81B, kGraphemeBreakLVT},
+    {0xC81C, 0xC81C, kGraphemeBreakLV},
+    {0xC81D, 0xC837, kGraphemeBreakLVT},
+    {0xC838, 0xC838, kGraphemeBreakLV},
+    {0xC839, 0xC853, kGraphemeBreakLVT},
+    {0xC854, 0xC854, kGraphemeBreakLV},
+    {0xC855, 0xC86F, kGraphemeBreakLVT},
+    {0xC870, 0xC870, kGraphemeBreakLV},
+    {0xC871, 0xC88B, kGraphemeBreakLVT},
+    {0xC88C, 0xC88C, kGraphemeBreakLV},
+    {0xC88D, 0xC8A7, kGraphemeBreakLVT},
+    {0xC8A8, 0xC8A8, kGraphemeBreakLV},
+    {0xC8A9, 0xC8C3, kGraphemeBreakLVT},
+    {0xC8C4, 0xC8C4, kGraphemeBreakLV},
+    {0xC8C5, 0xC8DF, kGraphemeBreakLVT},
+    {0xC8E0, 0xC8E0, kGraphemeBreakLV},
+    {0xC8E1, 0xC8FB, kGraphemeBreakLVT},
+    {0xC8FC, 0xC8FC, kGraphemeBreakLV},
+    {0xC8FD, 0xC917, kGraphemeBreakLVT},
+    {0xC918, 0xC918, kGraphemeBreakLV},
+    {0xC919, 0xC933, kGraphemeBreakLVT},
+    {0xC934, 0xC934, kGraphemeBreakLV},
+    {0xC935, 0xC94F, kGraphemeBreakLVT},
+    {0xC950, 0xC950, kGraphemeBreakLV},
+    {0xC951, 0xC96B, kGraphemeBreakLVT},
+    {0xC96C, 0xC96C, kGraphemeBreakLV},
+    {0xC96D, 0xC987, kGraphemeBreakLVT},
+    {0xC988, 0xC988, kGraphemeBreakLV},
+    {0xC989, 0xC9A3, kGraphemeBreakLVT},
+    {0xC9A4, 0xC9A4, kGraphemeBreakLV},
+    {0xC9A5, 0xC9BF, kGraphemeBreakLVT},
+    {0xC9C0, 0xC9C0, kGraphemeBreakLV},
+    {0xC9C1, 0xC9DB, kGraphemeBreakLVT},
+    {0xC9DC, 0xC9DC, kGraphemeBreakLV},
+    {0xC9DD, 0xC9F7, kGraphemeBreakLVT},
+    {0xC9F8, 0xC9F8, kGraphemeBreakLV},
+    {0xC9F9, 0xCA13, kGraphemeBreakLVT},
+    {0xCA14, 0xCA14, kGraphemeBreakLV},
+    {0xCA15, 0xCA2F, kGraphemeBreakLVT},
+    {0xCA30, 0xCA30, kGraphemeBreakLV},
+    {0xCA31, 0xCA4B, kGraphemeBreakLVT},
+    {0xCA4C, 0xCA4C, kGraphemeBreakLV},
+    {0xCA4D, 0xCA67, kGraphemeBreakLVT},
+    {0xCA68, 0xCA68, kGraphemeBreakLV},
+    {0xCA69, 0xCA83, kGraphemeBreakLVT},
+    {0xCA84, 0xCA84, kGraphemeBreakLV},
+    {0xCA85, 0xCA9F, kGraphemeBreakLVT},
+    {0xCAA0, 0xCAA0, kGraphemeBreakLV},
+    {0xCAA1, 0xCABB, kGraphemeBreakLVT},
+    {0xCABC, 0xCABC, kGraphemeBreakLV},
+    {0xCABD, 0xCAD7, kGraphemeBreakLVT},
+    {0xCAD8, 0xCAD8, kGraphemeBreakLV},
+    {0xCAD9, 0xCAF3, kGraphemeBreakLVT},
+    {0xCAF4, 0xCAF4, kGraphemeBreakLV},
+    {0xCAF5, 0xCB0F, kGraphemeBreakLVT},
+    {0xCB10, 0xCB10, kGraphemeBreakLV},
+    {0xCB11, 0xCB2B, kGraphemeBreakLVT},
+    {0xCB2C, 0xCB2C, kGraphemeBreakLV},
+    {0xCB2D, 0xCB47, kGraphemeBreakLVT},
+    {0xCB48, 0xCB48, kGraphemeBreakLV},
+    {0xCB49, 0xCB63, kGraphemeBreakLVT},
+    {0xCB64, 0xCB64, kGraphemeBreakLV},
+    {0xCB65, 0xCB7F, kGraphemeBreakLVT},
+    {0xCB80, 0xCB80, kGraphemeBreakLV},
+    {0xCB81, 0xCB9B, kGraphemeBreakLVT},
+    {0xCB9C, 0xCB9C, kGraphemeBreakLV},
+    {0xCB9D, 0xCBB7, kGraphemeBreakLVT},
+    {0xCBB8, 0xCBB8, kGraphemeBreakLV},
+    {0xCBB9, 0xCBD3, kGraphemeBreakLVT},
+    {0xCBD4, 0xCBD4, kGraphemeBreakLV},
+    {0xCBD5, 0xCBEF, kGraphemeBreakLVT},
+    {0xCBF0, 0xCBF0, kGraphemeBreakLV},
+    {0xCBF1, 0xCC0B, kGraphemeBreakLVT},
+    {0xCC0C, 0xCC0C, kGraphemeBreakLV},
+    {0xCC0D, 0xCC27, kGraphemeBreakLVT},
+    {0xCC28, 0xCC28, kGraphemeBreakLV},
+    {0xCC29, 0xCC43, kGraphemeBreakLVT},
+    {0xCC44, 0xCC44, kGraphemeBreakLV},
+    {0xCC45, 0xCC5F, kGraphemeBreakLVT},
+    {0xCC60, 0xCC60, kGraphemeBreakLV},
+    {0xCC61, 0xCC7B, kGraphemeBreakLVT},
+    {0xCC7C, 0xCC7C, kGraphemeBreakLV},
+    {0xCC7D, 0xCC97, kGraphemeBreakLVT},
+    {0xCC98, 0xCC98, kGraphemeBreakLV},
+    {0xCC99, 0xCCB3, kGraphemeBreakLVT},
+    {0xCCB4, 0xCCB4, kGraphemeBreakLV},
+    {0xCCB5, 0xCCCF, kGraphemeBreakLVT},
+    {0xCCD0, 0xCCD0, kGraphemeBreakLV},
+    {0xCCD1, 0xCCEB, kGraphemeBreakLVT},
+    {0xCCEC, 0xCCEC, kGraphemeBreakLV},
+    {0xCCED, 0xCD07, kGraphemeBreakLVT},
+    {0xCD08, 0xCD08, kGraphemeBreakLV},
+    {0xCD09, 0xCD23, kGraphemeBreakLVT},
+    {0xCD24, 0xCD24, kGraphemeBreakLV},
+    {0xCD25, 0xCD3F, kGraphemeBreakLVT},
+    {0xCD40, 0xCD40, kGraphemeBreakLV},
+    {0xCD41, 0xCD5B, kGraphemeBreakLVT},
+    {0xCD5C, 0xCD5C, kGraphemeBreakLV},
+    {0xCD5D, 0xCD77, kGraphemeBreakLVT},
+    {0xCD78, 0xCD78, kGraphemeBreakLV},
+    {0xCD79, 0xCD93, kGraphemeBreakLVT},
+    {0xCD94, 0xCD94, kGraphemeBreakLV},
+    {0xCD95, 0xCDAF, kGraphemeBreakLVT},
+    {0xCDB0, 0xCDB0, kGraphemeBreakLV},
+    {0xCDB1, 0xCDCB, kGraphemeBreakLVT},
+    {0xCDCC, 0xCDCC, kGraphemeBreakLV},
+    {0xCDCD, 0xCDE7, kGraphemeBreakLVT},
+    {0xCDE8, 0xCDE8, kGraphemeBreakLV},
+    {0xCDE9, 0xCE03, kGraphemeBreakLVT},
+    {0xCE04, 0xCE04, kGraphemeBreakLV},
+    {0xCE05, 0xCE1F, kGraphemeBreakLVT},
+    {0xCE20, 0xCE20, kGraphemeBreakLV},
+    {0xCE21, 0xCE3B, kGraphemeBreakLVT},
+    {0xCE3C, 0xCE3C, kGraphemeBreakLV},
+    {0xCE3D, 0xCE57, kGraphemeBreakLVT},
+    {0xCE58, 0xCE58, kGraphemeBreakLV},
+    {0xCE59, 0xCE73, kGraphemeBreakLVT},
+    {0xCE74, 0xCE74, kGraphemeBreakLV},
+    {0xCE75, 0xCE8F, kGraphemeBreakLVT},
+    {0xCE90, 0xCE90, kGraphemeBreakLV},
+    {0xCE91, 0xCEAB, kGraphemeBreakLVT},
+    {0xCEAC, 0xCEAC, kGraphemeBreakLV},
+    {0xCEAD, 0xCEC7, kGraphemeBreakLVT},
+    {0xCEC8, 0xCEC8, kGraphemeBreakLV},
+    {0xCEC9, 0xCEE3, kGraphemeBreakLVT},
+    {0xCEE4, 0xCEE4, kGraphemeBreakLV},
+    {0xCEE5, 0xCEFF, kGraphemeBreakLVT},
+    {0xCF00, 0xCF00, kGraphemeBreakLV},
+    {0xCF01, 0xCF1B, kGraphemeBreakLVT},
+    {0xCF1C, 0xCF1C, kGraphemeBreakLV},
+    {0xCF1D, 0xCF37, kGraphemeBreakLVT},
+    {0xCF38, 0xCF38, kGraphemeBreakLV},
+    {0xCF39, 0xCF53, kGraphemeBreakLVT},
+    {0xCF54, 0xCF54, kGraphemeBreakLV},
+    {0xCF55, 0xCF6F, kGraphemeBreakLVT},
+    {0xCF70, 0xCF70, kGraphemeBreakLV},
+    {0xCF71, 0xCF8B, kGraphemeBreakLVT},
+    {0xCF8C, 0xCF8C, kGraphemeBreakLV},
+    {0xCF8D, 0xCFA7, kGraphemeBreakLVT},
+    {0xCFA8, 0xCFA8, kGraphemeBreakLV},
+    {0xCFA9, 0xCFC3, kGraphemeBreakLVT},
+    {0xCFC4, 0xCFC4, kGraphemeBreakLV},
+    {0xCFC5, 0xCFDF, kGraphemeBreakLVT},
+    {0xCFE0, 0xCFE0, kGraphemeBreakLV},
+    {0xCFE1, 0xCFFB, kGraphemeBreakLVT},
+    {0xCFFC, 0xCFFC, kGraphemeBreakLV},
+    {0xCFFD, 0xD017, kGraphemeBreakLVT},
+    {0xD018, 0xD018, kGraphemeBreakLV},
+    {0xD019, 0xD033, kGraphemeBreakLVT},
+    {0xD034, 0xD034, kGraphemeBreakLV},
+    {0xD035, 0xD04F, kGraphemeBreakLVT},
+    {0xD050, 0xD050, kGraphemeBreakLV},
+    {0xD051, 0xD06B, kGraphemeBreakLVT},
+    {0xD06C, 0xD06C, kGraphemeBreakLV},
+    {0xD06D, 0xD087, kGraphemeBreakLVT},
+    {0xD088, 0xD088, kGraphemeBreakLV},
+    {0xD089, 0xD0A3, kGraphemeBreakLVT},
+    {0xD0A4, 0xD0A4, kGraphemeBreakLV},
+    {0xD0A5, 0xD0BF, kGraphemeBreakLVT},
+    {0xD0C0, 0xD0C0, kGraphemeBreakLV},
+    {0xD0C1, 0xD0DB, kGraphemeBreakLVT},
+    {0xD0DC, 0xD0DC, kGraphemeBreakLV},
+    {0xD0DD, 0xD0F7, kGraphemeBreakLVT},
+    {0xD0F8, 0xD0F8, kGraphemeBreakLV},
+    {0xD0F9, 0xD113, kGraphemeBreakLVT},
+    {0xD114, 0xD114, kGraphemeBreakLV},
+    {0xD115, 0xD12F, kGraphemeBreakLVT},
+    {0xD130, 0xD130, kGraphemeBreakLV},
+    {0xD131, 0xD14B, kGraphemeBreakLVT},
+    {0xD14C, 0xD14C, kGraphemeBreakLV},
+    {0xD14D, 0xD167, kGraphemeBreakLVT},
+    {0xD168, 0xD168, kGraphemeBreakLV},
+    {0xD169, 0xD183, kGraphemeBreakLVT},
+    {0xD184, 0xD184, kGraphemeBreakLV},
+    {0xD185, 0xD19F, kGraphemeBreakLVT},
+    {0xD1A0, 0xD1A0, kGraphemeBreakLV},
+    {0xD1A1, 0xD1BB, kGraphemeBreakLVT},
+    {0xD1BC, 0xD1BC, kGraphemeBreakLV},
+    {0xD1BD, 0xD1D7, kGraphemeBreakLVT},
+    {0xD1D8, 0xD1D8, kGraphemeBreakLV},
+    {0xD1D9, 0xD1F3, kGraphemeBreakLVT},
+    {0xD1F4, 0xD1F4, kGraphemeBreakLV},
+    {0xD1F5, 0xD20F, kGraphemeBreakLVT},
+    {0xD210, 0xD210, kGraphemeBreakLV},
+    {0xD211, 0xD22B, kGraphemeBreakLVT},
+    {0xD22C, 0xD22C, kGraphemeBreakLV},
+    {0xD22D, 0xD247, kGraphemeBreakLVT},
+    {0xD248, 0xD248, kGraphemeBreakLV},
+    {0xD249, 0xD263, kGraphemeBreakLVT},
+    {0xD264, 0xD264, kGraphemeBreakLV},
+    {0xD265, 0xD27F, kGraphemeBreakLVT},
+    {0xD280, 0xD280, kGraphemeBreakLV},
+    {0xD281, 0xD29B, kGraphemeBreakLVT},
+    {0xD29C, 0xD29C, kGraphemeBreakLV},
+    {0xD29D, 0xD2B7, kGraphemeBreakLVT},
+    {0xD2B8, 0xD2B8, kGraphemeBreakLV},
+    {0xD2B9, 0xD2D3, kGraphemeBreakLVT},
+    {0xD2D4, 0xD2D4, kGraphemeBreakLV},
+    {0xD2D5, 0xD2EF, kGraphemeBreakLVT},
+    {0xD2F0, 0xD2F0, kGraphemeBreakLV},
+    {0xD2F1, 0xD30B, kGraphemeBreakLVT},
+    {0xD30C, 0xD30C, kGraphemeBreakLV},
+    {0xD30D, 0xD327, kGraphemeBreakLVT},
+    {0xD328, 0xD328, kGraphemeBreakLV},
+    {0xD329, 0xD343, kGraphemeBreakLVT},
+    {0xD344, 0xD344, kGraphemeBreakLV},
+    {0xD345, 0xD35F, kGraphemeBreakLVT},
+    {0xD360, 0xD360, kGraphemeBreakLV},
+    {0xD361, 0xD37B, kGraphemeBreakLVT},
+    {0xD37C, 0xD37C, kGraphemeBreakLV},
+    {0xD37D, 0xD397, kGraphemeBreakLVT},
+    {0xD398, 0xD398, kGraphemeBreakLV},
+    {0xD399, 0xD3B3, kGraphemeBreakLVT},
+    {0xD3B4, 0xD3B4, kGraphemeBreakLV},
+    {0xD3B5, 0xD3CF, kGraphemeBreakLVT},
+    {0xD3D0, 0xD3D0, kGraphemeBreakLV},
+    {0xD3D1, 0xD3EB, kGraphemeBreakLVT},
+    {0xD3EC, 0xD3EC, kGraphemeBreakLV},
+    {0xD3ED, 0xD407, kGraphemeBreakLVT},
+    {0xD408, 0xD408, kGraphemeBreakLV},
+    {0xD409, 0xD423, kGraphemeBreakLVT},
+    {0xD424, 0xD424, kGraphemeBreakLV},
+    {0xD425, 0xD43F, kGraphemeBreakLVT},
+    {0xD440, 0xD440, kGraphemeBreakLV},
+    {0xD441, 0xD45B, kGraphemeBreakLVT},
+    {0xD45C, 0xD45C, kGraphemeBreakLV},
+    {0xD45D, 0xD477, kGraphemeBreakLVT},
+    {0xD478, 0xD478, kGraphemeBreakLV},
+    {0xD479, 0xD493, kGraphemeBreakLVT},
+    {0xD494, 0xD494, kGraphemeBreakLV},
+    {0xD495, 0xD4AF, kGraphemeBreakLVT},
+    {0xD4B0, 0xD4B0, kGraphemeBreakLV},
+    {0xD4B1, 0xD4CB, kGraphemeBreakLVT},
+    {0xD4CC, 0xD4CC, kGraphemeBreakLV},
+    {0xD4CD, 0xD4E7, kGraphemeBreakLVT},
+    {0xD4E8, 0xD4E8, kGraphemeBreakLV},
+    {0xD4E9, 0xD503, kGraphemeBreakLVT},
+    {0xD504, 0xD504, kGraphemeBreakLV},
+    {0xD505, 0xD51F, kGraphemeBreakLVT},
+    {0xD520, 0xD520, kGraphemeBreakLV},
+    {0xD521, 0xD53B, kGraphemeBreakLVT},
+    {0xD53C, 0xD53C, kGraphemeBreakLV},
+    {0xD53D, 0xD557, kGraphemeBreakLVT},
+    {0xD558, 0xD558, kGraphemeBreakLV},
+    {0xD559, 0xD573, kGraphemeBreakLVT},
+    {0xD574, 0xD574, kGraphemeBreakLV},
+    {0xD575, 0xD58F, kGraphemeBreakLVT},
+    {0xD590, 0xD590, kGraphemeBreakLV},
+    {0xD591, 0xD5AB, kGraphemeBreakLVT},
+    {0xD5AC, 0xD5AC, kGraphemeBreakLV},
+    {0xD5AD, 0xD5C7, kGraphemeBreakLVT},
+    {0xD5C8, 0xD5C8, kGraphemeBreakLV},
+    {0xD5C9, 0xD5E3, kGraphemeBreakLVT},
+    {0xD5E4, 0xD5E4, kGraphemeBreakLV},
+    {0xD5E5, 0xD5FF, kGraphemeBreakLVT},
+    {0xD600, 0xD600, kGraphemeBreakLV},
+    {0xD601, 0xD61B, kGraphemeBreakLVT},
+    {0xD61C, 0xD61C, kGraphemeBreakLV},
+    {0xD61D, 0xD637, kGraphemeBreakLVT},
+    {0xD638, 0xD638, kGraphemeBreakLV},
+    {0xD639, 0xD653, kGraphemeBreakLVT},
+    {0xD654, 0xD654, kGraphemeBreakLV},
+    {0xD655, 0xD66F, kGraphemeBreakLVT},
+    {0xD670, 0xD670, kGraphemeBreakLV},
+    {0xD671, 0xD68B, kGraphemeBreakLVT},
+    {0xD68C, 0xD68C, kGraphemeBreakLV},
+    {0xD68D, 0xD6A7, kGraphemeBreakLVT},
+    {0xD6A8, 0xD6A8, kGraphemeBreakLV},
+    {0xD6A9, 0xD6C3, kGraphemeBreakLVT},
+    {0xD6C4, 0xD6C4, kGraphemeBreakLV},
+    {0xD6C5, 0xD6DF, kGraphemeBreakLVT},
+    {0xD6E0, 0xD6E0, kGraphemeBreakLV},
+    {0xD6E1, 0xD6FB, kGraphemeBreakLVT},
+    {0xD6FC, 0xD6FC, kGraphemeBreakLV},
+    {0xD6FD, 0xD717, kGraphemeBreakLVT},
+    {0xD718, 0xD718, kGraphemeBreakLV},
+    {0xD719, 0xD733, kGraphemeBreakLVT},
+    {0xD734, 0xD734, kGraphemeBreakLV},
+    {0xD735, 0xD74F, kGraphemeBreakLVT},
+    {0xD750, 0xD750, kGraphemeBreakLV},
+    {0xD751, 0xD76B, kGraphemeBreakLVT},
+    {0xD76C, 0xD76C, kGraphemeBreakLV},
+    {0xD76D, 0xD787, kGraphemeBreakLVT},
+    {0xD788, 0xD788, kGraphemeBreakLV},
+    {0xD789, 0xD7A3, kGraphemeBreakLVT},
+    {0xD7B0, 0xD7C6, kGraphemeBreakV},
+    {0xD7CB, 0xD7FB, kGraphemeBreakT},
+    {0xFB1E, 0xFB1E, kGraphemeBreakExtend},
+    {0xFE00, 0xFE0F, kGraphemeBreakExtend},
+    {0xFE20, 0xFE2F, kGraphemeBreakExtend},
+    {0xFEFF, 0xFEFF, kGraphemeBreakControl},
+    {0xFF9E, 0xFF9F, kGraphemeBreakExtend},
+    {0xFFF0, 0xFFFB, kGraphemeBreakControl},
+    {0x101FD, 0x101FD, kGraphemeBreakExtend},
+    {0x102E0, 0x102E0, kGraphemeBreakExtend},
+    {0x10376, 0x1037A, kGraphemeBreakExtend},
+    {0x10A01, 0x10A03, kGraphemeBreakExtend},
+    {0x10A05, 0x10A06, kGraphemeBreakExtend},
+    {0x10A0C, 0x10A0F, kGraphemeBreakExtend},
+    {0x10A38, 0x10A3A, kGraphemeBreakExtend},
+    {0x10A3F, 0x10A3F, kGraphemeBreakExtend},
+    {0x10AE5, 0x10AE6, kGraphemeBreakExtend},
+    {0x10D24, 0x10D27, kGraphemeBreakExtend},
+    {0x10EAB, 0x10EAC, kGraphemeBreakExtend},
+    {0x10EFD, 0x10EFF, kGraphemeBreakExtend},
+    {0x10F46, 0x10F50, kGraphemeBreakExtend},
+    {0x10F82, 0x10F85, kGraphemeBreakExtend},
+    {0x11000, 0x11000, kGraphemeBreakSpacingMark},
+    {0x11001, 0x11001, kGraphemeBreakExtend},
+    {0x11002, 0x11002, kGraphemeBreakSpacingMark},
+    {0x11038, 0x11046, kGraphemeBreakExtend},
+    {0x11070, 0x11070, kGraphemeBreakExtend},
+    {0x11073, 0x11074, kGraphemeBreakExtend},
+    {0x1107F, 0x11081, kGraphemeBreakExtend},
+    {0x11082, 0x11082, kGraphemeBreakSpacingMark},
+    {0x110B0, 0x110B2, kGraphemeBreakSpacingMark},
+    {0x110B3, 0x110B6, kGraphemeBreakExtend},
+    {0x110B7, 0x110B8, kGraphemeBreakSpacingMark},
+    {0x110B9, 0x110BA, kGraphemeBreakExtend},
+    {0x110BD, 0x110BD, kGraphemeBreakPrepend},
+    {0x110C2, 0x110C2, kGraphemeBreakExtend},
+    {0x110CD, 0x110CD, kGraphemeBreakPrepend},
+    {0x11100, 0x11102, kGraphemeBreakExtend},
+    {0x11127, 0x1112B, kGraphemeBreakExtend},
+    {0x1112C, 0x1112C, kGraphemeBreakSpacingMark},
+    {0x1112D, 0x11134, kGraphemeBreakExtend},
+    {0x11145, 0x11146, kGraphemeBreakSpacingMark},
+    {0x11173, 0x11173, kGraphemeBreakExtend},
+    {0x11180, 0x11181, kGraphemeBreakExtend},
+    {0x11182, 0x11182, kGraphemeBreakSpacingMark},
+    {0x111B3, 0x111B5, kGraphemeBreakSpacingMark},
+    {0x111B6, 0x111BE, kGraphemeBreakExtend},
+    {0x111BF, 0x111C0, kGraphemeBreakSpacingMark},
+    {0x111C2, 0x111C3, kGraphemeBreakPrepend},
+    {0x111C9, 0x111CC, kGraphemeBreakExtend},
+    {0x111CE, 0x111CE, kGraphemeBreakSpacingMark},
+    {0x111CF, 0x111CF, kGraphemeBreakExtend},
+    {0x1122C, 0x1122E, kGraphemeBreakSpacingMark},
+    {0x1122F, 0x11231, kGraphemeBreakExtend},
+    {0x11232, 0x11233, kGraphemeBreakSpacingMark},
+    {0x11234, 0x11234, kGraphemeBreakExtend},
+    {0x11235, 0x11235, kGraphemeBreakSpacingMark},
+    {0x11236, 0x11237, kGraphemeBreakExtend},
+    {0x1123E, 0x1123E, kGraphemeBreakExtend},
+    {0x11241, 0x11241, kGraphemeBreakExtend},
+    {0x112DF, 0x112DF, kGraphemeBreakExtend},
+    {0x112E0, 0x112E2, kGraphemeBreakSpacingMark},
+    {0x112E3, 0x112EA, kGraphemeBreakExtend},
+    {0x11300, 0x11301, kGraphemeBreakExtend},
+    {0x11302, 0x11303, kGraphemeBreakSpacingMark},
+    {0x1133B, 0x1133C, kGraphemeBreakExtend},
+    {0x1133E, 0x1133E, kGraphemeBreakExtend},
+    {0x1133F, 0x1133F, kGraphemeBreakSpacingMark},
+    {0x11340, 0x11340, kGraphemeBreakExtend},
+    {0x11341, 0x11344, kGraphemeBreakSpacingMark},
+    {0x11347, 0x11348, kGraphemeBreakSpacingMark},
+    {0x1134B, 0x1134D, kGraphemeBreakSpacingMark},
+    {0x11357, 0x11357, kGraphemeBreakExtend},
+    {0x11362, 0x11363, kGraphemeBreakSpacingMark},
+    {0x11366, 0x1136C, kGraphemeBreakExtend},
+    {0x11370, 0x11374, kGraphemeBreakExtend},
+    {0x11435, 0x11437, kGraphemeBreakSpacingMark},
+    {0x11438, 0x1143F, kGraphemeBreakExtend},
+    {0x11440, 0x11441, kGraphemeBreakSpacingMark},
+    {0x11442, 0x11444, kGraphemeBreakExtend},
+    {0x11445, 0x11445, kGraphemeBreakSpacingMark},
+    {0x11446, 0x11446, kGraphemeBreakExtend},
+    {0x1145E, 0x1145E, kGraphemeBreakExtend},
+    {0x114B0, 0x114B0, kGraphemeBreakExtend},
+    {0x114B1, 0x114B2, kGraphemeBreakSpacingMark},
+    {0x114B3, 0x114B8, kGraphemeBreakExtend},
+    {0x114B9, 0x114B9, kGraphemeBreakSpacingMark},
+    {0x114BA, 0x114BA, kGraphemeBreakExtend},
+    {0x114BB, 0x114BC, kGraphemeBreakSpacingMark},
+    {0x114BD, 0x114BD, kGraphemeBreakExtend},
+    {0x114BE, 0x114BE, kGraphemeBreakSpacingMark},
+    {0x114BF, 0x114C0, kGraphemeBreakExtend},
+    {0x114C1, 0x114C1, kGraphemeBreakSpacingMark},
+    {0x114C2, 0x114C3, kGraphemeBreakExtend},
+    {0x115AF, 0x115AF, kGraphemeBreakExtend},
+    {0x115B0, 0x115B1, kGraphemeBreakSpacingMark},
+    {0x115B2, 0x115B5, kGraphemeBreakExtend},
+    {0x115B8, 0x115BB, kGraphemeBreakSpacingMark},
+    {0x115BC, 0x115BD, kGraphemeBreakExtend},
+    {0x115BE, 0x115BE, kGraphemeBreakSpacingMark},
+    {0x115BF, 0x115C0, kGraphemeBreakExtend},
+    {0x115DC, 0x115DD, kGraphemeBreakExtend},
+    {0x11630, 0x11632, kGraphemeBreakSpacingMark},
+    {0x11633, 0x1163A, kGraphemeBreakExtend},
+    {0x1163B, 0x1163C, kGraphemeBreakSpacingMark},
+    {0x1163D, 0x1163D, kGraphemeBreakExtend},
+    {0x1163E, 0x1163E, kGraphemeBreakSpacingMark},
+    {0x1163F, 0x11640, kGraphemeBreakExtend},
+    {0x116AB, 0x116AB, kGraphemeBreakExtend},
+    {0x116AC, 0x116AC, kGraphemeBreakSpacingMark},
+    {0x116AD, 0x116AD, kGraphemeBreakExtend},
+    {0x116AE, 0x116AF, kGraphemeBreakSpacingMark},
+    {0x116B0, 0x116B5, kGraphemeBreakExtend},
+    {0x116B6, 0x116B6, kGraphemeBreakSpacingMark},
+    {0x116B7, 0x116B7, kGraphemeBreakExtend},
+    {0x1171D, 0x1171F, kGraphemeBreakExtend},
+    {0x11722, 0x11725, kGraphemeBreakExtend},
+    {0x11726, 0x11726, kGraphemeBreakSpacingMark},
+    {0x11727, 0x1172B, kGraphemeBreakExtend},
+    {0x1182C, 0x1182E, kGraphemeBreakSpacingMark},
+    {0x1182F, 0x11837, kGraphemeBreakExtend},
+    {0x11838, 0x11838, kGraphemeBreakSpacingMark},
+    {0x11839, 0x1183A, kGraphemeBreakExtend},
+    {0x11930, 0x11930, kGraphemeBreakExtend},
+    {0x11931, 0x11935, kGraphemeBreakSpacingMark},
+    {0x11937, 0x11938, kGraphemeBreakSpacingMark},
+    {0x1193B, 0x1193C, kGraphemeBreakExtend},
+    {0x1193D, 0x1193D, kGraphemeBreakSpacingMark},
+    {0x1193E, 0x1193E, kGraphemeBreakExtend},
+    {0x1193F, 0x1193F, kGraphemeBreakPrepend},
+    {0x11940, 0x11940, kGraphemeBreakSpacingMark},
+    {0x11941, 0x11941, kGraphemeBreakPrepend},
+    {0x11942, 0x11942, kGraphemeBreakSpacingMark},
+    {0x11943, 0x11943, kGraphemeBreakExtend},
+    {0x119D1, 0x119D3, kGraphemeBreakSpacingMark},
+    {0x119D4, 0x119D7, kGraphemeBreakExtend},
+    {0x119DA, 0x119DB, kGraphemeBreakExtend},
+    {0x119DC, 0x119DF, kGraphemeBreakSpacingMark},
+    {0x119E0, 0x119E0, kGraphemeBreakExtend},
+    {0x119E4, 0x119E4, kGraphemeBreakSpacingMark},
+    {0x11A01, 0x11A0A, kGraphemeBreakExtend},
+    {0x11A33, 0x11A38, kGraphemeBreakExtend},
+    {0x11A39, 0x11A39, kGraphemeBreakSpacingMark},
+    {0x11A3A, 0x11A3A, kGraphemeBreakPrepend},
+    {0x11A3B, 0x11A3E, kGraphemeBreakExtend},
+    {0x11A47, 0x11A47, kGraphemeBreakExtend},
+    {0x11A51, 0x11A56, kGraphemeBreakExtend},
+    {0x11A57, 0x11A58, kGraphemeBreakSpacingMark},
+    {0x11A59, 0x11A5B, kGraphemeBreakExtend},
+    {0x11A84, 0x11A89, kGraphemeBreakPrepend},
+    {0x11A8A, 0x11A96, kGraphemeBreakExtend},
+    {0x11A97, 0x11A97, kGraphemeBreakSpacingMark},
+    {0x11A98, 0x11A99, kGraphemeBreakExtend},
+    {0x11C2F, 0x11C2F, kGraphemeBreakSpacingMark},
+    {0x11C30, 0x11C36, kGraphemeBreakExtend},
+    {0x11C38, 0x11C3D, kGraphemeBreakExtend},
+    {0x11C3E, 0x11C3E, kGraphemeBreakSpacingMark},
+    {0x11C3F, 0x11C3F, kGraphemeBreakExtend},
+    {0x11C92, 0x11CA7, kGraphemeBreakExtend},
+    {0x11CA9, 0x11CA9, kGraphemeBreakSpacingMark},
+    {0x11CAA, 0x11CB0, kGraphemeBreakExtend},
+    {0x11CB1, 0x11CB1, kGraphemeBreakSpacingMark},
+    {0x11CB2, 0x11CB3, kGraphemeBreakExtend},
+    {0x11CB4, 0x11CB4, kGraphemeBreakSpacingMark},
+    {0x11CB5, 0x11CB6, kGraphemeBreakExtend},
+    {0x11D31, 0x11D36, kGraphemeBreakExtend},
+    {0x11D3A, 0x11D3A, kGraphemeBreakExtend},
+    {0x11D3C, 0x11D3D, kGraphemeBreakExtend},
+    {0x11D3F, 0x11D45, kGraphemeBreakExtend},
+    {0x11D46, 0x11D46, kGraphemeBreakPrepend},
+    {0x11D47, 0x11D47, kGraphemeBreakExtend},
+    {0x11D8A, 0x11D8E, kGraphemeBreakSpacingMark},
+    {0x11D90, 0x11D91, kGraphemeBreakExtend},
+    {0x11D93, 0x11D94, kGraphemeBreakSpacingMark},
+    {0x11D95, 0x11D95, kGraphemeBreakExtend},
+    {0x11D96, 0x11D96, kGraphemeBreakSpacingMark},
+    {0x11D97, 0x11D97, kGraphemeBreakExtend},
+    {0x11EF3, 0x11EF4, kGraphemeBreakExtend},
+    {0x11EF5, 0x11EF6, kGraphemeBreakSpacingMark},
+    {0x11F00, 0x11F01, kGraphemeBreakExtend},
+    {0x11F02, 0x11F02, kGraphemeBreakPrepend},
+    {0x11F03, 0x11F03, kGraphemeBreakSpacingMark},
+    {0x11F34, 0x11F35, kGraphemeBreakSpacingMark},
+    {0x11F36, 0x11F3A, kGraphemeBreakExtend},
+    {0x11F3E, 0x11F3F, kGraphemeBreakSpacingMark},
+    {0x11F40, 0x11F40, kGraphemeBreakExtend},
+    {0x11F41, 0x11F41, kGraphemeBreakSpacingMark},
+    {0x11F42, 0x11F42, kGraphemeBreakExtend},
+    {0x13430, 0x1343F, kGraphemeBreakControl},
+    {0x13440, 0x13440, kGraphemeBreakExtend},
+    {0x13447, 0x13455, kGraphemeBreakExtend},
+    {0x16AF0, 0x16AF4, kGraphemeBreakExtend},
+    {0x16B30, 0x16B36, kGraphemeBreakExtend},
+    {0x16F4F, 0x16F4F, kGraphemeBreakExtend},
+    {0x16F51, 0x16F87, kGraphemeBreakSpacingMark},
+    {0x16F8F, 0x16F92, kGraphemeBreakExtend},
+    {0x16FE4, 0x16FE4, kGraphemeBreakExtend},
+    {0x16FF0, 0x16FF1, kGraphemeBreakSpacingMark},
+    {0x1BC9D, 0x1BC9E, kGraphemeBreakExtend},
+    {0x1BCA0, 0x1BCA3, kGraphemeBreakControl},
+    {0x1CF00, 0x1CF2D, kGraphemeBreakExtend},
+    {0x1CF30, 0x1CF46, kGraphemeBreakExtend},
+    {0x1D165, 0x1D165, kGraphemeBreakExtend},
+    {0x1D166, 0x1D166, kGraphemeBreakSpacingMark},
+    {0x1D167, 0x1D169, kGraphemeBreakExtend},
+    {0x1D16D, 0x1D16D, kGraphemeBreakSpacingMark},
+    {0x1D16E, 0x1D172, kGraphemeBreakExtend},
+    {0x1D173, 0x1D17A, kGraphemeBreakControl},
+    {0x1D17B, 0x1D182, kGraphemeBreakExtend},
+    {0x1D185, 0x1D18B, kGraphemeBreakExtend},
+    {0x1D1AA, 0x1D1AD, kGraphemeBreakExtend},
+    {0x1D242, 0x1D244, kGraphemeBreakExtend},
+    {0x1DA00, 0x1DA36, kGraphemeBreakExtend},
+    {0x1DA3B, 0x1DA6C, kGraphemeBreakExtend},
+    {0x1DA75, 0x1DA75, kGraphemeBreakExtend},
+    {0x1DA84, 0x1DA84, kGraphemeBreakExtend},
+    {0x1DA9B, 0x1DA9F, kGraphemeBreakExtend},
+    {0x1DAA1, 0x1DAAF, kGraphemeBreakExtend},
+    {0x1E000, 0x1E006, kGraphemeBreakExtend},
+    {0x1E008, 0x1E018, kGraphemeBreakExtend},
+    {0x1E01B, 0x1E021, kGraphemeBreakExtend},
+    {0x1E023, 0x1E024, kGraphemeBreakExtend},
+    {0x1E026, 0x1E02A, kGraphemeBreakExtend},
+    {0x1E08F, 0x1E08F, kGraphemeBreakExtend},
+    {0x1E130, 0x1E136, kGraphemeBreakExtend},
+    {0x1E2AE, 0x1E2AE, kGraphemeBreakExtend},
+    {0x1E2EC, 0x1E2EF, kGraphemeBreakExtend},
+    {0x1E4EC, 0x1E4EF, kGraphemeBreakExtend},
+    {0x1E8D0, 0x1E8D6, kGraphemeBreakExtend},
+    {0x1E944, 0x1E94A, kGraphemeBreakExtend},
+    {0x1F000, 0x1F0FF, kGraphemeBreakExtendedPictographic},
+    {0x1F10D, 0x1F10F, kGraphemeBreakExtendedPictographic},
+    {0x1F12F, 0x1F12F, kGraphemeBreakExtendedPictographic},
+    {0x1F16C, 0x1F171, kGraphemeBreakExtendedPictographic},
+    {0x1F17E, 0x1F17F, kGraphemeBreakExtendedPictographic},
+    {0x1F18E, 0x1F18E, kGraphemeBreakExtendedPictographic},
+    {0x1F191, 0x1F19A, kGraphemeBreakExtendedPictographic},
+    {0x1F1AD, 0x1F1E5, kGraphemeBreakExtendedPictographic},
+    {0x1F1E6, 0x1F1FF, kGraphemeBreakRegionalIndicator},
+    {0x1F201, 0x1F20F, kGraphemeBreakExtendedPictographic},
+    {0x1F21A, 0x1F21A, kGraphemeBreakExtendedPictographic},
+    {0x1F22F, 0x1F22F, kGraphemeBreakExtendedPictographic},
+    {0x1F232, 0x1F23A, kGraphemeBreakExtendedPictographic},
+    {0x1F23C, 0x1F23F, kGraphemeBreakExtendedPictographic},
+    {0x1F249, 0x1F3FA, kGraphemeBreakExtendedPictographic},
+    {0x1F3FB, 0x1F3FF, kGraphemeBreakExtend},
+    {0x1F400, 0x1F53D, kGraphemeBreakExtendedPictographic},
+    {0x1F546, 0x1F64F, kGraphemeBreakExtendedPictographic},
+    {0x1F680, 0x1F6FF, kGraphemeBreakExtendedPictographic},
+    {0x1F774, 0x1F77F, kGraphemeBreakExtendedPictographic},
+    {0x1F7D5, 0x1F7FF, kGraphemeBreakExtendedPictographic},
+    {0x1F80C, 0x1F80F, kGraphemeBreakExtendedPictographic},
+    {0x1F848, 0x1F84F, kGraphemeBreakExtendedPictographic},
+    {0x1F85A, 0x1F85F, kGraphemeBreakExtendedPictographic},
+    {0x1F888, 0x1F88F, kGraphemeBreakExtendedPictographic},
+    {0x1F8AE, 0x1F8FF, kGraphemeBreakExtendedPictographic},
+    {0x1F90C, 0x1F93A, kGraphemeBreakExtendedPictographic},
+    {0x1F93C, 0x1F945, kGraphemeBreakExtendedPictographic},
+    {0x1F947, 0x1FAFF, kGraphemeBreakExtendedPictographic},
+    {0x1FC00, 0x1FFFD, kGraphemeBreakExtendedPictographic},
+    {0xE0000, 0xE001F, kGraphemeBreakControl},
+    {0xE0020, 0xE007F, kGraphemeBreakExtend},
+    {0xE0080, 0xE00FF, kGraphemeBreakControl},
+    {0xE0100, 0xE01EF, kGraphemeBreakExtend},
+    {0xE01F0, 0xE0FFF, kGraphemeBreakControl},
+}};
+
+enum WordBreakValue : uint8_t {
+  kWordBreakOther = 0,
+  kWordBreakCR = 1,
+  kWordBreakLF = 2,
+  kWordBreakNewline = 3,
+  kWordBreakExtend = 4,
+  kWordBreakZWJ = 5,
+  kWordBreakRegionalIndicator = 6,
+  kWordBreakFormat = 7,
+  kWordBreakKatakana = 8,
+  kWordBreakHebrewLetter = 9,
+  kWordBreakALetter = 10,
+  kWordBreakSingleQuote = 11,
+  kWordBreakDoubleQuote = 12,
+  kWordBreakMidNumLet = 13,
+  kWordBreakMidLetter = 14,
+  kWordBreakMidNum = 15,
+  kWordBreakNumeric = 16,
+  kWordBreakExtendNumLet = 17,
+  kWordBreakWSegSpace = 18,
+};
+
+struct WordBreakRange {
+  uint32_t first;
+  uint32_t last;
+  WordBreakValue value;
+};
+
+inline constexpr std::array<WordBreakRange, 1053> kWordBreakRanges{{
+    {0x000A, 0x000A, kWordBreakLF},
+    {0x000B, 0x000C, kWordBreakNewline},
+    {0x000D, 0x000D, kWordBreakCR},
+    {0x0020, 0x0020, kWordBreakWSegSpace},
+    {0x0022, 0x0022, kWordBreakDoubleQuote},
+    {0x0027, 0x0027, kWordBreakSingleQuote},
+    {0x002C, 0x002C, kWordBreakMidNum},
+    {0x002E, 0x002E, kWordBreakMidNumLet},
+    {0x0030, 0x0039, kWordBreakNumeric},
+    {0x003A, 0x003A, kWordBreakMidLetter},
+    {0x003B, 0x003B, kWordBreakMidNum},
+    {0x0041, 0x005A, kWordBreakALetter},
+    {0x005F, 0x005F, kWordBreakExtendNumLet},
+    {0x0061, 0x007A, kWordBreakALetter},
+    {0x0085, 0x0085, kWordBreakNewline},
+    {0x00AA, 0x00AA, kWordBreakALetter},
+    {0x00AD, 0x00AD, kWordBreakFormat},
+    {0x00B5, 0x00B5, kWordBreakALetter},
+    {0x00B7, 0x00B7, kWordBreakMidLetter},
+    {0x00BA, 0x00BA, kWordBreakALetter},
+    {0x00C0, 0x00D6, kWordBreakALetter},
+    {0x00D8, 0x00F6, kWordBreakALetter},
+    {0x00F8, 0x02D7, kWordBreakALetter},
+    {0x02DE, 0x02FF, kWordBreakALetter},
+    {0x0300, 0x036F, kWordBreakExtend},
+    {0x0370, 0x0374, kWordBreakALetter},
+    {0x0376, 0x0377, kWordBreakALetter},
+    {0x037A, 0x037D, kWordBreakALetter},
+    {0x037E, 0x037E, kWordBreakMidNum},
+    {0x037F, 0x037F, kWordBreakALetter},
+    {0x0386, 0x0386, kWordBreakALetter},
+    {0x0387, 0x0387, kWordBreakMidLetter},
+    {0x0388, 0x038A, kWordBreakALetter},
+    {0x038C, 0x038C, kWordBreakALetter},
+    {0x038E, 0x03A1, kWordBreakALetter},
+    {0x03A3, 0x03F5, kWordBreakALetter},
+    {0x03F7, 0x0481, kWordBreakALetter},
+    {0x0483, 0x0489, kWordBreakExtend},
+    {0x048A, 0x052F, kWordBreakALetter},
+    {0x0531, 0x0556, kWordBreakALetter},
+    {0x0559, 0x055C, kWordBreakALetter},
+    {0x055E, 0x055E, kWordBreakALetter},
+    {0x055F, 0x055F, kWordBreakMidLetter},
+    {0x0560, 0x0588, kWordBreakALetter},
+    {0x0589, 0x0589, kWordBreakMidNum},
+    {0x058A, 0x058A, kWordBreakALetter},
+    {0x0591, 0x05BD, kWordBreakExtend},
+    {0x05BF, 0x05BF, kWordBreakExtend},
+    {0x05C1, 0x05C2, kWordBreakExtend},
+    {0x05C4, 0x05C5, kWordBreakExtend},
+    {0x05C7, 0x05C7, kWordBreakExtend},
+    {0x05D0, 0x05EA, kWordBreakHebrewLetter},
+    {0x05EF, 0x05F2, kWordBreakHebrewLetter},
+    {0x05F3, 0x05F3, kWordBreakALetter},
+    {0x05F4, 0x05F4, kWordBreakMidLetter},
+    {0x0600, 0x0605, kWordBreakFormat},
+    {0x060C, 0x060D, kWordBreakMidNum},
+    {0x0610, 0x061A, kWordBreakExtend},
+    {0x061C, 0x061C, kWordBreakFormat},
+    {0x0620, 0x064A, kWordBreakALetter},
+    {0x064B, 0x065F, kWordBreakExtend},
+    {0x0660, 0x0669, kWordBreakNumeric},
+    {0x066B, 0x066B, kWordBreakNumeric},
+    {0x066C, 0x066C, kWordBreakMidNum},
+    {0x066E, 0x066F, kWordBreakALetter},
+    {0x0670, 0x0670, kWordBreakExtend},
+    {0x0671, 0x06D3, kWordBreakALetter},
+    {0x06D5, 0x06D5, kWordBreakALetter},
+    {0x06D6, 0x06DC, kWordBreakExtend},
+    {0x06DD, 0x06DD, kWordBreakFormat},
+    {0x06DF, 0x06E4, kWordBreakExtend},
+    {0x06E5, 0x06E6, kWordBreakALetter},
+    {0x06E7, 0x06E8, kWordBreakExtend},
+    {0x06EA, 0x06ED, kWordBreakExtend},
+    {0x06EE, 0x06EF, kWordBreakALetter},
+    {0x06F0, 0x06F9, kWordBreakNumeric},
+    {0x06FA, 0x06FC, kWordBreakALetter},
+    {0x06FF, 0x06FF, kWordBreakALetter},
+    {0x070F, 0x070F, kWordBreakFormat},
+    {0x0710, 0x0710, kWordBreakALetter},
+    {0x0711, 0x0711, kWordBreakExtend},
+    {0x0712, 0x072F, kWordBreakALetter},
+    {0x0730, 0x074A, kWordBreakExtend},
+    {0x074D, 0x07A5, kWordBreakALetter},
+    {0x07A6, 0x07B0, kWordBreakExtend},
+    {0x07B1, 0x07B1, kWordBreakALetter},
+    {0x07C0, 0x07C9, kWordBreakNumeric},
+    {0x07CA, 0x07EA, kWordBreakALetter},
+    {0x07EB, 0x07F3, kWordBreakExtend},
+    {0x07F4, 0x07F5, kWordBreakALetter},
+    {0x07F8, 0x07F8, kWordBreakMidNum},
+    {0x07FA, 0x07FA, kWordBreakALetter},
+    {0x07FD, 0x07FD, kWordBreakExtend},
+    {0x0800, 0x0815, kWordBreakALetter},
+    {0x0816, 0x0819, kWordBreakExtend},
+    {0x081A, 0x081A, kWordBreakALetter},
+    {0x081B, 0x0823, kWordBreakExtend},
+    {0x0824, 0x0824, kWordBreakALetter},
+    {0x0825, 0x0827, kWordBreakExtend},
+    {0x0828, 0x0828, kWordBreakALetter},
+    {0x0829, 0x082D, kWordBreakExtend},
+    {0x0840, 0x0858, kWordBreakALetter},
+    {0x0859, 0x085B, kWordBreakExtend},
+    {0x0860, 0x086A, kWordBreakALetter},
+    {0x0870, 0x0887, kWordBreakALetter},
+    {0x0889, 0x088E, kWordBreakALetter},
+    {0x0890, 0x0891, kWordBreakFormat},
+    {0x0898, 0x089F, kWordBreakExtend},
+    {0x08A0, 0x08C9, kWordBreakALetter},
+    {0x08CA, 0x08E1, kWordBreakExtend},
+    {0x08E2, 0x08E2, kWordBreakFormat},
+    {0x08E3, 0x0903, kWordBreakExtend},
+    {0x0904, 0x0939, kWordBreakALetter},
+    {0x093A, 0x093C, kWordBreakExtend},
+    {0x093D, 0x093D, kWordBreakALetter},
+    {0x093E, 0x094F, kWordBreakExtend},
+    {0x0950, 0x0950, kWordBreakALetter},
+    {0x0951, 0x0957, kWordBreakExtend},
+    {0x0958, 0x0961, kWordBreakALetter},
+    {0x0962, 0x0963, kWordBreakExtend},
+    {0x0966, 0x096F, kWordBreakNumeric},
+    {0x0971, 0x0980, kWordBreakALetter},
+    {0x0981, 0x0983, kWordBreakExtend},
+    {0x0985, 0x098C, kWordBreakALetter},
+    {0x098F, 0x0990, kWordBreakALetter},
+    {0x0993, 0x09A8, kWordBreakALetter},
+    {0x09AA, 0x09B0, kWordBreakALetter},
+    {0x09B2, 0x09B2, kWordBreakALetter},
+    {0x09B6, 0x09B9, kWordBreakALetter},
+    {0x09BC, 0x09BC, kWordBreakExtend},
+    {0x09BD, 0x09BD, kWordBreakALetter},
+    {0x09BE, 0x09C4, kWordBreakExtend},
+    {0x09C7, 0x09C8, kWordBreakExtend},
+    {0x09CB, 0x09CD, kWordBreakExtend},
+    {0x09CE, 0x09CE, kWordBreakALetter},
+    {0x09D7, 0x09D7, kWordBreakExtend},
+    {0x09DC, 0x09DD, kWordBreakALetter},
+    {0x09DF, 0x09E1, kWordBreakALetter},
+    {0x09E2, 0x09E3, kWordBreakExtend},
+    {0x09E6, 0x09EF, kWordBreakNumeric},
+    {0x09F0, 0x09F1, kWordBreakALetter},
+    {0x09FC, 0x09FC, kWordBreakALetter},
+    {0x09FE, 0x09FE, kWordBreakExtend},
+    {0x0A01, 0x0A03, kWordBreakExtend},
+    {0x0A05, 0x0A0A, kWordBreakALetter},
+    {0x0A0F, 0x0A10, kWordBreakALetter},
+    {0x0A13, 0x0A28, kWordBreakALetter},
+    {0x0A2A, 0x0A30, kWordBreakALetter},
+    {0x0A32, 0x0A33, kWordBreakALetter},
+    {0x0A35, 0x0A36, kWordBreakALetter},
+    {0x0A38, 0x0A39, kWordBreakALetter},
+    {0x0A3C, 0x0A3C, kWordBreakExtend},
+    {0x0A3E, 0x0A42, kWordBreakExtend},
+    {0x0A47, 0x0A48, kWordBreakExtend},
+    {0x0A4B, 0x0A4D, kWordBreakExtend},
+    {0x0A51, 0x0A51, kWordBreakExtend},
+    {0x0A59, 0x0A5C, kWordBreakALetter},
+    {0x0A5E, 0x0A5E, kWordBreakALetter},
+    {0x0A66, 0x0A6F, kWordBreakNumeric},
+    {0x0A70, 0x0A71, kWordBreakExtend},
+    {0x0A72, 0x0A74, kWordBreakALetter},
+    {0x0A75, 0x0A75, kWordBreakExtend},
+    {0x0A81, 0x0A83, kWordBreakExtend},
+    {0x0A85, 0x0A8D, kWordBreakALetter},
+    {0x0A8F, 0x0A91, kWordBreakALetter},
+    {0x0A93, 0x0AA8, kWordBreakALetter},
+    {0x0AAA, 0x0AB0, kWordBreakALetter},
+    {0x0AB2, 0x0AB3, kWordBreakALetter},
+    {0x0AB5, 0x0AB9, kWordBreakALetter},
+    {0x0ABC, 0x0ABC, kWordBreakExtend},
+    {0x0ABD, 0x0ABD, kWordBreakALetter},
+    {0x0ABE, 0x0AC5, kWordBreakExtend},
+    {0x0AC7, 0x0AC9, kWordBreakExtend},
+    {0x0ACB, 0x0ACD, kWordBreakExtend},
+    {0x0AD0, 0x0AD0, kWordBreakALetter},
+    {0x0AE0, 0x0AE1, kWordBreakALetter},
+    {0x0AE2, 0x0AE3, kWordBreakExtend},
+    {0x0AE6, 0x0AEF, kWordBreakNumeric},
+    {0x0AF9, 0x0AF9, kWordBreakALetter},
+    {0x0AFA, 0x0AFF, kWordBreakExtend},
+    {0x0B01, 0x0B03, kWordBreakExtend},
+    {0x0B05, 0x0B0C, kWordBreakALetter},
+    {0x0B0F, 0x0B10, kWordBreakALetter},
+    {0x0B13, 0x0B28, kWordBreakALetter},
+    {0x0B2A, 0x0B30, kWordBreakALetter},
+    {0x0B32, 0x0B33, kWordBreakALetter},
+    {0x0B35, 0x0B39, kWordBreakALetter},
+    {0x0B3C, 0x0B3C, kWordBreakExtend},
+    {0x0B3D, 0x0B3D, kWordBreakALetter},
+    {0x0B3E, 0x0B44, kWordBreakExtend},
+    {0x0B47, 0x0B48, kWordBreakExtend},
+    {0x0B4B, 0x0B4D, kWordBreakExtend},
+    {0x0B55, 0x0B57, kWordBreakExtend},
+    {0x0B5C, 0x0B5D, kWordBreakALetter},
+    {0x0B5F, 0x0B61, kWordBreakALetter},
+    {0x0B62, 0x0B63, kWordBreakExtend},
+    {0x0B66, 0x0B6F, kWordBreakNumeric},
+    {0x0B71, 0x0B71, kWordBreakALetter},
+    {0x0B82, 0x0B82, kWordBreakExtend},
+    {0x0B83, 0x0B83, kWordBreakALetter},
+    {0x0B85, 0x0B8A, kWordBreakALetter},
+    {0x0B8E, 0x0B90, kWordBreakALetter},
+    {0x0B92, 0x0B95, kWordBreakALetter},
+    {0x0B99, 0x0B9A, kWordBreakALetter},
+    {0x0B9C, 0x0B9C, kWordBreakALetter},
+    {0x0B9E, 0x0B9F, kWordBreakALetter},
+    {0x0BA3, 0x0BA4, kWordBreakALetter},
+    {0x0BA8, 0x0BAA, kWordBreakALetter},
+    {0x0BAE, 0x0BB9, kWordBreakALetter},
+    {0x0BBE, 0x0BC2, kWordBreakExtend},
+    {0x0BC6, 0x0BC8, kWordBreakExtend},
+    {0x0BCA, 0x0BCD, kWordBreakExtend},
+    {0x0BD0, 0x0BD0, kWordBreakALetter},
+    {0x0BD7, 0x0BD7, kWordBreakExtend},
+    {0x0BE6, 0x0BEF, kWordBreakNumeric},
+    {0x0C00, 0x0C04, kWordBreakExtend},
+    {0x0C05, 0x0C0C, kWordBreakALetter},
+    {0x0C0E, 0x0C10, kWordBreakALetter},
+    {0x0C12, 0x0C28, kWordBreakALetter},
+    {0x0C2A, 0x0C39, kWordBreakALetter},
+    {0x0C3C, 0x0C3C, kWordBreakExtend},
+    {0x0C3D, 0x0C3D, kWordBreakALetter},
+    {0x0C3E, 0x0C44, kWordBreakExtend},
+    {0x0C46, 0x0C48, kWordBreakExtend},
+    {0x0C4A, 0x0C4D, kWordBreakExtend},
+    {0x0C55, 0x0C56, kWordBreakExtend},
+    {0x0C58, 0x0C5A, kWordBreakALetter},
+    {0x0C5D, 0x0C5D, kWordBreakALetter},
+    {0x0C60, 0x0C61, kWordBreakALetter},
+    {0x0C62, 0x0C63, kWordBreakExtend},
+    {0x0C66, 0x0C6F, kWordBreakNumeric},
+    {0x0C80, 0x0C80, kWordBreakALetter},
+    {0x0C81, 0x0C83, kWordBreakExtend},
+    {0x0C85, 0x0C8C, kWordBreakALetter},
+    {0x0C8E, 0x0C90, kWordBreakALetter},
+    {0x0C92, 0x0CA8, kWordBreakALetter},
+    {0x0CAA, 0x0CB3, kWordBreakALetter},
+    {0x0CB5, 0x0CB9, kWordBreakALetter},
+    {0x0CBC, 0x0CBC, kWordBreakExtend},
+    {0x0CBD, 0x0CBD, kWordBreakALetter},
+    {0x0CBE, 0x0CC4, kWordBreakExtend},
+    {0x0CC6, 0x0CC8, kWordBreakExtend},
+    {0x0CCA, 0x0CCD, kWordBreakExtend},
+    {0x0CD5, 0x0CD6, kWordBreakExtend},
+    {0x0CDD, 0x0CDE, kWordBreakALetter},
+    {0x0CE0, 0x0CE1, kWordBreakALetter},
+    {0x0CE2, 0x0CE3, kWordBreakExtend},
+    {0x0CE6, 0x0CEF, kWordBreakNumeric},
+    {0x0CF1, 0x0CF2, kWordBreakALetter},
+    {0x0CF3, 0x0CF3, kWordBreakExtend},
+    {0x0D00, 0x0D03, kWordBreakExtend},
+    {0x0D04, 0x0D0C, kWordBreakALetter},
+    {0x0D0E, 0x0D10, kWordBreakALetter},
+    {0x0D12, 0x0D3A, kWordBreakALetter},
+    {0x0D3B, 0x0D3C, kWordBreakExtend},
+    {0x0D3D, 0x0D3D, kWordBreakALetter},
+    {0x0D3E, 0x0D44, kWordBreakExtend},
+    {0x0D46, 0x0D48, kWordBreakExtend},
+    {0x0D4A, 0x0D4D, kWordBreakExtend},
+    {0x0D4E, 0x0D4E, kWordBreakALetter},
+    {0x0D54, 0x0D56, kWordBreakALetter},
+    {0x0D57, 0x0D57, kWordBreakExtend},
+    {0x0D5F, 0x0D61, kWordBreakALetter},
+    {0x0D62, 0x0D63, kWordBreakExtend},
+    {0x0D66, 0x0D6F, kWordBreakNumeric},
+    {0x0D7A, 0x0D7F, kWordBreakALetter},
+    {0x0D81, 0x0D83, kWordBreakExtend},
+    {0x0D85, 0x0D96, kWordBreakALetter},
+    {0x0D9A, 0x0DB1, kWordBreakALetter},
+    {0x0DB3, 0x0DBB, kWordBreakALetter},
+    {0x0DBD, 0x0DBD, kWordBreakALetter},
+    {0x0DC0, 0x0DC6, kWordBreakALetter},
+    {0x0DCA, 0x0DCA, kWordBreakExtend},
+    {0x0DCF, 0x0DD4, kWordBreakExtend},
+    {0x0DD6, 0x0DD6, kWordBreakExtend},
+    {0x0DD8, 0x0DDF, kWordBreakExtend},
+    {0x0DE6, 0x0DEF, kWordBreakNumeric},
+    {0x0DF2, 0x0DF3, kWordBreakExtend},
+    {0x0E31, 0x0E31, kWordBreakExtend},
+    {0x0E34, 0x0E3A, kWordBreakExtend},
+    {0x0E47, 0x0E4E, kWordBreakExtend},
+    {0x0E50, 0x0E59, kWordBreakNumeric},
+    {0x0EB1, 0x0EB1, kWordBreakExtend},
+    {0x0EB4, 0x0EBC, kWordBreakExtend},
+    {0x0EC8, 0x0ECE, kWordBreakExtend},
+    {0x0ED0, 0x0ED9, kWordBreakNumeric},
+    {0x0F00, 0x0F00, kWordBreakALetter},
+    {0x0F18, 0x0F19, kWordBreakExtend},
+    {0x0F20, 0x0F29, kWordBreakNumeric},
+    {0x0F35, 0x0F35, kWordBreakExtend},
+    {0x0F37, 0x0F37, kWordBreakExtend},
+    {0x0F39, 0x0F39, kWordBreakExtend},
+    {0x0F3E, 0x0F3F, kWordBreakExtend},
+    {0x0F40, 0x0F47, kWordBreakALetter},
+    {0x0F49, 0x0F6C, kWordBreakALetter},
+    {0x0F71, 0x0F84, kWordBreakExtend},
+    {0x0F86, 0x0F87, kWordBreakExtend},
+    {0x0F88, 0x0F8C, kWordBreakALetter},
+    {0x0F8D, 0x0F97, kWordBreakExtend},
+    {0x0F99, 0x0FBC, kWordBreakExtend},
+    {0x0FC6, 0x0FC6, kWordBreakExtend},
+    {0x102B, 0x103E, kWordBreakExtend},
+    {0x1040, 0x1049, kWordBreakNumeric},
+    {0x1056, 0x1059, kWordBreakExtend},
+    {0x105E, 0x1060, kWordBreakExtend},
+    {0x1062, 0x1064, kWordBreakExtend},
+    {0x1067, 0x106D, kWordBreakExtend},
+    {0x1071, 0x1074, kWordBreakExtend},
+    {0x1082, 0x108D, kWordBreakExtend},
+    {0x108F, 0x108F, kWordBreakExtend},
+    {0x1090, 0x1099, kWordBreakNumeric},
+    {0x109A, 0x109D, kWordBreakExtend},
+    {0x10A0, 0x10C5, kWordBreakALetter},
+    {0x10C7, 0x10C7, kWordBreakALetter},
+    {0x10CD, 0x10CD, kWordBreakALetter},
+    {0x10D0, 0x10FA, kWordBreakALetter},
+    {0x10FC, 0x1248, kWordBreakALetter},
+    {0x124A, 0x124D, kWordBreakALetter},
+    {0x1250, 0x1256, kWordBreakALetter},
+    {0x1258, 0x1258, kWordBreakALetter},
+    {0x125A, 0x125D, kWordBreakALetter},
+    {0x1260, 0x1288, kWordBreakALetter},
+    {0x128A, 0x128D, kWordBreakALetter},
+    {0x1290, 0x12B0, kWordBreakALetter},
+    {0x12B2, 0x12B5, kWordBreakALetter},
+    {0x12B8, 0x12BE, kWordBreakALetter},
+    {0x12C0, 0x12C0, kWordBreakALetter},
+    {0x12C2, 0x12C5, kWordBreakALetter},
+    {0x12C8, 0x12D6, kWordBreakALetter},
+    {0x12D8, 0x1310, kWordBreakALetter},
+    {0x1312, 0x1315, kWordBreakALetter},
+    {0x1318, 0x135A, kWordBreakALetter},
+    {0x135D, 0x135F, kWordBreakExtend},
+    {0x1380, 0x138F, kWordBreakALetter},
+    {0x13A0, 0x13F5, kWordBreakALetter},
+    {0x13F8, 0x13FD, kWordBreakALetter},
+    {0x1401, 0x166C, kWordBreakALetter},
+    {0x166F, 0x167F, kWordBreakALetter},
+    {0x1680, 0x1680, kWordBreakWSegSpace},
+    {0x1681, 0x169A, kWordBreakALetter},
+    {0x16A0, 0x16EA, kWordBreakALetter},
+    {0x16EE, 0x16F8, kWordBreakALetter},
+    {0x1700, 0x1711, kWordBreakALetter},
+    {0x1712, 0x1715, kWordBreakExtend},
+    {0x171F, 0x1731, kWordBreakALetter},
+    {0x1732, 0x1734, kWordBreakExtend},
+    {0x1740, 0x1751, kWordBreakALetter},
+    {0x1752, 0x1753, kWordBreakExtend},
+    {0x1760, 0x176C, kWordBreakALetter},
+    {0x176E, 0x1770, kWordBreakALetter},
+    {0x1772, 0x1773, kWordBreakExtend},
+    {0x17B4, 0x17D3, kWordBreakExtend},
+    {0x17DD, 0x17DD, kWordBreakExtend},
+    {0x17E0, 0x17E9, kWordBreakNumeric},
+    {0x180B, 0x180D, kWordBreakExtend},
+    {0x180E, 0x180E, kWordBreakFormat},
+    {0x180F, 0x180F, kWordBreakExtend},
+    {0x1810, 0x1819, kWordBreakNumeric},
+    {0x1820, 0x1878, kWordBreakALetter},
+    {0x1880, 0x1884, kWordBreakALetter},
+    {0x1885, 0x1886, kWordBreakExtend},
+    {0x1887, 0x18A8, kWordBreakALetter},
+    {0x18A9, 0x18A9, kWordBreakExtend},
+    {0x18AA, 0x18AA, kWordBreakALetter},
+    {0x18B0, 0x18F5, kWordBreakALetter},
+    {0x1900, 0x191E, kWordBreakALetter},
+    {0x1920, 0x192B, kWordBreakExtend},
+    {0x1930, 0x193B, kWordBreakExtend},
+    {0x1946, 0x194F, kWordBreakNumeric},
+    {0x19D0, 0x19D9, kWordBreakNumeric},
+    {0x1A00, 0x1A16, kWordBreakALetter},
+    {0x1A17, 0x1A1B, kWordBreakExtend},
+    {0x1A55, 0x1A5E, kWordBreakExtend},
+    {0x1A60, 0x1A7C, kWordBreakExtend},
+    {0x1A7F, 0x1A7F, kWordBreakExtend},
+    {0x1A80, 0x1A89, kWordBreakNumeric},
+    {0x1A90, 0x1A99, kWordBreakNumeric},
+    {0x1AB0, 0x1ACE, kWordBreakExtend},
+    {0x1B00, 0x1B04, kWordBreakExtend},
+    {0x1B05, 0x1B33, kWordBreakALetter},
+    {0x1B34, 0x1B44, kWordBreakExtend},
+    {0x1B45, 0x1B4C, kWordBreakALetter},
+    {0x1B50, 0x1B59, kWordBreakNumeric},
+    {0x1B6B, 0x1B73, kWordBreakExtend},
+    {0x1B80, 0x1B82, kWordBreakExtend},
+    {0x1B83, 0x1BA0, kWordBreakALetter},
+    {0x1BA1, 0x1BAD, kWordBreakExtend},
+    {0x1BAE, 0x1BAF, kWordBreakALetter},
+    {0x1BB0, 0x1BB9, kWordBreakNumeric},
+    {0x1BBA, 0x1BE5, kWordBreakALetter},
+    {0x1BE6, 0x1BF3, kWordBreakExtend},
+    {0x1C00, 0x1C23, kWordBreakALetter},
+    {0x1C24, 0x1C37, kWordBreakExtend},
+    {0x1C40, 0x1C49, kWordBreakNumeric},
+    {0x1C4D, 0x1C4F, kWordBreakALetter},
+    {0x1C50, 0x1C59, kWordBreakNumeric},
+    {0x1C5A, 0x1C7D, kWordBreakALetter},
+    {0x1C80, 0x1C88, kWordBreakALetter},
+    {0x1C90, 0x1CBA, kWordBreakALetter},
+    {0x1CBD, 0x1CBF, kWordBreakALetter},
+    {0x1CD0, 0x1CD2, kWordBreakExtend},
+    {0x1CD4, 0x1CE8, kWordBreakExtend},
+    {0x1CE9, 0x1CEC, kWordBreakALetter},
+    {0x1CED, 0x1CED, kWordBreakExtend},
+    {0x1CEE, 0x1CF3, kWordBreakALetter},
+    {0x1CF4, 0x1CF4, kWordBreakExtend},
+    {0x1CF5, 0x1CF6, kWordBreakALetter},
+    {0x1CF7, 0x1CF9, kWordBreakExtend},
+    {0x1CFA, 0x1CFA, kWordBreakALetter},
+    {0x1D00, 0x1DBF, kWordBreakALetter},
+    {0x1DC0, 0x1DFF, kWordBreakExtend},
+    {0x1E00, 0x1F15, kWordBreakALetter},
+    {0x1F18, 0x1F1D, kWordBreakALetter},
+    {0x1F20, 0x1F45, kWordBreakALetter},
+    {0x1F48, 0x1F4D, kWordBreakALetter},
+    {0x1F50, 0x1F57, kWordBreakALetter},
+    {0x1F59, 0x1F59, kWordBreakALetter},
+    {0x1F5B, 0x1F5B, kWordBreakALetter},
+    {0x1F5D, 0x1F5D, kWordBreakALetter},
+    {0x1F5F, 0x1F7D, kWordBreakALetter},
+    {0x1F80, 0x1FB4, kWordBreakALetter},
+    {0x1FB6, 0x1FBC, kWordBreakALetter},
+    {0x1FBE, 0x1FBE, kWordBreakALetter},
+    {0x1FC2, 0x1FC4, kWordBreakALetter},
+    {0x1FC6, 0x1FCC, kWordBreakALetter},
+    {0x1FD0, 0x1FD3, kWordBreakALetter},
+    {0x1FD6, 0x1FDB, kWordBreakALetter},
+    {0x1FE0, 0x1FEC, kWordBreakALetter},
+    {0x1FF2, 0x1FF4, kWordBreakALetter},
+    {0x1FF6, 0x1FFC, kWordBreakALetter},
+    {0x2000, 0x2006, kWordBreakWSegSpace},
+    {0x2008, 0x200A, kWordBreakWSegSpace},
+    {0x200C, 0x200C, kWordBreakExtend},
+    {0x200D, 0x200D, kWordBreakZWJ},
+    {0x200E, 0x200F, kWordBreakFormat},
+    {0x2018, 0x2019, kWordBreakMidNumLet},
+    {0x2024, 0x2024, kWordBreakMidNumLet},
+    {0x2027, 0x2027, kWordBreakMidLetter},
+    {0x2028, 0x2029, kWordBreakNewline},
+    {0x202A, 0x202E, kWordBreakFormat},
+    {0x202F, 0x202F, kWordBreakExtendNumLet},
+    {0x203F, 0x2040, kWordBreakExtendNumLet},
+    {0x2044, 0x2044, kWordBreakMidNum},
+    {0x2054, 0x2054, kWordBreakExtendNumLet},
+    {0x205F, 0x205F, kWordBreakWSegSpace},
+    {0x2060, 0x2064, kWordBreakFormat},
+    {0x2066, 0x206F, kWordBreakFormat},
+    {0x2071, 0x2071, kWordBreakALetter},
+    {0x207F, 0x207F, kWordBreakALetter},
+    {0x2090, 0x209C, kWordBreakALetter},
+    {0x20D0, 0x20F0, kWordBreakExtend},
+    {0x2102, 0x2102, kWordBreakALetter},
+    {0x2107, 0x2107, kWordBreakALetter},
+    {0x210A, 0x2113, kWordBreakALetter},
+    {0x2115, 0x2115, kWordBreakALetter},
+    {0x2119, 0x211D, kWordBreakALetter},
+    {0x2124, 0x2124, kWordBreakALetter},
+    {0x2126, 0x2126, kWordBreakALetter},
+    {0x2128, 0x2128, kWordBreakALetter},
+    {0x212A, 0x212D, kWordBreakALetter},
+    {0x212F, 0x2139, kWordBreakALetter},
+    {0x213C, 0x213F, kWordBreakALetter},
+    {0x2145, 0x2149, kWordBreakALetter},
+    {0x214E, 0x214E, kWordBreakALetter},
+    {0x2160, 0x2188, kWordBreakALetter},
+    {0x24B6, 0x24E9, kWordBreakALetter},
+    {0x2C00, 0x2CE4, kWordBreakALetter},
+    {0x2CEB, 0x2CEE, kWordBreakALetter},
+    {0x2CEF, 0x2CF1, kWordBreakExtend},
+    {0x2CF2, 0x2CF3, kWordBreakALetter},
+    {0x2D00, 0x2D25, kWordBreakALetter},
+    {0x2D27, 0x2D27, kWordBreakALetter},
+    {0x2D2D, 0x2D2D, kWordBreakALetter},
+    {0x2D30, 0x2D67, kWordBreakALetter},
+    {0x2D6F, 0x2D6F, kWordBreakALetter},
+    {0x2D7F, 0x2D7F, kWordBreakExtend},
+    {0x2D80, 0x2D96, kWordBreakALetter},
+    {0x2DA0, 0x2DA6, kWordBreakALetter},
+    {0x2DA8, 0x2DAE, kWordBreakALetter},
+    {0x2DB0, 0x2DB6, kWordBreakALetter},
+    {0x2DB8, 0x2DBE, kWordBreakALetter},
+    {0x2DC0, 0x2DC6, kWordBreakALetter},
+    {0x2DC8, 0x2DCE, kWordBreakALetter},
+    {0x2DD0, 0x2DD6, kWordBreakALetter},
+    {0x2DD8, 0x2DDE, kWordBreakALetter},
+    {0x2DE0, 0x2DFF, kWordBreakExtend},
+    {0x2E2F, 0x2E2F, kWordBreakALetter},
+    {0x3000, 0x3000, kWordBreakWSegSpace},
+    {0x3005, 0x3005, kWordBreakALetter},
+    {0x302A, 0x302F, kWordBreakExtend},
+    {0x3031, 0x3035, kWordBreakKatakana},
+    {0x303B, 0x303C, kWordBreakALetter},
+    {0x3099, 0x309A, kWordBreakExtend},
+    {0x309B, 0x309C, kWordBreakKatakana},
+    {0x30A0, 0x30FA, kWordBreakKatakana},
+    {0x30FC, 0x30FF, kWordBreakKatakana},
+    {0x3105, 0x312F, kWordBreakALetter},
+    {0x3131, 0x318E, kWordBreakALetter},
+    {0x31A0, 0x31BF, kWordBreakALetter},
+    {0x31F0, 0x31FF, kWordBreakKatakana},
+    {0x32D0, 0x32FE, kWordBreakKatakana},
+    {0x3300, 0x3357, kWordBreakKatakana},
+    {0xA000, 0xA48C, kWordBreakALetter},
+    {0xA4D0, 0xA4FD, kWordBreakALetter},
+    {0xA500, 0xA60C, kWordBreakALetter},
+    {0xA610, 0xA61F, kWordBreakALetter},
+    {0xA620, 0xA629, kWordBreakNumeric},
+    {0xA62A, 0xA62B, kWordBreakALetter},
+    {0xA640, 0xA66E, kWordBreakALetter},
+    {0xA66F, 0xA672, kWordBreakExtend},
+    {0xA674, 0xA67D, kWordBreakExtend},
+    {0xA67F, 0xA69D, kWordBreakALetter},
+    {0xA69E, 0xA69F, kWordBreakExtend},
+    {0xA6A0, 0xA6EF, kWordBreakALetter},
+    {0xA6F0, 0xA6F1, kWordBreakExtend},
+    {0xA708, 0xA7CA, kWordBreakALetter},
+    {0xA7D0, 0xA7D1, kWordBreakALetter},
+    {0xA7D3, 0xA7D3, kWordBreakALetter},
+    {0xA7D5, 0xA7D9, kWordBreakALetter},
+    {0xA7F2, 0xA801, kWordBreakALetter},
+    {0xA802, 0xA802, kWordBreakExtend},
+    {0xA803, 0xA805, kWordBreakALetter},
+    {0xA806, 0xA806, kWordBreakExtend},
+    {0xA807, 0xA80A, kWordBreakALetter},
+    {0xA80B, 0xA80B, kWordBreakExtend},
+    {0xA80C, 0xA822, kWordBreakALetter},
+    {0xA823, 0xA827, kWordBreakExtend},
+    {0xA82C, 0xA82C, kWordBreakExtend},
+    {0xA840, 0xA873, kWordBreakALetter},
+    {0xA880, 0xA881, kWordBreakExtend},
+    {0xA882, 0xA8B3, kWordBreakALetter},
+    {0xA8B4, 0xA8C5, kWordBreakExtend},
+    {0xA8D0, 0xA8D9, kWordBreakNumeric},
+    {0xA8E0, 0xA8F1, kWordBreakExtend},
+    {0xA8F2, 0xA8F7, kWordBreakALetter},
+    {0xA8FB, 0xA8FB, kWordBreakALetter},
+    {0xA8FD, 0xA8FE, kWordBreakALetter},
+    {0xA8FF, 0xA8FF, kWordBreakExtend},
+    {0xA900, 0xA909, kWordBreakNumeric},
+    {0xA90A, 0xA925, kWordBreakALetter},
+    {0xA926, 0xA92D, kWordBreakExtend},
+    {0xA930, 0xA946, kWordBreakALetter},
+    {0xA947, 0xA953, kWordBreakExtend},
+    {0xA960, 0xA97C, kWordBreakALetter},
+    {0xA980, 0xA983, kWordBreakExtend},
+    {0xA984, 0xA9B2, kWordBreakALetter},
+    {0xA9B3, 0xA9C0, kWordBreakExtend},
+    {0xA9CF, 0xA9CF, kWordBreakALetter},
+    {0xA9D0, 0xA9D9, kWordBreakNumeric},
+    {0xA9E5, 0xA9E5, kWordBreakExtend},
+    {0xA9F0, 0xA9F9, kWordBreakNumeric},
+    {0xAA00, 0xAA28, kWordBreakALetter},
+    {0xAA29, 0xAA36, kWordBreakExtend},
+    {0xAA40, 0xAA42, kWordBreakALetter},
+    {0xAA43, 0xAA43, kWordBreakExtend},
+    {0xAA44, 0xAA4B, kWordBreakALetter},
+    {0xAA4C, 0xAA4D, kWordBreakExtend},
+    {0xAA50, 0xAA59, kWordBreakNumeric},
+    {0xAA7B, 0xAA7D, kWordBreakExtend},
+    {0xAAB0, 0xAAB0, kWordBreakExtend},
+    {0xAAB2, 0xAAB4, kWordBreakExtend},
+    {0xAAB7, 0xAAB8, kWordBreakExtend},
+    {0xAABE, 0xAABF, kWordBreakExtend},
+    {0xAAC1, 0xAAC1, kWordBreakExtend},
+    {0xAAE0, 0xAAEA, kWordBreakALetter},
+    {0xAAEB, 0xAAEF, kWordBreakExtend},
+    {0xAAF2, 0xAAF4, kWordBreakALetter},
+    {0xAAF5, 0xAAF6, kWordBreakExtend},
+    {0xAB01, 0xAB06, kWordBreakALetter},
+    {0xAB09, 0xAB0E, kWordBreakALetter},
+    {0xAB11, 0xAB16, kWordBreakALetter},
+    {0xAB20, 0xAB26, kWordBreakALetter},
+    {0xAB28, 0xAB2E, kWordBreakALetter},
+    {0xAB30, 0xAB69, kWordBreakALetter},
+    {0xAB70, 0xABE2, kWordBreakALetter},
+    {0xABE3, 0xABEA, kWordBreakExtend},
+    {0xABEC, 0xABED, kWordBreakExtend},
+    {0xABF0, 0xABF9, kWordBreakNumeric},
+    {0xAC00, 0xD7A3, kWordBreakALetter},
+    {0xD7B0, 0xD7C6, kWordBreakALetter},
+    {0xD7CB, 0xD7FB, kWordBreakALetter},
+    {0xFB00, 0xFB06, kWordBreakALetter},
+    {0xFB13, 0xFB17, kWordBreakALetter},
+    {0xFB1D, 0xFB1D, kWordBreakHebrewLetter},
+    {0xFB1E, 0xFB1E, kWordBreakExtend},
+    {0xFB1F, 0xFB28, kWordBreakHebrewLetter},
+    {0xFB2A, 0xFB36, kWordBreakHebrewLetter},
+    {0xFB38, 0xFB3C, kWordBreakHebrewLetter},
+    {0xFB3E, 0xFB3E, kWordBreakHebrewLetter},
+    {0xFB40, 0xFB41, kWordBreakHebrewLetter},
+    {0xFB43, 0xFB44, kWordBreakHebrewLetter},
+    {0xFB46, 0xFB4F, kWordBreakHebrewLetter},
+    {0xFB50, 0xFBB1, kWordBreakALetter},
+    {0xFBD3, 0xFD3D, kWordBreakALetter},
+    {0xFD50, 0xFD8F, kWordBreakALetter},
+    {0xFD92, 0xFDC7, kWordBreakALetter},
+    {0xFDF0, 0xFDFB, kWordBreakALetter},
+    {0xFE00, 0xFE0F, kWordBreakExtend},
+    {0xFE10, 0xFE10, kWordBreakMidNum},
+    {0xFE13, 0xFE13, kWordBreakMidLetter},
+    {0xFE14, 0xFE14, kWordBreakMidNum},
+    {0xFE20, 0xFE2F, kWordBreakExtend},
+    {0xFE33, 0xFE34, kWordBreakExtendNumLet},
+    {0xFE4D, 0xFE4F, kWordBreakExtendNumLet},
+    {0xFE50, 0xFE50, kWordBreakMidNum},
+    {0xFE52, 0xFE52, kWordBreakMidNumLet},
+    {0xFE54, 0xFE54, kWordBreakMidNum},
+    {0xFE55, 0xFE55, kWordBreakMidLetter},
+    {0xFE70, 0xFE74, kWordBreakALetter},
+    {0xFE76, 0xFEFC, kWordBreakALetter},
+    {0xFEFF, 0xFEFF, kWordBreakFormat},
+    {0xFF07, 0xFF07, kWordBreakMidNumLet},
+    {0xFF0C, 0xFF0C, kWordBreakMidNum},
+    {0xFF0E, 0xFF0E, kWordBreakMidNumLet},
+    {0xFF10, 0xFF19, kWordBreakNumeric},
+    {0xFF1A, 0xFF1A, kWordBreakMidLetter},
+    {0xFF1B, 0xFF1B, kWordBreakMidNum},
+    {0xFF21, 0xFF3A, kWordBreakALetter},
+    {0xFF3F, 0xFF3F, kWordBreakExtendNumLet},
+    {0xFF41, 0xFF5A, kWordBreakALetter},
+    {0xFF66, 0xFF9D, kWordBreakKatakana},
+    {0xFF9E, 0xFF9F, kWordBreakExtend},
+    {0xFFA0, 0xFFBE, kWordBreakALetter},
+    {0xFFC2, 0xFFC7, kWordBreakALetter},
+    {0xFFCA, 0xFFCF, kWordBreakALetter},
+    {0xFFD2, 0xFFD7, kWordBreakALetter},
+    {0xFFDA, 0xFFDC, kWordBreakALetter},
+    {0xFFF9, 0xFFFB, kWordBreakFormat},
+    {0x10000, 0x1000B, kWordBreakALetter},
+    {0x1000D, 0x10026, kWordBreakALetter},
+    {0x10028, 0x1003A, kWordBreakALetter},
+    {0x1003C, 0x1003D, kWordBreakALetter},
+    {0x1003F, 0x1004D, kWordBreakALetter},
+    {0x10050, 0x1005D, kWordBreakALetter},
+    {0x10080, 0x100FA, kWordBreakALetter},
+    {0x10140, 0x10174, kWordBreakALetter},
+    {0x101FD, 0x101FD, kWordBreakExtend},
+    {0x10280, 0x1029C, kWordBreakALetter},
+    {0x102A0, 0x102D0, kWordBreakALetter},
+    {0x102E0, 0x102E0, kWordBreakExtend},
+    {0x10300, 0x1031F, kWordBreakALetter},
+    {0x1032D, 0x1034A, kWordBreakALetter},
+    {0x10350, 0x10375, kWordBreakALetter},
+    {0x10376, 0x1037A, kWordBreakExtend},
+    {0x10380, 0x1039D, kWordBreakALetter},
+    {0x103A0, 0x103C3, kWordBreakALetter},
+    {0x103C8, 0x103CF, kWordBreakALetter},
+    {0x103D1, 0x103D5, kWordBreakALetter},
+    {0x10400, 0x1049D, kWordBreakALetter},
+    {0x104A0, 0x104A9, kWordBreakNumeric},
+    {0x104B0, 0x104D3, kWordBreakALetter},
+    {0x104D8, 0x104FB, kWordBreakALetter},
+    {0x10500, 0x10527, kWordBreakALetter},
+    {0x10530, 0x10563, kWordBreakALetter},
+    {0x10570, 0x1057A, kWordBreakALetter},
+    {0x1057C, 0x1058A, kWordBreakALetter},
+    {0x1058C, 0x10592, kWordBreakALetter},
+    {0x10594, 0x10595, kWordBreakALetter},
+    {0x10597, 0x105A1, kWordBreakALetter},
+    {0x105A3, 0x105B1, kWordBreakALetter},
+    {0x105B3, 0x105B9, kWordBreakALetter},
+    {0x105BB, 0x105BC, kWordBreakALetter},
+    {0x10600, 0x10736, kWordBreakALetter},
+    {0x10740, 0x10755, kWordBreakALetter},
+    {0x10760, 0x10767, kWordBreakALetter},
+    {0x10780, 0x10785, kWordBreakALetter},
+    {0x10787, 0x107B0, kWordBreakALetter},
+    {0x107B2, 0x107BA, kWordBreakALetter},
+    {0x10800, 0x10805, kWordBreakALetter},
+    {0x10808, 0x10808, kWordBreakALetter},
+    {0x1080A, 0x10835, kWordBreakALetter},
+    {0x10837, 0x10838, kWordBreakALetter},
+    {0x1083C, 0x1083C, kWordBreakALetter},
+    {0x1083F, 0x10855, kWordBreakALetter},
+    {0x10860, 0x10876, kWordBreakALetter},
+    {0x10880, 0x1089E, kWordBreakALetter},
+    {0x108E0, 0x108F2, kWordBreakALetter},
+    {0x108F4, 0x108F5, kWordBreakALetter},
+    {0x10900, 0x10915, kWordBreakALetter},
+    {0x10920, 0x10939, kWordBreakALetter},
+    {0x10980, 0x109B7, kWordBreakALetter},
+    {0x109BE, 0x109BF, kWordBreakALetter},
+    {0x10A00, 0x10A00, kWordBreakALetter},
+    {0x10A01, 0x10A03, kWordBreakExtend},
+    {0x10A05, 0x10A06, kWordBreakExtend},
+    {0x10A0C, 0x10A0F, kWordBreakExtend},
+    {0x10A10, 0x10A13, kWordBreakALetter},
+    {0x10A15, 0x10A17, kWordBreakALetter},
+    {0x10A19, 0x10A35, kWordBreakALetter},
+    {0x10A38, 0x10A3A, kWordBreakExtend},
+    {0x10A3F, 0x10A3F, kWordBreakExtend},
+    {0x10A60, 0x10A7C, kWordBreakALetter},
+    {0x10A80, 0x10A9C, kWordBreakALetter},
+    {0x10AC0, 0x10AC7, kWordBreakALetter},
+    {0x10AC9, 0x10AE4, kWordBreakALetter},
+    {0x10AE5, 0x10AE6, kWordBreakExtend},
+    {0x10B00, 0x10B35, kWordBreakALetter},
+    {0x10B40, 0x10B55, kWordBreakALetter},
+    {0x10B60, 0x10B72, kWordBreakALetter},
+    {0x10B80, 0x10B91, kWordBreakALetter},
+    {0x10C00, 0x10C48, kWordBreakALetter},
+    {0x10C80, 0x10CB2, kWordBreakALetter},
+    {0x10CC0, 0x10CF2, kWordBreakALetter},
+    {0x10D00, 0x10D23, kWordBreakALetter},
+    {0x10D24, 0x10D27, kWordBreakExtend},
+    {0x10D30, 0x10D39, kWordBreakNumeric},
+    {0x10E80, 0x10EA9, kWordBreakALetter},
+    {0x10EAB, 0x10EAC, kWordBreakExtend},
+    {0x10EB0, 0x10EB1, kWordBreakALetter},
+    {0x10EFD, 0x10EFF, kWordBreakExtend},
+    {0x10F00, 0x10F1C, kWordBreakALetter},
+    {0x10F27, 0x10F27, kWordBreakALetter},
+    {0x10F30, 0x10F45, kWordBreakALetter},
+    {0x10F46, 0x10F50, kWordBreakExtend},
+    {0x10F70, 0x10F81, kWordBreakALetter},
+    {0x10F82, 0x10F85, kWordBreakExtend},
+    {0x10FB0, 0x10FC4, kWordBreakALetter},
+    {0x10FE0, 0x10FF6, kWordBreakALetter},
+    {0x11000, 0x11002, kWordBreakExtend},
+    {0x11003, 0x11037, kWordBreakALetter},
+    {0x11038, 0x11046, kWordBreakExtend},
+    {0x11066, 0x1106F, kWordBreakNumeric},
+    {0x11070, 0x11070, kWordBreakExtend},
+    {0x11071, 0x11072, kWordBreakALetter},
+    {0x11073, 0x11074, kWordBreakExtend},
+    {0x11075, 0x11075, kWordBreakALetter},
+    {0x1107F, 0x11082, kWordBreakExtend},
+    {0x11083, 0x110AF, kWordBreakALetter},
+    {0x110B0, 0x110BA, kWordBreakExtend},
+    {0x110BD, 0x110BD, kWordBreakFormat},
+    {0x110C2, 0x110C2, kWordBreakExtend},
+    {0x110CD, 0x110CD, kWordBreakFormat},
+    {0x110D0, 0x110E8, kWordBreakALetter},
+    {0x110F0, 0x110F9, kWordBreakNumeric},
+    {0x11100, 0x11102, kWordBreakExtend},
+    {0x11103, 0x11126, kWordBreakALetter},
+    {0x11127, 0x11134, kWordBreakExtend},
+    {0x11136, 0x1113F, kWordBreakNumeric},
+    {0x11144, 0x11144, kWordBreakALetter},
+    {0x11145, 0x11146, kWordBreakExtend},
+    {0x11147, 0x11147, kWordBreakALetter},
+    {0x11150, 0x11172, kWordBreakALetter},
+    {0x11173, 0x11173, kWordBreakExtend},
+    {0x11176, 0x11176, kWordBreakALetter},
+    {0x11180, 0x11182, kWordBreakExtend},
+    {0x11183, 0x111B2, kWordBreakALetter},
+    {0x111B3, 0x111C0, kWordBreakExtend},
+    {0x111C1, 0x111C4, kWordBreakALetter},
+    {0x111C9, 0x111CC, kWordBreakExtend},
+    {0x111CE, 0x111CF, kWordBreakExtend},
+    {0x111D0, 0x111D9, kWordBreakNumeric},
+    {0x111DA, 0x111DA, kWordBreakALetter},
+    {0x111DC, 0x111DC, kWordBreakALetter},
+    {0x11200, 0x11211, kWordBreakALetter},
+    {0x11213, 0x1122B, kWordBreakALetter},
+    {0x1122C, 0x11237, kWordBreakExtend},
+    {0x1123E, 0x1123E, kWordBreakExtend},
+    {0x1123F, 0x11240, kWordBreakALetter},
+    {0x11241, 0x11241, kWordBreakExtend},
+    {0x11280, 0x11286, kWordBreakALetter},
+    {0x11288, 0x11288, kWordBreakALetter},
+    {0x1128A, 0x1128D, kWordBreakALetter},
+    {0x1128F, 0x1129D, kWordBreakALetter},
+    {0x1129F, 0x112A8, kWordBreakALetter},
+    {0x112B0, 0x112DE, kWordBreakALetter},
+    {0x112DF, 0x112EA, kWordBreakExtend},
+    {0x112F0, 0x112F9, kWordBreakNumeric},
+    {0x11300, 0x11303, kWordBreakExtend},
+    {0x11305, 0x1130C, kWordBreakALetter},
+    {0x1130F, 0x11310, kWordBreakALetter},
+    {0x11313, 0x11328, kWordBreakALetter},
+    {0x1132A, 0x11330, kWordBreakALetter},
+    {0x11332, 0x11333, kWordBreakALetter},
+    {0x11335, 0x11339, kWordBreakALetter},
+    {0x1133B, 0x1133C, kWordBreakExtend},
+    {0x1133D, 0x1133D, kWordBreakALetter},
+    {0x1133E, 0x11344, kWordBreakExtend},
+    {0x11347, 0x11348, kWordBreakExtend},
+    {0x1134B, 0x1134D, kWordBreakExtend},
+    {0x11350, 0x11350, kWordBreakALetter},
+    {0x11357, 0x11357, kWordBreakExtend},
+    {0x1135D, 0x11361, kWordBreakALetter},
+    {0x11362, 0x11363, kWordBreakExtend},
+    {0x11366, 0x1136C, kWordBreakExtend},
+    {0x11370, 0x11374, kWordBreakExtend},
+    {0x11400, 0x11434, kWordBreakALetter},
+    {0x11435, 0x11446, kWordBreakExtend},
+    {0x11447, 0x1144A, kWordBreakALetter},
+    {0x11450, 0x11459, kWordBreakNumeric},
+    {0x1145E, 0x1145E, kWordBreakExtend},
+    {0x1145F, 0x11461, kWordBreakALetter},
+    {0x11480, 0x114AF, kWordBreakALetter},
+    {0x114B0, 0x114C3, kWordBreakExtend},
+    {0x114C4, 0x114C5, kWordBreakALetter},
+    {0x114C7, 0x114C7, kWordBreakALetter},
+    {0x114D0, 0x114D9, kWordBreakNumeric},
+    {0x11580, 0x115AE, kWordBreakALetter},
+    {0x115AF, 0x115B5, kWordBreakExtend},
+    {0x115B8, 0x115C0, kWordBreakExtend},
+    {0x115D8, 0x115DB, kWordBreakALetter},
+    {0x115DC, 0x115DD, kWordBreakExtend},
+    {0x11600, 0x1162F, kWordBreakALetter},
+    {0x11630, 0x11640, kWordBreakExtend},
+    {0x11644, 0x11644, kWordBreakALetter},
+    {0x11650, 0x11659, kWordBreakNumeric},
+    {0x11680, 0x116AA, kWordBreakALetter},
+    {0x116AB, 0x116B7, kWordBreakExtend},
+    {0x116B8, 0x116B8, kWordBreakALetter},
+    {0x116C0, 0x116C9, kWordBreakNumeric},
+    {0x1171D, 0x1172B, kWordBreakExtend},
+    {0x11730, 0x11739, kWordBreakNumeric},
+    {0x11800, 0x1182B, kWordBreakALetter},
+    {0x1182C, 0x1183A, kWordBreakExtend},
+    {0x118A0, 0x118DF, kWordBreakALetter},
+    {0x118E0, 0x118E9, kWordBreakNumeric},
+    {0x118FF, 0x11906, kWordBreakALetter},
+    {0x11909, 0x11909, kWordBreakALetter},
+    {0x1190C, 0x11913, kWordBreakALetter},
+    {0x11915, 0x11916, kWordBreakALetter},
+    {0x11918, 0x1192F, kWordBreakALetter},
+    {0x11930, 0x11935, kWordBreakExtend},
+    {0x11937, 0x11938, kWordBreakExtend},
+    {0x1193B, 0x1193E, kWordBreakExtend},
+    {0x1193F, 0x1193F, kWordBreakALetter},
+    {0x11940, 0x11940, kWordBreakExtend},
+    {0x11941, 0x11941, kWordBreakALetter},
+    {0x11942, 0x11943, kWordBreakExtend},
+    {0x11950, 0x11959, kWordBreakNumeric},
+    {0x119A0, 0x119A7, kWordBreakALetter},
+    {0x119AA, 0x119D0, kWordBreakALetter},
+    {0x119D1, 0x119D7, kWordBreakExtend},
+    {0x119DA, 0x119E0, kWordBreakExtend},
+    {0x119E1, 0x119E1, kWordBreakALetter},
+    {0x119E3, 0x119E3, kWordBreakALetter},
+    {0x119E4, 0x119E4, kWordBreakExtend},
+    {0x11A00, 0x11A00, kWordBreakALetter},
+    {0x11A01, 0x11A0A, kWordBreakExtend},
+    {0x11A0B, 0x11A32, kWordBreakALetter},
+    {0x11A33, 0x11A39, kWordBreakExtend},
+    {0x11A3A, 0x11A3A, kWordBreakALetter},
+    {0x11A3B, 0x11A3E, kWordBreakExtend},
+    {0x11A47, 0x11A47, kWordBreakExtend},
+    {0x11A50, 0x11A50, kWordBreakALetter},
+    {0x11A51, 0x11A5B, kWordBreakExtend},
+    {0x11A5C, 0x11A89, kWordBreakALetter},
+    {0x11A8A, 0x11A99, kWordBreakExtend},
+    {0x11A9D, 0x11A9D, kWordBreakALetter},
+    {0x11AB0, 0x11AF8, kWordBreakALetter},
+    {0x11C00, 0x11C08, kWordBreakALetter},
+    {0x11C0A, 0x11C2E, kWordBreakALetter},
+    {0x11C2F, 0x11C36, kWordBreakExtend},
+    {0x11C38, 0x11C3F, kWordBreakExtend},
+    {0x11C40, 0x11C40, kWordBreakALetter},
+    {0x11C50, 0x11C59, kWordBreakNumeric},
+    {0x11C72, 0x11C8F, kWordBreakALetter},
+    {0x11C92, 0x11CA7, kWordBreakExtend},
+    {0x11CA9, 0x11CB6, kWordBreakExtend},
+    {0x11D00, 0x11D06, kWordBreakALetter},
+    {0x11D08, 0x11D09, kWordBreakALetter},
+    {0x11D0B, 0x11D30, kWordBreakALetter},
+    {0x11D31, 0x11D36, kWordBreakExtend},
+    {0x11D3A, 0x11D3A, kWordBreakExtend},
+    {0x11D3C, 0x11D3D, kWordBreakExtend},
+    {0x11D3F, 0x11D45, kWordBreakExtend},
+    {0x11D46, 0x11D46, kWordBreakALetter},
+    {0x11D47, 0x11D47, kWordBreakExtend},
+    {0x11D50, 0x11D59, kWordBreakNumeric},
+    {0x11D60, 0x11D65, kWordBreakALetter},
+    {0x11D67, 0x11D68, kWordBreakALetter},
+    {0x11D6A, 0x11D89, kWordBreakALetter},
+    {0x11D8A, 0x11D8E, kWordBreakExtend},
+    {0x11D90, 0x11D91, kWordBreakExtend},
+    {0x11D93, 0x11D97, kWordBreakExtend},
+    {0x11D98, 0x11D98, kWordBreakALetter},
+    {0x11DA0, 0x11DA9, kWordBreakNumeric},
+    {0x11EE0, 0x11EF2, kWordBreakALetter},
+    {0x11EF3, 0x11EF6, kWordBreakExtend},
+    {0x11F00, 0x11F01, kWordBreakExtend},
+    {0x11F02, 0x11F02, kWordBreakALetter},
+    {0x11F03, 0x11F03, kWordBreakExtend},
+    {0x11F04, 0x11F10, kWordBreakALetter},
+    {0x11F12, 0x11F33, kWordBreakALetter},
+    {0x11F34, 0x11F3A, kWordBreakExtend},
+    {0x11F3E, 0x11F42, kWordBreakExtend},
+    {0x11F50, 0x11F59, kWordBreakNumeric},
+    {0x11FB0, 0x11FB0, kWordBreakALetter},
+    {0x12000, 0x12399, kWordBreakALetter},
+    {0x12400, 0x1246E, kWordBreakALetter},
+    {0x12480, 0x12543, kWordBreakALetter},
+    {0x12F90, 0x12FF0, kWordBreakALetter},
+    {0x13000, 0x1342F, kWordBreakALetter},
+    {0x13430, 0x1343F, kWordBreakFormat},
+    {0x13440, 0x13440, kWordBreakExtend},
+    {0x13441, 0x13446, kWordBreakALetter},
+    {0x13447, 0x13455, kWordBreakExtend},
+    {0x14400, 0x14646, kWordBreakALetter},
+    {0x16800, 0x16A38, kWordBreakALetter},
+    {0x16A40, 0x16A5E, kWordBreakALetter},
+    {0x16A60, 0x16A69, kWordBreakNumeric},
+    {0x16A70, 0x16ABE, kWordBreakALetter},
+    {0x16AC0, 0x16AC9, kWordBreakNumeric},
+    {0x16AD0, 0x16AED, kWordBreakALetter},
+    {0x16AF0, 0x16AF4, kWordBreakExtend},
+    {0x16B00, 0x16B2F, kWordBreakALetter},
+    {0x16B30, 0x16B36, kWordBreakExtend},
+    {0x16B40, 0x16B43, kWordBreakALetter},
+    {0x16B50, 0x16B59, kWordBreakNumeric},
+    {0x16B63, 0x16B77, kWordBreakALetter},
+    {0x16B7D, 0x16B8F, kWordBreakALetter},
+    {0x16E40, 0x16E7F, kWordBreakALetter},
+    {0x16F00, 0x16F4A, kWordBreakALetter},
+    {0x16F4F, 0x16F4F, kWordBreakExtend},
+    {0x16F50, 0x16F50, kWordBreakALetter},
+    {0x16F51, 0x16F87, kWordBreakExtend},
+    {0x16F8F, 0x16F92, kWordBreakExtend},
+    {0x16F93, 0x16F9F, kWordBreakALetter},
+    {0x16FE0, 0x16FE1, kWordBreakALetter},
+    {0x16FE3, 0x16FE3, kWordBreakALetter},
+    {0x16FE4, 0x16FE4, kWordBreakExtend},
+    {0x16FF0, 0x16FF1, kWordBreakExtend},
+    {0x1AFF0, 0x1AFF3, kWordBreakKatakana},
+    {0x1AFF5, 0x1AFFB, kWordBreakKatakana},
+    {0x1AFFD, 0x1AFFE, kWordBreakKatakana},
+    {0x1B000, 0x1B000, kWordBreakKatakana},
+    {0x1B120, 0x1B122, kWordBreakKatakana},
+    {0x1B155, 0x1B155, kWordBreakKatakana},
+    {0x1B164, 0x1B167, kWordBreakKatakana},
+    {0x1BC00, 0x1BC6A, kWordBreakALetter},
+    {0x1BC70, 0x1BC7C, kWordBreakALetter},
+    {0x1BC80, 0x1BC88, kWordBreakALetter},
+    {0x1BC90, 0x1BC99, kWordBreakALetter},
+    {0x1BC9D, 0x1BC9E, kWordBreakExtend},
+    {0x1BCA0, 0x1BCA3, kWordBreakFormat},
+    {0x1CF00, 0x1CF2D, kWordBreakExtend},
+    {0x1CF30, 0x1CF46, kWordBreakExtend},
+    {0x1D165, 0x1D169, kWordBreakExtend},
+    {0x1D16D, 0x1D172, kWordBreakExtend},
+    {0x1D173, 0x1D17A, kWordBreakFormat},
+    {0x1D17B, 0x1D182, kWordBreakExtend},
+    {0x1D185, 0x1D18B, kWordBreakExtend},
+    {0x1D1AA, 0x1D1AD, kWordBreakExtend},
+    {0x1D242, 0x1D244, kWordBreakExtend},
+    {0x1D400, 0x1D454, kWordBreakALetter},
+    {0x1D456, 0x1D49C, kWordBreakALetter},
+    {0x1D49E, 0x1D49F, kWordBreakALetter},
+    {0x1D4A2, 0x1D4A2, kWordBreakALetter},
+    {0x1D4A5, 0x1D4A6, kWordBreakALetter},
+    {0x1D4A9, 0x1D4AC, kWordBreakALetter},
+    {0x1D4AE, 0x1D4B9, kWordBreakALetter},
+    {0x1D4BB, 0x1D4BB, kWordBreakALetter},
+    {0x1D4BD, 0x1D4C3, kWordBreakALetter},
+    {0x1D4C5, 0x1D505, kWordBreakALetter},
+    {0x1D507, 0x1D50A, kWordBreakALetter},
+    {0x1D50D, 0x1D514, kWordBreakALetter},
+    {0x1D516, 0x1D51C, kWordBreakALetter},
+    {0x1D51E, 0x1D539, kWordBreakALetter},
+    {0x1D53B, 0x1D53E, kWordBreakALetter},
+    {0x1D540, 0x1D544, kWordBreakALetter},
+    {0x1D546, 0x1D546, kWordBreakALetter},
+    {0x1D54A, 0x1D550, kWordBreakALetter},
+    {0x1D552, 0x1D6A5, kWordBreakALetter},
+    {0x1D6A8, 0x1D6C0, kWordBreakALetter},
+    {0x1D6C2, 0x1D6DA, kWordBreakALetter},
+    {0x1D6DC, 0x1D6FA, kWordBreakALetter},
+    {0x1D6FC, 0x1D714, kWordBreakALetter},
+    {0x1D716, 0x1D734, kWordBreakALetter},
+    {0x1D736, 0x1D74E, kWordBreakALetter},
+    {0x1D750, 0x1D76E, kWordBreakALetter},
+    {0x1D770, 0x1D788, kWordBreakALetter},
+    {0x1D78A, 0x1D7A8, kWordBreakALetter},
+    {0x1D7AA, 0x1D7C2, kWordBreakALetter},
+    {0x1D7C4, 0x1D7CB, kWordBreakALetter},
+    {0x1D7CE, 0x1D7FF, kWordBreakNumeric},
+    {0x1DA00, 0x1DA36, kWordBreakExtend},
+    {0x1DA3B, 0x1DA6C, kWordBreakExtend},
+    {0x1DA75, 0x1DA75, kWordBreakExtend},
+    {0x1DA84, 0x1DA84, kWordBreakExtend},
+    {0x1DA9B, 0x1DA9F, kWordBreakExtend},
+    {0x1DAA1, 0x1DAAF, kWordBreakExtend},
+    {0x1DF00, 0x1DF1E, kWordBreakALetter},
+    {0x1DF25, 0x1DF2A, kWordBreakALetter},
+    {0x1E000, 0x1E006, kWordBreakExtend},
+    {0x1E008, 0x1E018, kWordBreakExtend},
+    {0x1E01B, 0x1E021, kWordBreakExtend},
+    {0x1E023, 0x1E024, kWordBreakExtend},
+    {0x1E026, 0x1E02A, kWordBreakExtend},
+    {0x1E030, 0x1E06D, kWordBreakALetter},
+    {0x1E08F, 0x1E08F, kWordBreakExtend},
+    {0x1E100, 0x1E12C, kWordBreakALetter},
+    {0x1E130, 0x1E136, kWordBreakExtend},
+    {0x1E137, 0x1E13D, kWordBreakALetter},
+    {0x1E140, 0x1E149, kWordBreakNumeric},
+    {0x1E14E, 0x1E14E, kWordBreakALetter},
+    {0x1E290, 0x1E2AD, kWordBreakALetter},
+    {0x1E2AE, 0x1E2AE, kWordBreakExtend},
+    {0x1E2C0, 0x1E2EB, kWordBreakALetter},
+    {0x1E2EC, 0x1E2EF, kWordBreakExtend},
+    {0x1E2F0, 0x1E2F9, kWordBreakNumeric},
+    {0x1E4D0, 0x1E4EB, kWordBreakALetter},
+    {0x1E4EC, 0x1E4EF, kWordBreakExtend},
+    {0x1E4F0, 0x1E4F9, kWordBreakNumeric},
+    {0x1E7E0, 0x1E7E6, kWordBreakALetter},
+    {0x1E7E8, 0x1E7EB, kWordBreakALetter},
+    {0x1E7ED, 0x1E7EE, kWordBreakALetter},
+    {0x1E7F0, 0x1E7FE, kWordBreakALetter},
+    {0x1E800, 0x1E8C4, kWordBreakALetter},
+    {0x1E8D0, 0x1E8D6, kWordBreakExtend},
+    {0x1E900, 0x1E943, kWordBreakALetter},
+    {0x1E944, 0x1E94A, kWordBreakExtend},
+    {0x1E94B, 0x1E94B, kWordBreakALetter},
+    {0x1E950, 0x1E959, kWordBreakNumeric},
+    {0x1EE00, 0x1EE03, kWordBreakALetter},
+    {0x1EE05, 0x1EE1F, kWordBreakALetter},
+    {0x1EE21, 0x1EE22, kWordBreakALetter},
+    {0x1EE24, 0x1EE24, kWordBreakALetter},
+    {0x1EE27, 0x1EE27, kWordBreakALetter},
+    {0x1EE29, 0x1EE32, kWordBreakALetter},
+    {0x1EE34, 0x1EE37, kWordBreakALetter},
+    {0x1EE39, 0x1EE39, kWordBreakALetter},
+    {0x1EE3B, 0x1EE3B, kWordBreakALetter},
+    {0x1EE42, 0x1EE42, kWordBreakALetter},
+    {0x1EE47, 0x1EE47, kWordBreakALetter},
+    {0x1EE49, 0x1EE49, kWordBreakALetter},
+    {0x1EE4B, 0x1EE4B, kWordBreakALetter},
+    {0x1EE4D, 0x1EE4F, kWordBreakALetter},
+    {0x1EE51, 0x1EE52, kWordBreakALetter},
+    {0x1EE54, 0x1EE54, kWordBreakALetter},
+    {0x1EE57, 0x1EE57, kWordBreakALetter},
+    {0x1EE59, 0x1EE59, kWordBreakALetter},
+    {0x1EE5B, 0x1EE5B, kWordBreakALetter},
+    {0x1EE5D, 0x1EE5D, kWordBreakALetter},
+    {0x1EE5F, 0x1EE5F, kWordBreakALetter},
+    {0x1EE61, 0x1EE62, kWordBreakALetter},
+    {0x1EE64, 0x1EE64, kWordBreakALetter},
+    {0x1EE67, 0x1EE6A, kWordBreakALetter},
+    {0x1EE6C, 0x1EE72, kWordBreakALetter},
+    {0x1EE74, 0x1EE77, kWordBreakALetter},
+    {0x1EE79, 0x1EE7C, kWordBreakALetter},
+    {0x1EE7E, 0x1EE7E, kWordBreakALetter},
+    {0x1EE80, 0x1EE89, kWordBreakALetter},
+    {0x1EE8B, 0x1EE9B, kWordBreakALetter},
+    {0x1EEA1, 0x1EEA3, kWordBreakALetter},
+    {0x1EEA5, 0x1EEA9, kWordBreakALetter},
+    {0x1EEAB, 0x1EEBB, kWordBreakALetter},
+    {0x1F130, 0x1F149, kWordBreakALetter},
+    {0x1F150, 0x1F169, kWordBreakALetter},
+    {0x1F170, 0x1F189, kWordBreakALetter},
+    {0x1F1E6, 0x1F1FF, kWordBreakRegionalIndicator},
+    {0x1F3FB, 0x1F3FF, kWordBreakExtend},
+    {0x1FBF0, 0x1FBF9, kWordBreakNumeric},
+    {0xE0001, 0xE0001, kWordBreakFormat},
+    {0xE0020, 0xE007F, kWordBreakExtend},
+    {0xE0100, 0xE01EF, kWordBreakExtend},
+}};
+
+enum SentenceBreakValue : uint8_t {
+  kSentenceBreakOther = 0,
+  kSentenceBreakCR = 1,
+  kSentenceBreakLF = 2,
+  kSentenceBreakExtend = 3,
+  kSentenceBreakSep = 4,
+  kSentenceBreakFormat = 5,
+  kSentenceBreakSp = 6,
+  kSentenceBreakLower = 7,
+  kSentenceBreakUpper = 8,
+  kSentenceBreakOLetter = 9,
+  kSentenceBreakNumeric = 10,
+  kSentenceBreakATerm = 11,
+  kSentenceBreakSContinue = 12,
+  kSentenceBreakSTerm = 13,
+  kSentenceBreakClose = 14,
+};
+
+struct SentenceBreakRange {
+  uint32_t first;
+  uint32_t last;
+  SentenceBreakValue value;
+};
+
+inline constexpr std::array<SentenceBreakRange, 2422> kSentenceBreakRanges{{
+    {0x0009, 0x0009, kSentenceBreakSp},
+    {0x000A, 0x000A, kSentenceBreakLF},
+    {0x000B, 0x000C, kSentenceBreakSp},
+    {0x000D, 0x000D, kSentenceBreakCR},
+    {0x0020, 0x0020, kSentenceBreakSp},
+    {0x0021, 0x0021, kSentenceBreakSTerm},
+    {0x0022, 0x0022, kSentenceBreakClose},
+    {0x0027, 0x0029, kSentenceBreakClose},
+    {0x002C, 0x002D, kSentenceBreakSContinue},
+    {0x002E, 0x002E, kSentenceBreakATerm},
+    {0x0030, 0x0039, kSentenceBreakNumeric},
+    {0x003A, 0x003A, kSentenceBreakSContinue},
+    {0x003F, 0x003F, kSentenceBreakSTerm},
+    {0x0041, 0x005A, kSentenceBreakUpper},
+    {0x005B, 0x005B, kSentenceBreakClose},
+    {0x005D, 0x005D, kSentenceBreakClose},
+    {0x0061, 0x007A, kSentenceBreakLower},
+    {0x007B, 0x007B, kSentenceBreakClose},
+    {0x007D, 0x007D, kSentenceBreakClose},
+    {0x0085, 0x0085, kSentenceBreakSep},
+    {0x00A0, 0x00A0, kSentenceBreakSp},
+    {0x00AA, 0x00AA, kSentenceBreakLower},
+    {0x00AB, 0x00AB, kSentenceBreakClose},
+    {0x00AD, 0x00AD, kSentenceBreakFormat},
+    {0x00B5, 0x00B5, kSentenceBreakLower},
+    {0x00BA, 0x00BA, kSentenceBreakLower},
+    {0x00BB, 0x00BB, kSentenceBreakClose},
+    {0x00C0, 0x00D6, kSentenceBreakUpper},
+    {0x00D8, 0x00DE, kSentenceBreakUpper},
+    {0x00DF, 0x00F6, kSentenceBreakLower},
+    {0x00F8, 0x00FF, kSentenceBreakLower},
+    {0x0100, 0x0100, kSentenceBreakUpper},
+    {0x0101, 0x0101, kSentenceBreakLower},
+    {0x0102, 0x0102, kSentenceBreakUpper},
+    {0x0103, 0x0103, kSentenceBreakLower},
+    {0x0104, 0x0104, kSentenceBreakUpper},
+    {0x0105, 0x0105, kSentenceBreakLower},
+    {0x0106, 0x0106, kSentenceBreakUpper},
+    {0x0107, 0x0107, kSentenceBreakLower},
+    {0x0108, 0x0108, kSentenceBreakUpper},
+    {0x0109, 0x0109, kSentenceBreakLower},
+    {0x010A, 0x010A, kSentenceBreakUpper},
+    {0x010B, 0x010B, kSentenceBreakLower},
+    {0x010C, 0x010C, kSentenceBreakUpper},
+    {0x010D, 0x010D, kSentenceBreakLower},
+    {0x010E, 0x010E, kSentenceBreakUpper},
+    {0x010F, 0x010F, kSentenceBreakLower},
+    {0x0110, 0x0110, kSentenceBreakUpper},
+    {0x0111, 0x0111, kSentenceBreakLower},
+    {0x0112, 0x0112, kSentenceBreakUpper},
+    {0x0113, 0x0113, kSentenceBreakLower},
+    {0x0114, 0x0114, kSentenceBreakUpper},
+    {0x0115, 0x0115, kSentenceBreakLower},
+    {0x0116, 0x0116, kSentenceBreakUpper},
+    {0x0117, 0x0117, kSentenceBreakLower},
+    {0x0118, 0x0118, kSentenceBreakUpper},
+    {0x0119, 0x0119, kSentenceBreakLower},
+    {0x011A, 0x011A, kSentenceBreakUpper},
+    {0x011B, 0x011B, kSentenceBreakLower},
+    {0x011C, 0x011C, kSentenceBreakUpper},
+    {0x011D, 0x011D, kSentenceBreakLower},
+    {0x011E, 0x011E, kSentenceBreakUpper},
+    {0x011F, 0x011F, kSentenceBreakLower},
+    {0x0120, 0x0120, kSentenceBreakUpper},
+    {0x0121, 0x0121, kSentenceBreakLower},
+    {0x0122, 0x0122, kSentenceBreakUpper},
+    {0x0123, 0x0123, kSentenceBreakLower},
+    {0x0124, 0x0124, kSentenceBreakUpper},
+    {0x0125, 0x0125, kSentenceBreakLower},
+    {0x0126, 0x0126, kSentenceBreakUpper},
+    {0x0127, 0x0127, kSentenceBreakLower},
+    {0x0128, 0x0128, kSentenceBreakUpper},
+    {0x0129, 0x0129, kSentenceBreakLower},
+    {0x012A, 0x012A, kSentenceBreakUpper},
+    {0x012B, 0x012B, kSentenceBreakLower},
+    {0x012C, 0x012C, kSentenceBreakUpper},
+    {0x012D, 0x012D, kSentenceBreakLower},
+    {0x012E, 0x012E, kSentenceBreakUpper},
+    {0x012F, 0x012F, kSentenceBreakLower},
+    {0x0130, 0x0130, kSentenceBreakUpper},
+    {0x0131, 0x0131, kSentenceBreakLower},
+    {0x0132, 0x0132, kSentenceBreakUpper},
+    {0x0133, 0x0133, kSentenceBreakLower},
+    {0x0134, 0x0134, kSentenceBreakUpper},
+    {0x0135, 0x0135, kSentenceBreakLower},
+    {0x0136, 0x0136, kSentenceBreakUpper},
+    {0x0137, 0x0138, kSentenceBreakLower},
+    {0x0139, 0x0139, kSentenceBreakUpper},
+    {0x013A, 0x013A, kSentenceBreakLower},
+    {0x013B, 0x013B, kSentenceBreakUpper},
+    {0x013C, 0x013C, kSentenceBreakLower},
+    {0x013D, 0x013D, kSentenceBreakUpper},
+    {0x013E, 0x013E, kSentenceBreakLower},
+    {0x013F, 0x013F, kSentenceBreakUpper},
+    {0x0140, 0x0140, kSentenceBreakLower},
+    {0x0141, 0x0141, kSentenceBreakUpper},
+    {0x0142, 0x0142, kSentenceBreakLower},
+    {0x0143, 0x0143, kSentenceBreakUpper},
+    {0x0144, 0x0144, kSentenceBreakLower},
+    {0x0145, 0x0145, kSentenceBreakUpper},
+    {0x0146, 0x0146, kSentenceBreakLower},
+    {0x0147, 0x0147, kSentenceBreakUpper},
+    {0x0148, 0x0149, kSentenceBreakLower},
+    {0x014A, 0x014A, kSentenceBreakUpper},
+    {0x014B, 0x014B, kSentenceBreakLower},
+    {0x014C, 0x014C, kSentenceBreakUpper},
+    {0x014D, 0x014D, kSentenceBreakLower},
+    {0x014E, 0x014E, kSentenceBreakUpper},
+    {0x014F, 0x014F, kSentenceBreakLower},
+    {0x0150, 0x0150, kSentenceBreakUpper},
+    {0x0151, 0x0151, kSentenceBreakLower},
+    {0x0152, 0x0152, kSentenceBreakUpper},
+    {0x0153, 0x0153, kSentenceBreakLower},
+    {0x0154, 0x0154, kSentenceBreakUpper},
+    {0x0155, 0x0155, kSentenceBreakLower},
+    {0x0156, 0x0156, kSentenceBreakUpper},
+    {0x0157, 0x0157, kSentenceBreakLower},
+    {0x0158, 0x0158, kSentenceBreakUpper},
+    {0x0159, 0x0159, kSentenceBreakLower},
+    {0x015A, 0x015A, kSentenceBreakUpper},
+    {0x015B, 0x015B, kSentenceBreakLower},
+    {0x015C, 0x015C, kSentenceBreakUpper},
+    {0x015D, 0x015D, kSentenceBreakLower},
+    {0x015E, 0x015E, kSentenceBreakUpper},
+    {0x015F, 0x015F, kSentenceBreakLower},
+    {0x0160, 0x0160, kSentenceBreakUpper},
+    {0x0161, 0x0161, kSentenceBreakLower},
+    {0x0162, 0x0162, kSentenceBreakUpper},
+    {0x0163, 0x0163, kSentenceBreakLower},
+    {0x0164, 0x0164, kSentenceBreakUpper},
+    {0x0165, 0x0165, kSentenceBreakLower},
+    {0x0166, 0x0166, kSentenceBreakUpper},
+    {0x0167, 0x0167, kSentenceBreakLower},
+    {0x0168, 0x0168, kSentenceBreakUpper},
+    {0x0169, 0x0169, kSentenceBreakLower},
+    {0x016A, 0x016A, kSentenceBreakUpper},
+    {0x016B, 0x016B, kSentenceBreakLower},
+    {0x016C, 0x016C, kSentenceBreakUpper},
+    {0x016D, 0x016D, kSentenceBreakLower},
+    {0x016E, 0x016E, kSentenceBreakUpper},
+    {0x016F, 0x016F, kSentenceBreakLower},
+    {0x0170, 0x0170, kSentenceBreakUpper},
+    {0x0171, 0x0171, kSentenceBreakLower},
+    {0x0172, 0x0172, kSentenceBreakUpper},
+    {0x0173, 0x0173, kSentenceBreakLower},
+    {0x0174, 0x0174, kSentenceBreakUpper},
+    {0x0175, 0x0175, kSentenceBreakLower},
+    {0x0176, 0x0176, kSentenceBreakUpper},
+    {0x0177, 0x0177, kSentenceBreakLower},
+    {0x0178, 0x0179, kSentenceBreakUpper},
+    {0x017A, 0x017A, kSentenceBreakLower},
+    {0x017B, 0x017B, kSentenceBreakUpper},
+    {0x017C, 0x017C, kSentenceBreakLower},
+    {0x017D, 0x017D, kSentenceBreakUpper},
+    {0x017E, 0x0180, kSentenceBreakLower},
+    {0x0181, 0x0182, kSentenceBreakUpper},
+    {0x0183, 0x0183, kSentenceBreakLower},
+    {0x0184, 0x0184, kSentenceBreakUpper},
+    {0x0185, 0x0185, kSentenceBreakLower},
+    {0x0186, 0x0187, kSentenceBreakUpper},
+    {0x0188, 0x0188, kSentenceBreakLower},
+    {0x0189, 0x018B, kSentenceBreakUpper},
+    {0x018C, 0x018D, kSentenceBreakLower},
+    {0x018E, 0x0191, kSentenceBreakUpper},
+    {0x0192, 0x0192, kSentenceBreakLower},
+    {0x0193, 0x0194, kSentenceBreakUpper},
+    {0x0195, 0x0195, kSentenceBreakLower},
+    {0x0196, 0x0198, kSentenceBreakUpper},
+    {0x0199, 0x019B, kSentenceBreakLower},
+    {0x019C, 0x019D, kSentenceBreakUpper},
+    {0x019E, 0x019E, kSentenceBreakLower},
+    {0x019F, 0x01A0, kSentenceBreakUpper},
+    {0x01A1, 0x01A1, kSentenceBreakLower},
+    {0x01A2, 0x01A2, kSentenceBreakUpper},
+    {0x01A3, 0x01A3, kSentenceBreakLower},
+    {0x01A4, 0x01A4, kSentenceBreakUpper},
+    {0x01A5, 0x01A5, kSentenceBreakLower},
+    {0x01A6, 0x01A7, kSentenceBreakUpper},
+    {0x01A8, 0x01A8, kSentenceBreakLower},
+    {0x01A9, 0x01A9, kSentenceBreakUpper},
+    {0x01AA, 0x01AB, kSentenceBreakLower},
+    {0x01AC, 0x01AC, kSentenceBreakUpper},
+    {0x01AD, 0x01AD, kSentenceBreakLower},
+    {0x01AE, 0x01AF, kSentenceBreakUpper},
+    {0x01B0, 0x01B0, kSentenceBreakLower},
+    {0x01B1, 0x01B3, kSentenceBreakUpper},
+    {0x01B4, 0x01B4, kSentenceBreakLower},
+    {0x01B5, 0x01B5, kSentenceBreakUpper},
+    {0x01B6, 0x01B6, kSentenceBreakLower},
+    {0x01B7, 0x01B8, kSentenceBreakUpper},
+    {0x01B9, 0x01BA, kSentenceBreakLower},
+    {0x01BB, 0x01BB, kSentenceBreakOLetter},
+    {0x01BC, 0x01BC, kSentenceBreakUpper},
+    {0x01BD, 0x01BF, kSentenceBreakLower},
+    {0x01C0, 0x01C3, kSentenceBreakOLetter},
+    {0x01C4, 0x01C5, kSentenceBreakUpper},
+    {0x01C6, 0x01C6, kSentenceBreakLower},
+    {0x01C7, 0x01C8, kSentenceBreakUpper},
+    {0x01C9, 0x01C9, kSentenceBreakLower},
+    {0x01CA, 0x01CB, kSentenceBreakUpper},
+    {0x01CC, 0x01CC, kSentenceBreakLower},
+    {0x01CD, 0x01CD, kSentenceBreakUpper},
+    {0x01CE, 0x01CE, kSentenceBreakLower},
+    {0x01CF, 0x01CF, kSentenceBreakUpper},
+    {0x01D0, 0x01D0, kSentenceBreakLower},
+    {0x01D1, 0x01D1, kSentenceBreakUpper},
+    {0x01D2, 0x01D2, kSentenceBreakLower},
+    {0x01D3, 0x01D3, kSentenceBreakUpper},
+    {0x01D4, 0x01D4, kSentenceBreakLower},
+    {0x01D5, 0x01D5, kSentenceBreakUpper},
+    {0x01D6, 0x01D6, kSentenceBreakLower},
+    {0x01D7, 0x01D7, kSentenceBreakUpper},
+    {0x01D8, 0x01D8, kSentenceBreakLower},
+    {0x01D9, 0x01D9, kSentenceBreakUpper},
+    {0x01DA, 0x01DA, kSentenceBreakLower},
+    {0x01DB, 0x01DB, kSentenceBreakUpper},
+    {0x01DC, 0x01DD, kSentenceBreakLower},
+    {0x01DE, 0x01DE, kSentenceBreakUpper},
+    {0x01DF, 0x01DF, kSentenceBreakLower},
+    {0x01E0, 0x01E0, kSentenceBreakUpper},
+    {0x01E1, 0x01E1, kSentenceBreakLower},
+    {0x01E2, 0x01E2, kSentenceBreakUpper},
+    {0x01E3, 0x01E3, kSentenceBreakLower},
+    {0x01E4, 0x01E4, kSentenceBreakUpper},
+    {0x01E5, 0x01E5, kSentenceBreakLower},
+    {0x01E6, 0x01E6, kSentenceBreakUpper},
+    {0x01E7, 0x01E7, kSentenceBreakLower},
+    {0x01E8, 0x01E8, kSentenceBreakUpper},
+    {0x01E9, 0x01E9, kSentenceBreakLower},
+    {0x01EA, 0x01EA, kSentenceBreakUpper},
+    {0x01EB, 0x01EB, kSentenceBreakLower},
+    {0x01EC, 0x01EC, kSentenceBreakUpper},
+    {0x01ED, 0x01ED, kSentenceBreakLower},
+    {0x01EE, 0x01EE, kSentenceBreakUpper},
+    {0x01EF, 0x01F0, kSentenceBreakLower},
+    {0x01F1, 0x01F2, kSentenceBreakUpper},
+    {0x01F3, 0x01F3, kSentenceBreakLower},
+    {0x01F4, 0x01F4, kSentenceBreakUpper},
+    {0x01F5, 0x01F5, kSentenceBreakLower},
+    {0x01F6, 0x01F8, kSentenceBreakUpper},
+    {0x01F9, 0x01F9, kSentenceBreakLower},
+    {0x01FA, 0x01FA, kSentenceBreakUpper},
+    {0x01FB, 0x01FB, kSentenceBreakLower},
+    {0x01FC, 0x01FC, kSentenceBreakUpper},
+    {0x01FD, 0x01FD, kSentenceBreakLower},
+    {0x01FE, 0x01FE, kSentenceBreakUpper},
+    {0x01FF, 0x01FF, kSentenceBreakLower},
+    {0x0200, 0x0200, kSentenceBreakUpper},
+    {0x0201, 0x0201, kSentenceBreakLower},
+    {0x0202, 0x0202, kSentenceBreakUpper},
+    {0x0203, 0x0203, kSentenceBreakLower},
+    {0x0204, 0x0204, kSentenceBreakUpper},
+    {0x0205, 0x0205, kSentenceBreakLower},
+    {0x0206, 0x0206, kSentenceBreakUpper},
+    {0x0207, 0x0207, kSentenceBreakLower},
+    {0x0208, 0x0208, kSentenceBreakUpper},
+    {0x0209, 0x0209, kSentenceBreakLower},
+    {0x020A, 0x020A, kSentenceBreakUpper},
+    {0x020B, 0x020B, kSentenceBreakLower},
+    {0x020C, 0x020C, kSentenceBreakUpper},
+    {0x020D, 0x020D, kSentenceBreakLower},
+    {0x020E, 0x020E, kSentenceBreakUpper},
+    {0x020F, 0x020F, kSentenceBreakLower},
+    {0x0210, 0x0210, kSentenceBreakUpper},
+    {0x0211, 0x0211, kSentenceBreakLower},
+    {0x0212, 0x0212, kSentenceBreakUpper},
+    {0x0213, 0x0213, kSentenceBreakLower},
+    {0x0214, 0x0214, kSentenceBreakUpper},
+    {0x0215, 0x0215, kSentenceBreakLower},
+    {0x0216, 0x0216, kSentenceBreakUpper},
+    {0x0217, 0x0217, kSentenceBreakLower},
+    {0x0218, 0x0218, kSentenceBreakUpper},
+    {0x0219, 0x0219, kSentenceBreakLower},
+    {0x021A, 0x021A, kSentenceBreakUpper},
+    {0x021B, 0x021B, kSentenceBreakLower},
+    {0x021C, 0x021C, kSentenceBreakUpper},
+    {0x021D, 0x021D, kSentenceBreakLower},
+    {0x021E, 0x021E, kSentenceBreakUpper},
+    {0x021F, 0x021F, kSentenceBreakLower},
+    {0x0220, 0x0220, kSentenceBreakUpper},
+    {0x0221, 0x0221, kSentenceBreakLower},
+    {0x0222, 0x0222, kSentenceBreakUpper},
+    {0x0223, 0x0223, kSentenceBreakLower},
+    {0x0224, 0x0224, kSentenceBreakUpper},
+    {0x0225, 0x0225, kSentenceBreakLower},
+    {0x0226, 0x0226, kSentenceBreakUpper},
+    {0x0227, 0x0227, kSentenceBreakLower},
+    {0x0228, 0x0228, kSentenceBreakUpper},
+    {0x0229, 0x0229, kSentenceBreakLower},
+    {0x022A, 0x022A, kSentenceBreakUpper},
+    {0x022B, 0x022B, kSentenceBreakLower},
+    {0x022C, 0x022C, kSentenceBreakUpper},
+    {0x022D, 0x022D, kSentenceBreakLower},
+    {0x022E, 0x022E, kSentenceBreakUpper},
+    {0x022F, 0x022F, kSentenceBreakLower},
+    {0x0230, 0x0230, kSentenceBreakUpper},
+    {0x0231, 0x0231, kSentenceBreakLower},
+    {0x0232, 0x0232, kSentenceBreakUpper},
+    {0x0233, 0x0239, kSentenceBreakLower},
+    {0x023A, 0x023B, kSentenceBreakUpper},
+    {0x023C, 0x023C, kSentenceBreakLower},
+    {0x023D, 0x023E, kSentenceBreakUpper},
+    {0x023F, 0x0240, kSentenceBreakLower},
+    {0x0241, 0x0241, kSentenceBreakUpper},
+    {0x0242, 0x0242, kSentenceBreakLower},
+    {0x0243, 0x0246, kSentenceBreakUpper},
+    {0x0247, 0x0247, kSentenceBreakLower},
+    {0x0248, 0x0248, kSentenceBreakUpper},
+    {0x0249, 0x0249, kSentenceBreakLower},
+    {0x024A, 0x024A, kSentenceBreakUpper},
+    {0x024B, 0x024B, kSentenceBreakLower},
+    {0x024C, 0x024C, kSentenceBreakUpper},
+    {0x024D, 0x024D, kSentenceBreakLower},
+    {0x024E, 0x024E, kSentenceBreakUpper},
+    {0x024F, 0x0293, kSentenceBreakLower},
+    {0x0294, 0x0294, kSentenceBreakOLetter},
+    {0x0295, 0x02B8, kSentenceBreakLower},
+    {0x02B9, 0x02BF, kSentenceBreakOLetter},
+    {0x02C0, 0x02C1, kSentenceBreakLower},
+    {0x02C6, 0x02D1, kSentenceBreakOLetter},
+    {0x02E0, 0x02E4, kSentenceBreakLower},
+    {0x02EC, 0x02EC, kSentenceBreakOLetter},
+    {0x02EE, 0x02EE, kSentenceBreakOLetter},
+    {0x0300, 0x036F, kSentenceBreakExtend},
+    {0x0370, 0x0370, kSentenceBreakUpper},
+    {0x0371, 0x0371, kSentenceBreakLower},
+    {0x0372, 0x0372, kSentenceBreakUpper},
+    {0x0373, 0x0373, kSentenceBreakLower},
+    {0x0374, 0x0374, kSentenceBreakOLetter},
+    {0x0376, 0x0376, kSentenceBreakUpper},
+    {0x0377, 0x0377, kSentenceBreakLower},
+    {0x037A, 0x037D, kSentenceBreakLower},
+    {0x037F, 0x037F, kSentenceBreakUpper},
+    {0x0386, 0x0386, kSentenceBreakUpper},
+    {0x0388, 0x038A, kSentenceBreakUpper},
+    {0x038C, 0x038C, kSentenceBreakUpper},
+    {0x038E, 0x038F, kSentenceBreakUpper},
+    {0x0390, 0x0390, kSentenceBreakLower},
+    {0x0391, 0x03A1, kSentenceBreakUpper},
+    {0x03A3, 0x03AB, kSentenceBreakUpper},
+    {0x03AC, 0x03CE, kSentenceBreakLower},
+    {0x03CF, 0x03CF, kSentenceBreakUpper},
+    {0x03D0, 0x03D1, kSentenceBreakLower},
+    {0x03D2, 0x03D4, kSentenceBreakUpper},
+    {0x03D5, 0x03D7, kSentenceBreakLower},
+    {0x03D8, 0x03D8, kSentenceBreakUpper},
+    {0x03D9, 0x03D9, kSentenceBreakLower},
+    {0x03DA, 0x03DA, kSentenceBreakUpper},
+    {0x03DB, 0x03DB, kSentenceBreakLower},
+    {0x03DC, 0x03DC, kSentenceBreakUpper},
+    {0x03DD, 0x03DD, kSentenceBreakLower},
+    {0x03DE, 0x03DE, kSentenceBreakUpper},
+    {0x03DF, 0x03DF, kSentenceBreakLower},
+    {0x03E0, 0x03E0, kSentenceBreakUpper},
+    {0x03E1, 0x03E1, kSentenceBreakLower},
+    {0x03E2, 0x03E2, kSentenceBreakUpper},
+    {0x03E3, 0x03E3, kSentenceBreakLower},
+    {0x03E4, 0x03E4, kSentenceBreakUpper},
+    {0x03E5, 0x03E5, kSentenceBreakLower},
+    {0x03E6, 0x03E6, kSentenceBreakUpper},
+    {0x03E7, 0x03E7, kSentenceBreakLower},
+    {0x03E8, 0x03E8, kSentenceBreakUpper},
+    {0x03E9, 0x03E9, kSentenceBreakLower},
+    {0x03EA, 0x03EA, kSentenceBreakUpper},
+    {0x03EB, 0x03EB, kSentenceBreakLower},
+    {0x03EC, 0x03EC, kSentenceBreakUpper},
+    {0x03ED, 0x03ED, kSentenceBreakLower},
+    {0x03EE, 0x03EE, kSentenceBreakUpper},
+    {0x03EF, 0x03F3, kSentenceBreakLower},
+    {0x03F4, 0x03F4, kSentenceBreakUpper},
+    {0x03F5, 0x03F5, kSentenceBreakLower},
+    {0x03F7, 0x03F7, kSentenceBreakUpper},
+    {0x03F8, 0x03F8, kSentenceBreakLower},
+    {0x03F9, 0x03FA, kSentenceBreakUpper},
+    {0x03FB, 0x03FC, kSentenceBreakLower},
+    {0x03FD, 0x042F, kSentenceBreakUpper},
+    {0x0430, 0x045F, kSentenceBreakLower},
+    {0x0460, 0x0460, kSentenceBreakUpper},
+    {0x0461, 0x0461, kSentenceBreakLower},
+    {0x0462, 0x0462, kSentenceBreakUpper},
+    {0x0463, 0x0463, kSentenceBreakLower},
+    {0x0464, 0x0464, kSentenceBreakUpper},
+    {0x0465, 0x0465, kSentenceBreakLower},
+    {0x0466, 0x0466, kSentenceBreakUpper},
+    {0x0467, 0x0467, kSentenceBreakLower},
+    {0x0468, 0x0468, kSentenceBreakUpper},
+    {0x0469, 0x0469, kSentenceBreakLower},
+    {0x046A, 0x046A, kSentenceBreakUpper},
+    {0x046B, 0x046B, kSentenceBreakLower},
+    {0x046C, 0x046C, kSentenceBreakUpper},
+    {0x046D, 0x046D, kSentenceBreakLower},
+    {0x046E, 0x046E, kSentenceBreakUpper},
+    {0x046F, 0x046F, kSentenceBreakLower},
+    {0x0470, 0x0470, kSentenceBreakUpper},
+    {0x0471, 0x0471, kSentenceBreakLower},
+    {0x0472, 0x0472, kSentenceBreakUpper},
+    {0x0473, 0x0473, kSentenceBreakLower},
+    {0x0474, 0x0474, kSentenceBreakUpper},
+    {0x0475, 0x0475, kSentenceBreakLower},
+    {0x0476, 0x0476, kSentenceBreakUpper},
+    {0x0477, 0x0477, kSentenceBreakLower},
+    {0x0478, 0x0478, kSentenceBreakUpper},
+    {0x0479, 0x0479, kSentenceBreakLower},
+    {0x047A, 0x047A, kSentenceBreakUpper},
+    {0x047B, 0x047B, kSentenceBreakLower},
+    {0x047C, 0x047C, kSentenceBreakUpper},
+    {0x047D, 0x047D, kSentenceBreakLower},
+    {0x047E, 0x047E, kSentenceBreakUpper},
+    {0x047F, 0x047F, kSentenceBreakLower},
+    {0x0480, 0x0480, kSentenceBreakUpper},
+    {0x0481, 0x0481, kSentenceBreakLower},
+    {0x0483, 0x0489, kSentenceBreakExtend},
+    {0x048A, 0x048A, kSentenceBreakUpper},
+    {0x048B, 0x048B, kSentenceBreakLower},
+    {0x048C, 0x048C, kSentenceBreakUpper},
+    {0x048D, 0x048D, kSentenceBreakLower},
+    {0x048E, 0x048E, kSentenceBreakUpper},
+    {0x048F, 0x048F, kSentenceBreakLower},
+    {0x0490, 0x0490, kSentenceBreakUpper},
+    {0x0491, 0x0491, kSentenceBreakLower},
+    {0x0492, 0x0492, kSentenceBreakUpper},
+    {0x0493, 0x0493, kSentenceBreakLower},
+    {0x0494, 0x0494, kSentenceBreakUpper},
+    {0x0495, 0x0495, kSentenceBreakLower},
+    {0x0496, 0x0496, kSentenceBreakUpper},
+    {0x0497, 0x0497, kSentenceBreakLower},
+    {0x0498, 0x0498, kSentenceBreakUpper},
+    {0x0499, 0x0499, kSentenceBreakLower},
+    {0x049A, 0x049A, kSentenceBreakUpper},
+    {0x049B, 0x049B, kSentenceBreakLower},
+    {0x049C, 0x049C, kSentenceBreakUpper},
+    {0x049D, 0x049D, kSentenceBreakLower},
+    {0x049E, 0x049E, kSentenceBreakUpper},
+    {0x049F, 0x049F, kSentenceBreakLower},
+    {0x04A0, 0x04A0, kSentenceBreakUpper},
+    {0x04A1, 0x04A1, kSentenceBreakLower},
+    {0x04A2, 0x04A2, kSentenceBreakUpper},
+    {0x04A3, 0x04A3, kSentenceBreakLower},
+    {0x04A4, 0x04A4, kSentenceBreakUpper},
+    {0x04A5, 0x04A5, kSentenceBreakLower},
+    {0x04A6, 0x04A6, kSentenceBreakUpper},
+    {0x04A7, 0x04A7, kSentenceBreakLower},
+    {0x04A8, 0x04A8, kSentenceBreakUpper},
+    {0x04A9, 0x04A9, kSentenceBreakLower},
+    {0x04AA, 0x04AA, kSentenceBreakUpper},
+    {0x04AB, 0x04AB, kSentenceBreakLower},
+    {0x04AC, 0x04AC, kSentenceBreakUpper},
+    {0x04AD, 0x04AD, kSentenceBreakLower},
+    {0x04AE, 0x04AE, kSentenceBreakUpper},
+    {0x04AF, 0x04AF, kSentenceBreakLower},
+    {0x04B0, 0x04B0, kSentenceBreakUpper},
+    {0x04B1, 0x04B1, kSentenceBreakLower},
+    {0x04B2, 0x04B2, kSentenceBreakUpper},
+    {0x04B3, 0x04B3, kSentenceBreakLower},
+    {0x04B4, 0x04B4, kSentenceBreakUpper},
+    {0x04B5, 0x04B5, kSentenceBreakLower},
+    {0x04B6, 0x04B6, kSentenceBreakUpper},
+    {0x04B7, 0x04B7, kSentenceBreakLower},
+    {0x04B8, 0x04B8, kSentenceBreakUpper},
+    {0x04B9, 0x04B9, kSentenceBreakLower},
+    {0x04BA, 0x04BA, kSentenceBreakUpper},
+    {0x04BB, 0x04BB, kSentenceBreakLower},
+    {0x04BC, 0x04BC, kSentenceBreakUpper},
+    {0x04BD, 0x04BD, kSentenceBreakLower},
+    {0x04BE, 0x04BE, kSentenceBreakUpper},
+    {0x04BF, 0x04BF, kSentenceBreakLower},
+    {0x04C0, 0x04C1, kSentenceBreakUpper},
+    {0x04C2, 0x04C2, kSentenceBreakLower},
+    {0x04C3, 0x04C3, kSentenceBreakUpper},
+    {0x04C4, 0x04C4, kSentenceBreakLower},
+    {0x04C5, 0x04C5, kSentenceBreakUpper},
+    {0x04C6, 0x04C6, kSentenceBreakLower},
+    {0x04C7, 0x04C7, kSentenceBreakUpper},
+    {0x04C8, 0x04C8, kSentenceBreakLower},
+    {0x04C9, 0x04C9, kSentenceBreakUpper},
+    {0x04CA, 0x04CA, kSentenceBreakLower},
+    {0x04CB, 0x04CB, kSentenceBreakUpper},
+    {0x04CC, 0x04CC, kSentenceBreakLower},
+    {0x04CD, 0x04CD, kSentenceBreakUpper},
+    {0x04CE, 0x04CF, kSentenceBreakLower},
+    {0x04D0, 0x04D0, kSentenceBreakUpper},
+    {0x04D1, 0x04D1, kSentenceBreakLower},
+    {0x04D2, 0x04D2, kSentenceBreakUpper},
+    {0x04D3, 0x04D3, kSentenceBreakLower},
+    {0x04D4, 0x04D4, kSentenceBreakUpper},
+    {0x04D5, 0x04D5, kSentenceBreakLower},
+    {0x04D6, 0x04D6, kSentenceBreakUpper},
+    {0x04D7, 0x04D7, kSentenceBreakLower},
+    {0x04D8, 0x04D8, kSentenceBreakUpper},
+    {0x04D9, 0x04D9, kSentenceBreakLower},
+    {0x04DA, 0x04DA, kSentenceBreakUpper},
+    {0x04DB, 0x04DB, kSentenceBreakLower},
+    {0x04DC, 0x04DC, kSentenceBreakUpper},
+    {0x04DD, 0x04DD, kSentenceBreakLower},
+    {0x04DE, 0x04DE, kSentenceBreakUpper},
+    {0x04DF, 0x04DF, kSentenceBreakLower},
+    {0x04E0, 0x04E0, kSentenceBreakUpper},
+    {0x04E1, 0x04E1, kSentenceBreakLower},
+    {0x04E2, 0x04E2, kSentenceBreakUpper},
+    {0x04E3, 0x04E3, kSentenceBreakLower},
+    {0x04E4, 0x04E4, kSentenceBreakUpper},
+    {0x04E5, 0x04E5, kSentenceBreakLower},
+    {0x04E6, 0x04E6, kSentenceBreakUpper},
+    {0x04E7, 0x04E7, kSentenceBreakLower},
+    {0x04E8, 0x04E8, kSentenceBreakUpper},
+    {0x04E9, 0x04E9, kSentenceBreakLower},
+    {0x04EA, 0x04EA, kSentenceBreakUpper},
+    {0x04EB, 0x04EB, kSentenceBreakLower},
+    {0x04EC, 0x04EC, kSentenceBreakUpper},
+    {0x04ED, 0x04ED, kSentenceBreakLower},
+    {0x04EE, 0x04EE, kSentenceBreakUpper},
+    {0x04EF, 0x04EF, kSentenceBreakLower},
+    {0x04F0, 0x04F0, kSentenceBreakUpper},
+    {0x04F1, 0x04F1, kSentenceBreakLower},
+    {0x04F2, 0x04F2, kSentenceBreakUpper},
+    {0x04F3, 0x04F3, kSentenceBreakLower},
+    {0x04F4, 0x04F4, kSentenceBreakUpper},
+    {0x04F5, 0x04F5, kSentenceBreakLower},
+    {0x04F6, 0x04F6, kSentenceBreakUpper},
+    {0x04F7, 0x04F7, kSentenceBreakLower},
+    {0x04F8, 0x04F8, kSentenceBreakUpper},
+    {0x04F9, 0x04F9, kSentenceBreakLower},
+    {0x04FA, 0x04FA, kSentenceBreakUpper},
+    {0x04FB, 0x04FB, kSentenceBreakLower},
+    {0x04FC, 0x04FC, kSentenceBreakUpper},
+    {0x04FD, 0x04FD, kSentenceBreakLower},
+    {0x04FE, 0x04FE, kSentenceBreakUpper},
+    {0x04FF, 0x04FF, kSentenceBreakLower},
+    {0x0500, 0x0500, kSentenceBreakUpper},
+    {0x0501, 0x0501, kSentenceBreakLower},
+    {0x0502, 0x0502, kSentenceBreakUpper},
+    {0x0503, 0x0503, kSentenceBreakLower},
+    {0x0504, 0x0504, kSentenceBreakUpper},
+    {0x0505, 0x0505, kSentenceBreakLower},
+    {0x0506, 0x0506, kSentenceBreakUpper},
+    {0x0507, 0x0507, kSentenceBreakLower},
+    {0x0508, 0x0508, kSentenceBreakUpper},
+    {0x0509, 0x0509, kSentenceBreakLower},
+    {0x050A, 0x050A, kSentenceBreakUpper},
+    {0x050B, 0x050B, kSentenceBreakLower},
+    {0x050C, 0x050C, kSentenceBreakUpper},
+    {0x050D, 0x050D, kSentenceBreakLower},
+    {0x050E, 0x050E, kSentenceBreakUpper},
+    {0x050F, 0x050F, kSentenceBreakLower},
+    {0x0510, 0x0510, kSentenceBreakUpper},
+    {0x0511, 0x0511, kSentenceBreakLower},
+    {0x0512, 0x0512, kSentenceBreakUpper},
+    {0x0513, 0x0513, kSentenceBreakLower},
+    {0x0514, 0x0514, kSentenceBreakUpper},
+    {0x0515, 0x0515, kSentenceBreakLower},
+    {0x0516, 0x0516, kSentenceBreakUpper},
+    {0x0517, 0x0517, kSentenceBreakLower},
+    {0x0518, 0x0518, kSentenceBreakUpper},
+    {0x0519, 0x0519, kSentenceBreakLower},
+    {0x051A, 0x051A, kSentenceBreakUpper},
+    {0x051B, 0x051B, kSentenceBreakLower},
+    {0x051C, 0x051C, kSentenceBreakUpper},
+    {0x051D, 0x051D, kSentenceBreakLower},
+    {0x051E, 0x051E, kSentenceBreakUpper},
+    {0x051F, 0x051F, kSentenceBreakLower},
+    {0x0520, 0x0520, kSentenceBreakUpper},
+    {0x0521, 0x0521, kSentenceBreakLower},
+    {0x0522, 0x0522, kSentenceBreakUpper},
+    {0x0523, 0x0523, kSentenceBreakLower},
+    {0x0524, 0x0524, kSentenceBreakUpper},
+    {0x0525, 0x0525, kSentenceBreakLower},
+    {0x0526, 0x0526, kSentenceBreakUpper},
+    {0x0527, 0x0527, kSentenceBreakLower},
+    {0x0528, 0x0528, kSentenceBreakUpper},
+    {0x0529, 0x0529, kSentenceBreakLower},
+    {0x052A, 0x052A, kSentenceBreakUpper},
+    {0x052B, 0x052B, kSentenceBreakLower},
+    {0x052C, 0x052C, kSentenceBreakUpper},
+    {0x052D, 0x052D, kSentenceBreakLower},
+    {0x052E, 0x052E, kSentenceBreakUpper},
+    {0x052F, 0x052F, kSentenceBreakLower},
+    {0x0531, 0x0556, kSentenceBreakUpper},
+    {0x0559, 0x0559, kSentenceBreakOLetter},
+    {0x055D, 0x055D, kSentenceBreakSContinue},
+    {0x0560, 0x0588, kSentenceBreakLower},
+    {0x0589, 0x0589, kSentenceBreakSTerm},
+    {0x0591, 0x05BD, kSentenceBreakExtend},
+    {0x05BF, 0x05BF, kSentenceBreakExtend},
+    {0x05C1, 0x05C2, kSentenceBreakExtend},
+    {0x05C4, 0x05C5, kSentenceBreakExtend},
+    {0x05C7, 0x05C7, kSentenceBreakExtend},
+    {0x05D0, 0x05EA, kSentenceBreakOLetter},
+    {0x05EF, 0x05F3, kSentenceBreakOLetter},
+    {0x0600, 0x0605, kSentenceBreakFormat},
+    {0x060C, 0x060D, kSentenceBreakSContinue},
+    {0x0610, 0x061A, kSentenceBreakExtend},
+    {0x061C, 0x061C, kSentenceBreakFormat},
+    {0x061D, 0x061F, kSentenceBreakSTerm},
+    {0x0620, 0x064A, kSentenceBreakOLetter},
+    {0x064B, 0x065F, kSentenceBreakExtend},
+    {0x0660, 0x0669, kSentenceBreakNumeric},
+    {0x066B, 0x066C, kSentenceBreakNumeric},
+    {0x066E, 0x066F, kSentenceBreakOLetter},
+    {0x0670, 0x0670, kSentenceBreakExtend},
+    {0x0671, 0x06D3, kSentenceBreakOLetter},
+    {0x06D4, 0x06D4, kSentenceBreakSTerm},
+    {0x06D5, 0x06D5, kSentenceBreakOLetter},
+    {0x06D6, 0x06DC, kSentenceBreakExtend},
+    {0x06DD, 0x06DD, kSentenceBreakFormat},
+    {0x06DF, 0x06E4, kSentenceBreakExtend},
+    {0x06E5, 0x06E6, kSentenceBreakOLetter},
+    {0x06E7, 0x06E8, kSentenceBreakExtend},
+    {0x06EA, 0x06ED, kSentenceBreakExtend},
+    {0x06EE, 0x06EF, kSentenceBreakOLetter},
+    {0x06F0, 0x06F9, kSentenceBreakNumeric},
+    {0x06FA, 0x06FC, kSentenceBreakOLetter},
+    {0x06FF, 0x06FF, kSentenceBreakOLetter},
+    {0x0700, 0x0702, kSentenceBreakSTerm},
+    {0x070F, 0x070F, kSentenceBreakFormat},
+    {0x0710, 0x0710, kSentenceBreakOLetter},
+    {0x0711, 0x0711, kSentenceBreakExtend},
+    {0x0712, 0x072F, kSentenceBreakOLetter},
+    {0x0730, 0x074A, kSentenceBreakExtend},
+    {0x074D, 0x07A5, kSentenceBreakOLetter},
+    {0x07A6, 0x07B0, kSentenceBreakExtend},
+    {0x07B1, 0x07B1, kSentenceBreakOLetter},
+    {0x07C0, 0x07C9, kSentenceBreakNumeric},
+    {0x07CA, 0x07EA, kSentenceBreakOLetter},
+    {0x07EB, 0x07F3, kSentenceBreakExtend},
+    {0x07F4, 0x07F5, kSentenceBreakOLetter},
+    {0x07F8, 0x07F8, kSentenceBreakSContinue},
+    {0x07F9, 0x07F9, kSentenceBreakSTerm},
+    {0x07FA, 0x07FA, kSentenceBreakOLetter},
+    {0x07FD, 0x07FD, kSentenceBreakExtend},
+    {0x0800, 0x0815, kSentenceBreakOLetter},
+    {0x0816, 0x0819, kSentenceBreakExtend},
+    {0x081A, 0x081A, kSentenceBreakOLetter},
+    {0x081B, 0x0823, kSentenceBreakExtend},
+    {0x0824, 0x0824, kSentenceBreakOLetter},
+    {0x0825, 0x0827, kSentenceBreakExtend},
+    {0x0828, 0x0828, kSentenceBreakOLetter},
+    {0x0829, 0x082D, kSentenceBreakExtend},
+    {0x0837, 0x0837, kSentenceBreakSTerm},
+    {0x0839, 0x0839, kSentenceBreakSTerm},
+    {0x083D, 0x083E, kSentenceBreakSTerm},
+    {0x0840, 0x0858, kSentenceBreakOLetter},
+    {0x0859, 0x085B, kSentenceBreakExtend},
+    {0x0860, 0x086A, kSentenceBreakOLetter},
+    {0x0870, 0x0887, kSentenceBreakOLetter},
+    {0x0889, 0x088E, kSentenceBreakOLetter},
+    {0x0890, 0x0891, kSentenceBreakFormat},
+    {0x0898, 0x089F, kSentenceBreakExtend},
+    {0x08A0, 0x08C9, kSentenceBreakOLetter},
+    {0x08CA, 0x08E1, kSentenceBreakExtend},
+    {0x08E2, 0x08E2, kSentenceBreakFormat},
+    {0x08E3, 0x0903, kSentenceBreakExtend},
+    {0x0904, 0x0939, kSentenceBreakOLetter},
+    {0x093A, 0x093C, kSentenceBreakExtend},
+    {0x093D, 0x093D, kSentenceBreakOLetter},
+    {0x093E, 0x094F, kSentenceBreakExtend},
+    {0x0950, 0x0950, kSentenceBreakOLetter},
+    {0x0951, 0x0957, kSentenceBreakExtend},
+    {0x0958, 0x0961, kSentenceBreakOLetter},
+    {0x0962, 0x0963, kSentenceBreakExtend},
+    {0x0964, 0x0965, kSentenceBreakSTerm},
+    {0x0966, 0x096F, kSentenceBreakNumeric},
+    {0x0971, 0x0980, kSentenceBreakOLetter},
+    {0x0981, 0x0983, kSentenceBreakExtend},
+    {0x0985, 0x098C, kSentenceBreakOLetter},
+    {0x098F, 0x0990, kSentenceBreakOLetter},
+    {0x0993, 0x09A8, kSentenceBreakOLetter},
+    {0x09AA, 0x09B0, kSentenceBreakOLetter},
+    {0x09B2, 0x09B2, kSentenceBreakOLetter},
+    {0x09B6, 0x09B9, kSentenceBreakOLetter},
+    {0x09BC, 0x09BC, kSentenceBreakExtend},
+    {0x09BD, 0x09BD, kSentenceBreakOLetter},
+    {0x09BE, 0x09C4, kSentenceBreakExtend},
+    {0x09C7, 0x09C8, kSentenceBreakExtend},
+    {0x09CB, 0x09CD, kSentenceBreakExtend},
+    {0x09CE, 0x09CE, kSentenceBreakOLetter},
+    {0x09D7, 0x09D7, kSentenceBreakExtend},
+    {0x09DC, 0x09DD, kSentenceBreakOLetter},
+    {0x09DF, 0x09E1, kSentenceBreakOLetter},
+    {0x09E2, 0x09E3, kSentenceBreakExtend},
+    {0x09E6, 0x09EF, kSentenceBreakNumeric},
+    {0x09F0, 0x09F1, kSentenceBreakOLetter},
+    {0x09FC, 0x09FC, kSentenceBreakOLetter},
+    {0x09FE, 0x09FE, kSentenceBreakExtend},
+    {0x0A01, 0x0A03, kSentenceBreakExtend},
+    {0x0A05, 0x0A0A, kSentenceBreakOLetter},
+    {0x0A0F, 0x0A10, kSentenceBreakOLetter},
+    {0x0A13, 0x0A28, kSentenceBreakOLetter},
+    {0x0A2A, 0x0A30, kSentenceBreakOLetter},
+    {0x0A32, 0x0A33, kSentenceBreakOLetter},
+    {0x0A35, 0x0A36, kSentenceBreakOLetter},
+    {0x0A38, 0x0A39, kSentenceBreakOLetter},
+    {0x0A3C, 0x0A3C, kSentenceBreakExtend},
+    {0x0A3E, 0x0A42, kSentenceBreakExtend},
+    {0x0A47, 0x0A48, kSentenceBreakExtend},
+    {0x0A4B, 0x0A4D, kSentenceBreakExtend},
+    {0x0A51, 0x0A51, kSentenceBreakExtend},
+    {0x0A59, 0x0A5C, kSentenceBreakOLetter},
+    {0x0A5E, 0x0A5E, kSentenceBreakOLetter},
+    {0x0A66, 0x0A6F, kSentenceBreakNumeric},
+    {0x0A70, 0x0A71, kSentenceBreakExtend},
+    {0x0A72, 0x0A74, kSentenceBreakOLetter},
+    {0x0A75, 0x0A75, kSentenceBreakExtend},
+    {0x0A81, 0x0A83, kSentenceBreakExtend},
+    {0x0A85, 0x0A8D, kSentenceBreakOLetter},
+    {0x0A8F, 0x0A91, kSentenceBreakOLetter},
+    {0x0A93, 0x0AA8, kSentenceBreakOLetter},
+    {0x0AAA, 0x0AB0, kSentenceBreakOLetter},
+    {0x0AB2, 0x0AB3, kSentenceBreakOLetter},
+    {0x0AB5, 0x0AB9, kSentenceBreakOLetter},
+    {0x0ABC, 0x0ABC, kSentenceBreakExtend},
+    {0x0ABD, 0x0ABD, kSentenceBreakOLetter},
+    {0x0ABE, 0x0AC5, kSentenceBreakExtend},
+    {0x0AC7, 0x0AC9, kSentenceBreakExtend},
+    {0x0ACB, 0x0ACD, kSentenceBreakExtend},
+    {0x0AD0, 0x0AD0, kSentenceBreakOLetter},
+    {0x0AE0, 0x0AE1, kSentenceBreakOLetter},
+    {0x0AE2, 0x0AE3, kSentenceBreakExtend},
+    {0x0AE6, 0x0AEF, kSentenceBreakNumeric},
+    {0x0AF9, 0x0AF9, kSentenceBreakOLetter},
+    {0x0AFA, 0x0AFF, kSentenceBreakExtend},
+    {0x0B01, 0x0B03, kSentenceBreakExtend},
+    {0x0B05, 0x0B0C, kSentenceBreakOLetter},
+    {0x0B0F, 0x0B10, kSentenceBreakOLetter},
+    {0x0B13, 0x0B28, kSentenceBreakOLetter},
+    {0x0B2A, 0x0B30, kSentenceBreakOLetter},
+    {0x0B32, 0x0B33, kSentenceBreakOLetter},
+    {0x0B35, 0x0B39, kSentenceBreakOLetter},
+    {0x0B3C, 0x0B3C, kSentenceBreakExtend},
+    {0x0B3D, 0x0B3D, kSentenceBreakOLetter},
+    {0x0B3E, 0x0B44, kSentenceBreakExtend},
+    {0x0B47, 0x0B48, kSentenceBreakExtend},
+    {0x0B4B, 0x0B4D, kSentenceBreakExtend},
+    {0x0B55, 0x0B57, kSentenceBreakExtend},
+    {0x0B5C, 0x0B5D, kSentenceBreakOLetter},
+    {0x0B5F, 0x0B61, kSentenceBreakOLetter},
+    {0x0B62, 0x0B63, kSentenceBreakExtend},
+    {0x0B66, 0x0B6F, kSentenceBreakNumeric},
+    {0x0B71, 0x0B71, kSentenceBreakOLetter},
+    {0x0B82, 0x0B82, kSentenceBreakExtend},
+    {0x0B83, 0x0B83, kSentenceBreakOLetter},
+    {0x0B85, 0x0B8A, kSentenceBreakOLetter},
+    {0x0B8E, 0x0B90, kSentenceBreakOLetter},
+    {0x0B92, 0x0B95, kSentenceBreakOLetter},
+    {0x0B99, 0x0B9A, kSentenceBreakOLetter},
+    {0x0B9C, 0x0B9C, kSentenceBreakOLetter},
+    {0x0B9E, 0x0B9F, kSentenceBreakOLetter},
+    {0x0BA3, 0x0BA4, kSentenceBreakOLetter},
+    {0x0BA8, 0x0BAA, kSentenceBreakOLetter},
+    {0x0BAE, 0x0BB9, kSentenceBreakOLetter},
+    {0x0BBE, 0x0BC2, kSentenceBreakExtend},
+    {0x0BC6, 0x0BC8, kSentenceBreakExtend},
+    {0x0BCA, 0x0BCD, kSentenceBreakExtend},
+    {0x0BD0, 0x0BD0, kSentenceBreakOLetter},
+    {0x0BD7, 0x0BD7, kSentenceBreakExtend},
+    {0x0BE6, 0x0BEF, kSentenceBreakNumeric},
+    {0x0C00, 0x0C04, kSentenceBreakExtend},
+    {0x0C05, 0x0C0C, kSentenceBreakOLetter},
+    {0x0C0E, 0x0C10, kSentenceBreakOLetter},
+    {0x0C12, 0x0C28, kSentenceBreakOLetter},
+    {0x0C2A, 0x0C39, kSentenceBreakOLetter},
+    {0x0C3C, 0x0C3C, kSentenceBreakExtend},
+    {0x0C3D, 0x0C3D, kSentenceBreakOLetter},
+    {0x0C3E, 0x0C44, kSentenceBreakExtend},
+    {0x0C46, 0x0C48, kSentenceBreakExtend},
+    {0x0C4A, 0x0C4D, kSentenceBreakExtend},
+    {0x0C55, 0x0C56, kSentenceBreakExtend},
+    {0x0C58, 0x0C5A, kSentenceBreakOLetter},
+    {0x0C5D, 0x0C5D, kSentenceBreakOLetter},
+    {0x0C60, 0x0C61, kSentenceBreakOLetter},
+    {0x0C62, 0x0C63, kSentenceBreakExtend},
+    {0x0C66, 0x0C6F, kSentenceBreakNumeric},
+    {0x0C80, 0x0C80, kSentenceBreakOLetter},
+    {0x0C81, 0x0C83, kSentenceBreakExtend},
+    {0x0C85, 0x0C8C, kSentenceBreakOLetter},
+    {0x0C8E, 0x0C90, kSentenceBreakOLetter},
+    {0x0C92, 0x0CA8, kSentenceBreakOLetter},
+    {0x0CAA, 0x0CB3, kSentenceBreakOLetter},
+    {0x0CB5, 0x0CB9, kSentenceBreakOLetter},
+    {0x0CBC, 0x0CBC, kSentenceBreakExtend},
+    {0x0CBD, 0x0CBD, kSentenceBreakOLetter},
+    {0x0CBE, 0x0CC4, kSentenceBreakExtend},
+    {0x0CC6, 0x0CC8, kSentenceBreakExtend},
+    {0x0CCA, 0x0CCD, kSentenceBreakExtend},
+    {0x0CD5, 0x0CD6, kSentenceBreakExtend},
+    {0x0CDD, 0x0CDE, kSentenceBreakOLetter},
+    {0x0CE0, 0x0CE1, kSentenceBreakOLetter},
+    {0x0CE2, 0x0CE3, kSentenceBreakExtend},
+    {0x0CE6, 0x0CEF, kSentenceBreakNumeric},
+    {0x0CF1, 0x0CF2, kSentenceBreakOLetter},
+    {0x0CF3, 0x0CF3, kSentenceBreakExtend},
+    {0x0D00, 0x0D03, kSentenceBreakExtend},
+    {0x0D04, 0x0D0C, kSentenceBreakOLetter},
+    {0x0D0E, 0x0D10, kSentenceBreakOLetter},
+    {0x0D12, 0x0D3A, kSentenceBreakOLetter},
+    {0x0D3B, 0x0D3C, kSentenceBreakExtend},
+    {0x0D3D, 0x0D3D, kSentenceBreakOLetter},
+    {0x0D3E, 0x0D44, kSentenceBreakExtend},
+    {0x0D46, 0x0D48, kSentenceBreakExtend},
+    {0x0D4A, 0x0D4D, kSentenceBreakExtend},
+    {0x0D4E, 0x0D4E, kSentenceBreakOLetter},
+    {0x0D54, 0x0D56, kSentenceBreakOLetter},
+    {0x0D57, 0x0D57, kSentenceBreakExtend},
+    {0x0D5F, 0x0D61, kSentenceBreakOLetter},
+    {0x0D62, 0x0D63, kSentenceBreakExtend},
+    {0x0D66, 0x0D6F, kSentenceBreakNumeric},
+    {0x0D7A, 0x0D7F, kSentenceBreakOLetter},
+    {0x0D81, 0x0D83, kSentenceBreakExtend},
+    {0x0D85, 0x0D96, kSentenceBreakOLetter},
+    {0x0D9A, 0x0DB1, kSentenceBreakOLetter},
+    {0x0DB3, 0x0DBB, kSentenceBreakOLetter},
+    {0x0DBD, 0x0DBD, kSentenceBreakOLetter},
+    {0x0DC0, 0x0DC6, kSentenceBreakOLetter},
+    {0x0DCA, 0x0DCA, kSentenceBreakExtend},
+    {0x0DCF, 0x0DD4, kSentenceBreakExtend},
+    {0x0DD6, 0x0DD6, kSentenceBreakExtend},
+    {0x0DD8, 0x0DDF, kSentenceBreakExtend},
+    {0x0DE6, 0x0DEF, kSentenceBreakNumeric},
+    {0x0DF2, 0x0DF3, kSentenceBreakExtend},
+    {0x0E01, 0x0E30, kSentenceBreakOLetter},
+    {0x0E31, 0x0E31, kSentenceBreakExtend},
+    {0x0E32, 0x0E33, kSentenceBreakOLetter},
+    {0x0E34, 0x0E3A, kSentenceBreakExtend},
+    {0x0E40, 0x0E46, kSentenceBreakOLetter},
+    {0x0E47, 0x0E4E, kSentenceBreakExtend},
+    {0x0E50, 0x0E59, kSentenceBreakNumeric},
+    {0x0E81, 0x0E82, kSentenceBreakOLetter},
+    {0x0E84, 0x0E84, kSentenceBreakOLetter},
+    {0x0E86, 0x0E8A, kSentenceBreakOLetter},
+    {0x0E8C, 0x0EA3, kSentenceBreakOLetter},
+    {0x0EA5, 0x0EA5, kSentenceBreakOLetter},
+    {0x0EA7, 0x0EB0, kSentenceBreakOLetter},
+    {0x0EB1, 0x0EB1, kSentenceBreakExtend},
+    {0x0EB2, 0x0EB3, kSentenceBreakOLetter},
+    {0x0EB4, 0x0EBC, kSentenceBreakExtend},
+    {0x0EBD, 0x0EBD, kSentenceBreakOLetter},
+    {0x0EC0, 0x0EC4, kSentenceBreakOLetter},
+    {0x0EC6, 0x0EC6, kSentenceBreakOLetter},
+    {0x0EC8, 0x0ECE, kSentenceBreakExtend},
+    {0x0ED0, 0x0ED9, kSentenceBreakNumeric},
+    {0x0EDC, 0x0EDF, kSentenceBreakOLetter},
+    {0x0F00, 0x0F00, kSentenceBreakOLetter},
+    {0x0F18, 0x0F19, kSentenceBreakExtend},
+    {0x0F20, 0x0F29, kSentenceBreakNumeric},
+    {0x0F35, 0x0F35, kSentenceBreakExtend},
+    {0x0F37, 0x0F37, kSentenceBreakExtend},
+    {0x0F39, 0x0F39, kSentenceBreakExtend},
+    {0x0F3A, 0x0F3D, kSentenceBreakClose},
+    {0x0F3E, 0x0F3F, kSentenceBreakExtend},
+    {0x0F40, 0x0F47, kSentenceBreakOLetter},
+    {0x0F49, 0x0F6C, kSentenceBreakOLetter},
+    {0x0F71, 0x0F84, kSentenceBreakExtend},
+    {0x0F86, 0x0F87, kSentenceBreakExtend},
+    {0x0F88, 0x0F8C, kSentenceBreakOLetter},
+    {0x0F8D, 0x0F97, kSentenceBreakExtend},
+    {0x0F99, 0x0FBC, kSentenceBreakExtend},
+    {0x0FC6, 0x0FC6, kSentenceBreakExtend},
+    {0x1000, 0x102A, kSentenceBreakOLetter},
+    {0x102B, 0x103E, kSentenceBreakExtend},
+    {0x103F, 0x103F, kSentenceBreakOLetter},
+    {0x1040, 0x1049, kSentenceBreakNumeric},
+    {0x104A, 0x104B, kSentenceBreakSTerm},
+    {0x1050, 0x1055, kSentenceBreakOLetter},
+    {0x1056, 0x1059, kSentenceBreakExtend},
+    {0x105A, 0x105D, kSentenceBreakOLetter},
+    {0x105E, 0x1060, kSentenceBreakExtend},
+    {0x1061, 0x1061, kSentenceBreakOLetter},
+    {0x1062, 0x1064, kSentenceBreakExtend},
+    {0x1065, 0x1066, kSentenceBreakOLetter},
+    {0x1067, 0x106D, kSentenceBreakExtend},
+    {0x106E, 0x1070, kSentenceBreakOLetter},
+    {0x1071, 0x1074, kSentenceBreakExtend},
+    {0x1075, 0x1081, kSentenceBreakOLetter},
+    {0x1082, 0x108D, kSentenceBreakExtend},
+    {0x108E, 0x108E, kSentenceBreakOLetter},
+    {0x108F, 0x108F, kSentenceBreakExtend},
+    {0x1090, 0x1099, kSentenceBreakNumeric},
+    {0x109A, 0x109D, kSentenceBreakExtend},
+    {0x10A0, 0x10C5, kSentenceBreakUpper},
+    {0x10C7, 0x10C7, kSentenceBreakUpper},
+    {0x10CD, 0x10CD, kSentenceBreakUpper},
+    {0x10D0, 0x10FA, kSentenceBreakOLetter},
+    {0x10FC, 0x10FC, kSentenceBreakLower},
+    {0x10FD, 0x1248, kSentenceBreakOLetter},
+    {0x124A, 0x124D, kSentenceBreakOLetter},
+    {0x1250, 0x1256, kSentenceBreakOLetter},
+    {0x1258, 0x1258, kSentenceBreakOLetter},
+    {0x125A, 0x125D, kSentenceBreakOLetter},
+    {0x1260, 0x1288, kSentenceBreakOLetter},
+    {0x128A, 0x128D, kSentenceBreakOLetter},
+    {0x1290, 0x12B0, kSentenceBreakOLetter},
+    {0x12B2, 0x12B5, kSentenceBreakOLetter},
+    {0x12B8, 0x12BE, kSentenceBreakOLetter},
+    {0x12C0, 0x12C0, kSentenceBreakOLetter},
+    {0x12C2, 0x12C5, kSentenceBreakOLetter},
+    {0x12C8, 0x12D6, kSentenceBreakOLetter},
+    {0x12D8, 0x1310, kSentenceBreakOLetter},
+    {0x1312, 0x1315, kSentenceBreakOLetter},
+    {0x1318, 0x135A, kSentenceBreakOLetter},
+    {0x135D, 0x135F, kSentenceBreakExtend},
+    {0x1362, 0x1362, kSentenceBreakSTerm},
+    {0x1367, 0x1368, kSentenceBreakSTerm},
+    {0x1380, 0x138F, kSentenceBreakOLetter},
+    {0x13A0, 0x13F5, kSentenceBreakUpper},
+    {0x13F8, 0x13FD, kSentenceBreakLower},
+    {0x1401, 0x166C, kSentenceBreakOLetter},
+    {0x166E, 0x166E, kSentenceBreakSTerm},
+    {0x166F, 0x167F, kSentenceBreakOLetter},
+    {0x1680, 0x1680, kSentenceBreakSp},
+    {0x1681, 0x169A, kSentenceBreakOLetter},
+    {0x169B, 0x169C, kSentenceBreakClose},
+    {0x16A0, 0x16EA, kSentenceBreakOLetter},
+    {0x16EE, 0x16F8, kSentenceBreakOLetter},
+    {0x1700, 0x1711, kSentenceBreakOLetter},
+    {0x1712, 0x1715, kSentenceBreakExtend},
+    {0x171F, 0x1731, kSentenceBreakOLetter},
+    {0x1732, 0x1734, kSentenceBreakExtend},
+    {0x1735, 0x1736, kSentenceBreakSTerm},
+    {0x1740, 0x1751, kSentenceBreakOLetter},
+    {0x1752, 0x1753, kSentenceBreakExtend},
+    {0x1760, 0x176C, kSentenceBreakOLetter},
+    {0x176E, 0x1770, kSentenceBreakOLetter},
+    {0x1772, 0x1773, kSentenceBreakExtend},
+    {0x1780, 0x17B3, kSentenceBreakOLetter},
+    {0x17B4, 0x17D3, kSentenceBreakExtend},
+    {0x17D7, 0x17D7, kSentenceBreakOLetter},
+    {0x17DC, 0x17DC, kSentenceBreakOLetter},
+    {0x17DD, 0x17DD, kSentenceBreakExtend},
+    {0x17E0, 0x17E9, kSentenceBreakNumeric},
+    {0x1802, 0x1802, kSentenceBreakSContinue},
+    {0x1803, 0x1803, kSentenceBreakSTerm},
+    {0x1808, 0x1808, kSentenceBreakSContinue},
+    {0x1809, 0x1809, kSentenceBreakSTerm},
+    {0x180B, 0x180D, kSentenceBreakExtend},
+    {0x180E, 0x180E, kSentenceBreakFormat},
+    {0x180F, 0x180F, kSentenceBreakExtend},
+    {0x1810, 0x1819, kSentenceBreakNumeric},
+    {0x1820, 0x1878, kSentenceBreakOLetter},
+    {0x1880, 0x1884, kSentenceBreakOLetter},
+    {0x1885, 0x1886, kSentenceBreakExtend},
+    {0x1887, 0x18A8, kSentenceBreakOLetter},
+    {0x18A9, 0x18A9, kSentenceBreakExtend},
+    {0x18AA, 0x18AA, kSentenceBreakOLetter},
+    {0x18B0, 0x18F5, kSentenceBreakOLetter},
+    {0x1900, 0x191E, kSentenceBreakOLetter},
+    {0x1920, 0x192B, kSentenceBreakExtend},
+    {0x1930, 0x193B, kSentenceBreakExtend},
+    {0x1944, 0x1945, kSentenceBreakSTerm},
+    {0x1946, 0x194F, kSentenceBreakNumeric},
+    {0x1950, 0x196D, kSentenceBreakOLetter},
+    {0x1970, 0x1974, kSentenceBreakOLetter},
+    {0x1980, 0x19AB, kSentenceBreakOLetter},
+    {0x19B0, 0x19C9, kSentenceBreakOLetter},
+    {0x19D0, 0x19D9, kSentenceBreakNumeric},
+    {0x1A00, 0x1A16, kSentenceBreakOLetter},
+    {0x1A17, 0x1A1B, kSentenceBreakExtend},
+    {0x1A20, 0x1A54, kSentenceBreakOLetter},
+    {0x1A55, 0x1A5E, kSentenceBreakExtend},
+    {0x1A60, 0x1A7C, kSentenceBreakExtend},
+    {0x1A7F, 0x1A7F, kSentenceBreakExtend},
+    {0x1A80, 0x1A89, kSentenceBreakNumeric},
+    {0x1A90, 0x1A99, kSentenceBreakNumeric},
+    {0x1AA7, 0x1AA7, kSentenceBreakOLetter},
+    {0x1AA8, 0x1AAB, kSentenceBreakSTerm},
+    {0x1AB0, 0x1ACE, kSentenceBreakExtend},
+    {0x1B00, 0x1B04, kSentenceBreakExtend},
+    {0x1B05, 0x1B33, kSentenceBreakOLetter},
+    {0x1B34, 0x1B44, kSentenceBreakExtend},
+    {0x1B45, 0x1B4C, kSentenceBreakOLetter},
+    {0x1B50, 0x1B59, kSentenceBreakNumeric},
+    {0x1B5A, 0x1B5B, kSentenceBreakSTerm},
+    {0x1B5E, 0x1B5F, kSentenceBreakSTerm},
+    {0x1B6B, 0x1B73, kSentenceBreakExtend},
+    {0x1B7D, 0x1B7E, kSentenceBreakSTerm},
+    {0x1B80, 0x1B82, kSentenceBreakExtend},
+    {0x1B83, 0x1BA0, kSentenceBreakOLetter},
+    {0x1BA1, 0x1BAD, kSentenceBreakExtend},
+    {0x1BAE, 0x1BAF, kSentenceBreakOLetter},
+    {0x1BB0, 0x1BB9, kSentenceBreakNumeric},
+    {0x1BBA, 0x1BE5, kSentenceBreakOLetter},
+    {0x1BE6, 0x1BF3, kSentenceBreakExtend},
+    {0x1C00, 0x1C23, kSentenceBreakOLetter},
+    {0x1C24, 0x1C37, kSentenceBreakExtend},
+    {0x1C3B, 0x1C3C, kSentenceBreakSTerm},
+    {0x1C40, 0x1C49, kSentenceBreakNumeric},
+    {0x1C4D, 0x1C4F, kSentenceBreakOLetter},
+    {0x1C50, 0x1C59, kSentenceBreakNumeric},
+    {0x1C5A, 0x1C7D, kSentenceBreakOLetter},
+    {0x1C7E, 0x1C7F, kSentenceBreakSTerm},
+    {0x1C80, 0x1C88, kSentenceBreakLower},
+    {0x1C90, 0x1CBA, kSentenceBreakOLetter},
+    {0x1CBD, 0x1CBF, kSentenceBreakOLetter},
+    {0x1CD0, 0x1CD2, kSentenceBreakExtend},
+    {0x1CD4, 0x1CE8, kSentenceBreakExtend},
+    {0x1CE9, 0x1CEC, kSentenceBreakOLetter},
+    {0x1CED, 0x1CED, kSentenceBreakExtend},
+    {0x1CEE, 0x1CF3, kSentenceBreakOLetter},
+    {0x1CF4, 0x1CF4, kSentenceBreakExtend},
+    {0x1CF5, 0x1CF6, kSentenceBreakOLetter},
+    {0x1CF7, 0x1CF9, kSentenceBreakExtend},
+    {0x1CFA, 0x1CFA, kSentenceBreakOLetter},
+    {0x1D00, 0x1DBF, kSentenceBreakLower},
+    {0x1DC0, 0x1DFF, kSentenceBreakExtend},
+    {0x1E00, 0x1E00, kSentenceBreakUpper},
+    {0x1E01, 0x1E01, kSentenceBreakLower},
+    {0x1E02, 0x1E02, kSentenceBreakUpper},
+    {0x1E03, 0x1E03, kSentenceBreakLower},
+    {0x1E04, 0x1E04, kSentenceBreakUpper},
+    {0x1E05, 0x1E05, kSentenceBreakLower},
+    {0x1E06, 0x1E06, kSentenceBreakUpper},
+    {0x1E07, 0x1E07, kSentenceBreakLower},
+    {0x1E08, 0x1E08, kSentenceBreakUpper},
+    {0x1E09, 0x1E09, kSentenceBreakLower},
+    {0x1E0A, 0x1E0A, kSentenceBreakUpper},
+    {0x1E0B, 0x1E0B, kSentenceBreakLower},
+    {0x1E0C, 0x1E0C, kSentenceBreakUpper},
+    {0x1E0D, 0x1E0D, kSentenceBreakLower},
+    {0x1E0E, 0x1E0E, kSentenceBreakUpper},
+    {0x1E0F, 0x1E0F, kSentenceBreakLower},
+    {0x1E10, 0x1E10, kSentenceBreakUpper},
+    {0x1E11, 0x1E11, kSentenceBreakLower},
+    {0x1E12, 0x1E12, kSentenceBreakUpper},
+    {0x1E13, 0x1E13, kSentenceBreakLower},
+    {0x1E14, 0x1E14, kSentenceBreakUpper},
+    {0x1E15, 0x1E15, kSentenceBreakLower},
+    {0x1E16, 0x1E16, kSentenceBreakUpper},
+    {0x1E17, 0x1E17, kSentenceBreakLower},
+    {0x1E18, 0x1E18, kSentenceBreakUpper},
+    {0x1E19, 0x1E19, kSentenceBreakLower},
+    {0x1E1A, 0x1E1A, kSentenceBreakUpper},
+    {0x1E1B, 0x1E1B, kSentenceBreakLower},
+    {0x1E1C, 0x1E1C, kSentenceBreakUpper},
+    {0x1E1D, 0x1E1D, kSentenceBreakLower},
+    {0x1E1E, 0x1E1E, kSentenceBreakUpper},
+    {0x1E1F, 0x1E1F, kSentenceBreakLower},
+    {0x1E20, 0x1E20, kSentenceBreakUpper},
+    {0x1E21, 0x1E21, kSentenceBreakLower},
+    {0x1E22, 0x1E22, kSentenceBreakUpper},
+    {0x1E23, 0x1E23, kSentenceBreakLower},
+    {0x1E24, 0x1E24, kSentenceBreakUpper},
+    {0x1E25, 0x1E25, kSentenceBreakLower},
+    {0x1E26, 0x1E26, kSentenceBreakUpper},
+    {0x1E27, 0x1E27, kSentenceBreakLower},
+    {0x1E28, 0x1E28, kSentenceBreakUpper},
+    {0x1E29, 0x1E29, kSentenceBreakLower},
+    {0x1E2A, 0x1E2A, kSentenceBreakUpper},
+    {0x1E2B, 0x1E2B, kSentenceBreakLower},
+    {0x1E2C, 0x1E2C, kSentenceBreakUpper},
+    {0x1E2D, 0x1E2D, kSentenceBreakLower},
+    {0x1E2E, 0x1E2E, kSentenceBreakUpper},
+    {0x1E2F, 0x1E2F, kSentenceBreakLower},
+    {0x1E30, 0x1E30, kSentenceBreakUpper},
+    {0x1E31, 0x1E31, kSentenceBreakLower},
+    {0x1E32, 0x1E32, kSentenceBreakUpper},
+    {0x1E33, 0x1E33, kSentenceBreakLower},
+    {0x1E34, 0x1E34, kSentenceBreakUpper},
+    {0x1E35, 0x1E35, kSentenceBreakLower},
+    {0x1E36, 0x1E36, kSentenceBreakUpper},
+    {0x1E37, 0x1E37, kSentenceBreakLower},
+    {0x1E38, 0x1E38, kSentenceBreakUpper},
+    {0x1E39, 0x1E39, kSentenceBreakLower},
+    {0x1E3A, 0x1E3A, kSentenceBreakUpper},
+    {0x1E3B, 0x1E3B, kSentenceBreakLower},
+    {0x1E3C, 0x1E3C, kSentenceBreakUpper},
+    {0x1E3D, 0x1E3D, kSentenceBreakLower},
+    {0x1E3E, 0x1E3E, kSentenceBreakUpper},
+    {0x1E3F, 0x1E3F, kSentenceBreakLower},
+    {0x1E40, 0x1E40, kSentenceBreakUpper},
+    {0x1E41, 0x1E41, kSentenceBreakLower},
+    {0x1E42, 0x1E42, kSentenceBreakUpper},
+    {0x1E43, 0x1E43, kSentenceBreakLower},
+    {0x1E44, 0x1E44, kSentenceBreakUpper},
+    {0x1E45, 0x1E45, kSentenceBreakLower},
+    {0x1E46, 0x1E46, kSentenceBreakUpper},
+    {0x1E47, 0x1E47, kSentenceBreakLower},
+    {0x1E48, 0x1E48, kSentenceBreakUpper},
+    {0x1E49, 0x1E49, kSentenceBreakLower},
+    {0x1E4A, 0x1E4A, kSentenceBreakUpper},
+    {0x1E4B, 0x1E4B, kSentenceBreakLower},
+    {0x1E4C, 0x1E4C, kSentenceBreakUpper},
+    {0x1E4D, 0x1E4D, kSentenceBreakLower},
+    {0x1E4E, 0x1E4E, kSentenceBreakUpper},
+    {0x1E4F, 0x1E4F, kSentenceBreakLower},
+    {0x1E50, 0x1E50, kSentenceBreakUpper},
+    {0x1E51, 0x1E51, kSentenceBreakLower},
+    {0x1E52, 0x1E52, kSentenceBreakUpper},
+    {0x1E53, 0x1E53, kSentenceBreakLower},
+    {0x1E54, 0x1E54, kSentenceBreakUpper},
+    {0x1E55, 0x1E55, kSentenceBreakLower},
+    {0x1E56, 0x1E56, kSentenceBreakUpper},
+    {0x1E57, 0x1E57, kSentenceBreakLower},
+    {0x1E58, 0x1E58, kSentenceBreakUpper},
+    {0x1E59, 0x1E59, kSentenceBreakLower},
+    {0x1E5A, 0x1E5A, kSentenceBreakUpper},
+    {0x1E5B, 0x1E5B, kSentenceBreakLower},
+    {0x1E5C, 0x1E5C, kSentenceBreakUpper},
+    {0x1E5D, 0x1E5D, kSentenceBreakLower},
+    {0x1E5E, 0x1E5E, kSentenceBreakUpper},
+    {0x1E5F, 0x1E5F, kSentenceBreakLower},
+    {0x1E60, 0x1E60, kSentenceBreakUpper},
+    {0x1E61, 0x1E61, kSentenceBreakLower},
+    {0x1E62, 0x1E62, kSentenceBreakUpper},
+    {0x1E63, 0x1E63, kSentenceBreakLower},
+    {0x1E64, 0x1E64, kSentenceBreakUpper},
+    {0x1E65, 0x1E65, kSentenceBreakLower},
+    {0x1E66, 0x1E66, kSentenceBreakUpper},
+    {0x1E67, 0x1E67, kSentenceBreakLower},
+    {0x1E68, 0x1E68, kSentenceBreakUpper},
+    {0x1E69, 0x1E69, kSentenceBreakLower},
+    {0x1E6A, 0x1E6A, kSentenceBreakUpper},
+    {0x1E6B, 0x1E6B, kSentenceBreakLower},
+    {0x1E6C, 0x1E6C, kSentenceBreakUpper},
+    {0x1E6D, 0x1E6D, kSentenceBreakLower},
+    {0x1E6E, 0x1E6E, kSentenceBreakUpper},
+    {0x1E6F, 0x1E6F, kSentenceBreakLower},
+    {0x1E70, 0x1E70, kSentenceBreakUpper},
+    {0x1E71, 0x1E71, kSentenceBreakLower},
+    {0x1E72, 0x1E72, kSentenceBreakUpper},
+    {0x1E73, 0x1E73, kSentenceBreakLower},
+    {0x1E74, 0x1E74, kSentenceBreakUpper},
+    {0x1E75, 0x1E75, kSentenceBreakLower},
+    {0x1E76, 0x1E76, kSentenceBreakUpper},
+    {0x1E77, 0x1E77, kSentenceBreakLower},
+    {0x1E78, 0x1E78, kSentenceBreakUpper},
+    {0x1E79, 0x1E79, kSentenceBreakLower},
+    {0x1E7A, 0x1E7A, kSentenceBreakUpper},
+    {0x1E7B, 0x1E7B, kSentenceBreakLower},
+    {0x1E7C, 0x1E7C, kSentenceBreakUpper},
+    {0x1E7D, 0x1E7D, kSentenceBreakLower},
+    {0x1E7E, 0x1E7E, kSentenceBreakUpper},
+    {0x1E7F, 0x1E7F, kSentenceBreakLower},
+    {0x1E80, 0x1E80, kSentenceBreakUpper},
+    {0x1E81, 0x1E81, kSentenceBreakLower},
+    {0x1E82, 0x1E82, kSentenceBreakUpper},
+    {0x1E83, 0x1E83, kSentenceBreakLower},
+    {0x1E84, 0x1E84, kSentenceBreakUpper},
+    {0x1E85, 0x1E85, kSentenceBreakLower},
+    {0x1E86, 0x1E86, kSentenceBreakUpper},
+    {0x1E87, 0x1E87, kSentenceBreakLower},
+    {0x1E88, 0x1E88, kSentenceBreakUpper},
+    {0x1E89, 0x1E89, kSentenceBreakLower},
+    {0x1E8A, 0x1E8A, kSentenceBreakUpper},
+    {0x1E8B, 0x1E8B, kSentenceBreakLower},
+    {0x1E8C, 0x1E8C, kSentenceBreakUpper},
+    {0x1E8D, 0x1E8D, kSentenceBreakLower},
+    {0x1E8E, 0x1E8E, kSentenceBreakUpper},
+    {0x1E8F, 0x1E8F, kSentenceBreakLower},
+    {0x1E90, 0x1E90, kSentenceBreakUpper},
+    {0x1E91, 0x1E91, kSentenceBreakLower},
+    {0x1E92, 0x1E92, kSentenceBreakUpper},
+    {0x1E93, 0x1E93, kSentenceBreakLower},
+    {0x1E94, 0x1E94, kSentenceBreakUpper},
+    {0x1E95, 0x1E9D, kSentenceBreakLower},
+    {0x1E9E, 0x1E9E, kSentenceBreakUpper},
+    {0x1E9F, 0x1E9F, kSentenceBreakLower},
+    {0x1EA0, 0x1EA0, kSentenceBreakUpper},
+    {0x1EA1, 0x1EA1, kSentenceBreakLower},
+    {0x1EA2, 0x1EA2, kSentenceBreakUpper},
+    {0x1EA3, 0x1EA3, kSentenceBreakLower},
+    {0x1EA4, 0x1EA4, kSentenceBreakUpper},
+    {0x1EA5, 0x1EA5, kSentenceBreakLower},
+    {0x1EA6, 0x1EA6, kSentenceBreakUpper},
+    {0x1EA7, 0x1EA7, kSentenceBreakLower},
+    {0x1EA8, 0x1EA8, kSentenceBreakUpper},
+    {0x1EA9, 0x1EA9, kSentenceBreakLower},
+    {0x1EAA, 0x1EAA, kSentenceBreakUpper},
+    {0x1EAB, 0x1EAB, kSentenceBreakLower},
+    {0x1EAC, 0x1EAC, kSentenceBreakUpper},
+    {0x1EAD, 0x1EAD, kSentenceBreakLower},
+    {0x1EAE, 0x1EAE, kSentenceBreakUpper},
+    {0x1EAF, 0x1EAF, kSentenceBreakLower},
+    {0x1EB0, 0x1EB0, kSentenceBreakUpper},
+    {0x1EB1, 0x1EB1, kSentenceBreakLower},
+    {0x1EB2, 0x1EB2, kSentenceBreakUpper},
+    {0x1EB3, 0x1EB3, kSentenceBreakLower},
+    {0x1EB4, 0x1EB4, kSentenceBreakUpper},
+    {0x1EB5, 0x1EB5, kSentenceBreakLower},
+    {0x1EB6, 0x1EB6, kSentenceBreakUpper},
+    {0x1EB7, 0x1EB7, kSentenceBreakLower},
+    {0x1EB8, 0x1EB8, kSentenceBreakUpper},
+    {0x1EB9, 0x1EB9, kSentenceBreakLower},
+    {0x1EBA, 0x1EBA, kSentenceBreakUpper},
+    {0x1EBB, 0x1EBB, kSentenceBreakLower},
+    {0x1EBC, 0x1EBC, kSentenceBreakUpper},
+    {0x1EBD, 0x1EBD, kSentenceBreakLower},
+    {0x1EBE, 0x1EBE, kSentenceBreakUpper},
+    {0x1EBF, 0x1EBF, kSentenceBreakLower},
+    {0x1EC0, 0x1EC0, kSentenceBreakUpper},
+    {0x1EC1, 0x1EC1, kSentenceBreakLower},
+    {0x1EC2, 0x1EC2, kSentenceBreakUpper},
+    {0x1EC3, 0x1EC3, kSentenceBreakLower},
+    {0x1EC4, 0x1EC4, kSentenceBreakUpper},
+    {0x1EC5, 0x1EC5, kSentenceBreakLower},
+    {0x1EC6, 0x1EC6, kSentenceBreakUpper},
+    {0x1EC7, 0x1EC7, kSentenceBreakLower},
+    {0x1EC8, 0x1EC8, kSentenceBreakUpper},
+    {0x1EC9, 0x1EC9, kSentenceBreakLower},
+    {0x1ECA, 0x1ECA, kSentenceBreakUpper},
+    {0x1ECB, 0x1ECB, kSentenceBreakLower},
+    {0x1ECC, 0x1ECC, kSentenceBreakUpper},
+    {0x1ECD, 0x1ECD, kSentenceBreakLower},
+    {0x1ECE, 0x1ECE, kSentenceBreakUpper},
+    {0x1ECF, 0x1ECF, kSentenceBreakLower},
+    {0x1ED0, 0x1ED0, kSentenceBreakUpper},
+    {0x1ED1, 0x1ED1, kSentenceBreakLower},
+    {0x1ED2, 0x1ED2, kSentenceBreakUpper},
+    {0x1ED3, 0x1ED3, kSentenceBreakLower},
+    {0x1ED4, 0x1ED4, kSentenceBreakUpper},
+    {0x1ED5, 0x1ED5, kSentenceBreakLower},
+    {0x1ED6, 0x1ED6, kSentenceBreakUpper},
+    {0x1ED7, 0x1ED7, kSentenceBreakLower},
+    {0x1ED8, 0x1ED8, kSentenceBreakUpper},
+    {0x1ED9, 0x1ED9, kSentenceBreakLower},
+    {0x1EDA, 0x1EDA, kSentenceBreakUpper},
+    {0x1EDB, 0x1EDB, kSentenceBreakLower},
+    {0x1EDC, 0x1EDC, kSentenceBreakUpper},
+    {0x1EDD, 0x1EDD, kSentenceBreakLower},
+    {0x1EDE, 0x1EDE, kSentenceBreakUpper},
+    {0x1EDF, 0x1EDF, kSentenceBreakLower},
+    {0x1EE0, 0x1EE0, kSentenceBreakUpper},
+    {0x1EE1, 0x1EE1, kSentenceBreakLower},
+    {0x1EE2, 0x1EE2, kSentenceBreakUpper},
+    {0x1EE3, 0x1EE3, kSentenceBreakLower},
+    {0x1EE4, 0x1EE4, kSentenceBreakUpper},
+    {0x1EE5, 0x1EE5, kSentenceBreakLower},
+    {0x1EE6, 0x1EE6, kSentenceBreakUpper},
+    {0x1EE7, 0x1EE7, kSentenceBreakLower},
+    {0x1EE8, 0x1EE8, kSentenceBreakUpper},
+    {0x1EE9, 0x1EE9, kSentenceBreakLower},
+    {0x1EEA, 0x1EEA, kSentenceBreakUpper},
+    {0x1EEB, 0x1EEB, kSentenceBreakLower},
+    {0x1EEC, 0x1EEC, kSentenceBreakUpper},
+    {0x1EED, 0x1EED, kSentenceBreakLower},
+    {0x1EEE, 0x1EEE, kSentenceBreakUpper},
+    {0x1EEF, 0x1EEF, kSentenceBreakLower},
+    {0x1EF0, 0x1EF0, kSentenceBreakUpper},
+    {0x1EF1, 0x1EF1, kSentenceBreakLower},
+    {0x1EF2, 0x1EF2, kSentenceBreakUpper},
+    {0x1EF3, 0x1EF3, kSentenceBreakLower},
+    {0x1EF4, 0x1EF4, kSentenceBreakUpper},
+    {0x1EF5, 0x1EF5, kSentenceBreakLower},
+    {0x1EF6, 0x1EF6, kSentenceBreakUpper},
+    {0x1EF7, 0x1EF7, kSentenceBreakLower},
+    {0x1EF8, 0x1EF8, kSentenceBreakUpper},
+    {0x1EF9, 0x1EF9, kSentenceBreakLower},
+    {0x1EFA, 0x1EFA, kSentenceBreakUpper},
+    {0x1EFB, 0x1EFB, kSentenceBreakLower},
+    {0x1EFC, 0x1EFC, kSentenceBreakUpper},
+    {0x1EFD, 0x1EFD, kSentenceBreakLower},
+    {0x1EFE, 0x1EFE, kSentenceBreakUpper},
+    {0x1EFF, 0x1F07, kSentenceBreakLower},
+    {0x1F08, 0x1F0F, kSentenceBreakUpper},
+    {0x1F10, 0x1F15, kSentenceBreakLower},
+    {0x1F18, 0x1F1D, kSentenceBreakUpper},
+    {0x1F20, 0x1F27, kSentenceBreakLower},
+    {0x1F28, 0x1F2F, kSentenceBreakUpper},
+    {0x1F30, 0x1F37, kSentenceBreakLower},
+    {0x1F38, 0x1F3F, kSentenceBreakUpper},
+    {0x1F40, 0x1F45, kSentenceBreakLower},
+    {0x1F48, 0x1F4D, kSentenceBreakUpper},
+    {0x1F50, 0x1F57, kSentenceBreakLower},
+    {0x1F59, 0x1F59, kSentenceBreakUpper},
+    {0x1F5B, 0x1F5B, kSentenceBreakUpper},
+    {0x1F5D, 0x1F5D, kSentenceBreakUpper},
+    {0x1F5F, 0x1F5F, kSentenceBreakUpper},
+    {0x1F60, 0x1F67, kSentenceBreakLower},
+    {0x1F68, 0x1F6F, kSentenceBreakUpper},
+    {0x1F70, 0x1F7D, kSentenceBreakLower},
+    {0x1F80, 0x1F87, kSentenceBreakLower},
+    {0x1F88, 0x1F8F, kSentenceBreakUpper},
+    {0x1F90, 0x1F97, kSentenceBreakLower},
+    {0x1F98, 0x1F9F, kSentenceBreakUpper},
+    {0x1FA0, 0x1FA7, kSentenceBreakLower},
+    {0x1FA8, 0x1FAF, kSentenceBreakUpper},
+    {0x1FB0, 0x1FB4, kSentenceBreakLower},
+    {0x1FB6, 0x1FB7, kSentenceBreakLower},
+    {0x1FB8, 0x1FBC, kSentenceBreakUpper},
+    {0x1FBE, 0x1FBE, kSentenceBreakLower},
+    {0x1FC2, 0x1FC4, kSentenceBreakLower},
+    {0x1FC6, 0x1FC7, kSentenceBreakLower},
+    {0x1FC8, 0x1FCC, kSentenceBreakUpper},
+    {0x1FD0, 0x1FD3, kSentenceBreakLower},
+    {0x1FD6, 0x1FD7, kSentenceBreakLower},
+    {0x1FD8, 0x1FDB, kSentenceBreakUpper},
+    {0x1FE0, 0x1FE7, kSentenceBreakLower},
+    {0x1FE8, 0x1FEC, kSentenceBreakUpper},
+    {0x1FF2, 0x1FF4, kSentenceBreakLower},
+    {0x1FF6, 0x1FF7, kSentenceBreakLower},
+    {0x1FF8, 0x1FFC, kSentenceBreakUpper},
+    {0x2000, 0x200A, kSentenceBreakSp},
+    {0x200B, 0x200B, kSentenceBreakFormat},
+    {0x200C, 0x200D, kSentenceBreakExtend},
+    {0x200E, 0x200F, kSentenceBreakFormat},
+    {0x2013, 0x2014, kSentenceBreakSContinue},
+    {0x2018, 0x201F, kSentenceBreakClose},
+    {0x2024, 0x2024, kSentenceBreakATerm},
+    {0x2028, 0x2029, kSentenceBreakSep},
+    {0x202A, 0x202E, kSentenceBreakFormat},
+    {0x202F, 0x202F, kSentenceBreakSp},
+    {0x2039, 0x203A, kSentenceBreakClose},
+    {0x203C, 0x203D, kSentenceBreakSTerm},
+    {0x2045, 0x2046, kSentenceBreakClose},
+    {0x2047, 0x2049, kSentenceBreakSTerm},
+    {0x205F, 0x205F, kSentenceBreakSp},
+    {0x2060, 0x2064, kSentenceBreakFormat},
+    {0x2066, 0x206F, kSentenceBreakFormat},
+    {0x2071, 0x2071, kSentenceBreakLower},
+    {0x207D, 0x207E, kSentenceBreakClose},
+    {0x207F, 0x207F, kSentenceBreakLower},
+    {0x208D, 0x208E, kSentenceBreakClose},
+    {0x2090, 0x209C, kSentenceBreakLower},
+    {0x20D0, 0x20F0, kSentenceBreakExtend},
+    {0x2102, 0x2102, kSentenceBreakUpper},
+    {0x2107, 0x2107, kSentenceBreakUpper},
+    {0x210A, 0x210A, kSentenceBreakLower},
+    {0x210B, 0x210D, kSentenceBreakUpper},
+    {0x210E, 0x210F, kSentenceBreakLower},
+    {0x2110, 0x2112, kSentenceBreakUpper},
+    {0x2113, 0x2113, kSentenceBreakLower},
+    {0x2115, 0x2115, kSentenceBreakUpper},
+    {0x2119, 0x211D, kSentenceBreakUpper},
+    {0x2124, 0x2124, kSentenceBreakUpper},
+    {0x2126, 0x2126, kSentenceBreakUpper},
+    {0x2128, 0x2128, kSentenceBreakUpper},
+    {0x212A, 0x212D, kSentenceBreakUpper},
+    {0x212F, 0x212F, kSentenceBreakLower},
+    {0x2130, 0x2133, kSentenceBreakUpper},
+    {0x2134, 0x2134, kSentenceBreakLower},
+    {0x2135, 0x2138, kSentenceBreakOLetter},
+    {0x2139, 0x2139, kSentenceBreakLower},
+    {0x213C, 0x213D, kSentenceBreakLower},
+    {0x213E, 0x213F, kSentenceBreakUpper},
+    {0x2145, 0x2145, kSentenceBreakUpper},
+    {0x2146, 0x2149, kSentenceBreakLower},
+    {0x214E, 0x214E, kSentenceBreakLower},
+    {0x2160, 0x216F, kSentenceBreakUpper},
+    {0x2170, 0x217F, kSentenceBreakLower},
+    {0x2180, 0x2182, kSentenceBreakOLetter},
+    {0x2183, 0x2183, kSentenceBreakUpper},
+    {0x2184, 0x2184, kSentenceBreakLower},
+    {0x2185, 0x2188, kSentenceBreakOLetter},
+    {0x2308, 0x230B, kSentenceBreakClose},
+    {0x2329, 0x232A, kSentenceBreakClose},
+    {0x24B6, 0x24CF, kSentenceBreakUpper},
+    {0x24D0, 0x24E9, kSentenceBreakLower},
+    {0x275B, 0x2760, kSentenceBreakClose},
+    {0x2768, 0x2775, kSentenceBreakClose},
+    {0x27C5, 0x27C6, kSentenceBreakClose},
+    {0x27E6, 0x27EF, kSentenceBreakClose},
+    {0x2983, 0x2998, kSentenceBreakClose},
+    {0x29D8, 0x29DB, kSentenceBreakClose},
+    {0x29FC, 0x29FD, kSentenceBreakClose},
+    {0x2C00, 0x2C2F, kSentenceBreakUpper},
+    {0x2C30, 0x2C5F, kSentenceBreakLower},
+    {0x2C60, 0x2C60, kSentenceBreakUpper},
+    {0x2C61, 0x2C61, kSentenceBreakLower},
+    {0x2C62, 0x2C64, kSentenceBreakUpper},
+    {0x2C65, 0x2C66, kSentenceBreakLower},
+    {0x2C67, 0x2C67, kSentenceBreakUpper},
+    {0x2C68, 0x2C68, kSentenceBreakLower},
+    {0x2C69, 0x2C69, kSentenceBreakUpper},
+    {0x2C6A, 0x2C6A, kSentenceBreakLower},
+    {0x2C6B, 0x2C6B, kSentenceBreakUpper},
+    {0x2C6C, 0x2C6C, kSentenceBreakLower},
+    {0x2C6D, 0x2C70, kSentenceBreakUpper},
+    {0x2C71, 0x2C71, kSentenceBreakLower},
+    {0x2C72, 0x2C72, kSentenceBreakUpper},
+    {0x2C73, 0x2C74, kSentenceBreakLower},
+    {0x2C75, 0x2C75, kSentenceBreakUpper},
+    {0x2C76, 0x2C7D, kSentenceBreakLower},
+    {0x2C7E, 0x2C80, kSentenceBreakUpper},
+    {0x2C81, 0x2C81, kSentenceBreakLower},
+    {0x2C82, 0x2C82, kSentenceBreakUpper},
+    {0x2C83, 0x2C83, kSentenceBreakLower},
+    {0x2C84, 0x2C84, kSentenceBreakUpper},
+    {0x2C85, 0x2C85, kSentenceBreakLower},
+    {0x2C86, 0x2C86, kSentenceBreakUpper},
+    {0x2C87, 0x2C87, kSentenceBreakLower},
+    {0x2C88, 0x2C88, kSentenceBreakUpper},
+    {0x2C89, 0x2C89, kSentenceBreakLower},
+    {0x2C8A, 0x2C8A, kSentenceBreakUpper},
+    {0x2C8B, 0x2C8B, kSentenceBreakLower},
+    {0x2C8C, 0x2C8C, kSentenceBreakUpper},
+    {0x2C8D, 0x2C8D, kSentenceBreakLower},
+    {0x2C8E, 0x2C8E, kSentenceBreakUpper},
+    {0x2C8F, 0x2C8F, kSentenceBreakLower},
+    {0x2C90, 0x2C90, kSentenceBreakUpper},
+    {0x2C91, 0x2C91, kSentenceBreakLower},
+    {0x2C92, 0x2C92, kSentenceBreakUpper},
+    {0x2C93, 0x2C93, kSentenceBreakLower},
+    {0x2C94, 0x2C94, kSentenceBreakUpper},
+    {0x2C95, 0x2C95, kSentenceBreakLower},
+    {0x2C96, 0x2C96, kSentenceBreakUpper},
+    {0x2C97, 0x2C97, kSentenceBreakLower},
+    {0x2C98, 0x2C98, kSentenceBreakUpper},
+    {0x2C99, 0x2C99, kSentenceBreakLower},
+    {0x2C9A, 0x2C9A, kSentenceBreakUpper},
+    {0x2C9B, 0x2C9B, kSentenceBreakLower},
+    {0x2C9C, 0x2C9C, kSentenceBreakUpper},
+    {0x2C9D, 0x2C9D, kSentenceBreakLower},
+    {0x2C9E, 0x2C9E, kSentenceBreakUpper},
+    {0x2C9F, 0x2C9F, kSentenceBreakLower},
+    {0x2CA0, 0x2CA0, kSentenceBreakUpper},
+    {0x2CA1, 0x2CA1, kSentenceBreakLower},
+    {0x2CA2, 0x2CA2, kSentenceBreakUpper},
+    {0x2CA3, 0x2CA3, kSentenceBreakLower},
+    {0x2CA4, 0x2CA4, kSentenceBreakUpper},
+    {0x2CA5, 0x2CA5, kSentenceBreakLower},
+    {0x2CA6, 0x2CA6, kSentenceBreakUpper},
+    {0x2CA7, 0x2CA7, kSentenceBreakLower},
+    {0x2CA8, 0x2CA8, kSentenceBreakUpper},
+    {0x2CA9, 0x2CA9, kSentenceBreakLower},
+    {0x2CAA, 0x2CAA, kSentenceBreakUpper},
+    {0x2CAB, 0x2CAB, kSentenceBreakLower},
+    {0x2CAC, 0x2CAC, kSentenceBreakUpper},
+    {0x2CAD, 0x2CAD, kSentenceBreakLower},
+    {0x2CAE, 0x2CAE, kSentenceBreakUpper},
+    {0x2CAF, 0x2CAF, kSentenceBreakLower},
+    {0x2CB0, 0x2CB0, kSentenceBreakUpper},
+    {0x2CB1, 0x2CB1, kSentenceBreakLower},
+    {0x2CB2, 0x2CB2, kSentenceBreakUpper},
+    {0x2CB3, 0x2CB3, kSentenceBreakLower},
+    {0x2CB4, 0x2CB4, kSentenceBreakUpper},
+    {0x2CB5, 0x2CB5, kSentenceBreakLower},
+    {0x2CB6, 0x2CB6, kSentenceBreakUpper},
+    {0x2CB7, 0x2CB7, kSentenceBreakLower},
+    {0x2CB8, 0x2CB8, kSentenceBreakUpper},
+    {0x2CB9, 0x2CB9, kSentenceBreakLower},
+    {0x2CBA, 0x2CBA, kSentenceBreakUpper},
+    {0x2CBB, 0x2CBB, kSentenceBreakLower},
+    {0x2CBC, 0x2CBC, kSentenceBreakUpper},
+    {0x2CBD, 0x2CBD, kSentenceBreakLower},
+    {0x2CBE, 0x2CBE, kSentenceBreakUpper},
+    {0x2CBF, 0x2CBF, kSentenceBreakLower},
+    {0x2CC0, 0x2CC0, kSentenceBreakUpper},
+    {0x2CC1, 0x2CC1, kSentenceBreakLower},
+    {0x2CC2, 0x2CC2, kSentenceBreakUpper},
+    {0x2CC3, 0x2CC3, kSentenceBreakLower},
+    {0x2CC4, 0x2CC4, kSentenceBreakUpper},
+    {0x2CC5, 0x2CC5, kSentenceBreakLower},
+    {0x2CC6, 0x2CC6, kSentenceBreakUpper},
+    {0x2CC7, 0x2CC7, kSentenceBreakLower},
+    {0x2CC8, 0x2CC8, kSentenceBreakUpper},
+    {0x2CC9, 0x2CC9, kSentenceBreakLower},
+    {0x2CCA, 0x2CCA, kSentenceBreakUpper},
+    {0x2CCB, 0x2CCB, kSentenceBreakLower},
+    {0x2CCC, 0x2CCC, kSentenceBreakUpper},
+    {0x2CCD, 0x2CCD, kSentenceBreakLower},
+    {0x2CCE, 0x2CCE, kSentenceBreakUpper},
+    {0x2CCF, 0x2CCF, kSentenceBreakLower},
+    {0x2CD0, 0x2CD0, kSentenceBreakUpper},
+    {0x2CD1, 0x2CD1, kSentenceBreakLower},
+    {0x2CD2, 0x2CD2, kSentenceBreakUpper},
+    {0x2CD3, 0x2CD3, kSentenceBreakLower},
+    {0x2CD4, 0x2CD4, kSentenceBreakUpper},
+    {0x2CD5, 0x2CD5, kSentenceBreakLower},
+    {0x2CD6, 0x2CD6, kSentenceBreakUpper},
+    {0x2CD7, 0x2CD7, kSentenceBreakLower},
+    {0x2CD8, 0x2CD8, kSentenceBreakUpper},
+    {0x2CD9, 0x2CD9, kSentenceBreakLower},
+    {0x2CDA, 0x2CDA, kSentenceBreakUpper},
+    {0x2CDB, 0x2CDB, kSentenceBreakLower},
+    {0x2CDC, 0x2CDC, kSentenceBreakUpper},
+    {0x2CDD, 0x2CDD, kSentenceBreakLower},
+    {0x2CDE, 0x2CDE, kSentenceBreakUpper},
+    {0x2CDF, 0x2CDF, kSentenceBreakLower},
+    {0x2CE0, 0x2CE0, kSentenceBreakUpper},
+    {0x2CE1, 0x2CE1, kSentenceBreakLower},
+    {0x2CE2, 0x2CE2, kSentenceBreakUpper},
+    {0x2CE3, 0x2CE4, kSentenceBreakLower},
+    {0x2CEB, 0x2CEB, kSentenceBreakUpper},
+    {0x2CEC, 0x2CEC, kSentenceBreakLower},
+    {0x2CED, 0x2CED, kSentenceBreakUpper},
+    {0x2CEE, 0x2CEE, kSentenceBreakLower},
+    {0x2CEF, 0x2CF1, kSentenceBreakExtend},
+    {0x2CF2, 0x2CF2, kSentenceBreakUpper},
+    {0x2CF3, 0x2CF3, kSentenceBreakLower},
+    {0x2D00, 0x2D25, kSentenceBreakLower},
+    {0x2D27, 0x2D27, kSentenceBreakLower},
+    {0x2D2D, 0x2D2D, kSentenceBreakLower},
+    {0x2D30, 0x2D67, kSentenceBreakOLetter},
+    {0x2D6F, 0x2D6F, kSentenceBreakOLetter},
+    {0x2D7F, 0x2D7F, kSentenceBreakExtend},
+    {0x2D80, 0x2D96, kSentenceBreakOLetter},
+    {0x2DA0, 0x2DA6, kSentenceBreakOLetter},
+    {0x2DA8, 0x2DAE, kSentenceBreakOLetter},
+    {0x2DB0, 0x2DB6, kSentenceBreakOLetter},
+    {0x2DB8, 0x2DBE, kSentenceBreakOLetter},
+    {0x2DC0, 0x2DC6, kSentenceBreakOLetter},
+    {0x2DC8, 0x2DCE, kSentenceBreakOLetter},
+    {0x2DD0, 0x2DD6, kSentenceBreakOLetter},
+    {0x2DD8, 0x2DDE, kSentenceBreakOLetter},
+    {0x2DE0, 0x2DFF, kSentenceBreakExtend},
+    {0x2E00, 0x2E0D, kSentenceBreakClose},
+    {0x2E1C, 0x2E1D, kSentenceBreakClose},
+    {0x2E20, 0x2E29, kSentenceBreakClose},
+    {0x2E2E, 0x2E2E, kSentenceBreakSTerm},
+    {0x2E2F, 0x2E2F, kSentenceBreakOLetter},
+    {0x2E3C, 0x2E3C, kSentenceBreakSTerm},
+    {0x2E42, 0x2E42, kSentenceBreakClose},
+    {0x2E53, 0x2E54, kSentenceBreakSTerm},
+    {0x2E55, 0x2E5C, kSentenceBreakClose},
+    {0x3000, 0x3000, kSentenceBreakSp},
+    {0x3001, 0x3001, kSentenceBreakSContinue},
+    {0x3002, 0x3002, kSentenceBreakSTerm},
+    {0x3005, 0x3007, kSentenceBreakOLetter},
+    {0x3008, 0x3011, kSentenceBreakClose},
+    {0x3014, 0x301B, kSentenceBreakClose},
+    {0x301D, 0x301F, kSentenceBreakClose},
+    {0x3021, 0x3029, kSentenceBreakOLetter},
+    {0x302A, 0x302F, kSentenceBreakExtend},
+    {0x3031, 0x3035, kSentenceBreakOLetter},
+    {0x3038, 0x303C, kSentenceBreakOLetter},
+    {0x3041, 0x3096, kSentenceBreakOLetter},
+    {0x3099, 0x309A, kSentenceBreakExtend},
+    {0x309D, 0x309F, kSentenceBreakOLetter},
+    {0x30A1, 0x30FA, kSentenceBreakOLetter},
+    {0x30FC, 0x30FF, kSentenceBreakOLetter},
+    {0x3105, 0x312F, kSentenceBreakOLetter},
+    {0x3131, 0x318E, kSentenceBreakOLetter},
+    {0x31A0, 0x31BF, kSentenceBreakOLetter},
+    {0x31F0, 0x31FF, kSentenceBreakOLetter},
+    {0x3400, 0x4DBF, kSentenceBreakOLetter},
+    {0x4E00, 0xA48C, kSentenceBreakOLetter},
+    {0xA4D0, 0xA4FD, kSentenceBreakOLetter},
+    {0xA4FF, 0xA4FF, kSentenceBreakSTerm},
+    {0xA500, 0xA60C, kSentenceBreakOLetter},
+    {0xA60E, 0xA60F, kSentenceBreakSTerm},
+    {0xA610, 0xA61F, kSentenceBreakOLetter},
+    {0xA620, 0xA629, kSentenceBreakNumeric},
+    {0xA62A, 0xA62B, kSentenceBreakOLetter},
+    {0xA640, 0xA640, kSentenceBreakUpper},
+    {0xA641, 0xA641, kSentenceBreakLower},
+    {0xA642, 0xA642, kSentenceBreakUpper},
+    {0xA643, 0xA643, kSentenceBreakLower},
+    {0xA644, 0xA644, kSentenceBreakUpper},
+    {0xA645, 0xA645, kSentenceBreakLower},
+    {0xA646, 0xA646, kSentenceBreakUpper},
+    {0xA647, 0xA647, kSentenceBreakLower},
+    {0xA648, 0xA648, kSentenceBreakUpper},
+    {0xA649, 0xA649, kSentenceBreakLower},
+    {0xA64A, 0xA64A, kSentenceBreakUpper},
+    {0xA64B, 0xA64B, kSentenceBreakLower},
+    {0xA64C, 0xA64C, kSentenceBreakUpper},
+    {0xA64D, 0xA64D, kSentenceBreakLower},
+    {0xA64E, 0xA64E, kSentenceBreakUpper},
+    {0xA64F, 0xA64F, kSentenceBreakLower},
+    {0xA650, 0xA650, kSentenceBreakUpper},
+    {0xA651, 0xA651, kSentenceBreakLower},
+    {0xA652, 0xA652, kSentenceBreakUpper},
+    {0xA653, 0xA653, kSentenceBreakLower},
+    {0xA654, 0xA654, kSentenceBreakUpper},
+    {0xA655, 0xA655, kSentenceBreakLower},
+    {0xA656, 0xA656, kSentenceBreakUpper},
+    {0xA657, 0xA657, kSentenceBreakLower},
+    {0xA658, 0xA658, kSentenceBreakUpper},
+    {0xA659, 0xA659, kSentenceBreakLower},
+    {0xA65A, 0xA65A, kSentenceBreakUpper},
+    {0xA65B, 0xA65B, kSentenceBreakLower},
+    {0xA65C, 0xA65C, kSentenceBreakUpper},
+    {0xA65D, 0xA65D, kSentenceBreakLower},
+    {0xA65E, 0xA65E, kSentenceBreakUpper},
+    {0xA65F, 0xA65F, kSentenceBreakLower},
+    {0xA660, 0xA660, kSentenceBreakUpper},
+    {0xA661, 0xA661, kSentenceBreakLower},
+    {0xA662, 0xA662, kSentenceBreakUpper},
+    {0xA663, 0xA663, kSentenceBreakLower},
+    {0xA664, 0xA664, kSentenceBreakUpper},
+    {0xA665, 0xA665, kSentenceBreakLower},
+    {0xA666, 0xA666, kSentenceBreakUpper},
+    {0xA667, 0xA667, kSentenceBreakLower},
+    {0xA668, 0xA668, kSentenceBreakUpper},
+    {0xA669, 0xA669, kSentenceBreakLower},
+    {0xA66A, 0xA66A, kSentenceBreakUpper},
+    {0xA66B, 0xA66B, kSentenceBreakLower},
+    {0xA66C, 0xA66C, kSentenceBreakUpper},
+    {0xA66D, 0xA66D, kSentenceBreakLower},
+    {0xA66E, 0xA66E, kSentenceBreakOLetter},
+    {0xA66F, 0xA672, kSentenceBreakExtend},
+    {0xA674, 0xA67D, kSentenceBreakExtend},
+    {0xA67F, 0xA67F, kSentenceBreakOLetter},
+    {0xA680, 0xA680, kSentenceBreakUpper},
+    {0xA681, 0xA681, kSentenceBreakLower},
+    {0xA682, 0xA682, kSentenceBreakUpper},
+    {0xA683, 0xA683, kSentenceBreakLower},
+    {0xA684, 0xA684, kSentenceBreakUpper},
+    {0xA685, 0xA685, kSentenceBreakLower},
+    {0xA686, 0xA686, kSentenceBreakUpper},
+    {0xA687, 0xA687, kSentenceBreakLower},
+    {0xA688, 0xA688, kSentenceBreakUpper},
+    {0xA689, 0xA689, kSentenceBreakLower},
+    {0xA68A, 0xA68A, kSentenceBreakUpper},
+    {0xA68B, 0xA68B, kSentenceBreakLower},
+    {0xA68C, 0xA68C, kSentenceBreakUpper},
+    {0xA68D, 0xA68D, kSentenceBreakLower},
+    {0xA68E, 0xA68E, kSentenceBreakUpper},
+    {0xA68F, 0xA68F, kSentenceBreakLower},
+    {0xA690, 0xA690, kSentenceBreakUpper},
+    {0xA691, 0xA691, kSentenceBreakLower},
+    {0xA692, 0xA692, kSentenceBreakUpper},
+    {0xA693, 0xA693, kSentenceBreakLower},
+    {0xA694, 0xA694, kSentenceBreakUpper},
+    {0xA695, 0xA695, kSentenceBreakLower},
+    {0xA696, 0xA696, kSentenceBreakUpper},
+    {0xA697, 0xA697, kSentenceBreakLower},
+    {0xA698, 0xA698, kSentenceBreakUpper},
+    {0xA699, 0xA699, kSentenceBreakLower},
+    {0xA69A, 0xA69A, kSentenceBreakUpper},
+    {0xA69B, 0xA69D, kSentenceBreakLower},
+    {0xA69E, 0xA69F, kSentenceBreakExtend},
+    {0xA6A0, 0xA6EF, kSentenceBreakOLetter},
+    {0xA6F0, 0xA6F1, kSentenceBreakExtend},
+    {0xA6F3, 0xA6F3, kSentenceBreakSTerm},
+    {0xA6F7, 0xA6F7, kSentenceBreakSTerm},
+    {0xA717, 0xA71F, kSentenceBreakOLetter},
+    {0xA722, 0xA722, kSentenceBreakUpper},
+    {0xA723, 0xA723, kSentenceBreakLower},
+    {0xA724, 0xA724, kSentenceBreakUpper},
+    {0xA725, 0xA725, kSentenceBreakLower},
+    {0xA726, 0xA726, kSentenceBreakUpper},
+    {0xA727, 0xA727, kSentenceBreakLower},
+    {0xA728, 0xA728, kSentenceBreakUpper},
+    {0xA729, 0xA729, kSentenceBreakLower},
+    {0xA72A, 0xA72A, kSentenceBreakUpper},
+    {0xA72B, 0xA72B, kSentenceBreakLower},
+    {0xA72C, 0xA72C, kSentenceBreakUpper},
+    {0xA72D, 0xA72D, kSentenceBreakLower},
+    {0xA72E, 0xA72E, kSentenceBreakUpper},
+    {0xA72F, 0xA731, kSentenceBreakLower},
+    {0xA732, 0xA732, kSentenceBreakUpper},
+    {0xA733, 0xA733, kSentenceBreakLower},
+    {0xA734, 0xA734, kSentenceBreakUpper},
+    {0xA735, 0xA735, kSentenceBreakLower},
+    {0xA736, 0xA736, kSentenceBreakUpper},
+    {0xA737, 0xA737, kSentenceBreakLower},
+    {0xA738, 0xA738, kSentenceBreakUpper},
+    {0xA739, 0xA739, kSentenceBreakLower},
+    {0xA73A, 0xA73A, kSentenceBreakUpper},
+    {0xA73B, 0xA73B, kSentenceBreakLower},
+    {0xA73C, 0xA73C, kSentenceBreakUpper},
+    {0xA73D, 0xA73D, kSentenceBreakLower},
+    {0xA73E, 0xA73E, kSentenceBreakUpper},
+    {0xA73F, 0xA73F, kSentenceBreakLower},
+    {0xA740, 0xA740, kSentenceBreakUpper},
+    {0xA741, 0xA741, kSentenceBreakLower},
+    {0xA742, 0xA742, kSentenceBreakUpper},
+    {0xA743, 0xA743, kSentenceBreakLower},
+    {0xA744, 0xA744, kSentenceBreakUpper},
+    {0xA745, 0xA745, kSentenceBreakLower},
+    {0xA746, 0xA746, kSentenceBreakUpper},
+    {0xA747, 0xA747, kSentenceBreakLower},
+    {0xA748, 0xA748, kSentenceBreakUpper},
+    {0xA749, 0xA749, kSentenceBreakLower},
+    {0xA74A, 0xA74A, kSentenceBreakUpper},
+    {0xA74B, 0xA74B, kSentenceBreakLower},
+    {0xA74C, 0xA74C, kSentenceBreakUpper},
+    {0xA74D, 0xA74D, kSentenceBreakLower},
+    {0xA74E, 0xA74E, kSentenceBreakUpper},
+    {0xA74F, 0xA74F, kSentenceBreakLower},
+    {0xA750, 0xA750, kSentenceBreakUpper},
+    {0xA751, 0xA751, kSentenceBreakLower},
+    {0xA752, 0xA752, kSentenceBreakUpper},
+    {0xA753, 0xA753, kSentenceBreakLower},
+    {0xA754, 0xA754, kSentenceBreakUpper},
+    {0xA755, 0xA755, kSentenceBreakLower},
+    {0xA756, 0xA756, kSentenceBreakUpper},
+    {0xA757, 0xA757, kSentenceBreakLower},
+    {0xA758, 0xA758, kSentenceBreakUpper},
+    {0xA759, 0xA759, kSentenceBreakLower},
+    {0xA75A, 0xA75A, kSentenceBreakUpper},
+    {0xA75B, 0xA75B, kSentenceBreakLower},
+    {0xA75C, 0xA75C, kSentenceBreakUpper},
+    {0xA75D, 0xA75D, kSentenceBreakLower},
+    {0xA75E, 0xA75E, kSentenceBreakUpper},
+    {0xA75F, 0xA75F, kSentenceBreakLower},
+    {0xA760, 0xA760, kSentenceBreakUpper},
+    {0xA761, 0xA761, kSentenceBreakLower},
+    {0xA762, 0xA762, kSentenceBreakUpper},
+    {0xA763, 0xA763, kSentenceBreakLower},
+    {0xA764, 0xA764, kSentenceBreakUpper},
+    {0xA765, 0xA765, kSentenceBreakLower},
+    {0xA766, 0xA766, kSentenceBreakUpper},
+    {0xA767, 0xA767, kSentenceBreakLower},
+    {0xA768, 0xA768, kSentenceBreakUpper},
+    {0xA769, 0xA769, kSentenceBreakLower},
+    {0xA76A, 0xA76A, kSentenceBreakUpper},
+    {0xA76B, 0xA76B, kSentenceBreakLower},
+    {0xA76C, 0xA76C, kSentenceBreakUpper},
+    {0xA76D, 0xA76D, kSentenceBreakLower},
+    {0xA76E, 0xA76E, kSentenceBreakUpper},
+    {0xA76F, 0xA778, kSentenceBreakLower},
+    {0xA779, 0xA779, kSentenceBreakUpper},
+    {0xA77A, 0xA77A, kSentenceBreakLower},
+    {0xA77B, 0xA77B, kSentenceBreakUpper},
+    {0xA77C, 0xA77C, kSentenceBreakLower},
+    {0xA77D, 0xA77E, kSentenceBreakUpper},
+    {0xA77F, 0xA77F, kSentenceBreakLower},
+    {0xA780, 0xA780, kSentenceBreakUpper},
+    {0xA781, 0xA781, kSentenceBreakLower},
+    {0xA782, 0xA782, kSentenceBreakUpper},
+    {0xA783, 0xA783, kSentenceBreakLower},
+    {0xA784, 0xA784, kSentenceBreakUpper},
+    {0xA785, 0xA785, kSentenceBreakLower},
+    {0xA786, 0xA786, kSentenceBreakUpper},
+    {0xA787, 0xA787, kSentenceBreakLower},
+    {0xA788, 0xA788, kSentenceBreakOLetter},
+    {0xA78B, 0xA78B, kSentenceBreakUpper},
+    {0xA78C, 0xA78C, kSentenceBreakLower},
+    {0xA78D, 0xA78D, kSentenceBreakUpper},
+    {0xA78E, 0xA78E, kSentenceBreakLower},
+    {0xA78F, 0xA78F, kSentenceBreakOLetter},
+    {0xA790, 0xA790, kSentenceBreakUpper},
+    {0xA791, 0xA791, kSentenceBreakLower},
+    {0xA792, 0xA792, kSentenceBreakUpper},
+    {0xA793, 0xA795, kSentenceBreakLower},
+    {0xA796, 0xA796, kSentenceBreakUpper},
+    {0xA797, 0xA797, kSentenceBreakLower},
+    {0xA798, 0xA798, kSentenceBreakUpper},
+    {0xA799, 0xA799, kSentenceBreakLower},
+    {0xA79A, 0xA79A, kSentenceBreakUpper},
+    {0xA79B, 0xA79B, kSentenceBreakLower},
+    {0xA79C, 0xA79C, kSentenceBreakUpper},
+    {0xA79D, 0xA79D, kSentenceBreakLower},
+    {0xA79E, 0xA79E, kSentenceBreakUpper},
+    {0xA79F, 0xA79F, kSentenceBreakLower},
+    {0xA7A0, 0xA7A0, kSentenceBreakUpper},
+    {0xA7A1, 0xA7A1, kSentenceBreakLower},
+    {0xA7A2, 0xA7A2, kSentenceBreakUpper},
+    {0xA7A3, 0xA7A3, kSentenceBreakLower},
+    {0xA7A4, 0xA7A4, kSentenceBreakUpper},
+    {0xA7A5, 0xA7A5, kSentenceBreakLower},
+    {0xA7A6, 0xA7A6, kSentenceBreakUpper},
+    {0xA7A7, 0xA7A7, kSentenceBreakLower},
+    {0xA7A8, 0xA7A8, kSentenceBreakUpper},
+    {0xA7A9, 0xA7A9, kSentenceBreakLower},
+    {0xA7AA, 0xA7AE, kSentenceBreakUpper},
+    {0xA7AF, 0xA7AF, kSentenceBreakLower},
+    {0xA7B0, 0xA7B4, kSentenceBreakUpper},
+    {0xA7B5, 0xA7B5, kSentenceBreakLower},
+    {0xA7B6, 0xA7B6, kSentenceBreakUpper},
+    {0xA7B7, 0xA7B7, kSentenceBreakLower},
+    {0xA7B8, 0xA7B8, kSentenceBreakUpper},
+    {0xA7B9, 0xA7B9, kSentenceBreakLower},
+    {0xA7BA, 0xA7BA, kSentenceBreakUpper},
+    {0xA7BB, 0xA7BB, kSentenceBreakLower},
+    {0xA7BC, 0xA7BC, kSentenceBreakUpper},
+    {0xA7BD, 0xA7BD, kSentenceBreakLower},
+    {0xA7BE, 0xA7BE, kSentenceBreakUpper},
+    {0xA7BF, 0xA7BF, kSentenceBreakLower},
+    {0xA7C0, 0xA7C0, kSentenceBreakUpper},
+    {0xA7C1, 0xA7C1, kSentenceBreakLower},
+    {0xA7C2, 0xA7C2, kSentenceBreakUpper},
+    {0xA7C3, 0xA7C3, kSentenceBreakLower},
+    {0xA7C4, 0xA7C7, kSentenceBreakUpper},
+    {0xA7C8, 0xA7C8, kSentenceBreakLower},
+    {0xA7C9, 0xA7C9, kSentenceBreakUpper},
+    {0xA7CA, 0xA7CA, kSentenceBreakLower},
+    {0xA7D0, 0xA7D0, kSentenceBreakUpper},
+    {0xA7D1, 0xA7D1, kSentenceBreakLower},
+    {0xA7D3, 0xA7D3, kSentenceBreakLower},
+    {0xA7D5, 0xA7D5, kSentenceBreakLower},
+    {0xA7D6, 0xA7D6, kSentenceBreakUpper},
+    {0xA7D7, 0xA7D7, kSentenceBreakLower},
+    {0xA7D8, 0xA7D8, kSentenceBreakUpper},
+    {0xA7D9, 0xA7D9, kSentenceBreakLower},
+    {0xA7F2, 0xA7F4, kSentenceBreakLower},
+    {0xA7F5, 0xA7F5, kSentenceBreakUpper},
+    {0xA7F6, 0xA7F6, kSentenceBreakLower},
+    {0xA7F7, 0xA7F7, kSentenceBreakOLetter},
+    {0xA7F8, 0xA7FA, kSentenceBreakLower},
+    {0xA7FB, 0xA801, kSentenceBreakOLetter},
+    {0xA802, 0xA802, kSentenceBreakExtend},
+    {0xA803, 0xA805, kSentenceBreakOLetter},
+    {0xA806, 0xA806, kSentenceBreakExtend},
+    {0xA807, 0xA80A, kSentenceBreakOLetter},
+    {0xA80B, 0xA80B, kSentenceBreakExtend},
+    {0xA80C, 0xA822, kSentenceBreakOLetter},
+    {0xA823, 0xA827, kSentenceBreakExtend},
+    {0xA82C, 0xA82C, kSentenceBreakExtend},
+    {0xA840, 0xA873, kSentenceBreakOLetter},
+    {0xA876, 0xA877, kSentenceBreakSTerm},
+    {0xA880, 0xA881, kSentenceBreakExtend},
+    {0xA882, 0xA8B3, kSentenceBreakOLetter},
+    {0xA8B4, 0xA8C5, kSentenceBreakExtend},
+    {0xA8CE, 0xA8CF, kSentenceBreakSTerm},
+    {0xA8D0, 0xA8D9, kSentenceBreakNumeric},
+    {0xA8E0, 0xA8F1, kSentenceBreakExtend},
+    {0xA8F2, 0xA8F7, kSentenceBreakOLetter},
+    {0xA8FB, 0xA8FB, kSentenceBreakOLetter},
+    {0xA8FD, 0xA8FE, kSentenceBreakOLetter},
+    {0xA8FF, 0xA8FF, kSentenceBreakExtend},
+    {0xA900, 0xA909, kSentenceBreakNumeric},
+    {0xA90A, 0xA925, kSentenceBreakOLetter},
+    {0xA926, 0xA92D, kSentenceBreakExtend},
+    {0xA92F, 0xA92F, kSentenceBreakSTerm},
+    {0xA930, 0xA946, kSentenceBreakOLetter},
+    {0xA947, 0xA953, kSentenceBreakExtend},
+    {0xA960, 0xA97C, kSentenceBreakOLetter},
+    {0xA980, 0xA983, kSentenceBreakExtend},
+    {0xA984, 0xA9B2, kSentenceBreakOLetter},
+    {0xA9B3, 0xA9C0, kSentenceBreakExtend},
+    {0xA9C8, 0xA9C9, kSentenceBreakSTerm},
+    {0xA9CF, 0xA9CF, kSentenceBreakOLetter},
+    {0xA9D0, 0xA9D9, kSentenceBreakNumeric},
+    {0xA9E0, 0xA9E4, kSentenceBreakOLetter},
+    {0xA9E5, 0xA9E5, kSentenceBreakExtend},
+    {0xA9E6, 0xA9EF, kSentenceBreakOLetter},
+    {0xA9F0, 0xA9F9, kSentenceBreakNumeric},
+    {0xA9FA, 0xA9FE, kSentenceBreakOLetter},
+    {0xAA00, 0xAA28, kSentenceBreakOLetter},
+    {0xAA29, 0xAA36, kSentenceBreakExtend},
+    {0xAA40, 0xAA42, kSentenceBreakOLetter},
+    {0xAA43, 0xAA43, kSentenceBreakExtend},
+    {0xAA44, 0xAA4B, kSentenceBreakOLetter},
+    {0xAA4C, 0xAA4D, kSentenceBreakExtend},
+    {0xAA50, 0xAA59, kSentenceBreakNumeric},
+    {0xAA5D, 0xAA5F, kSentenceBreakSTerm},
+    {0xAA60, 0xAA76, kSentenceBreakOLetter},
+    {0xAA7A, 0xAA7A, kSentenceBreakOLetter},
+    {0xAA7B, 0xAA7D, kSentenceBreakExtend},
+    {0xAA7E, 0xAAAF, kSentenceBreakOLetter},
+    {0xAAB0, 0xAAB0, kSentenceBreakExtend},
+    {0xAAB1, 0xAAB1, kSentenceBreakOLetter},
+    {0xAAB2, 0xAAB4, kSentenceBreakExtend},
+    {0xAAB5, 0xAAB6, kSentenceBreakOLetter},
+    {0xAAB7, 0xAAB8, kSentenceBreakExtend},
+    {0xAAB9, 0xAABD, kSentenceBreakOLetter},
+    {0xAABE, 0xAABF, kSentenceBreakExtend},
+    {0xAAC0, 0xAAC0, kSentenceBreakOLetter},
+    {0xAAC1, 0xAAC1, kSentenceBreakExtend},
+    {0xAAC2, 0xAAC2, kSentenceBreakOLetter},
+    {0xAADB, 0xAADD, kSentenceBreakOLetter},
+    {0xAAE0, 0xAAEA, kSentenceBreakOLetter},
+    {0xAAEB, 0xAAEF, kSentenceBreakExtend},
+    {0xAAF0, 0xAAF1, kSentenceBreakSTerm},
+    {0xAAF2, 0xAAF4, kSentenceBreakOLetter},
+    {0xAAF5, 0xAAF6, kSentenceBreakExtend},
+    {0xAB01, 0xAB06, kSentenceBreakOLetter},
+    {0xAB09, 0xAB0E, kSentenceBreakOLetter},
+    {0xAB11, 0xAB16, kSentenceBreakOLetter},
+    {0xAB20, 0xAB26, kSentenceBreakOLetter},
+    {0xAB28, 0xAB2E, kSentenceBreakOLetter},
+    {0xAB30, 0xAB5A, kSentenceBreakLower},
+    {0xAB5C, 0xAB69, kSentenceBreakLower},
+    {0xAB70, 0xABBF, kSentenceBreakLower},
+    {0xABC0, 0xABE2, kSentenceBreakOLetter},
+    {0xABE3, 0xABEA, kSentenceBreakExtend},
+    {0xABEB, 0xABEB, kSentenceBreakSTerm},
+    {0xABEC, 0xABED, kSentenceBreakExtend},
+    {0xABF0, 0xABF9, kSentenceBreakNumeric},
+    {0xAC00, 0xD7A3, kSentenceBreakOLetter},
+    {0xD7B0, 0xD7C6, kSentenceBreakOLetter},
+    {0xD7CB, 0xD7FB, kSentenceBreakOLetter},
+    {0xF900, 0xFA6D, kSentenceBreakOLetter},
+    {0xFA70, 0xFAD9, kSentenceBreakOLetter},
+    {0xFB00, 0xFB06, kSentenceBreakLower},
+    {0xFB13, 0xFB17, kSentenceBreakLower},
+    {0xFB1D, 0xFB1D, kSentenceBreakOLetter},
+    {0xFB1E, 0xFB1E, kSentenceBreakExtend},
+    {0xFB1F, 0xFB28, kSentenceBreakOLetter},
+    {0xFB2A, 0xFB36, kSentenceBreakOLetter},
+    {0xFB38, 0xFB3C, kSentenceBreakOLetter},
+    {0xFB3E, 0xFB3E, kSentenceBreakOLetter},
+    {0xFB40, 0xFB41, kSentenceBreakOLetter},
+    {0xFB43, 0xFB44, kSentenceBreakOLetter},
+    {0xFB46, 0xFBB1, kSentenceBreakOLetter},
+    {0xFBD3, 0xFD3D, kSentenceBreakOLetter},
+    {0xFD3E, 0xFD3F, kSentenceBreakClose},
+    {0xFD50, 0xFD8F, kSentenceBreakOLetter},
+    {0xFD92, 0xFDC7, kSentenceBreakOLetter},
+    {0xFDF0, 0xFDFB, kSentenceBreakOLetter},
+    {0xFE00, 0xFE0F, kSentenceBreakExtend},
+    {0xFE10, 0xFE11, kSentenceBreakSContinue},
+    {0xFE13, 0xFE13, kSentenceBreakSContinue},
+    {0xFE17, 0xFE18, kSentenceBreakClose},
+    {0xFE20, 0xFE2F, kSentenceBreakExtend},
+    {0xFE31, 0xFE32, kSentenceBreakSContinue},
+    {0xFE35, 0xFE44, kSentenceBreakClose},
+    {0xFE47, 0xFE48, kSentenceBreakClose},
+    {0xFE50, 0xFE51, kSentenceBreakSContinue},
+    {0xFE52, 0xFE52, kSentenceBreakATerm},
+    {0xFE55, 0xFE55, kSentenceBreakSContinue},
+    {0xFE56, 0xFE57, kSentenceBreakSTerm},
+    {0xFE58, 0xFE58, kSentenceBreakSContinue},
+    {0xFE59, 0xFE5E, kSentenceBreakClose},
+    {0xFE63, 0xFE63, kSentenceBreakSContinue},
+    {0xFE70, 0xFE74, kSentenceBreakOLetter},
+    {0xFE76, 0xFEFC, kSentenceBreakOLetter},
+    {0xFEFF, 0xFEFF, kSentenceBreakFormat},
+    {0xFF01, 0xFF01, kSentenceBreakSTerm},
+    {0xFF08, 0xFF09, kSentenceBreakClose},
+    {0xFF0C, 0xFF0D, kSentenceBreakSContinue},
+    {0xFF0E, 0xFF0E, kSentenceBreakATerm},
+    {0xFF10, 0xFF19, kSentenceBreakNumeric},
+    {0xFF1A, 0xFF1A, kSentenceBreakSContinue},
+    {0xFF1F, 0xFF1F, kSentenceBreakSTerm},
+    {0xFF21, 0xFF3A, kSentenceBreakUpper},
+    {0xFF3B, 0xFF3B, kSentenceBreakClose},
+    {0xFF3D, 0xFF3D, kSentenceBreakClose},
+    {0xFF41, 0xFF5A, kSentenceBreakLower},
+    {0xFF5B, 0xFF5B, kSentenceBreakClose},
+    {0xFF5D, 0xFF5D, kSentenceBreakClose},
+    {0xFF5F, 0xFF60, kSentenceBreakClose},
+    {0xFF61, 0xFF61, kSentenceBreakSTerm},
+    {0xFF62, 0xFF63, kSentenceBreakClose},
+    {0xFF64, 0xFF64, kSentenceBreakSContinue},
+    {0xFF66, 0xFF9D, kSentenceBreakOLetter},
+    {0xFF9E, 0xFF9F, kSentenceBreakExtend},
+    {0xFFA0, 0xFFBE, kSentenceBreakOLetter},
+    {0xFFC2, 0xFFC7, kSentenceBreakOLetter},
+    {0xFFCA, 0xFFCF, kSentenceBreakOLetter},
+    {0xFFD2, 0xFFD7, kSentenceBreakOLetter},
+    {0xFFDA, 0xFFDC, kSentenceBreakOLetter},
+    {0xFFF9, 0xFFFB, kSentenceBreakFormat},
+    {0x10000, 0x1000B, kSentenceBreakOLetter},
+    {0x1000D, 0x10026, kSentenceBreakOLetter},
+    {0x10028, 0x1003A, kSentenceBreakOLetter},
+    {0x1003C, 0x1003D, kSentenceBreakOLetter},
+    {0x1003F, 0x1004D, kSentenceBreakOLetter},
+    {0x10050, 0x1005D, kSentenceBreakOLetter},
+    {0x10080, 0x100FA, kSentenceBreakOLetter},
+    {0x10140, 0x10174, kSentenceBreakOLetter},
+    {0x101FD, 0x101FD, kSentenceBreakExtend},
+    {0x10280, 0x1029C, kSentenceBreakOLetter},
+    {0x102A0, 0x102D0, kSentenceBreakOLetter},
+    {0x102E0, 0x102E0, kSentenceBreakExtend},
+    {0x10300, 0x1031F, kSentenceBreakOLetter},
+    {0x1032D, 0x1034A, kSentenceBreakOLetter},
+    {0x10350, 0x10375, kSentenceBreakOLetter},
+    {0x10376, 0x1037A, kSentenceBreakExtend},
+    {0x10380, 0x1039D, kSentenceBreakOLetter},
+    {0x103A0, 0x103C3, kSentenceBreakOLetter},
+    {0x103C8, 0x103CF, kSentenceBreakOLetter},
+    {0x103D1, 0x103D5, kSentenceBreakOLetter},
+    {0x10400, 0x10427, kSentenceBreakUpper},
+    {0x10428, 0x1044F, kSentenceBreakLower},
+    {0x10450, 0x1049D, kSentenceBreakOLetter},
+    {0x104A0, 0x104A9, kSentenceBreakNumeric},
+    {0x104B0, 0x104D3, kSentenceBreakUpper},
+    {0x104D8, 0x104FB, kSentenceBreakLower},
+    {0x10500, 0x10527, kSentenceBreakOLetter},
+    {0x10530, 0x10563, kSentenceBreakOLetter},
+    {0x10570, 0x1057A, kSentenceBreakUpper},
+    {0x1057C, 0x1058A, kSentenceBreakUpper},
+    {0x1058C, 0x10592, kSentenceBreakUpper},
+    {0x10594, 0x10595, kSentenceBreakUpper},
+    {0x10597, 0x105A1, kSentenceBreakLower},
+    {0x105A3, 0x105B1, kSentenceBreakLower},
+    {0x105B3, 0x105B9, kSentenceBreakLower},
+    {0x105BB, 0x105BC, kSentenceBreakLower},
+    {0x10600, 0x10736, kSentenceBreakOLetter},
+    {0x10740, 0x10755, kSentenceBreakOLetter},
+    {0x10760, 0x10767, kSentenceBreakOLetter},
+    {0x10780, 0x10780, kSentenceBreakLower},
+    {0x10781, 0x10782, kSentenceBreakOLetter},
+    {0x10783, 0x10785, kSentenceBreakLower},
+    {0x10787, 0x107B0, kSentenceBreakLower},
+    {0x107B2, 0x107BA, kSentenceBreakLower},
+    {0x10800, 0x10805, kSentenceBreakOLetter},
+    {0x10808, 0x10808, kSentenceBreakOLetter},
+    {0x1080A, 0x10835, kSentenceBreakOLetter},
+    {0x10837, 0x10838, kSentenceBreakOLetter},
+    {0x1083C, 0x1083C, kSentenceBreakOLetter},
+    {0x1083F, 0x10855, kSentenceBreakOLetter},
+    {0x10860, 0x10876, kSentenceBreakOLetter},
+    {0x10880, 0x1089E, kSentenceBreakOLetter},
+    {0x108E0, 0x108F2, kSentenceBreakOLetter},
+    {0x108F4, 0x108F5, kSentenceBreakOLetter},
+    {0x10900, 0x10915, kSentenceBreakOLetter},
+    {0x10920, 0x10939, kSentenceBreakOLetter},
+    {0x10980, 0x109B7, kSentenceBreakOLetter},
+    {0x109BE, 0x109BF, kSentenceBreakOLetter},
+    {0x10A00, 0x10A00, kSentenceBreakOLetter},
+    {0x10A01, 0x10A03, kSentenceBreakExtend},
+    {0x10A05, 0x10A06, kSentenceBreakExtend},
+    {0x10A0C, 0x10A0F, kSentenceBreakExtend},
+    {0x10A10, 0x10A13, kSentenceBreakOLetter},
+    {0x10A15, 0x10A17, kSentenceBreakOLetter},
+    {0x10A19, 0x10A35, kSentenceBreakOLetter},
+    {0x10A38, 0x10A3A, kSentenceBreakExtend},
+    {0x10A3F, 0x10A3F, kSentenceBreakExtend},
+    {0x10A56, 0x10A57, kSentenceBreakSTerm},
+    {0x10A60, 0x10A7C, kSentenceBreakOLetter},
+    {0x10A80, 0x10A9C, kSentenceBreakOLetter},
+    {0x10AC0, 0x10AC7, kSentenceBreakOLetter},
+    {0x10AC9, 0x10AE4, kSentenceBreakOLetter},
+    {0x10AE5, 0x10AE6, kSentenceBreakExtend},
+    {0x10B00, 0x10B35, kSentenceBreakOLetter},
+    {0x10B40, 0x10B55, kSentenceBreakOLetter},
+    {0x10B60, 0x10B72, kSentenceBreakOLetter},
+    {0x10B80, 0x10B91, kSentenceBreakOLetter},
+    {0x10C00, 0x10C48, kSentenceBreakOLetter},
+    {0x10C80, 0x10CB2, kSentenceBreakUpper},
+    {0x10CC0, 0x10CF2, kSentenceBreakLower},
+    {0x10D00, 0x10D23, kSentenceBreakOLetter},
+    {0x10D24, 0x10D27, kSentenceBreakExtend},
+    {0x10D30, 0x10D39, kSentenceBreakNumeric},
+    {0x10E80, 0x10EA9, kSentenceBreakOLetter},
+    {0x10EAB, 0x10EAC, kSentenceBreakExtend},
+    {0x10EB0, 0x10EB1, kSentenceBreakOLetter},
+    {0x10EFD, 0x10EFF, kSentenceBreakExtend},
+    {0x10F00, 0x10F1C, kSentenceBreakOLetter},
+    {0x10F27, 0x10F27, kSentenceBreakOLetter},
+    {0x10F30, 0x10F45, kSentenceBreakOLetter},
+    {0x10F46, 0x10F50, kSentenceBreakExtend},
+    {0x10F55, 0x10F59, kSentenceBreakSTerm},
+    {0x10F70, 0x10F81, kSentenceBreakOLetter},
+    {0x10F82, 0x10F85, kSentenceBreakExtend},
+    {0x10F86, 0x10F89, kSentenceBreakSTerm},
+    {0x10FB0, 0x10FC4, kSentenceBreakOLetter},
+    {0x10FE0, 0x10FF6, kSentenceBreakOLetter},
+    {0x11000, 0x11002, kSentenceBreakExtend},
+    {0x11003, 0x11037, kSentenceBreakOLetter},
+    {0x11038, 0x11046, kSentenceBreakExtend},
+    {0x11047, 0x11048, kSentenceBreakSTerm},
+    {0x11066, 0x1106F, kSentenceBreakNumeric},
+    {0x11070, 0x11070, kSentenceBreakExtend},
+    {0x11071, 0x11072, kSentenceBreakOLetter},
+    {0x11073, 0x11074, kSentenceBreakExtend},
+    {0x11075, 0x11075, kSentenceBreakOLetter},
+    {0x1107F, 0x11082, kSentenceBreakExtend},
+    {0x11083, 0x110AF, kSentenceBreakOLetter},
+    {0x110B0, 0x110BA, kSentenceBreakExtend},
+    {0x110BD, 0x110BD, kSentenceBreakFormat},
+    {0x110BE, 0x110C1, kSentenceBreakSTerm},
+    {0x110C2, 0x110C2, kSentenceBreakExtend},
+    {0x110CD, 0x110CD, kSentenceBreakFormat},
+    {0x110D0, 0x110E8, kSentenceBreakOLetter},
+    {0x110F0, 0x110F9, kSentenceBreakNumeric},
+    {0x11100, 0x11102, kSentenceBreakExtend},
+    {0x11103, 0x11126, kSentenceBreakOLetter},
+    {0x11127, 0x11134, kSentenceBreakExtend},
+    {0x11136, 0x1113F, kSentenceBreakNumeric},
+    {0x11141, 0x11143, kSentenceBreakSTerm},
+    {0x11144, 0x11144, kSentenceBreakOLetter},
+    {0x11145, 0x11146, kSentenceBreakExtend},
+    {0x11147, 0x11147, kSentenceBreakOLetter},
+    {0x11150, 0x11172, kSentenceBreakOLetter},
+    {0x11173, 0x11173, kSentenceBreakExtend},
+    {0x11176, 0x11176, kSentenceBreakOLetter},
+    {0x11180, 0x11182, kSentenceBreakExtend},
+    {0x11183, 0x111B2, kSentenceBreakOLetter},
+    {0x111B3, 0x111C0, kSentenceBreakExtend},
+    {0x111C1, 0x111C4, kSentenceBreakOLetter},
+    {0x111C5, 0x111C6, kSentenceBreakSTerm},
+    {0x111C9, 0x111CC, kSentenceBreakExtend},
+    {0x111CD, 0x111CD, kSentenceBreakSTerm},
+    {0x111CE, 0x111CF, kSentenceBreakExtend},
+    {0x111D0, 0x111D9, kSentenceBreakNumeric},
+    {0x111DA, 0x111DA, kSentenceBreakOLetter},
+    {0x111DC, 0x111DC, kSentenceBreakOLetter},
+    {0x111DE, 0x111DF, kSentenceBreakSTerm},
+    {0x11200, 0x11211, kSentenceBreakOLetter},
+    {0x11213, 0x1122B, kSentenceBreakOLetter},
+    {0x1122C, 0x11237, kSentenceBreakExtend},
+    {0x11238, 0x11239, kSentenceBreakSTerm},
+    {0x1123B, 0x1123C, kSentenceBreakSTerm},
+    {0x1123E, 0x1123E, kSentenceBreakExtend},
+    {0x1123F, 0x11240, kSentenceBreakOLetter},
+    {0x11241, 0x11241, kSentenceBreakExtend},
+    {0x11280, 0x11286, kSentenceBreakOLetter},
+    {0x11288, 0x11288, kSentenceBreakOLetter},
+    {0x1128A, 0x1128D, kSentenceBreakOLetter},
+    {0x1128F, 0x1129D, kSentenceBreakOLetter},
+    {0x1129F, 0x112A8, kSentenceBreakOLetter},
+    {0x112A9, 0x112A9, kSentenceBreakSTerm},
+    {0x112B0, 0x112DE, kSentenceBreakOLetter},
+    {0x112DF, 0x112EA, kSentenceBreakExtend},
+    {0x112F0, 0x112F9, kSentenceBreakNumeric},
+    {0x11300, 0x11303, kSentenceBreakExtend},
+    {0x11305, 0x1130C, kSentenceBreakOLetter},
+    {0x1130F, 0x11310, kSentenceBreakOLetter},
+    {0x11313, 0x11328, kSentenceBreakOLetter},
+    {0x1132A, 0x11330, kSentenceBreakOLetter},
+    {0x11332, 0x11333, kSentenceBreakOLetter},
+    {0x11335, 0x11339, kSentenceBreakOLetter},
+    {0x1133B, 0x1133C, kSentenceBreakExtend},
+    {0x1133D, 0x1133D, kSentenceBreakOLetter},
+    {0x1133E, 0x11344, kSentenceBreakExtend},
+    {0x11347, 0x11348, kSentenceBreakExtend},
+    {0x1134B, 0x1134D, kSentenceBreakExtend},
+    {0x11350, 0x11350, kSentenceBreakOLetter},
+    {0x11357, 0x11357, kSentenceBreakExtend},
+    {0x1135D, 0x11361, kSentenceBreakOLetter},
+    {0x11362, 0x11363, kSentenceBreakExtend},
+    {0x11366, 0x1136C, kSentenceBreakExtend},
+    {0x11370, 0x11374, kSentenceBreakExtend},
+    {0x11400, 0x11434, kSentenceBreakOLetter},
+    {0x11435, 0x11446, kSentenceBreakExtend},
+    {0x11447, 0x1144A, kSentenceBreakOLetter},
+    {0x1144B, 0x1144C, kSentenceBreakSTerm},
+    {0x11450, 0x11459, kSentenceBreakNumeric},
+    {0x1145E, 0x1145E, kSentenceBreakExtend},
+    {0x1145F, 0x11461, kSentenceBreakOLetter},
+    {0x11480, 0x114AF, kSentenceBreakOLetter},
+    {0x114B0, 0x114C3, kSentenceBreakExtend},
+    {0x114C4, 0x114C5, kSentenceBreakOLetter},
+    {0x114C7, 0x114C7, kSentenceBreakOLetter},
+    {0x114D0, 0x114D9, kSentenceBreakNumeric},
+    {0x11580, 0x115AE, kSentenceBreakOLetter},
+    {0x115AF, 0x115B5, kSentenceBreakExtend},
+    {0x115B8, 0x115C0, kSentenceBreakExtend},
+    {0x115C2, 0x115C3, kSentenceBreakSTerm},
+    {0x115C9, 0x115D7, kSentenceBreakSTerm},
+    {0x115D8, 0x115DB, kSentenceBreakOLetter},
+    {0x115DC, 0x115DD, kSentenceBreakExtend},
+    {0x11600, 0x1162F, kSentenceBreakOLetter},
+    {0x11630, 0x11640, kSentenceBreakExtend},
+    {0x11641, 0x11642, kSentenceBreakSTerm},
+    {0x11644, 0x11644, kSentenceBreakOLetter},
+    {0x11650, 0x11659, kSentenceBreakNumeric},
+    {0x11680, 0x116AA, kSentenceBreakOLetter},
+    {0x116AB, 0x116B7, kSentenceBreakExtend},
+    {0x116B8, 0x116B8, kSentenceBreakOLetter},
+    {0x116C0, 0x116C9, kSentenceBreakNumeric},
+    {0x11700, 0x1171A, kSentenceBreakOLetter},
+    {0x1171D, 0x1172B, kSentenceBreakExtend},
+    {0x11730, 0x11739, kSentenceBreakNumeric},
+    {0x1173C, 0x1173E, kSentenceBreakSTerm},
+    {0x11740, 0x11746, kSentenceBreakOLetter},
+    {0x11800, 0x1182B, kSentenceBreakOLetter},
+    {0x1182C, 0x1183A, kSentenceBreakExtend},
+    {0x118A0, 0x118BF, kSentenceBreakUpper},
+    {0x118C0, 0x118DF, kSentenceBreakLower},
+    {0x118E0, 0x118E9, kSentenceBreakNumeric},
+    {0x118FF, 0x11906, kSentenceBreakOLetter},
+    {0x11909, 0x11909, kSentenceBreakOLetter},
+    {0x1190C, 0x11913, kSentenceBreakOLetter},
+    {0x11915, 0x11916, kSentenceBreakOLetter},
+    {0x11918, 0x1192F, kSentenceBreakOLetter},
+    {0x11930, 0x11935, kSentenceBreakExtend},
+    {0x11937, 0x11938, kSentenceBreakExtend},
+    {0x1193B, 0x1193E, kSentenceBreakExtend},
+    {0x1193F, 0x1193F, kSentenceBreakOLetter},
+    {0x11940, 0x11940, kSentenceBreakExtend},
+    {0x11941, 0x11941, kSentenceBreakOLetter},
+    {0x11942, 0x11943, kSentenceBreakExtend},
+    {0x11944, 0x11944, kSentenceBreakSTerm},
+    {0x11946, 0x11946, kSentenceBreakSTerm},
+    {0x11950, 0x11959, kSentenceBreakNumeric},
+    {0x119A0, 0x119A7, kSentenceBreakOLetter},
+    {0x119AA, 0x119D0, kSentenceBreakOLetter},
+    {0x119D1, 0x119D7, kSentenceBreakExtend},
+    {0x119DA, 0x119E0, kSentenceBreakExtend},
+    {0x119E1, 0x119E1, kSentenceBreakOLetter},
+    {0x119E3, 0x119E3, kSentenceBreakOLetter},
+    {0x119E4, 0x119E4, kSentenceBreakExtend},
+    {0x11A00, 0x11A00, kSentenceBreakOLetter},
+    {0x11A01, 0x11A0A, kSentenceBreakExtend},
+    {0x11A0B, 0x11A32, kSentenceBreakOLetter},
+    {0x11A33, 0x11A39, kSentenceBreakExtend},
+    {0x11A3A, 0x11A3A, kSentenceBreakOLetter},
+    {0x11A3B, 0x11A3E, kSentenceBreakExtend},
+    {0x11A42, 0x11A43, kSentenceBreakSTerm},
+    {0x11A47, 0x11A47, kSentenceBreakExtend},
+    {0x11A50, 0x11A50, kSentenceBreakOLetter},
+    {0x11A51, 0x11A5B, kSentenceBreakExtend},
+    {0x11A5C, 0x11A89, kSentenceBreakOLetter},
+    {0x11A8A, 0x11A99, kSentenceBreakExtend},
+    {0x11A9B, 0x11A9C, kSentenceBreakSTerm},
+    {0x11A9D, 0x11A9D, kSentenceBreakOLetter},
+    {0x11AB0, 0x11AF8, kSentenceBreakOLetter},
+    {0x11C00, 0x11C08, kSentenceBreakOLetter},
+    {0x11C0A, 0x11C2E, kSentenceBreakOLetter},
+    {0x11C2F, 0x11C36, kSentenceBreakExtend},
+    {0x11C38, 0x11C3F, kSentenceBreakExtend},
+    {0x11C40, 0x11C40, kSentenceBreakOLetter},
+    {0x11C41, 0x11C42, kSentenceBreakSTerm},
+    {0x11C50, 0x11C59, kSentenceBreakNumeric},
+    {0x11C72, 0x11C8F, kSentenceBreakOLetter},
+    {0x11C92, 0x11CA7, kSentenceBreakExtend},
+    {0x11CA9, 0x11CB6, kSentenceBreakExtend},
+    {0x11D00, 0x11D06, kSentenceBreakOLetter},
+    {0x11D08, 0x11D09, kSentenceBreakOLetter},
+    {0x11D0B, 0x11D30, kSentenceBreakOLetter},
+    {0x11D31, 0x11D36, kSentenceBreakExtend},
+    {0x11D3A, 0x11D3A, kSentenceBreakExtend},
+    {0x11D3C, 0x11D3D, kSentenceBreakExtend},
+    {0x11D3F, 0x11D45, kSentenceBreakExtend},
+    {0x11D46, 0x11D46, kSentenceBreakOLetter},
+    {0x11D47, 0x11D47, kSentenceBreakExtend},
+    {0x11D50, 0x11D59, kSentenceBreakNumeric},
+    {0x11D60, 0x11D65, kSentenceBreakOLetter},
+    {0x11D67, 0x11D68, kSentenceBreakOLetter},
+    {0x11D6A, 0x11D89, kSentenceBreakOLetter},
+    {0x11D8A, 0x11D8E, kSentenceBreakExtend},
+    {0x11D90, 0x11D91, kSentenceBreakExtend},
+    {0x11D93, 0x11D97, kSentenceBreakExtend},
+    {0x11D98, 0x11D98, kSentenceBreakOLetter},
+    {0x11DA0, 0x11DA9, kSentenceBreakNumeric},
+    {0x11EE0, 0x11EF2, kSentenceBreakOLetter},
+    {0x11EF3, 0x11EF6, kSentenceBreakExtend},
+    {0x11EF7, 0x11EF8, kSentenceBreakSTerm},
+    {0x11F00, 0x11F01, kSentenceBreakExtend},
+    {0x11F02, 0x11F02, kSentenceBreakOLetter},
+    {0x11F03, 0x11F03, kSentenceBreakExtend},
+    {0x11F04, 0x11F10, kSentenceBreakOLetter},
+    {0x11F12, 0x11F33, kSentenceBreakOLetter},
+    {0x11F34, 0x11F3A, kSentenceBreakExtend},
+    {0x11F3E, 0x11F42, kSentenceBreakExtend},
+    {0x11F43, 0x11F44, kSentenceBreakSTerm},
+    {0x11F50, 0x11F59, kSentenceBreakNumeric},
+    {0x11FB0, 0x11FB0, kSentenceBreakOLetter},
+    {0x12000, 0x12399, kSentenceBreakOLetter},
+    {0x12400, 0x1246E, kSentenceBreakOLetter},
+    {0x12480, 0x12543, kSentenceBreakOLetter},
+    {0x12F90, 0x12FF0, kSentenceBreakOLetter},
+    {0x13000, 0x1342F, kSentenceBreakOLetter},
+    {0x13430, 0x1343F, kSentenceBreakFormat},
+    {0x13440, 0x13440, kSentenceBreakExtend},
+    {0x13441, 0x13446, kSentenceBreakOLetter},
+    {0x13447, 0x13455, kSentenceBreakExtend},
+    {0x14400, 0x14646, kSentenceBreakOLetter},
+    {0x16800, 0x16A38, kSentenceBreakOLetter},
+    {0x16A40, 0x16A5E, kSentenceBreakOLetter},
+    {0x16A60, 0x16A69, kSentenceBreakNumeric},
+    {0x16A6E, 0x16A6F, kSentenceBreakSTerm},
+    {0x16A70, 0x16ABE, kSentenceBreakOLetter},
+    {0x16AC0, 0x16AC9, kSentenceBreakNumeric},
+    {0x16AD0, 0x16AED, kSentenceBreakOLetter},
+    {0x16AF0, 0x16AF4, kSentenceBreakExtend},
+    {0x16AF5, 0x16AF5, kSentenceBreakSTerm},
+    {0x16B00, 0x16B2F, kSentenceBreakOLetter},
+    {0x16B30, 0x16B36, kSentenceBreakExtend},
+    {0x16B37, 0x16B38, kSentenceBreakSTerm},
+    {0x16B40, 0x16B43, kSentenceBreakOLetter},
+    {0x16B44, 0x16B44, kSentenceBreakSTerm},
+    {0x16B50, 0x16B59, kSentenceBreakNumeric},
+    {0x16B63, 0x16B77, kSentenceBreakOLetter},
+    {0x16B7D, 0x16B8F, kSentenceBreakOLetter},
+    {0x16E40, 0x16E5F, kSentenceBreakUpper},
+    {0x16E60, 0x16E7F, kSentenceBreakLower},
+    {0x16E98, 0x16E98, kSentenceBreakSTerm},
+    {0x16F00, 0x16F4A, kSentenceBreakOLetter},
+    {0x16F4F, 0x16F4F, kSentenceBreakExtend},
+    {0x16F50, 0x16F50, kSentenceBreakOLetter},
+    {0x16F51, 0x16F87, kSentenceBreakExtend},
+    {0x16F8F, 0x16F92, kSentenceBreakExtend},
+    {0x16F93, 0x16F9F, kSentenceBreakOLetter},
+    {0x16FE0, 0x16FE1, kSentenceBreakOLetter},
+    {0x16FE3, 0x16FE3, kSentenceBreakOLetter},
+    {0x16FE4, 0x16FE4, kSentenceBreakExtend},
+    {0x16FF0, 0x16FF1, kSentenceBreakExtend},
+    {0x17000, 0x187F7, kSentenceBreakOLetter},
+    {0x18800, 0x18CD5, kSentenceBreakOLetter},
+    {0x18D00, 0x18D08, kSentenceBreakOLetter},
+    {0x1AFF0, 0x1AFF3, kSentenceBreakOLetter},
+    {0x1AFF5, 0x1AFFB, kSentenceBreakOLetter},
+    {0x1AFFD, 0x1AFFE, kSentenceBreakOLetter},
+    {0x1B000, 0x1B122, kSentenceBreakOLetter},
+    {0x1B132, 0x1B132, kSentenceBreakOLetter},
+    {0x1B150, 0x1B152, kSentenceBreakOLetter},
+    {0x1B155, 0x1B155, kSentenceBreakOLetter},
+    {0x1B164, 0x1B167, kSentenceBreakOLetter},
+    {0x1B170, 0x1B2FB, kSentenceBreakOLetter},
+    {0x1BC00, 0x1BC6A, kSentenceBreakOLetter},
+    {0x1BC70, 0x1BC7C, kSentenceBreakOLetter},
+    {0x1BC80, 0x1BC88, kSentenceBreakOLetter},
+    {0x1BC90, 0x1BC99, kSentenceBreakOLetter},
+    {0x1BC9D, 0x1BC9E, kSentenceBreakExtend},
+    {0x1BC9F, 0x1BC9F, kSentenceBreakSTerm},
+    {0x1BCA0, 0x1BCA3, kSentenceBreakFormat},
+    {0x1CF00, 0x1CF2D, kSentenceBreakExtend},
+    {0x1CF30, 0x1CF46, kSentenceBreakExtend},
+    {0x1D165, 0x1D169, kSentenceBreakExtend},
+    {0x1D16D, 0x1D172, kSentenceBreakExtend},
+    {0x1D173, 0x1D17A, kSentenceBreakFormat},
+    {0x1D17B, 0x1D182, kSentenceBreakExtend},
+    {0x1D185, 0x1D18B, kSentenceBreakExtend},
+    {0x1D1AA, 0x1D1AD, kSentenceBreakExtend},
+    {0x1D242, 0x1D244, kSentenceBreakExtend},
+    {0x1D400, 0x1D419, kSentenceBreakUpper},
+    {0x1D41A, 0x1D433, kSentenceBreakLower},
+    {0x1D434, 0x1D44D, kSentenceBreakUpper},
+    {0x1D44E, 0x1D454, kSentenceBreakLower},
+    {0x1D456, 0x1D467, kSentenceBreakLower},
+    {0x1D468, 0x1D481, kSentenceBreakUpper},
+    {0x1D482, 0x1D49B, kSentenceBreakLower},
+    {0x1D49C, 0x1D49C, kSentenceBreakUpper},
+    {0x1D49E, 0x1D49F, kSentenceBreakUpper},
+    {0x1D4A2, 0x1D4A2, kSentenceBreakUpper},
+    {0x1D4A5, 0x1D4A6, kSentenceBreakUpper},
+    {0x1D4A9, 0x1D4AC, kSentenceBreakUpper},
+    {0x1D4AE, 0x1D4B5, kSentenceBreakUpper},
+    {0x1D4B6, 0x1D4B9, kSentenceBreakLower},
+    {0x1D4BB, 0x1D4BB, kSentenceBreakLower},
+    {0x1D4BD, 0x1D4C3, kSentenceBreakLower},
+    {0x1D4C5, 0x1D4CF, kSentenceBreakLower},
+    {0x1D4D0, 0x1D4E9, kSentenceBreakUpper},
+    {0x1D4EA, 0x1D503, kSentenceBreakLower},
+    {0x1D504, 0x1D505, kSentenceBreakUpper},
+    {0x1D507, 0x1D50A, kSentenceBreakUpper},
+    {0x1D50D, 0x1D514, kSentenceBreakUpper},
+    {0x1D516, 0x1D51C, kSentenceBreakUpper},
+    {0x1D51E, 0x1D537, kSentenceBreakLower},
+    {0x1D538, 0x1D539, kSentenceBreakUpper},
+    {0x1D53B, 0x1D53E, kSentenceBreakUpper},
+    {0x1D540, 0x1D544, kSentenceBreakUpper},
+    {0x1D546, 0x1D546, kSentenceBreakUpper},
+    {0x1D54A, 0x1D550, kSentenceBreakUpper},
+    {0x1D552, 0x1D56B, kSentenceBreakLower},
+    {0x1D56C, 0x1D585, kSentenceBreakUpper},
+    {0x1D586, 0x1D59F, kSentenceBreakLower},
+    {0x1D5A0, 0x1D5B9, kSentenceBreakUpper},
+    {0x1D5BA, 0x1D5D3, kSentenceBreakLower},
+    {0x1D5D4, 0x1D5ED, kSentenceBreakUpper},
+    {0x1D5EE, 0x1D607, kSentenceBreakLower},
+    {0x1D608, 0x1D621, kSentenceBreakUpper},
+    {0x1D622, 0x1D63B, kSentenceBreakLower},
+    {0x1D63C, 0x1D655, kSentenceBreakUpper},
+    {0x1D656, 0x1D66F, kSentenceBreakLower},
+    {0x1D670, 0x1D689, kSentenceBreakUpper},
+    {0x1D68A, 0x1D6A5, kSentenceBreakLower},
+    {0x1D6A8, 0x1D6C0, kSentenceBreakUpper},
+    {0x1D6C2, 0x1D6DA, kSentenceBreakLower},
+    {0x1D6DC, 0x1D6E1, kSentenceBreakLower},
+    {0x1D6E2, 0x1D6FA, kSentenceBreakUpper},
+    {0x1D6FC, 0x1D714, kSentenceBreakLower},
+    {0x1D716, 0x1D71B, kSentenceBreakLower},
+    {0x1D71C, 0x1D734, kSentenceBreakUpper},
+    {0x1D736, 0x1D74E, kSentenceBreakLower},
+    {0x1D750, 0x1D755, kSentenceBreakLower},
+    {0x1D756, 0x1D76E, kSentenceBreakUpper},
+    {0x1D770, 0x1D788, kSentenceBreakLower},
+    {0x1D78A, 0x1D78F, kSentenceBreakLower},
+    {0x1D790, 0x1D7A8, kSentenceBreakUpper},
+    {0x1D7AA, 0x1D7C2, kSentenceBreakLower},
+    {0x1D7C4, 0x1D7C9, kSentenceBreakLower},
+    {0x1D7CA, 0x1D7CA, kSentenceBreakUpper},
+    {0x1D7CB, 0x1D7CB, kSentenceBreakLower},
+    {0x1D7CE, 0x1D7FF, kSentenceBreakNumeric},
+    {0x1DA00, 0x1DA36, kSentenceBreakExtend},
+    {0x1DA3B, 0x1DA6C, kSentenceBreakExtend},
+    {0x1DA75, 0x1DA75, kSentenceBreakExtend},
+    {0x1DA84, 0x1DA84, kSentenceBreakExtend},
+    {0x1DA88, 0x1DA88, kSentenceBreakSTerm},
+    {0x1DA9B, 0x1DA9F, kSentenceBreakExtend},
+    {0x1DAA1, 0x1DAAF, kSentenceBreakExtend},
+    {0x1DF00, 0x1DF09, kSentenceBreakLower},
+    {0x1DF0A, 0x1DF0A, kSentenceBreakOLetter},
+    {0x1DF0B, 0x1DF1E, kSentenceBreakLower},
+    {0x1DF25, 0x1DF2A, kSentenceBreakLower},
+    {0x1E000, 0x1E006, kSentenceBreakExtend},
+    {0x1E008, 0x1E018, kSentenceBreakExtend},
+    {0x1E01B, 0x1E021, kSentenceBreakExtend},
+    {0x1E023, 0x1E024, kSentenceBreakExtend},
+    {0x1E026, 0x1E02A, kSentenceBreakExtend},
+    {0x1E030, 0x1E06D, kSentenceBreakLower},
+    {0x1E08F, 0x1E08F, kSentenceBreakExtend},
+    {0x1E100, 0x1E12C, kSentenceBreakOLetter},
+    {0x1E130, 0x1E136, kSentenceBreakExtend},
+    {0x1E137, 0x1E13D, kSentenceBreakOLetter},
+    {0x1E140, 0x1E149, kSentenceBreakNumeric},
+    {0x1E14E, 0x1E14E, kSentenceBreakOLetter},
+    {0x1E290, 0x1E2AD, kSentenceBreakOLetter},
+    {0x1E2AE, 0x1E2AE, kSentenceBreakExtend},
+    {0x1E2C0, 0x1E2EB, kSentenceBreakOLetter},
+    {0x1E2EC, 0x1E2EF, kSentenceBreakExtend},
+    {0x1E2F0, 0x1E2F9, kSentenceBreakNumeric},
+    {0x1E4D0, 0x1E4EB, kSentenceBreakOLetter},
+    {0x1E4EC, 0x1E4EF, kSentenceBreakExtend},
+    {0x1E4F0, 0x1E4F9, kSentenceBreakNumeric},
+    {0x1E7E0, 0x1E7E6, kSentenceBreakOLetter},
+    {0x1E7E8, 0x1E7EB, kSentenceBreakOLetter},
+    {0x1E7ED, 0x1E7EE, kSentenceBreakOLetter},
+    {0x1E7F0, 0x1E7FE, kSentenceBreakOLetter},
+    {0x1E800, 0x1E8C4, kSentenceBreakOLetter},
+    {0x1E8D0, 0x1E8D6, kSentenceBreakExtend},
+    {0x1E900, 0x1E921, kSentenceBreakUpper},
+    {0x1E922, 0x1E943, kSentenceBreakLower},
+    {0x1E944, 0x1E94A, kSentenceBreakExtend},
+    {0x1E94B, 0x1E94B, kSentenceBreakOLetter},
+    {0x1E950, 0x1E959, kSentenceBreakNumeric},
+    {0x1EE00, 0x1EE03, kSentenceBreakOLetter},
+    {0x1EE05, 0x1EE1F, kSentenceBreakOLetter},
+    {0x1EE21, 0x1EE22, kSentenceBreakOLetter},
+    {0x1EE24, 0x1EE24, kSentenceBreakOLetter},
+    {0x1EE27, 0x1EE27, kSentenceBreakOLetter},
+    {0x1EE29, 0x1EE32, kSentenceBreakOLetter},
+    {0x1EE34, 0x1EE37, kSentenceBreakOLetter},
+    {0x1EE39, 0x1EE39, kSentenceBreakOLetter},
+    {0x1EE3B, 0x1EE3B, kSentenceBreakOLetter},
+    {0x1EE42, 0x1EE42, kSentenceBreakOLetter},
+    {0x1EE47, 0x1EE47, kSentenceBreakOLetter},
+    {0x1EE49, 0x1EE49, kSentenceBreakOLetter},
+    {0x1EE4B, 0x1EE4B, kSentenceBreakOLetter},
+    {0x1EE4D, 0x1EE4F, kSentenceBreakOLetter},
+    {0x1EE51, 0x1EE52, kSentenceBreakOLetter},
+    {0x1EE54, 0x1EE54, kSentenceBreakOLetter},
+    {0x1EE57, 0x1EE57, kSentenceBreakOLetter},
+    {0x1EE59, 0x1EE59, kSentenceBreakOLetter},
+    {0x1EE5B, 0x1EE5B, kSentenceBreakOLetter},
+    {0x1EE5D, 0x1EE5D, kSentenceBreakOLetter},
+    {0x1EE5F, 0x1EE5F, kSentenceBreakOLetter},
+    {0x1EE61, 0x1EE62, kSentenceBreakOLetter},
+    {0x1EE64, 0x1EE64, kSentenceBreakOLetter},
+    {0x1EE67, 0x1EE6A, kSentenceBreakOLetter},
+    {0x1EE6C, 0x1EE72, kSentenceBreakOLetter},
+    {0x1EE74, 0x1EE77, kSentenceBreakOLetter},
+    {0x1EE79, 0x1EE7C, kSentenceBreakOLetter},
+    {0x1EE7E, 0x1EE7E, kSentenceBreakOLetter},
+    {0x1EE80, 0x1EE89, kSentenceBreakOLetter},
+    {0x1EE8B, 0x1EE9B, kSentenceBreakOLetter},
+    {0x1EEA1, 0x1EEA3, kSentenceBreakOLetter},
+    {0x1EEA5, 0x1EEA9, kSentenceBreakOLetter},
+    {0x1EEAB, 0x1EEBB, kSentenceBreakOLetter},
+    {0x1F130, 0x1F149, kSentenceBreakUpper},
+    {0x1F150, 0x1F169, kSentenceBreakUpper},
+    {0x1F170, 0x1F189, kSentenceBreakUpper},
+    {0x1F676, 0x1F678, kSentenceBreakClose},
+    {0x1FBF0, 0x1FBF9, kSentenceBreakNumeric},
+    {0x20000, 0x2A6DF, kSentenceBreakOLetter},
+    {0x2A700, 0x2B739, kSentenceBreakOLetter},
+    {0x2B740, 0x2B81D, kSentenceBreakOLetter},
+    {0x2B820, 0x2CEA1, kSentenceBreakOLetter},
+    {0x2CEB0, 0x2EBE0, kSentenceBreakOLetter},
+    {0x2EBF0, 0x2EE5D, kSentenceBreakOLetter},
+    {0x2F800, 0x2FA1D, kSentenceBreakOLetter},
+    {0x30000, 0x3134A, kSentenceBreakOLetter},
+    {0x31350, 0x323AF, kSentenceBreakOLetter},
+    {0xE0001, 0xE0001, kSentenceBreakFormat},
+    {0xE0020, 0xE007F, kSentenceBreakExtend},
+    {0xE0100, 0xE01EF, kSentenceBreakExtend},
+}};
+
+struct CodepointRange {
+  uint32_t first;
+  uint32_t last;
+};
+
+inline constexpr std::array<CodepointRange, 78> kExtendedPictographicRanges{{
+    {0x00A9, 0x00A9},
+    {0x00AE, 0x00AE},
+    {0x203C, 0x203C},
+    {0x2049, 0x2049},
+    {0x2122, 0x2122},
+    {0x2139, 0x2139},
+    {0x2194, 0x2199},
+    {0x21A9, 0x21AA},
+    {0x231A, 0x231B},
+    {0x2328, 0x2328},
+    {0x2388, 0x2388},
+    {0x23CF, 0x23CF},
+    {0x23E9, 0x23F3},
+    {0x23F8, 0x23FA},
+    {0x24C2, 0x24C2},
+    {0x25AA, 0x25AB},
+    {0x25B6, 0x25B6},
+    {0x25C0, 0x25C0},
+    {0x25FB, 0x25FE},
+    {0x2600, 0x2605},
+    {0x2607, 0x2612},
+    {0x2614, 0x2685},
+    {0x2690, 0x2705},
+    {0x2708, 0x2712},
+    {0x2714, 0x2714},
+    {0x2716, 0x2716},
+    {0x271D, 0x271D},
+    {0x2721, 0x2721},
+    {0x2728, 0x2728},
+    {0x2733, 0x2734},
+    {0x2744, 0x2744},
+    {0x2747, 0x2747},
+    {0x274C, 0x274C},
+    {0x274E, 0x274E},
+    {0x2753, 0x2755},
+    {0x2757, 0x2757},
+    {0x2763, 0x2767},
+    {0x2795, 0x2797},
+    {0x27A1, 0x27A1},
+    {0x27B0, 0x27B0},
+    {0x27BF, 0x27BF},
+    {0x2934, 0x2935},
+    {0x2B05, 0x2B07},
+    {0x2B1B, 0x2B1C},
+    {0x2B50, 0x2B50},
+    {0x2B55, 0x2B55},
+    {0x3030, 0x3030},
+    {0x303D, 0x303D},
+    {0x3297, 0x3297},
+    {0x3299, 0x3299},
+    {0x1F000, 0x1F0FF},
+    {0x1F10D, 0x1F10F},
+    {0x1F12F, 0x1F12F},
+    {0x1F16C, 0x1F171},
+    {0x1F17E, 0x1F17F},
+    {0x1F18E, 0x1F18E},
+    {0x1F191, 0x1F19A},
+    {0x1F1AD, 0x1F1E5},
+    {0x1F201, 0x1F20F},
+    {0x1F21A, 0x1F21A},
+    {0x1F22F, 0x1F22F},
+    {0x1F232, 0x1F23A},
+    {0x1F23C, 0x1F23F},
+    {0x1F249, 0x1F3FA},
+    {0x1F400, 0x1F53D},
+    {0x1F546, 0x1F64F},
+    {0x1F680, 0x1F6FF},
+    {0x1F774, 0x1F77F},
+    {0x1F7D5, 0x1F7FF},
+    {0x1F80C, 0x1F80F},
+    {0x1F848, 0x1F84F},
+    {0x1F85A, 0x1F85F},
+    {0x1F888, 0x1F88F},
+    {0x1F8AE, 0x1F8FF},
+    {0x1F90C, 0x1F93A},
+    {0x1F93C, 0x1F945},
+    {0x1F947, 0x1FAFF},
+    {0x1FC00, 0x1FFFD},
+}};
+
+inline constexpr std::array<CodepointRange, 169>
+    kIndicConjunctBreakExtendRanges{{
+        {0x0300, 0x034E},
+        {0x0350, 0x036F},
+        {0x0483, 0x0487},
+        {0x0591, 0x05BD},
+        {0x05BF, 0x05BF},
+        {0x05C1, 0x05C2},
+        {0x05C4, 0x05C5},
+        {0x05C7, 0x05C7},
+        {0x0610, 0x061A},
+        {0x064B, 0x065F},
+        {0x0670, 0x0670},
+        {0x06D6, 0x06DC},
+        {0x06DF, 0x06E4},
+        {0x06E7, 0x06E8},
+        {0x06EA, 0x06ED},
+        {0x0711, 0x0711},
+        {0x0730, 0x074A},
+        {0x07EB, 0x07F3},
+        {0x07FD, 0x07FD},
+        {0x0816, 0x0819},
+        {0x081B, 0x0823},
+        {0x0825, 0x0827},
+        {0x0829, 0x082D},
+        {0x0859, 0x085B},
+        {0x0898, 0x089F},
+        {0x08CA, 0x08E1},
+        {0x08E3, 0x08FF},
+        {0x093C, 0x093C},
+        {0x0951, 0x0954},
+        {0x09BC, 0x09BC},
+        {0x09FE, 0x09FE},
+        {0x0A3C, 0x0A3C},
+        {0x0ABC, 0x0ABC},
+        {0x0B3C, 0x0B3C},
+        {0x0C3C, 0x0C3C},
+        {0x0C55, 0x0C56},
+        {0x0CBC, 0x0CBC},
+        {0x0D3B, 0x0D3C},
+        {0x0E38, 0x0E3A},
+        {0x0E48, 0x0E4B},
+        {0x0EB8, 0x0EBA},
+        {0x0EC8, 0x0ECB},
+        {0x0F18, 0x0F19},
+        {0x0F35, 0x0F35},
+        {0x0F37, 0x0F37},
+        {0x0F39, 0x0F39},
+        {0x0F71, 0x0F72},
+        {0x0F74, 0x0F74},
+        {0x0F7A, 0x0F7D},
+        {0x0F80, 0x0F80},
+        {0x0F82, 0x0F84},
+        {0x0F86, 0x0F87},
+        {0x0FC6, 0x0FC6},
+        {0x1037, 0x1037},
+        {0x1039, 0x103A},
+        {0x108D, 0x108D},
+        {0x135D, 0x135F},
+        {0x1714, 0x1714},
+        {0x17D2, 0x17D2},
+        {0x17DD, 0x17DD},
+        {0x18A9, 0x18A9},
+        {0x1939, 0x193B},
+        {0x1A17, 0x1A18},
+        {0x1A60, 0x1A60},
+        {0x1A75, 0x1A7C},
+        {0x1A7F, 0x1A7F},
+        {0x1AB0, 0x1ABD},
+        {0x1ABF, 0x1ACE},
+        {0x1B34, 0x1B34},
+        {0x1B6B, 0x1B73},
+        {0x1BAB, 0x1BAB},
+        {0x1BE6, 0x1BE6},
+        {0x1C37, 0x1C37},
+        {0x1CD0, 0x1CD2},
+        {0x1CD4, 0x1CE0},
+        {0x1CE2, 0x1CE8},
+        {0x1CED, 0x1CED},
+        {0x1CF4, 0x1CF4},
+        {0x1CF8, 0x1CF9},
+        {0x1DC0, 0x1DFF},
+        {0x200D, 0x200D},
+        {0x20D0, 0x20DC},
+        {0x20E1, 0x20E1},
+        {0x20E5, 0x20F0},
+        {0x2CEF, 0x2CF1},
+        {0x2D7F, 0x2D7F},
+        {0x2DE0, 0x2DFF},
+        {0x302A, 0x302F},
+        {0x3099, 0x309A},
+        {0xA66F, 0xA66F},
+        {0xA674, 0xA67D},
+        {0xA69E, 0xA69F},
+        {0xA6F0, 0xA6F1},
+        {0xA82C, 0xA82C},
+        {0xA8E0, 0xA8F1},
+        {0xA92B, 0xA92D},
+        {0xA9B3, 0xA9B3},
+        {0xAAB0, 0xAAB0},
+        {0xAAB2, 0xAAB4},
+        {0xAAB7, 0xAAB8},
+        {0xAABE, 0xAABF},
+        {0xAAC1, 0xAAC1},
+        {0xAAF6, 0xAAF6},
+        {0xABED, 0xABED},
+        {0xFB1E, 0xFB1E},
+        {0xFE20, 0xFE2F},
+        {0x101FD, 0x101FD},
+        {0x102E0, 0x102E0},
+        {0x10376, 0x1037A},
+        {0x10A0D, 0x10A0D},
+        {0x10A0F, 0x10A0F},
+        {0x10A38, 0x10A3A},
+        {0x10A3F, 0x10A3F},
+        {0x10AE5, 0x10AE6},
+        {0x10D24, 0x10D27},
+        {0x10EAB, 0x10EAC},
+        {0x10EFD, 0x10EFF},
+        {0x10F46, 0x10F50},
+        {0x10F82, 0x10F85},
+        {0x11070, 0x11070},
+        {0x1107F, 0x1107F},
+        {0x110BA, 0x110BA},
+        {0x11100, 0x11102},
+        {0x11133, 0x11134},
+        {0x11173, 0x11173},
+        {0x111CA, 0x111CA},
+        {0x11236, 0x11236},
+        {0x112E9, 0x112EA},
+        {0x1133B, 0x1133C},
+        {0x11366, 0x1136C},
+        {0x11370, 0x11374},
+        {0x11446, 0x11446},
+        {0x1145E, 0x1145E},
+        {0x114C3, 0x114C3},
+        {0x115C0, 0x115C0},
+        {0x116B7, 0x116B7},
+        {0x1172B, 0x1172B},
+        {0x1183A, 0x1183A},
+        {0x1193E, 0x1193E},
+        {0x11943, 0x11943},
+        {0x11A34, 0x11A34},
+        {0x11A47, 0x11A47},
+        {0x11A99, 0x11A99},
+        {0x11D42, 0x11D42},
+        {0x11D44, 0x11D45},
+        {0x11D97, 0x11D97},
+        {0x11F42, 0x11F42},
+        {0x16AF0, 0x16AF4},
+        {0x16B30, 0x16B36},
+        {0x1BC9E, 0x1BC9E},
+        {0x1D165, 0x1D165},
+        {0x1D167, 0x1D169},
+        {0x1D16E, 0x1D172},
+        {0x1D17B, 0x1D182},
+        {0x1D185, 0x1D18B},
+        {0x1D1AA, 0x1D1AD},
+        {0x1D242, 0x1D244},
+        {0x1E000, 0x1E006},
+        {0x1E008, 0x1E018},
+        {0x1E01B, 0x1E021},
+        {0x1E023, 0x1E024},
+        {0x1E026, 0x1E02A},
+        {0x1E08F, 0x1E08F},
+        {0x1E130, 0x1E136},
+        {0x1E2AE, 0x1E2AE},
+        {0x1E2EC, 0x1E2EF},
+        {0x1E4EC, 0x1E4EF},
+        {0x1E8D0, 0x1E8D6},
+        {0x1E944, 0x1E94A},
+    }};
+
+inline constexpr std::array<uint32_t, 6> kIndicConjunctBreakLinkers{{
+    0x094D, 0x09CD, 0x0ACD, 0x0B4D, 0x0C4D, 0x0D4D,
+}};
+}  // namespace subset
@@ -1,17 +1,28 @@
+#include <hb-ot.h>
 #include <hb.h>
 
+#include <algorithm>
 #include <array>
 #include <cstddef>
 #include <cstdint>
+#include <initializer_list>
+#include <iterator>
 #include <limits>
+#include <memory>
 #include <mutex>
+#include <utility>
 #include <vector>
 
 extern "C" {
 #include <SheenBidi.h>
 }
 
+#include "case_mapping_table.h"
+#include "emoji_table.h"
+#include "script_extensions_table.h"
+#include "segmentation_table.h"
 #include "subset.h"
+#include "vertical_orientation_table.h"
 
 namespace {
 hb_font_t* CreateFont(hb_blob_t* blob, unsigned int index) noexcept {
@@ -125,42 +136,1756 @@ struct BidiLineDeleter {
   void operator()(SBLineRef line) noexcept { SBLineRelease(line); }
 };
 
-void ScriptsForRun(const uint32_t* unichars, const SBRun& run,
-                   std::vector<hb_script_t>& scripts) noexcept {
+// Large enough to hold the biggest Script_Extensions set.
+constexpr std::size_t kMaxScriptSetSize = 32;
+
+// N.B. Same as the maximum explicit embedding depth used by the bidi
+// algorithm when pairing brackets.
+constexpr std::size_t kMaxBracketDepth = 63;
+
+struct ScriptSet {
+  std::array<hb_script_t, kMaxScriptSetSize> scripts{};
+  std::size_t count{0};
+
+  bool IsEmpty() const noexcept { return count == 0; }
+
+  hb_script_t First() const noexcept {
+    return count == 0 ? HB_SCRIPT_COMMON : scripts[0];
+  }
+
+  void Assign(hb_script_t script) noexcept {
+    scripts[0] = script;
+    count = 1;
+  }
+
+  // Intersects this set with `other` in place. Returns `false`, leaving
+  // this set untouched, if the intersection is empty.
+  bool Intersect(const ScriptSet& other) noexcept {
+    std::size_t new_count{0};
+    std::array<hb_script_t, kMaxScriptSetSize> new_scripts{};
+    for (std::size_t index = 0; index < count; ++index) {
+      for (std::size_t other_index = 0; other_index < other.count;
+           ++other_index) {
+        if (scripts[index] == other.scripts[other_index]) {
+          new_scripts[new_count++] = scripts[index];
+          break;
+        }
+      }
+    }
+
+    if (new_count == 0) {
+      return false;
+    }
+
+    scripts = new_scripts;
+    count = new_count;
+    return true;
+  }
+};
+
+// Returns the range of the sorted `ranges` table covering `unichar`, or
+// `nullptr` if none does.
+template <typename Ranges>
+const typename Ranges::value_type* FindRange(const Ranges& ranges,
+                                             uint32_t unichar) noexcept {
+  auto range = std::upper_bound(
+      ranges.begin(), ranges.end(), unichar,
+      [](uint32_t value, const typename Ranges::value_type& range) {
+        return value < range.first;
+      });
+  if (range != ranges.begin() && unichar <= (--range)->last) {
+    return &*range;
+  }
+
+  return nullptr;
+}
+
+// Fills `script_set` with the Script_Extensions of `unichar`, falling back
+// to its Script property value. Common and Inherited characters, that
+// don't have any explicit extensions, leave the set empty.
+void ScriptSetForCodepoint(hb_unicode_funcs_t* unicode_funcs, uint32_t unichar,
+                           ScriptSet& script_set) noexcept {
+  script_set.count = 0;
+
+  const auto* range = FindRange(subset::kScriptExtensionRanges, unichar);
+  if (range != nullptr) {
+    auto count = std::min<std::size_t>(range->script_count, kMaxScriptSetSize);
+    for (std::size_t index = 0; index < count; ++index) {
+      script_set.scripts[index] = static_cast<hb_script_t>(
+          subset::kScriptExtensionScripts[range->script_index + index]);
+    }
+
+    script_set.count = count;
+    return;
+  }
+
+  auto script = hb_unicode_script(unicode_funcs, unichar);
+  if (script != HB_SCRIPT_COMMON && script != HB_SCRIPT_INHERITED) {
+    script_set.Assign(script);
+  }
+}
+
+struct BracketEntry {
+  hb_codepoint_t closing;
+  std::size_t index;
+};
+
+// Itemizes `unichars` into script runs roughly following UAX #24. Every
+// character is assigned a script using its Script_Extensions, common and
+// inherited characters join the surrounding run and closing brackets take
+// the script of their opening partner.
+void ScriptsForText(const uint32_t* unichars, std::size_t unichar_count,
+                    std::vector<hb_script_t>& scripts) noexcept {
   auto* unicode_funcs = hb_unicode_funcs_get_default();
 
-  scripts.clear();
-  scripts.reserve(run.length);
+  scripts.assign(unichar_count, HB_SCRIPT_COMMON);
+
+  std::vector<BracketEntry> brackets{};
+  ScriptSet run_set{};
+  ScriptSet script_set{};
+  std::size_t run_start{0};
+  for (std::size_t index = 0; index < unichar_count; ++index) {
+    auto unichar = unichars[index];
+    ScriptSetForCodepoint(unicode_funcs, unichar, script_set);
+
+    auto category = hb_unicode_general_category(unicode_funcs, unichar);
+    if (category == HB_UNICODE_GENERAL_CATEGORY_OPEN_PUNCTUATION) {
+      auto closing = hb_unicode_mirroring(unicode_funcs, unichar);
+      if (closing != unichar && brackets.size() < kMaxBracketDepth) {
+        brackets.push_back(BracketEntry{closing, index});
+      }
+    } else if (category == HB_UNICODE_GENERAL_CATEGORY_CLOSE_PUNCTUATION) {
+      for (auto it = brackets.rbegin(); it != brackets.rend(); ++it) {
+        if (it->closing != unichar) {
+          continue;
+        }
+
+        // N.B. An opening bracket in the current run will get the same
+        // script as the closing one anyway.
+        auto opening_index = it->index;
+        if (opening_index < run_start) {
+          script_set.Assign(scripts[opening_index]);
+        }
+
+        brackets.erase(std::next(it).base(), brackets.end());
+        break;
+      }
+    }
+
+    if (script_set.IsEmpty()) {
+      continue;
+    }
+
+    if (run_set.IsEmpty()) {
+      run_set = script_set;
+      continue;
+    }
+
+    if (run_set.Intersect(script_set)) {
+      continue;
+    }
+
+    std::fill(scripts.begin() + static_cast<std::ptrdiff_t>(run_start),
+              scripts.begin() + static_cast<std::ptrdiff_t>(index),
+              run_set.First());
+    run_start = index;
+    run_set = script_set;
+  }
+
+  std::fill(scripts.begin() + static_cast<std::ptrdiff_t>(run_start),
+            scripts.end(), run_set.First());
+}
+
+constexpr uint32_t kZeroWidthJoiner = 0x200D;
+constexpr uint32_t kCombiningEnclosingKeycap = 0x20E3;
+constexpr uint32_t kTextPresentationSelector = 0xFE0E;
+constexpr uint32_t kEmojiPresentationSelector = 0xFE0F;
+constexpr uint32_t kRegionalIndicatorFirst = 0x1F1E6;
+constexpr uint32_t kRegionalIndicatorLast = 0x1F1FF;
+constexpr uint32_t kTagFirst = 0xE0020;
+constexpr uint32_t kTagLast = 0xE007E;
+constexpr uint32_t kCancelTag = 0xE007F;
+
+uint8_t EmojiProperties(uint32_t unichar) noexcept {
+  const auto* range = FindRange(subset::kEmojiRanges, unichar);
+  return range != nullptr ? range->properties : 0;
+}
+
+bool IsRegionalIndicator(uint32_t unichar) noexcept {
+  return unichar >= kRegionalIndicatorFirst &&
+         unichar <= kRegionalIndicatorLast;
+}
+
+bool IsKeycapBase(uint32_t unichar) noexcept {
+  return (unichar >= '0' && unichar <= '9') || unichar == '#' ||
+         unichar == '*';
+}
+
+bool IsEmojiCharacter(uint32_t unichar) noexcept {
+  return (EmojiProperties(unichar) & subset::kEmoji) != 0;
+}
+
+// Consumes a single `emoji_zwj_element` as defined by UTS #51 starting at
+// `index`, i.e. an emoji character possibly followed by a modifier, a
+// presentation selector or a tag spec. Returns the index past the element.
+std::size_t ConsumeEmojiElement(
+    const uint32_t* unichars, std::size_t unichar_count, std::size_t index,
+    SubsetEmojiPresentation& presentation) noexcept {
+  auto properties = EmojiProperties(unichars[index++]);
+  presentation = (properties & subset::kEmojiPresentation) != 0
+                     ? SUBSET_EMOJI_PRESENTATION_EMOJI
+                     : SUBSET_EMOJI_PRESENTATION_TEXT;
+  if (index == unichar_count) {
+    return index;
+  }
+
+  auto next = unichars[index];
+  if ((properties & subset::kEmojiModifierBase) != 0 &&
+      (EmojiProperties(next) & subset::kEmojiModifier) != 0) {
+    presentation = SUBSET_EMOJI_PRESENTATION_EMOJI;
+    ++index;
+  } else if (next == kEmojiPresentationSelector) {
+    presentation = SUBSET_EMOJI_PRESENTATION_EMOJI;
+    ++index;
+  } else if (next == kTextPresentationSelector) {
+    presentation = SUBSET_EMOJI_PRESENTATION_TEXT;
+    ++index;
+  } else if (next >= kTagFirst && next <= kTagLast) {
+    auto end = index;
+    while (end < unichar_count && unichars[end] >= kTagFirst &&
+           unichars[end] <= kTagLast) {
+      ++end;
+    }
+
+    if (end < unichar_count && unichars[end] == kCancelTag) {
+      presentation = SUBSET_EMOJI_PRESENTATION_EMOJI;
+      index = end + 1;
+    }
+  }
+
+  return index;
+}
+
+// Returns the length of the emoji sequence, following UTS #51, starting at
+// `offset`. Zero is returned if there's no sequence or if it's a single
+// character defaulting to text presentation, since that's just plain text.
+std::size_t EmojiSequenceLength(
+    const uint32_t* unichars, std::size_t unichar_count, std::size_t offset,
+    SubsetEmojiPresentation& presentation) noexcept {
+  presentation = SUBSET_EMOJI_PRESENTATION_EMOJI;
+
+  auto unichar = unichars[offset];
+  if (IsRegionalIndicator(unichar)) {
+    if (offset + 1 < unichar_count &&
+        IsRegionalIndicator(unichars[offset + 1])) {
+      return 2;
+    }
+
+    return 1;
+  }
+
+  if (IsKeycapBase(unichar)) {
+    auto index = offset + 1;
+    if (index < unichar_count &&
+        unichars[index] == kEmojiPresentationSelector) {
+      ++index;
+    }
+
+    if (index < unichar_count && unichars[index] == kCombiningEnclosingKeycap) {
+      return index + 1 - offset;
+    }
+  }
+
+  if (!IsEmojiCharacter(unichar)) {
+    return 0;
+  }
+
+  auto index =
+      ConsumeEmojiElement(unichars, unichar_count, offset, presentation);
+  auto explicit_text = presentation == SUBSET_EMOJI_PRESENTATION_TEXT &&
+                       unichars[index - 1] == kTextPresentationSelector;
+  while (index + 1 < unichar_count && unichars[index] == kZeroWidthJoiner &&
+         IsEmojiCharacter(unichars[index + 1])) {
+    SubsetEmojiPresentation element_presentation{};
+    index = ConsumeEmojiElement(unichars, unichar_count, index + 1,
+                                element_presentation);
+    presentation = SUBSET_EMOJI_PRESENTATION_EMOJI;
+    explicit_text = false;
+  }
+
+  if (presentation == SUBSET_EMOJI_PRESENTATION_TEXT && !explicit_text) {
+    return 0;
+  }
+
+  return index - offset;
+}
+
+// Assigns an emoji presentation to every character in `unichars` and marks
+// where each emoji sequence starts.
+void EmojiPresentationsForText(
+    const uint32_t* unichars, std::size_t unichar_count,
+    std::vector<SubsetEmojiPresentation>& presentations,
+    std::vector<bool>& sequence_starts) noexcept {
+  presentations.assign(unichar_count, SUBSET_EMOJI_PRESENTATION_NONE);
+  sequence_starts.assign(unichar_count, false);
+
+  std::size_t index{0};
+  while (index < unichar_count) {
+    SubsetEmojiPresentation presentation{};
+    auto length =
+        EmojiSequenceLength(unichars, unichar_count, index, presentation);
+    if (length == 0) {
+      ++index;
+      continue;
+    }
+
+    sequence_starts[index] = true;
+    auto first = presentations.begin() + static_cast<std::ptrdiff_t>(index);
+    std::fill(first, first + static_cast<std::ptrdiff_t>(length),
+              presentation);
+    index += length;
+  }
+}
+
+bool IsSequenceIgnorable(uint32_t unichar) noexcept {
+  return unichar == kZeroWidthJoiner || unichar == kTextPresentationSelector ||
+         unichar == kEmojiPresentationSelector;
+}
+
+bool IsVariationSelector(uint32_t unichar) noexcept {
+  return (unichar >= 0x180B && unichar <= 0x180D) || unichar == 0x180F ||
+         (unichar >= 0xFE00 && unichar <= 0xFE0F) ||
+         (unichar >= 0xE0100 && unichar <= 0xE01EF);
+}
+
+float FontStyleScore(SubsetFont* font,
+                     const SubsetFontStyle& font_style) noexcept {
+  constexpr float kMaxWidthScore = 225.0;
+  float width_score{0};
+  auto width = subset_font_width(font);
+  if (font_style.width <= 100.0) {
+    if (width <= font_style.width) {
+      width_score = kMaxWidthScore - font_style.width + width;
+    } else {
+      width_score = kMaxWidthScore - width;
+    }
+  } else {
+    if (width > font_style.width) {
+      width_score = kMaxWidthScore + font_style.width - width;
+    } else {
+      width_score = width;
+    }
+  }
+
+  constexpr float kItalicMatchScore = 3.0;
+  float italic_score{1};
+  if (subset_font_is_italic(font)) {
+    if (font_style.italic) {
+      italic_score = kItalicMatchScore;
+    }
+  } else {
+    if (!font_style.italic) {
+      italic_score = kItalicMatchScore;
+    }
+  }
+
+  constexpr float kMaxWeightScore = 1000.0;
+  constexpr float kNormalWeight = 400.0;
+  constexpr float kMediumWeight = 500.0;
+  float weight_score{0};
+  auto weight = subset_font_weight(font);
+  if (font_style.weight == weight) {
+    weight_score = kMaxWeightScore;
+  } else if (font_style.weight < kNormalWeight) {
+    if (weight <= font_style.weight) {
+      weight_score = kMaxWeightScore - font_style.weight + weight;
+    } else {
+      weight_score = kMaxWeightScore - weight;
+    }
+  } else if (font_style.weight <= kMediumWeight) {
+    if (weight >= font_style.weight && weight <= kMediumWeight) {
+      weight_score = kMaxWeightScore + font_style.weight - weight;
+    } else if (weight <= font_style.weight) {
+      weight_score = kMediumWeight + weight;
+    } else {
+      weight_score = kMaxWeightScore - weight;
+    }
+  } else if (font_style.weight > kMediumWeight) {
+    if (weight > font_style.weight) {
+      weight_score = kMaxWeightScore + font_style.weight - weight;
+    } else {
+      weight_score = weight;
+    }
+  }
+
+  constexpr float kWidthScoreMultiplier = 1e7;
+  constexpr float kItalicScoreMultiplier = 1e4;
+  return width_score * kWidthScoreMultiplier +
+         italic_score * kItalicScoreMultiplier + weight_score;
+}
+
+// Returns the best font match among the fonts where `coverage` is non-zero.
+// Higher coverage wins over matching the emoji presentation, which wins over
+// the font style score.
+template <typename Coverage>
+bool FindBestFontMatch(const SubsetFontStyle& font_style,
+                       SubsetEmojiPresentation emoji_presentation,
+                       size_t font_count, SubsetFontProvider font_provider,
+                       void* font_provider_context, size_t* best_index,
+                       Coverage coverage) noexcept {
+  if (font_provider == nullptr || font_count == 0) {
+    return false;
+  }
+
+  bool found{false};
+  int max_coverage{0};
+  bool max_presentation_match{false};
+  float max_score{0};
+  size_t max_index{0};
+  for (size_t index = 0; index < font_count; ++index) {
+    auto* font = font_provider(index, font_provider_context);
+    int font_coverage = coverage(font);
+    if (font_coverage == 0) {
+      continue;
+    }
+
+    bool presentation_match{true};
+    if (emoji_presentation != SUBSET_EMOJI_PRESENTATION_NONE) {
+      auto has_color_glyphs = subset_font_has_color_glyphs(font);
+      presentation_match =
+          (emoji_presentation == SUBSET_EMOJI_PRESENTATION_EMOJI) ==
+          has_color_glyphs;
+    }
+
+    auto score = FontStyleScore(font, font_style);
+    if (!found || font_coverage > max_coverage ||
+        (font_coverage == max_coverage &&
+         (presentation_match && !max_presentation_match)) ||
+        (font_coverage == max_coverage &&
+         presentation_match == max_presentation_match && max_score < score)) {
+      found = true;
+      max_coverage = font_coverage;
+      max_presentation_match = presentation_match;
+      max_score = score;
+      max_index = index;
+    }
+  }
+
+  if (found && best_index != nullptr) {
+    *best_index = max_index;
+  }
+
+  return found;
+}
+
+subset::VerticalOrientationValue VerticalOrientation(
+    uint32_t unichar) noexcept {
+  const auto* range = FindRange(subset::kVerticalOrientationRanges, unichar);
+  return range != nullptr ? range->value : subset::kVerticalRotated;
+}
+
+bool IsMarkOrJoiner(hb_unicode_funcs_t* unicode_funcs,
+                    uint32_t unichar) noexcept {
+  if (unichar == kZeroWidthJoiner || IsVariationSelector(unichar)) {
+    return true;
+  }
+
+  auto category = hb_unicode_general_category(unicode_funcs, unichar);
+  return category == HB_UNICODE_GENERAL_CATEGORY_NON_SPACING_MARK ||
+         category == HB_UNICODE_GENERAL_CATEGORY_SPACING_MARK ||
+         category == HB_UNICODE_GENERAL_CATEGORY_ENCLOSING_MARK;
+}
+
+// Resolves the orientation of every character in `unichars` in vertical text.
+// Marks, joiners and emoji sequences follow the orientation of their base
+// character.
+void OrientationsForText(
+    const uint32_t* unichars, std::size_t unichar_count,
+    const std::vector<bool>& sequence_starts,
+    const std::vector<SubsetEmojiPresentation>& presentations,
+    std::vector<subset::VerticalOrientationValue>& orientations) noexcept {
+  auto* unicode_funcs = hb_unicode_funcs_get_default();
+
+  orientations.assign(unichar_count, subset::kVerticalRotated);
+  for (std::size_t index = 0; index < unichar_count; ++index) {
+    auto unichar = unichars[index];
+    auto in_sequence = presentations[index] != SUBSET_EMOJI_PRESENTATION_NONE &&
+                       !sequence_starts[index];
+    if (index > 0 && (in_sequence || IsMarkOrJoiner(unicode_funcs, unichar))) {
+      orientations[index] = orientations[index - 1];
+    } else {
+      orientations[index] = VerticalOrientation(unichar);
+    }
+  }
+}
+
+// Offset of the control characters inserted by `ApplyBidiSpans`.
+constexpr std::size_t kBidiControlOffset =
+    std::numeric_limits<std::size_t>::max();
+
+// Returns the control characters that open and close a span of `control`.
+std::pair<uint32_t, uint32_t> BidiControlCharacters(
+    SubsetBidiControl control) noexcept {
+  switch (control) {
+    case SUBSET_BIDI_CONTROL_ISOLATE_LTR:
+      return {0x2066, 0x2069};
+    case SUBSET_BIDI_CONTROL_ISOLATE_RTL:
+      return {0x2067, 0x2069};
+    case SUBSET_BIDI_CONTROL_ISOLATE_FIRST_STRONG:
+      return {0x2068, 0x2069};
+    case SUBSET_BIDI_CONTROL_EMBED_LTR:
+      return {0x202A, 0x202C};
+    case SUBSET_BIDI_CONTROL_EMBED_RTL:
+      return {0x202B, 0x202C};
+    case SUBSET_BIDI_CONTROL_OVERRIDE_LTR:
+      return {0x202D, 0x202C};
+    case SUBSET_BIDI_CONTROL_OVERRIDE_RTL:
+      return {0x202E, 0x202C};
+  }
+  return {0x2068, 0x2069};
+}
+
+// Copies `unichars` to `text` with the control characters of `spans` around
+// their text, for the bidi algorithm only, and stores the offset in `unichars`
+// of every character of `text`, or `kBidiControlOffset` for the controls.
+void ApplyBidiSpans(const uint32_t* unichars, std::size_t unichar_count,
+                    const SubsetBidiSpan* spans, std::size_t span_count,
+                    std::vector<uint32_t>& text,
+                    std::vector<std::size_t>& offsets) noexcept {
+  std::vector<SubsetBidiSpan> sorted{};
+  for (std::size_t index = 0; index < span_count; ++index) {
+    auto span = spans[index];
+    span.offset = std::min(span.offset, unichar_count);
+    span.length = std::min(span.length, unichar_count - span.offset);
+    if (span.length > 0) {
+      sorted.push_back(span);
+    }
+  }
+  // N.B. Outer spans open first.
+  std::stable_sort(sorted.begin(), sorted.end(),
+                   [](const SubsetBidiSpan& a, const SubsetBidiSpan& b) {
+                     return a.offset < b.offset ||
+                            (a.offset == b.offset && a.length > b.length);
+                   });
+
+  text.clear();
+  offsets.clear();
+  text.reserve(unichar_count + sorted.size() * 2);
+  offsets.reserve(unichar_count + sorted.size() * 2);
+  std::vector<std::pair<std::size_t, uint32_t>> open{};
+  auto next = sorted.begin();
+  for (std::size_t index = 0; index <= unichar_count; ++index) {
+    while (!open.empty() && open.back().first <= index) {
+      text.push_back(open.back().second);
+      offsets.push_back(kBidiControlOffset);
+      open.pop_back();
+    }
+    if (index == unichar_count) {
+      break;
+    }
+
+    for (; next != sorted.end() && next->offset == index; ++next) {
+      auto end = next->offset + next->length;
+      if (!open.empty()) {
+        end = std::min(end, open.back().first);
+      }
+      auto [opening, closing] = BidiControlCharacters(next->control);
+      text.push_back(opening);
+      offsets.push_back(kBidiControlOffset);
+      open.emplace_back(end, closing);
+    }
+    text.push_back(unichars[index]);
+    offsets.push_back(index);
+  }
+}
+
+// Maps the bidi `run` over the text of `ApplyBidiSpans` back to the offsets of
+// the original text. Returns false if the run has nothing but controls.
+bool UnapplyBidiSpans(const std::vector<std::size_t>& offsets,
+                      SBRun& run) noexcept {
+  std::size_t start = kBidiControlOffset;
+  std::size_t length = 0;
+  for (std::size_t index = 0; index < run.length; ++index) {
+    auto offset = offsets[run.offset + index];
+    if (offset != kBidiControlOffset) {
+      start = std::min(start, offset);
+      ++length;
+    }
+  }
+  if (length == 0) {
+    return false;
+  }
+
+  run.offset = start;
+  run.length = length;
+  return true;
+}
+
+// A character of normalized or case mapped text and the offset of the one it
+// comes from.
+struct MappedCharacter {
+  uint32_t unichar;
+  std::size_t offset;
+};
+
+// Appends the full canonical decomposition of `unichar` to `characters`.
+void Decompose(hb_unicode_funcs_t* unicode_funcs, uint32_t unichar,
+               std::size_t offset,
+               std::vector<MappedCharacter>& characters) noexcept {
+  hb_codepoint_t first{0};
+  hb_codepoint_t second{0};
+  if (!hb_unicode_decompose(unicode_funcs, unichar, &first, &second)) {
+    characters.push_back({unichar, offset});
+    return;
+  }
+
+  Decompose(unicode_funcs, first, offset, characters);
+  if (second != 0) {
+    Decompose(unicode_funcs, second, offset, characters);
+  }
+}
+
+// Sorts every sequence of non-starters in `characters` by combining class, as
+// in the canonical ordering algorithm.
+void ReorderMarks(hb_unicode_funcs_t* unicode_funcs,
+                  std::vector<MappedCharacter>& characters) noexcept {
+  auto combining_class = [unicode_funcs](const MappedCharacter& c) {
+    return hb_unicode_combining_class(unicode_funcs, c.unichar);
+  };
+  auto begin = characters.begin();
+  while (begin != characters.end()) {
+    if (combining_class(*begin) == 0) {
+      ++begin;
+      continue;
+    }
+
+    auto end = std::find_if(begin, characters.end(),
+                            [&](const MappedCharacter& c) {
+                              return combining_class(c) == 0;
+                            });
+    std::stable_sort(begin, end,
+                     [&](const MappedCharacter& a,
+                         const MappedCharacter& b) {
+                       return combining_class(a) < combining_class(b);
+                     });
+    begin = end;
+  }
+}
+
+// Composes the decomposed `characters` in place, as in the canonical
+// composition algorithm, skipping compositions `filter` rejects.
+void Compose(hb_unicode_funcs_t* unicode_funcs,
+             std::vector<MappedCharacter>& characters,
+             SubsetCompositionFilter filter, void* context) noexcept {
+  constexpr auto kNoStarter = std::numeric_limits<std::size_t>::max();
+  std::size_t count = 0;
+  std::size_t starter = kNoStarter;
+  hb_unicode_combining_class_t last_class{0};
+  for (const auto& character : characters) {
+    auto combining_class =
+        hb_unicode_combining_class(unicode_funcs, character.unichar);
+    // N.B. A character is blocked from the starter by any character between
+    // them with a combining class of zero or not lower than its own.
+    if (starter != kNoStarter &&
+        (starter == count - 1 ||
+         (last_class != 0 && last_class < combining_class))) {
+      hb_codepoint_t composed{0};
+      if (hb_unicode_compose(unicode_funcs, characters[starter].unichar,
+                             character.unichar, &composed) &&
+          (filter == nullptr ||
+           filter(characters[starter].unichar, composed, context))) {
+        characters[starter].unichar = composed;
+        continue;
+      }
+    }
+
+    if (combining_class == 0) {
+      starter = count;
+    }
+    last_class = combining_class;
+    characters[count++] = character;
+  }
+  characters.resize(count);
+}
+
+// Splits the bidi `run` on script, emoji sequence and orientation boundaries if
+// needed, and calls `callback` with each part.
+void EmitTextRuns(const SBRun& run, SBLevel paragraph_level,
+                  const std::vector<hb_script_t>& scripts,
+                  const std::vector<SubsetEmojiPresentation>& presentations,
+                  const std::vector<bool>& sequence_starts,
+                  const std::vector<subset::VerticalOrientationValue>&
+                      orientations,
+                  SubsetTextRunCallback callback, void* context) noexcept {
+  std::size_t offset = 0;
+  std::size_t remaining = run.length;
+  hb_script_t last_script = HB_SCRIPT_INVALID;
+  SubsetEmojiPresentation last_presentation = SUBSET_EMOJI_PRESENTATION_NONE;
+  bool last_upright{false};
+  bool last_transformed{false};
+  for (std::size_t index = 0; index < run.length; ++index) {
+    auto script = scripts[run.offset + index];
+    auto presentation = presentations[run.offset + index];
+    auto sequence_start = sequence_starts[run.offset + index];
+    auto orientation = orientations[run.offset + index];
+    auto upright = orientation != subset::kVerticalRotated;
+    auto transformed = orientation == subset::kVerticalTransformedOrRotated;
+    if (last_script != HB_SCRIPT_INVALID &&
+        (script != last_script || presentation != last_presentation ||
+         sequence_start || upright != last_upright ||
+         transformed != last_transformed)) {
+      auto len = index - offset;
+      SubsetTextRun text_run{
+          .offset = run.offset + offset,
+          .length = len,
+          .bidi_level = run.level,
+          .paragraph_level = paragraph_level,
+          .script = last_script,
+          .emoji_presentation = last_presentation,
+          .upright = last_upright,
+          .transformed = last_transformed,
+      };
+      callback(text_run, context);
+      offset = index;
+      remaining -= len;
+    }
+
+    last_script = script;
+    last_presentation = presentation;
+    last_upright = upright;
+    last_transformed = transformed;
+  }
+
+  SubsetTextRun text_run{
+      .offset = run.offset + offset,
+      .length = remaining,
+      .bidi_level = run.level,
+      .paragraph_level = paragraph_level,
+      .script = last_script,
+      .emoji_presentation = last_presentation,
+      .upright = last_upright,
+      .transformed = last_transformed,
+  };
+  callback(text_run, context);
+}
+
+// Returns whether the GSUB or GPOS table of `face` has the feature.
+bool HasFeature(hb_face_t* face, hb_tag_t table_tag,
+                hb_tag_t feature_tag) noexcept {
+  std::array<hb_tag_t, 32> feature_tags{};
+  unsigned int start_offset{0};
+  unsigned int feature_count{0};
+  do {
+    feature_count = feature_tags.size();
+    hb_ot_layout_table_get_feature_tags(face, table_tag, start_offset,
+                                        &feature_count, feature_tags.data());
+    for (unsigned int index = 0; index < feature_count; ++index) {
+      if (feature_tags[index] == feature_tag) {
+        return true;
+      }
+    }
+
+    start_offset += feature_count;
+  } while (feature_count == feature_tags.size());
+
+  return false;
+}
+
+// Returns the features to use when shaping vertically. The `vrt2` feature
+// supersedes `vert` if the font has it.
+std::array<hb_feature_t, 2> VerticalFeatures(hb_font_t* font) noexcept {
+  auto has_vrt2 = HasFeature(hb_font_get_face(font), HB_OT_TAG_GSUB,
+                             HB_TAG('v', 'r', 't', '2'));
+  return {
+      hb_feature_t{HB_TAG('v', 'e', 'r', 't'), has_vrt2 ? 0U : 1U,
+                   HB_FEATURE_GLOBAL_START, HB_FEATURE_GLOBAL_END},
+      hb_feature_t{HB_TAG('v', 'r', 't', '2'), has_vrt2 ? 1U : 0U,
+                   HB_FEATURE_GLOBAL_START, HB_FEATURE_GLOBAL_END},
+  };
+}
+
+// Returns the features that disable optional ligatures, used when letters are
+// spaced apart.
+std::array<hb_feature_t, 4> NoLigatureFeatures() noexcept {
+  return {
+      hb_feature_t{HB_TAG('l', 'i', 'g', 'a'), 0U, HB_FEATURE_GLOBAL_START,
+                   HB_FEATURE_GLOBAL_END},
+      hb_feature_t{HB_TAG('c', 'l', 'i', 'g'), 0U, HB_FEATURE_GLOBAL_START,
+                   HB_FEATURE_GLOBAL_END},
+      hb_feature_t{HB_TAG('d', 'l', 'i', 'g'), 0U, HB_FEATURE_GLOBAL_START,
+                   HB_FEATURE_GLOBAL_END},
+      hb_feature_t{HB_TAG('h', 'l', 'i', 'g'), 0U, HB_FEATURE_GLOBAL_START,
+                   HB_FEATURE_GLOBAL_END},
+  };
+}
+
+// Returns whether the letters of `script` join, in which case they must not be
+// spaced apart.
+bool IsCursiveScript(hb_script_t script) noexcept {
+  switch (script) {
+    case HB_SCRIPT_ADLAM:
+    case HB_SCRIPT_ARABIC:
+    case HB_SCRIPT_HANIFI_ROHINGYA:
+    case HB_SCRIPT_MANDAIC:
+    case HB_SCRIPT_MANICHAEAN:
+    case HB_SCRIPT_MONGOLIAN:
+    case HB_SCRIPT_NKO:
+    case HB_SCRIPT_OLD_UYGHUR:
+    case HB_SCRIPT_PHAGS_PA:
+    case HB_SCRIPT_PSALTER_PAHLAVI:
+    case HB_SCRIPT_SOGDIAN:
+    case HB_SCRIPT_SYRIAC:
+      return true;
+    default:
+      return false;
+  }
+}
+
+// Returns whether `unichar` is a word separator, as defined by CSS
+// `word-spacing`.
+bool IsWordSeparator(uint32_t unichar) noexcept {
+  switch (unichar) {
+    case 0x0020:
+    case 0x00A0:
+    case 0x1361:
+    case 0x10100:
+    case 0x10101:
+    case 0x1039F:
+    case 0x1091F:
+      return true;
+    default:
+      return false;
+  }
+}
+
+struct BlobDeleter {
+  void operator()(hb_blob_t* blob) noexcept { hb_blob_destroy(blob); }
+};
+
+// Reads a big endian 16-bit value at `offset`, or zero if out of bounds.
+uint16_t ReadUInt16(hb_blob_t* blob, unsigned int offset) noexcept {
+  unsigned int length{0};
+  const auto* data =
+      reinterpret_cast<const uint8_t*>(hb_blob_get_data(blob, &length));
+  if (data == nullptr || offset + 2 > length) {
+    return 0;
+  }
+
+  return static_cast<uint16_t>((data[offset] << 8U) | data[offset + 1]);
+}
+
+int16_t ReadInt16(hb_blob_t* blob, unsigned int offset) noexcept {
+  return static_cast<int16_t>(ReadUInt16(blob, offset));
+}
+
+subset::GraphemeBreakValue GraphemeBreak(uint32_t unichar) noexcept {
+  const auto* range = FindRange(subset::kGraphemeBreakRanges, unichar);
+  return range != nullptr ? range->value : subset::kGraphemeBreakOther;
+}
+
+subset::WordBreakValue WordBreak(uint32_t unichar) noexcept {
+  const auto* range = FindRange(subset::kWordBreakRanges, unichar);
+  return range != nullptr ? range->value : subset::kWordBreakOther;
+}
+
+subset::SentenceBreakValue SentenceBreak(uint32_t unichar) noexcept {
+  const auto* range = FindRange(subset::kSentenceBreakRanges, unichar);
+  return range != nullptr ? range->value : subset::kSentenceBreakOther;
+}
+
+bool IsExtendedPictographic(uint32_t unichar) noexcept {
+  return FindRange(subset::kExtendedPictographicRanges, unichar) != nullptr;
+}
+
+bool IsIndicConjunctBreakExtend(uint32_t unichar) noexcept {
+  return FindRange(subset::kIndicConjunctBreakExtendRanges, unichar) !=
+         nullptr;
+}
+
+bool IsIndicConjunctBreakLinker(uint32_t unichar) noexcept {
+  const auto& linkers = subset::kIndicConjunctBreakLinkers;
+  return std::find(linkers.begin(), linkers.end(), unichar) != linkers.end();
+}
+
+// Returns `true` if `language` is `code` or a subtag of it, e.g. "fr-CA".
+bool LanguageIs(const char* language, const char* code) noexcept {
+  if (language == nullptr) {
+    return false;
+  }
+
+  for (; *code != '\0'; ++language, ++code) {
+    if (*language != *code) {
+      return false;
+    }
+  }
+
+  return *language == '\0' || *language == '-' || *language == '_';
+}
+
+// Grapheme cluster boundaries per UAX #29, including the start and the end of
+// the text.
+std::vector<size_t> GraphemeBoundaries(const uint32_t* unichars,
+                                       size_t unichar_count) {
+  using subset::GraphemeBreakValue;
+  std::vector<size_t> boundaries{0};
+  GraphemeBreakValue prev = subset::kGraphemeBreakOther;
+  // N.B. GB9c: Consonant [Extend Linker]* Linker [Extend Linker]* × Consonant.
+  bool incb_consonant = false;
+  bool incb_linker = false;
+  // N.B. GB11: ExtPict Extend* ZWJ × ExtPict.
+  bool pictographic = false;
+  bool pictographic_zwj = false;
+  size_t regional_indicator_count = 0;
+  for (size_t index = 0; index < unichar_count; ++index) {
+    const auto unichar = unichars[index];
+    const auto value = GraphemeBreak(unichar);
+    auto is_boundary = [&]() {
+      if (prev == subset::kGraphemeBreakCR &&
+          value == subset::kGraphemeBreakLF) {
+        return false;
+      }
+
+      if (prev == subset::kGraphemeBreakControl ||
+          prev == subset::kGraphemeBreakCR ||
+          prev == subset::kGraphemeBreakLF ||
+          value == subset::kGraphemeBreakControl ||
+          value == subset::kGraphemeBreakCR ||
+          value == subset::kGraphemeBreakLF) {
+        return true;
+      }
+
+      switch (prev) {
+        case subset::kGraphemeBreakL:
+          if (value == subset::kGraphemeBreakL ||
+              value == subset::kGraphemeBreakV ||
+              value == subset::kGraphemeBreakLV ||
+              value == subset::kGraphemeBreakLVT) {
+            return false;
+          }
+          break;
+        case subset::kGraphemeBreakLV:
+        case subset::kGraphemeBreakV:
+          if (value == subset::kGraphemeBreakV ||
+              value == subset::kGraphemeBreakT) {
+            return false;
+          }
+          break;
+        case subset::kGraphemeBreakLVT:
+        case subset::kGraphemeBreakT:
+          if (value == subset::kGraphemeBreakT) {
+            return false;
+          }
+          break;
+        default:
+          break;
+      }
+
+      if (value == subset::kGraphemeBreakExtend ||
+          value == subset::kGraphemeBreakZWJ ||
+          value == subset::kGraphemeBreakSpacingMark ||
+          prev == subset::kGraphemeBreakPrepend) {
+        return false;
+      }
+
+      if (incb_linker && value == subset::kGraphemeBreakInCBConsonant) {
+        return false;
+      }
+
+      if (pictographic_zwj &&
+          value == subset::kGraphemeBreakExtendedPictographic) {
+        return false;
+      }
+
+      return !(prev == subset::kGraphemeBreakRegionalIndicator &&
+               value == subset::kGraphemeBreakRegionalIndicator &&
+               regional_indicator_count % 2 == 1);
+    };
+    if (index > 0 && is_boundary()) {
+      boundaries.push_back(index);
+    }
+
+    if (value == subset::kGraphemeBreakInCBConsonant) {
+      incb_consonant = true;
+      incb_linker = false;
+    } else if (incb_consonant && IsIndicConjunctBreakLinker(unichar)) {
+      incb_linker = true;
+    } else if (!incb_consonant || !IsIndicConjunctBreakExtend(unichar)) {
+      incb_consonant = false;
+      incb_linker = false;
+    }
+
+    if (value == subset::kGraphemeBreakExtendedPictographic) {
+      pictographic = true;
+      pictographic_zwj = false;
+    } else if (pictographic && value == subset::kGraphemeBreakExtend) {
+      pictographic_zwj = false;
+    } else if (pictographic && value == subset::kGraphemeBreakZWJ) {
+      pictographic = false;
+      pictographic_zwj = true;
+    } else {
+      pictographic = false;
+      pictographic_zwj = false;
+    }
+
+    regional_indicator_count =
+        value == subset::kGraphemeBreakRegionalIndicator
+            ? regional_indicator_count + 1
+            : 0;
+    prev = value;
+  }
+
+  if (unichar_count > 0) {
+    boundaries.push_back(unichar_count);
+  }
+
+  return boundaries;
+}
+
+bool IsAHLetter(subset::WordBreakValue value) noexcept {
+  return value == subset::kWordBreakALetter ||
+         value == subset::kWordBreakHebrewLetter;
+}
+
+bool IsMidNumLetQ(subset::WordBreakValue value) noexcept {
+  return value == subset::kWordBreakMidNumLet ||
+         value == subset::kWordBreakSingleQuote;
+}
+
+bool IsNewline(subset::WordBreakValue value) noexcept {
+  return value == subset::kWordBreakNewline ||
+         value == subset::kWordBreakCR || value == subset::kWordBreakLF;
+}
+
+// Word boundaries per UAX #29, including the start and the end of the text.
+// If `break_after_apostrophe` is set, e.g. for French and Italian, elided
+// words like "l'" are separated from the word that follows.
+std::vector<size_t> WordBoundaries(const uint32_t* unichars,
+                                   size_t unichar_count,
+                                   bool break_after_apostrophe) {
+  using subset::WordBreakValue;
+  // N.B. WB4 is applied up front by collapsing Extend, Format and ZWJ into the
+  // preceding character, so the rules below only look at the base characters.
+  std::vector<size_t> starts;
+  std::vector<WordBreakValue> values;
+  for (size_t index = 0; index < unichar_count; ++index) {
+    const auto value = WordBreak(unichars[index]);
+    const bool ignorable = value == subset::kWordBreakExtend ||
+                           value == subset::kWordBreakFormat ||
+                           value == subset::kWordBreakZWJ;
+    if (!ignorable || values.empty() || IsNewline(values.back())) {
+      starts.push_back(index);
+      values.push_back(value);
+    }
+  }
+
+  auto value_at = [&values](size_t index) {
+    return index < values.size() ? values[index] : subset::kWordBreakOther;
+  };
+  auto is_boundary = [&](size_t index) {
+    const auto start = starts[index];
+    const auto raw_prev = WordBreak(unichars[start - 1]);
+    const auto prev_prev = index >= 2 ? values[index - 2]
+                                      : subset::kWordBreakOther;
+    const auto prev = values[index - 1];
+    const auto value = values[index];
+    const auto next = value_at(index + 1);
+    if (raw_prev == subset::kWordBreakCR && value == subset::kWordBreakLF) {
+      return false;
+    }
+
+    if (IsNewline(prev) || IsNewline(value)) {
+      return true;
+    }
+
+    if (raw_prev == subset::kWordBreakZWJ &&
+        IsExtendedPictographic(unichars[start])) {
+      return false;
+    }
+
+    if (raw_prev == subset::kWordBreakWSegSpace &&
+        value == subset::kWordBreakWSegSpace) {
+      return false;
+    }
+
+    if (IsAHLetter(prev) && IsAHLetter(value)) {
+      return false;
+    }
+
+    if (break_after_apostrophe && IsAHLetter(prev_prev) &&
+        (unichars[starts[index - 1]] == 0x0027 ||
+         unichars[starts[index - 1]] == 0x2019) &&
+        IsAHLetter(value)) {
+      return true;
+    }
+
+    if (IsAHLetter(prev) &&
+        (value == subset::kWordBreakMidLetter || IsMidNumLetQ(value)) &&
+        IsAHLetter(next)) {
+      return false;
+    }
+
+    if (IsAHLetter(prev_prev) &&
+        (prev == subset::kWordBreakMidLetter || IsMidNumLetQ(prev)) &&
+        IsAHLetter(value)) {
+      return false;
+    }
+
+    if (prev == subset::kWordBreakHebrewLetter &&
+        (value == subset::kWordBreakSingleQuote ||
+         (value == subset::kWordBreakDoubleQuote &&
+          next == subset::kWordBreakHebrewLetter))) {
+      return false;
+    }
+
+    if (prev_prev == subset::kWordBreakHebrewLetter &&
+        prev == subset::kWordBreakDoubleQuote &&
+        value == subset::kWordBreakHebrewLetter) {
+      return false;
+    }
+
+    if ((prev == subset::kWordBreakNumeric || IsAHLetter(prev)) &&
+        (value == subset::kWordBreakNumeric || IsAHLetter(value))) {
+      return false;
+    }
+
+    if (prev_prev == subset::kWordBreakNumeric &&
+        (prev == subset::kWordBreakMidNum || IsMidNumLetQ(prev)) &&
+        value == subset::kWordBreakNumeric) {
+      return false;
+    }
+
+    if (prev == subset::kWordBreakNumeric &&
+        (value == subset::kWordBreakMidNum || IsMidNumLetQ(value)) &&
+        next == subset::kWordBreakNumeric) {
+      return false;
+    }
+
+    if (prev == subset::kWordBreakKatakana &&
+        value == subset::kWordBreakKatakana) {
+      return false;
+    }
+
+    if ((IsAHLetter(prev) || prev == subset::kWordBreakNumeric ||
+         prev == subset::kWordBreakKatakana ||
+         prev == subset::kWordBreakExtendNumLet) &&
+        value == subset::kWordBreakExtendNumLet) {
+      return false;
+    }
+
+    if (prev == subset::kWordBreakExtendNumLet &&
+        (IsAHLetter(value) || value == subset::kWordBreakNumeric ||
+         value == subset::kWordBreakKatakana)) {
+      return false;
+    }
+
+    if (prev == subset::kWordBreakRegionalIndicator &&
+        value == subset::kWordBreakRegionalIndicator) {
+      size_t count = 0;
+      for (auto i = index; i > 0 && values[i - 1] == prev; --i) {
+        ++count;
+      }
+      return count % 2 == 0;
+    }
+
+    return true;
+  };
+
+  std::vector<size_t> boundaries{0};
+  for (size_t index = 1; index < starts.size(); ++index) {
+    if (is_boundary(index)) {
+      boundaries.push_back(starts[index]);
+    }
+  }
+
+  if (unichar_count > 0) {
+    boundaries.push_back(unichar_count);
+  }
+
+  return boundaries;
+}
+
+bool IsParagraphSeparator(subset::SentenceBreakValue value) noexcept {
+  return value == subset::kSentenceBreakSep ||
+         value == subset::kSentenceBreakCR || value == subset::kSentenceBreakLF;
+}
+
+bool IsSentenceTerminal(subset::SentenceBreakValue value) noexcept {
+  return value == subset::kSentenceBreakATerm ||
+         value == subset::kSentenceBreakSTerm;
+}
+
+// Sentence boundaries per UAX #29, including the start and the end of the
+// text.
+std::vector<size_t> SentenceBoundaries(const uint32_t* unichars,
+                                       size_t unichar_count) {
+  using subset::SentenceBreakValue;
+  // N.B. SB5 is applied up front by collapsing Extend and Format into the
+  // preceding character.
+  std::vector<size_t> starts;
+  std::vector<SentenceBreakValue> values;
+  for (size_t index = 0; index < unichar_count; ++index) {
+    const auto value = SentenceBreak(unichars[index]);
+    const bool ignorable = value == subset::kSentenceBreakExtend ||
+                           value == subset::kSentenceBreakFormat;
+    if (!ignorable || values.empty() || IsParagraphSeparator(values.back())) {
+      starts.push_back(index);
+      values.push_back(value);
+    }
+  }
+
+  auto is_boundary = [&](size_t index) {
+    const auto prev_prev = index >= 2 ? values[index - 2]
+                                      : subset::kSentenceBreakOther;
+    const auto prev = values[index - 1];
+    const auto value = values[index];
+    if (prev == subset::kSentenceBreakCR && value == subset::kSentenceBreakLF &&
+        starts[index] == starts[index - 1] + 1) {
+      return false;
+    }
+
+    if (IsParagraphSeparator(prev)) {
+      return true;
+    }
+
+    if (prev == subset::kSentenceBreakATerm &&
+        value == subset::kSentenceBreakNumeric) {
+      return false;
+    }
+
+    if ((prev_prev == subset::kSentenceBreakUpper ||
+         prev_prev == subset::kSentenceBreakLower) &&
+        prev == subset::kSentenceBreakATerm &&
+        value == subset::kSentenceBreakUpper) {
+      return false;
+    }
+
+    // N.B. Match SATerm Close* Sp* before the boundary.
+    auto term = index;
+    bool has_space = false;
+    while (term > 0 && values[term - 1] == subset::kSentenceBreakSp) {
+      --term;
+      has_space = true;
+    }
+
+    while (term > 0 && values[term - 1] == subset::kSentenceBreakClose) {
+      --term;
+    }
+
+    if (term == 0 || !IsSentenceTerminal(values[term - 1])) {
+      return false;
+    }
+
+    if (values[term - 1] == subset::kSentenceBreakATerm) {
+      auto next = index;
+      while (next < values.size() &&
+             values[next] != subset::kSentenceBreakOLetter &&
+             values[next] != subset::kSentenceBreakUpper &&
+             values[next] != subset::kSentenceBreakLower &&
+             !IsParagraphSeparator(values[next]) &&
+             !IsSentenceTerminal(values[next])) {
+        ++next;
+      }
+
+      if (next < values.size() && values[next] == subset::kSentenceBreakLower) {
+        return false;
+      }
+    }
+
+    if (value == subset::kSentenceBreakSContinue ||
+        IsSentenceTerminal(value)) {
+      return false;
+    }
+
+    if (!has_space && value == subset::kSentenceBreakClose) {
+      return false;
+    }
+
+    return value != subset::kSentenceBreakSp && !IsParagraphSeparator(value);
+  };
+
+  std::vector<size_t> boundaries{0};
+  for (size_t index = 1; index < starts.size(); ++index) {
+    if (is_boundary(index)) {
+      boundaries.push_back(starts[index]);
+    }
+  }
+
+  if (unichar_count > 0) {
+    boundaries.push_back(unichar_count);
+  }
+
+  return boundaries;
+}
+
+// Returns whether `unichar` is a mandatory break per UAX #14, i.e. of line
+// break class BK, CR, LF or NL.
+bool IsHardLineBreak(uint32_t unichar) noexcept {
+  switch (unichar) {
+    case 0x000A:
+    case 0x000B:
+    case 0x000C:
+    case 0x000D:
+    case 0x0085:
+    case 0x2028:
+    case 0x2029:
+      return true;
+    default:
+      return false;
+  }
+}
+
+// Hard line break boundaries per UAX #14 LB4 and LB5, i.e. after every
+// mandatory break but between CR and LF, including the start and the end of
+// the text.
+std::vector<size_t> HardLineBreakBoundaries(const uint32_t* unichars,
+                                            size_t unichar_count) {
+  std::vector<size_t> boundaries{0};
+  for (size_t index = 0; index + 1 < unichar_count; ++index) {
+    if (IsHardLineBreak(unichars[index]) &&
+        !(unichars[index] == 0x000D && unichars[index + 1] == 0x000A)) {
+      boundaries.push_back(index + 1);
+    }
+  }
+
+  if (unichar_count > 0) {
+    boundaries.push_back(unichar_count);
+  }
+
+  return boundaries;
+}
+
+// Returns the character to shape in place of a control character. Tabs become
+// spaces, so they get a blank glyph to widen to the next tab stop, and the
+// others zero width spaces, so they are hidden like default ignorables.
+uint32_t ControlCharacterReplacement(uint32_t unichar) noexcept {
+  if (unichar == 0x0009) {
+    return 0x0020;
+  }
+
+  if (unichar < 0x0020 || (unichar >= 0x007F && unichar <= 0x009F) ||
+      unichar == 0x2028 || unichar == 0x2029) {
+    return 0x200B;
+  }
+
+  return unichar;
+}
+
+// Returns the visible stand-in for an invisible character, or zero if it has
+// none.
+uint32_t InvisibleCharacterSubstitute(uint32_t unichar) noexcept {
+  switch (unichar) {
+    case 0x0009:
+      return 0x2192;  // RIGHTWARDS ARROW
+    case 0x000A:
+    case 0x000D:
+    case 0x0085:
+    case 0x2029:
+      return 0x00B6;  // PILCROW SIGN
+    case 0x0020:
+      return 0x00B7;  // MIDDLE DOT
+    case 0x007F:
+      return 0x2421;  // SYMBOL FOR DELETE
+    case 0x00A0:
+      return 0x00B0;  // DEGREE SIGN
+    case 0x2028:
+      return 0x21B5;  // DOWNWARDS ARROW WITH CORNER LEFTWARDS
+    default:
+      // N.B. The control pictures block mirrors C0.
+      return unichar < 0x0020 ? 0x2400 + unichar : 0;
+  }
+}
+
+// Looks up the simple case mapping of `unichar` in `ranges`. Returns `false` if
+// no range covers it.
+template <std::size_t N>
+bool LookupCaseRange(const std::array<subset::CaseRange, N>& ranges,
+                     uint32_t unichar, uint32_t& mapped) noexcept {
+  const auto* range = FindRange(ranges, unichar);
+  if (range == nullptr || (unichar - range->first) % range->stride != 0) {
+    return false;
+  }
+
+  mapped = static_cast<uint32_t>(static_cast<int32_t>(unichar) + range->delta);
+  return true;
+}
+
+template <std::size_t N>
+const subset::SpecialCasing* LookupSpecialCasing(
+    const std::array<subset::SpecialCasing, N>& special_casings,
+    uint32_t unichar) noexcept {
+  auto special_casing = std::lower_bound(
+      special_casings.begin(), special_casings.end(), unichar,
+      [](const subset::SpecialCasing& special_casing, uint32_t value) {
+        return special_casing.unichar < value;
+      });
+  if (special_casing == special_casings.end() ||
+      special_casing->unichar != unichar) {
+    return nullptr;
+  }
+
+  return &*special_casing;
+}
+
+enum class CaseMapping { kUpper, kLower, kTitle };
+
+// Appends the full case mapping of `unichar`, without language or context
+// tailorings, to `characters`.
+void AppendCaseMapping(uint32_t unichar, std::size_t offset,
+                       CaseMapping case_mapping,
+                       std::vector<MappedCharacter>& characters) noexcept {
+  const subset::SpecialCasing* special_casing{nullptr};
+  uint32_t mapped{unichar};
+  switch (case_mapping) {
+    case CaseMapping::kUpper:
+      special_casing =
+          LookupSpecialCasing(subset::kSpecialUppercase, unichar);
+      LookupCaseRange(subset::kUppercaseRanges, unichar, mapped);
+      break;
+    case CaseMapping::kLower:
+      special_casing =
+          LookupSpecialCasing(subset::kSpecialLowercase, unichar);
+      LookupCaseRange(subset::kLowercaseRanges, unichar, mapped);
+      break;
+    case CaseMapping::kTitle:
+      special_casing =
+          LookupSpecialCasing(subset::kSpecialTitlecase, unichar);
+      if (special_casing == nullptr &&
+          !LookupCaseRange(subset::kTitlecaseRanges, unichar, mapped)) {
+        AppendCaseMapping(unichar, offset, CaseMapping::kUpper, characters);
+        return;
+      }
+      break;
+  }
+
+  if (special_casing == nullptr) {
+    characters.push_back({mapped, offset});
+    return;
+  }
+
+  for (auto character : special_casing->mapping) {
+    if (character != 0) {
+      characters.push_back({character, offset});
+    }
+  }
+}
+
+bool IsCased(hb_unicode_funcs_t* unicode_funcs, uint32_t unichar) noexcept {
+  auto category = hb_unicode_general_category(unicode_funcs, unichar);
+  return category == HB_UNICODE_GENERAL_CATEGORY_UPPERCASE_LETTER ||
+         category == HB_UNICODE_GENERAL_CATEGORY_LOWERCASE_LETTER ||
+         category == HB_UNICODE_GENERAL_CATEGORY_TITLECASE_LETTER;
+}
+
+bool IsLetterOrNumber(hb_unicode_funcs_t* unicode_funcs,
+                      uint32_t unichar) noexcept {
+  switch (hb_unicode_general_category(unicode_funcs, unichar)) {
+    case HB_UNICODE_GENERAL_CATEGORY_LOWERCASE_LETTER:
+    case HB_UNICODE_GENERAL_CATEGORY_MODIFIER_LETTER:
+    case HB_UNICODE_GENERAL_CATEGORY_OTHER_LETTER:
+    case HB_UNICODE_GENERAL_CATEGORY_TITLECASE_LETTER:
+    case HB_UNICODE_GENERAL_CATEGORY_UPPERCASE_LETTER:
+    case HB_UNICODE_GENERAL_CATEGORY_DECIMAL_NUMBER:
+    case HB_UNICODE_GENERAL_CATEGORY_LETTER_NUMBER:
+    case HB_UNICODE_GENERAL_CATEGORY_OTHER_NUMBER:
+      return true;
+    default:
+      return false;
+  }
+}
+
+// N.B. Approximates the Case_Ignorable property by general category and the
+// word break MidLetter and MidNumLet characters.
+bool IsCaseIgnorable(hb_unicode_funcs_t* unicode_funcs,
+                     uint32_t unichar) noexcept {
+  switch (unichar) {
+    case 0x0027:
+    case 0x002E:
+    case 0x003A:
+    case 0x00B7:
+    case 0x2018:
+    case 0x2019:
+    case 0x2024:
+    case 0x2027:
+      return true;
+    default:
+      break;
+  }
+
+  auto category = hb_unicode_general_category(unicode_funcs, unichar);
+  return category == HB_UNICODE_GENERAL_CATEGORY_NON_SPACING_MARK ||
+         category == HB_UNICODE_GENERAL_CATEGORY_ENCLOSING_MARK ||
+         category == HB_UNICODE_GENERAL_CATEGORY_FORMAT ||
+         category == HB_UNICODE_GENERAL_CATEGORY_MODIFIER_LETTER ||
+         category == HB_UNICODE_GENERAL_CATEGORY_MODIFIER_SYMBOL;
+}
+
+// Returns whether the capital sigma at `index` ends a word, per the
+// Final_Sigma casing context.
+bool IsFinalSigma(hb_unicode_funcs_t* unicode_funcs, const uint32_t* unichars,
+                  std::size_t unichar_count, std::size_t index) noexcept {
+  bool after_cased{false};
+  for (auto before = index; before > 0; --before) {
+    if (!IsCaseIgnorable(unicode_funcs, unichars[before - 1])) {
+      after_cased = IsCased(unicode_funcs, unichars[before - 1]);
+      break;
+    }
+  }
+  if (!after_cased) {
+    return false;
+  }
 
-  bool backwards_scan{false};
-  hb_script_t last_script{HB_SCRIPT_INVALID};
-  for (std::size_t index = 0; index < run.length; ++index) {
-    auto script =
-        hb_unicode_script(unicode_funcs, unichars[run.offset + index]);
-    scripts.push_back(script);
-    if (script == HB_SCRIPT_COMMON || script == HB_SCRIPT_INHERITED) {
-      if (last_script != HB_SCRIPT_INVALID) {
-        scripts.back() = last_script;
-      } else {
-        backwards_scan = true;
-      }
-    } else {
-      last_script = script;
+  for (auto after = index + 1; after < unichar_count; ++after) {
+    if (!IsCaseIgnorable(unicode_funcs, unichars[after])) {
+      return !IsCased(unicode_funcs, unichars[after]);
+    }
+  }
+
+  return true;
+}
+
+bool IsStarterOrAbove(hb_unicode_funcs_t* unicode_funcs,
+                      uint32_t unichar) noexcept {
+  auto combining_class = hb_unicode_combining_class(unicode_funcs, unichar);
+  return combining_class == 0 || combining_class == 230;
+}
+
+// Returns the closest character before `index` with a combining class of zero
+// or 230, as in the After_I and After_Soft_Dotted casing contexts, or zero.
+uint32_t PrecedingStarterOrAbove(hb_unicode_funcs_t* unicode_funcs,
+                                 const uint32_t* unichars,
+                                 std::size_t index) noexcept {
+  for (auto before = index; before > 0; --before) {
+    if (IsStarterOrAbove(unicode_funcs, unichars[before - 1])) {
+      return unichars[before - 1];
     }
   }
 
-  if (backwards_scan) {
-    last_script = HB_SCRIPT_INVALID;
-    for (auto it = scripts.rbegin(); it != scripts.rend(); ++it) {
-      auto script = *it;
-      if (script == HB_SCRIPT_COMMON || script == HB_SCRIPT_INHERITED) {
-        if (last_script != HB_SCRIPT_INVALID) {
-          *it = last_script;
+  return 0;
+}
+
+// Returns the closest character after `index` with a combining class of zero
+// or 230, as in the More_Above and Before_Dot casing contexts, or zero.
+uint32_t FollowingStarterOrAbove(hb_unicode_funcs_t* unicode_funcs,
+                                 const uint32_t* unichars,
+                                 std::size_t unichar_count,
+                                 std::size_t index) noexcept {
+  for (auto after = index + 1; after < unichar_count; ++after) {
+    if (IsStarterOrAbove(unicode_funcs, unichars[after])) {
+      return unichars[after];
+    }
+  }
+
+  return 0;
+}
+
+bool IsSoftDotted(uint32_t unichar) noexcept {
+  switch (unichar) {
+    case 0x0069:
+    case 0x006A:
+    case 0x012F:
+    case 0x0249:
+    case 0x0268:
+    case 0x029D:
+    case 0x02B2:
+    case 0x03F3:
+    case 0x0456:
+    case 0x0458:
+    case 0x1D62:
+    case 0x1D96:
+    case 0x1DA4:
+    case 0x1DA8:
+    case 0x1E2D:
+    case 0x1ECB:
+    case 0x2071:
+    case 0x2148:
+    case 0x2149:
+    case 0x2C7C:
+      return true;
+    default:
+      // N.B. The mathematical alphanumeric i and j.
+      return unichar >= 0x1D422 && unichar <= 0x1D693 &&
+             (unichar - 0x1D422) % 0x34 < 2;
+  }
+}
+
+// The language tailorings of case mappings in SpecialCasing.txt.
+struct CaseTailoring {
+  bool turkic;
+  bool lithuanian;
+};
+
+// Appends the lowercase mapping of the character at `index` to `characters`.
+void AppendLowercase(hb_unicode_funcs_t* unicode_funcs,
+                     const uint32_t* unichars, std::size_t unichar_count,
+                     std::size_t index, CaseTailoring tailoring,
+                     std::vector<MappedCharacter>& characters) noexcept {
+  auto unichar = unichars[index];
+  auto push = [&](std::initializer_list<uint32_t> mapping) {
+    for (auto character : mapping) {
+      characters.push_back({character, index});
+    }
+  };
+  if (tailoring.turkic) {
+    // N.B. A dotless capital I lowercases to a dotless i, unless followed by a
+    // combining dot above, which then goes with it.
+    switch (unichar) {
+      case 0x0130:
+        push({0x0069});
+        return;
+      case 0x0307:
+        if (PrecedingStarterOrAbove(unicode_funcs, unichars, index) != 0x0049) {
+          push({0x0307});
         }
-      } else {
-        last_script = script;
+        return;
+      case 0x0049:
+        push({FollowingStarterOrAbove(unicode_funcs, unichars, unichar_count,
+                                      index) == 0x0307
+                  ? 0x0069U
+                  : 0x0131U});
+        return;
+      default:
+        break;
+    }
+  }
+
+  if (tailoring.lithuanian) {
+    // N.B. The i keeps an explicit dot when followed by accents above.
+    auto following =
+        FollowingStarterOrAbove(unicode_funcs, unichars, unichar_count, index);
+    auto more_above =
+        following != 0 &&
+        hb_unicode_combining_class(unicode_funcs, following) == 230;
+    switch (unichar) {
+      case 0x0049:
+      case 0x004A:
+      case 0x012E:
+        if (more_above) {
+          AppendCaseMapping(unichar, index, CaseMapping::kLower, characters);
+          push({0x0307});
+          return;
+        }
+        break;
+      case 0x00CC:
+        push({0x0069, 0x0307, 0x0300});
+        return;
+      case 0x00CD:
+        push({0x0069, 0x0307, 0x0301});
+        return;
+      case 0x0128:
+        push({0x0069, 0x0307, 0x0303});
+        return;
+      default:
+        break;
+    }
+  }
+
+  if (unichar == 0x03A3 &&
+      IsFinalSigma(unicode_funcs, unichars, unichar_count, index)) {
+    push({0x03C2});
+    return;
+  }
+
+  AppendCaseMapping(unichar, index, CaseMapping::kLower, characters);
+}
+
+// Appends the uppercase or titlecase mapping of the character at `index` to
+// `characters`.
+void AppendUppercase(hb_unicode_funcs_t* unicode_funcs,
+                     const uint32_t* unichars, std::size_t index,
+                     CaseMapping case_mapping, CaseTailoring tailoring,
+                     std::vector<MappedCharacter>& characters) noexcept {
+  auto unichar = unichars[index];
+  if (tailoring.turkic && unichar == 0x0069) {
+    characters.push_back({0x0130, index});
+    return;
+  }
+
+  // N.B. The explicit dot of a Lithuanian i goes away with its tittle.
+  if (tailoring.lithuanian && unichar == 0x0307 &&
+      IsSoftDotted(PrecedingStarterOrAbove(unicode_funcs, unichars, index))) {
+    return;
+  }
+
+  AppendCaseMapping(unichar, index, case_mapping, characters);
+}
+
+bool IsGreekAccent(uint32_t unichar) noexcept {
+  switch (unichar) {
+    case 0x0300:
+    case 0x0301:
+    case 0x0313:
+    case 0x0314:
+    case 0x0340:
+    case 0x0341:
+    case 0x0342:
+    case 0x0343:
+      return true;
+    default:
+      return false;
+  }
+}
+
+// Uppercases `unichars` the way Greek is written in capitals, i.e. without
+// accents and breathings, where an iota or upsilon after a vowel that lost its
+// accent gets a dialytika so the two are still read apart.
+void AppendGreekUppercase(hb_unicode_funcs_t* unicode_funcs,
+                          const uint32_t* unichars, std::size_t unichar_count,
+                          std::vector<MappedCharacter>& characters) noexcept {
+  bool after_accented_vowel{false};
+  std::vector<MappedCharacter> cluster{};
+  std::size_t index = 0;
+  while (index < unichar_count) {
+    auto start = index;
+    cluster.clear();
+    Decompose(unicode_funcs, unichars[index], index, cluster);
+    ++index;
+    while (index < unichar_count &&
+           hb_unicode_combining_class(unicode_funcs, unichars[index]) != 0) {
+      Decompose(unicode_funcs, unichars[index], index, cluster);
+      ++index;
+    }
+
+    if (hb_unicode_script(unicode_funcs, unichars[start]) != HB_SCRIPT_GREEK) {
+      for (auto character = start; character < index; ++character) {
+        AppendCaseMapping(unichars[character], character, CaseMapping::kUpper,
+                          characters);
+      }
+      after_accented_vowel = false;
+      continue;
+    }
+
+    uint32_t upper{cluster[0].unichar};
+    LookupCaseRange(subset::kUppercaseRanges, cluster[0].unichar, upper);
+    auto has_accent = std::any_of(
+        cluster.begin() + 1, cluster.end(),
+        [](const MappedCharacter& c) { return IsGreekAccent(c.unichar); });
+    auto has_dialytika = std::any_of(
+        cluster.begin() + 1, cluster.end(),
+        [](const MappedCharacter& c) { return c.unichar == 0x0308; });
+    auto iota_or_upsilon = upper == 0x0399 || upper == 0x03A5;
+    if (iota_or_upsilon && after_accented_vowel && !has_accent) {
+      has_dialytika = true;
+    }
+
+    if (has_dialytika && iota_or_upsilon) {
+      characters.push_back({upper == 0x0399 ? 0x03AAU : 0x03ABU, start});
+    } else {
+      characters.push_back({upper, start});
+      if (has_dialytika) {
+        characters.push_back({0x0308, start});
       }
     }
+    for (auto it = cluster.begin() + 1; it != cluster.end(); ++it) {
+      if (!IsGreekAccent(it->unichar) && it->unichar != 0x0308) {
+        // N.B. The ypogegrammeni becomes a capital iota.
+        AppendCaseMapping(it->unichar, it->offset, CaseMapping::kUpper,
+                          characters);
+      }
+    }
+
+    auto vowel = iota_or_upsilon || upper == 0x0391 || upper == 0x0395 ||
+                 upper == 0x0397 || upper == 0x039F || upper == 0x03A9;
+    after_accented_vowel = vowel && has_accent && !has_dialytika;
   }
 }
 
@@ -254,6 +1979,44 @@ bool subset_font_has_glyph(SubsetFont* font, uint32_t unichar) {
   return hb_font_get_nominal_glyph(FONT(font), unichar, &glyph) != 0;
 }
 
+bool subset_font_has_variation_glyph(SubsetFont* font, uint32_t unichar,
+                                     uint32_t variation_selector) {
+  hb_codepoint_t glyph{0};
+  return hb_font_get_variation_glyph(FONT(font), unichar, variation_selector,
+                                     &glyph) != 0;
+}
+
+bool subset_font_get_nominal_glyph(SubsetFont* font, uint32_t unichar,
+                                   uint32_t* glyph_id) {
+  hb_codepoint_t glyph{0};
+  auto found = hb_font_get_nominal_glyph(FONT(font), unichar, &glyph) != 0;
+  if (found && glyph_id != nullptr) {
+    *glyph_id = glyph;
+  }
+
+  return found;
+}
+
+bool subset_font_get_variation_glyph(SubsetFont* font, uint32_t unichar,
+                                     uint32_t variation_selector,
+                                     uint32_t* glyph_id) {
+  hb_codepoint_t glyph{0};
+  auto found = hb_font_get_variation_glyph(FONT(font), unichar,
+                                           variation_selector, &glyph) != 0;
+  if (found && glyph_id != nullptr) {
+    *glyph_id = glyph;
+  }
+
+  return found;
+}
+
+bool subset_font_has_color_glyphs(SubsetFont* font) {
+  auto* face = hb_font_get_face(FONT(font));
+  return hb_ot_color_has_paint(face) != 0 ||
+         hb_ot_color_has_layers(face) != 0 || hb_ot_color_has_png(face) != 0 ||
+         hb_ot_color_has_svg(face) != 0;
+}
+
 bool subset_font_is_italic(SubsetFont* font) {
   return hb_style_get_value(FONT(font), HB_STYLE_TAG_ITALIC) == 1.0;
 }
@@ -293,6 +2056,194 @@ bool subset_font_extents(SubsetFont* font, bool horizontal, int32_t* ascender,
   return found != 0;
 }
 
+bool subset_font_glyph_extents(SubsetFont* font, uint32_t glyph_id,
+                               int32_t* x_bearing, int32_t* y_bearing,
+                               int32_t* width, int32_t* height) {
+  hb_glyph_extents_t extents;
+  auto found = hb_font_get_glyph_extents(FONT(font), glyph_id, &extents);
+  if (found != 0) {
+    if (x_bearing != nullptr) {
+      *x_bearing = extents.x_bearing;
+    }
+
+    if (y_bearing != nullptr) {
+      *y_bearing = extents.y_bearing;
+    }
+
+    if (width != nullptr) {
+      *width = extents.width;
+    }
+
+    if (height != nullptr) {
+      *height = extents.height;
+    }
+  }
+
+  return found != 0;
+}
+
+int32_t subset_font_glyph_advance(SubsetFont* font, uint32_t glyph_id,
+                                  bool vertical) {
+  if (vertical) {
+    return hb_font_get_glyph_v_advance(FONT(font), glyph_id);
+  }
+
+  return hb_font_get_glyph_h_advance(FONT(font), glyph_id);
+}
+
+bool subset_font_metrics(SubsetFont* font, SubsetFontMetrics* metrics) {
+  if (font == nullptr || metrics == nullptr) {
+    return false;
+  }
+
+  auto metric = [font](hb_ot_metrics_tag_t tag) -> int32_t {
+    hb_position_t position{0};
+    hb_ot_metrics_get_position_with_fallback(FONT(font), tag, &position);
+    return position;
+  };
+
+  *metrics = SubsetFontMetrics{};
+  metrics->underline_offset = metric(HB_OT_METRICS_TAG_UNDERLINE_OFFSET);
+  metrics->underline_size = metric(HB_OT_METRICS_TAG_UNDERLINE_SIZE);
+  metrics->strikeout_offset = metric(HB_OT_METRICS_TAG_STRIKEOUT_OFFSET);
+  metrics->strikeout_size = metric(HB_OT_METRICS_TAG_STRIKEOUT_SIZE);
+  metrics->x_height = metric(HB_OT_METRICS_TAG_X_HEIGHT);
+  metrics->cap_height = metric(HB_OT_METRICS_TAG_CAP_HEIGHT);
+  metrics->subscript_x_offset =
+      metric(HB_OT_METRICS_TAG_SUBSCRIPT_EM_X_OFFSET);
+  metrics->subscript_y_offset =
+      metric(HB_OT_METRICS_TAG_SUBSCRIPT_EM_Y_OFFSET);
+  metrics->subscript_x_size = metric(HB_OT_METRICS_TAG_SUBSCRIPT_EM_X_SIZE);
+  metrics->subscript_y_size = metric(HB_OT_METRICS_TAG_SUBSCRIPT_EM_Y_SIZE);
+  metrics->superscript_x_offset =
+      metric(HB_OT_METRICS_TAG_SUPERSCRIPT_EM_X_OFFSET);
+  metrics->superscript_y_offset =
+      metric(HB_OT_METRICS_TAG_SUPERSCRIPT_EM_Y_OFFSET);
+  metrics->superscript_x_size =
+      metric(HB_OT_METRICS_TAG_SUPERSCRIPT_EM_X_SIZE);
+  metrics->superscript_y_size =
+      metric(HB_OT_METRICS_TAG_SUPERSCRIPT_EM_Y_SIZE);
+
+  auto* face = hb_font_get_face(FONT(font));
+  std::unique_ptr<hb_blob_t, BlobDeleter> os2(
+      hb_face_reference_table(face, HB_TAG('O', 'S', '/', '2')),
+      BlobDeleter{});
+  constexpr unsigned int kFsSelectionOffset = 62;
+  constexpr unsigned int kTypoAscenderOffset = 68;
+  constexpr unsigned int kTypoDescenderOffset = 70;
+  constexpr unsigned int kTypoLineGapOffset = 72;
+  constexpr unsigned int kWinAscentOffset = 74;
+  constexpr unsigned int kWinDescentOffset = 76;
+  constexpr uint16_t kUseTypoMetrics = 1U << 7U;
+  metrics->typo_ascender = ReadInt16(os2.get(), kTypoAscenderOffset);
+  metrics->typo_descender = ReadInt16(os2.get(), kTypoDescenderOffset);
+  metrics->typo_line_gap = ReadInt16(os2.get(), kTypoLineGapOffset);
+  metrics->win_ascent = ReadUInt16(os2.get(), kWinAscentOffset);
+  metrics->win_descent = ReadUInt16(os2.get(), kWinDescentOffset);
+  metrics->use_typo_metrics =
+      (ReadUInt16(os2.get(), kFsSelectionOffset) & kUseTypoMetrics) != 0;
+
+  std::unique_ptr<hb_blob_t, BlobDeleter> hhea(
+      hb_face_reference_table(face, HB_TAG('h', 'h', 'e', 'a')),
+      BlobDeleter{});
+  constexpr unsigned int kHheaAscenderOffset = 4;
+  constexpr unsigned int kHheaDescenderOffset = 6;
+  constexpr unsigned int kHheaLineGapOffset = 8;
+  metrics->hhea_ascender = ReadInt16(hhea.get(), kHheaAscenderOffset);
+  metrics->hhea_descender = ReadInt16(hhea.get(), kHheaDescenderOffset);
+  metrics->hhea_line_gap = ReadInt16(hhea.get(), kHheaLineGapOffset);
+  return true;
+}
+
+bool subset_font_get_baseline(SubsetFont* font, uint32_t baseline_tag,
+                              bool vertical, uint32_t script,
+                              const char* language, int32_t* coord) {
+  if (font == nullptr || coord == nullptr) {
+    return false;
+  }
+
+  const auto tag = static_cast<hb_ot_layout_baseline_tag_t>(baseline_tag);
+  const auto direction = vertical ? HB_DIRECTION_TTB : HB_DIRECTION_LTR;
+  const auto hb_script = static_cast<hb_script_t>(script);
+  const auto* lang = language == nullptr
+                         ? HB_LANGUAGE_INVALID
+                         : hb_language_from_string(language, -1);
+  hb_position_t position{0};
+  if (hb_ot_layout_get_baseline2(FONT(font), tag, direction, hb_script, lang,
+                                 &position)) {
+    *coord = position;
+    return true;
+  }
+
+  hb_ot_layout_get_baseline_with_fallback2(FONT(font), tag, direction,
+                                           hb_script, lang, &position);
+  *coord = position;
+  return false;
+}
+
+bool subset_font_has_feature(SubsetFont* font, uint32_t feature_tag) {
+  if (font == nullptr) {
+    return false;
+  }
+
+  auto* face = hb_font_get_face(FONT(font));
+  return HasFeature(face, HB_OT_TAG_GSUB, feature_tag) ||
+         HasFeature(face, HB_OT_TAG_GPOS, feature_tag);
+}
+
+SubsetGlyphClass subset_font_glyph_class(SubsetFont* font, uint32_t glyph_id) {
+  if (font == nullptr) {
+    return SUBSET_GLYPH_CLASS_UNCLASSIFIED;
+  }
+
+  auto* face = hb_font_get_face(FONT(font));
+  switch (hb_ot_layout_get_glyph_class(face, glyph_id)) {
+    case HB_OT_LAYOUT_GLYPH_CLASS_BASE_GLYPH:
+      return SUBSET_GLYPH_CLASS_BASE;
+    case HB_OT_LAYOUT_GLYPH_CLASS_LIGATURE:
+      return SUBSET_GLYPH_CLASS_LIGATURE;
+    case HB_OT_LAYOUT_GLYPH_CLASS_MARK:
+      return SUBSET_GLYPH_CLASS_MARK;
+    case HB_OT_LAYOUT_GLYPH_CLASS_COMPONENT:
+      return SUBSET_GLYPH_CLASS_COMPONENT;
+    default:
+      return SUBSET_GLYPH_CLASS_UNCLASSIFIED;
+  }
+}
+
+unsigned int subset_font_ligature_carets(SubsetFont* font,
+                                         SubsetDirection direction,
+                                         uint32_t glyph_id,
+                                         unsigned int start_offset,
+                                         unsigned int* caret_count,
+                                         int32_t* carets) {
+  if (font == nullptr) {
+    if (caret_count != nullptr) {
+      *caret_count = 0;
+    }
+
+    return 0;
+  }
+
+  hb_direction_t hb_direction = HB_DIRECTION_LTR;
+  switch (direction) {
+    case SUBSET_DIRECTION_RTL:
+      hb_direction = HB_DIRECTION_RTL;
+      break;
+    case SUBSET_DIRECTION_TTB:
+      hb_direction = HB_DIRECTION_TTB;
+      break;
+    case SUBSET_DIRECTION_BTT:
+      hb_direction = HB_DIRECTION_BTT;
+      break;
+    default:
+      break;
+  }
+
+  return hb_ot_layout_get_ligature_carets(FONT(font), hb_direction, glyph_id,
+                                          start_offset, caret_count, carets);
+}
+
 void subset_font_draw_glyph(SubsetFont* font, uint32_t glyph_id,
                             SubsetGlyphDrawer* drawer,
                             SubsetPathCommandCallback callback, void* context) {
@@ -305,14 +2256,28 @@ void subset_font_draw_glyph(SubsetFont* font, uint32_t glyph_id,
 }
 
 int subset_text_runs(const uint32_t* unichars, size_t unichar_count,
-                     uint8_t* paragraph_base_level,
+                     const SubsetBidiSpan* bidi_spans, size_t bidi_span_count,
+                     bool vertical, uint8_t* paragraph_base_level,
                      SubsetTextRunCallback callback, void* context) {
   if (unichars == nullptr || unichar_count == 0) {
     return 0;
   }
 
-  SBCodepointSequence codepoint_seq{SBStringEncodingUTF32, (void*)unichars,
-                                    unichar_count};
+  // N.B. The bidi algorithm sees the control characters of the spans, while
+  // runs are reported over the original text.
+  const uint32_t* bidi_text = unichars;
+  std::size_t bidi_text_count = unichar_count;
+  std::vector<uint32_t> text{};
+  std::vector<std::size_t> offsets{};
+  if (bidi_spans != nullptr && bidi_span_count > 0) {
+    ApplyBidiSpans(unichars, unichar_count, bidi_spans, bidi_span_count, text,
+                   offsets);
+    bidi_text = text.data();
+    bidi_text_count = text.size();
+  }
+
+  SBCodepointSequence codepoint_seq{SBStringEncodingUTF32, (void*)bidi_text,
+                                    bidi_text_count};
   std::unique_ptr<_SBAlgorithm, BidiAlgorithmDeleter> algo(
       SBAlgorithmCreate(&codepoint_seq), BidiAlgorithmDeleter{});
   if (!algo) {
@@ -326,10 +2291,8 @@ int subset_text_runs(const uint32_t* unichars, size_t unichar_count,
     return 1;
   }
 
-  if (SBParagraphGetLength(para.get()) != unichar_count) {
-    return 2;
-  }
-
+  // N.B. Paragraphs end after hard line breaks and are resolved one by one,
+  // each with its own base level.
   if (paragraph_base_level != nullptr) {
     *paragraph_base_level = SBParagraphGetBaseLevel(para.get());
   }
@@ -338,151 +2301,275 @@ int subset_text_runs(const uint32_t* unichars, size_t unichar_count,
     return 0;
   }
 
-  std::unique_ptr<_SBLine, BidiLineDeleter> line(
-      SBParagraphCreateLine(para.get(), 0, unichar_count), BidiLineDeleter{});
-  if (!line) {
-    return 1;
+  std::vector<hb_script_t> scripts{};
+  ScriptsForText(unichars, unichar_count, scripts);
+  std::vector<SubsetEmojiPresentation> presentations{};
+  std::vector<bool> sequence_starts{};
+  EmojiPresentationsForText(unichars, unichar_count, presentations,
+                            sequence_starts);
+  std::vector<subset::VerticalOrientationValue> orientations{};
+  if (vertical) {
+    OrientationsForText(unichars, unichar_count, sequence_starts,
+                        presentations, orientations);
+  } else {
+    orientations.assign(unichar_count, subset::kVerticalRotated);
   }
 
-  auto run_count = SBLineGetRunCount(line.get());
-  const auto* runs = SBLineGetRunsPtr(line.get());
+  std::size_t paragraph_offset = 0;
+  while (paragraph_offset < bidi_text_count) {
+    if (!para) {
+      return 1;
+    }
 
-  std::vector<hb_script_t> scripts{};
-  for (std::size_t run_index = 0; run_index < run_count; ++run_index) {
-    auto run = runs[run_index];
-    ScriptsForRun(unichars, run, scripts);
-
-    // Split the BiDi run on script boundaries if needed.
-    std::size_t offset = 0;
-    std::size_t remaining = run.length;
-    hb_script_t last_script = HB_SCRIPT_INVALID;
-    for (std::size_t index = 0; index < run.length; ++index) {
-      auto script = scripts[index];
-      if (last_script != HB_SCRIPT_INVALID && script != last_script) {
-        auto len = index - offset;
-        SubsetTextRun text_run{
-            .offset = run.offset + offset,
-            .length = len,
-            .bidi_level = run.level,
-            .script = script,
-        };
-        callback(text_run, context);
-        offset = index;
-        remaining -= len;
-      }
+    auto paragraph_length = SBParagraphGetLength(para.get());
+    if (paragraph_length == 0) {
+      return 2;
+    }
 
-      last_script = script;
+    std::unique_ptr<_SBLine, BidiLineDeleter> line(
+        SBParagraphCreateLine(para.get(), paragraph_offset, paragraph_length),
+        BidiLineDeleter{});
+    if (!line) {
+      return 1;
     }
 
-    SubsetTextRun text_run{
-        .offset = run.offset + offset,
-        .length = remaining,
-        .bidi_level = run.level,
-        .script = last_script,
-    };
-    callback(text_run, context);
+    auto paragraph_level = SBParagraphGetBaseLevel(para.get());
+    auto run_count = SBLineGetRunCount(line.get());
+    const auto* runs = SBLineGetRunsPtr(line.get());
+    for (std::size_t run_index = 0; run_index < run_count; ++run_index) {
+      auto run = runs[run_index];
+      if (!offsets.empty() && !UnapplyBidiSpans(offsets, run)) {
+        continue;
+      }
+      EmitTextRuns(run, paragraph_level, scripts, presentations,
+                   sequence_starts, orientations, callback, context);
+    }
+
+    paragraph_offset += paragraph_length;
+    if (paragraph_offset < bidi_text_count) {
+      para.reset(SBAlgorithmCreateParagraph(
+          algo.get(), paragraph_offset, bidi_text_count - paragraph_offset,
+          SBLevelDefaultLTR));
+    }
   }
 
   return 0;
 }
 
 // NOLINTNEXTLINE
-bool subset_find_best_font_match(uint32_t unichar, SubsetFontStyle font_style,
-                                 size_t font_count,
-                                 SubsetFontProvider font_provider,
-                                 void* font_provider_context,
-                                 size_t* best_index) {
-  if (font_provider == nullptr || font_count == 0) {
+bool subset_segment(const uint32_t* unichars, size_t unichar_count,
+                    SubsetSegmentation segmentation, const char* language,
+                    SubsetBoundaryCallback callback, void* context) {
+  if ((unichars == nullptr && unichar_count > 0) || callback == nullptr) {
     return false;
   }
 
-  float max_score{0};
-  size_t max_index{0};
-  for (size_t index = 0; index < font_count; ++index) {
-    auto* font = font_provider(index, font_provider_context);
-    if (!subset_font_has_glyph(font, unichar)) {
-      continue;
-    }
+  std::vector<size_t> boundaries;
+  switch (segmentation) {
+    case SUBSET_SEGMENTATION_GRAPHEME:
+      boundaries = GraphemeBoundaries(unichars, unichar_count);
+      break;
+    case SUBSET_SEGMENTATION_WORD:
+      boundaries = WordBoundaries(unichars, unichar_count,
+                                  LanguageIs(language, "fr") ||
+                                      LanguageIs(language, "it") ||
+                                      LanguageIs(language, "ca"));
+      break;
+    case SUBSET_SEGMENTATION_SENTENCE:
+      boundaries = SentenceBoundaries(unichars, unichar_count);
+      break;
+    case SUBSET_SEGMENTATION_HARD_LINE_BREAK:
+      boundaries = HardLineBreakBoundaries(unichars, unichar_count);
+      break;
+    default:
+      return false;
+  }
 
-    constexpr float kMaxWidthScore = 225.0;
-    float width_score{0};
-    auto width = subset_font_width(font);
-    if (font_style.width <= 100.0) {
-      if (width <= font_style.width) {
-        width_score = kMaxWidthScore - font_style.width + width;
-      } else {
-        width_score = kMaxWidthScore - width;
-      }
-    } else {
-      if (width > font_style.width) {
-        width_score = kMaxWidthScore + font_style.width - width;
-      } else {
-        width_score = width;
-      }
-    }
+  for (auto offset : boundaries) {
+    callback(offset, context);
+  }
 
-    constexpr float kItalicMatchScore = 3.0;
-    float italic_score{1};
-    if (subset_font_is_italic(font)) {
-      if (font_style.italic) {
-        italic_score = kItalicMatchScore;
-      }
-    } else {
-      if (!font_style.italic) {
-        italic_score = kItalicMatchScore;
+  return true;
+}
+
+bool subset_transform_text(const uint32_t* unichars, size_t unichar_count,
+                           SubsetTextTransform transform, const char* language,
+                           SubsetTransformedCallback callback, void* context) {
+  if ((unichars == nullptr && unichar_count > 0) || callback == nullptr) {
+    return false;
+  }
+
+  auto* unicode_funcs = hb_unicode_funcs_get_default();
+  CaseTailoring tailoring{
+      .turkic = LanguageIs(language, "tr") || LanguageIs(language, "az"),
+      .lithuanian = LanguageIs(language, "lt"),
+  };
+  std::vector<MappedCharacter> characters{};
+  characters.reserve(unichar_count);
+  switch (transform) {
+    case SUBSET_TEXT_TRANSFORM_UPPERCASE:
+      if (LanguageIs(language, "el")) {
+        AppendGreekUppercase(unicode_funcs, unichars, unichar_count,
+                             characters);
+        break;
       }
-    }
 
-    constexpr float kMaxWeightScore = 1000.0;
-    constexpr float kNormalWeight = 400.0;
-    constexpr float kMediumWeight = 500.0;
-    float weight_score{0};
-    auto weight = subset_font_weight(font);
-    if (font_style.weight == weight) {
-      weight_score = kMaxWeightScore;
-    } else if (font_style.weight < kNormalWeight) {
-      if (weight <= font_style.weight) {
-        weight_score = kMaxWeightScore - font_style.weight + weight;
-      } else {
-        weight_score = kMaxWeightScore - weight;
+      for (std::size_t index = 0; index < unichar_count; ++index) {
+        AppendUppercase(unicode_funcs, unichars, index, CaseMapping::kUpper,
+                        tailoring, characters);
       }
-    } else if (font_style.weight <= kMediumWeight) {
-      if (weight >= font_style.weight && weight <= kMediumWeight) {
-        weight_score = kMaxWeightScore + font_style.weight - weight;
-      } else if (weight <= font_style.weight) {
-        weight_score = kMediumWeight + weight;
-      } else {
-        weight_score = kMaxWeightScore - weight;
+      break;
+    case SUBSET_TEXT_TRANSFORM_LOWERCASE:
+      for (std::size_t index = 0; index < unichar_count; ++index) {
+        AppendLowercase(unicode_funcs, unichars, unichar_count, index,
+                        tailoring, characters);
       }
-    } else if (font_style.weight > kMediumWeight) {
-      if (weight > font_style.weight) {
-        weight_score = kMaxWeightScore + font_style.weight - weight;
-      } else {
-        weight_score = weight;
+      break;
+    case SUBSET_TEXT_TRANSFORM_CAPITALIZE: {
+      auto boundaries = WordBoundaries(unichars, unichar_count,
+                                       LanguageIs(language, "fr") ||
+                                           LanguageIs(language, "it") ||
+                                           LanguageIs(language, "ca"));
+      auto boundary = boundaries.begin();
+      bool capitalized{false};
+      for (std::size_t index = 0; index < unichar_count; ++index) {
+        if (boundary != boundaries.end() && *boundary == index) {
+          capitalized = false;
+          ++boundary;
+        }
+
+        if (!capitalized && IsLetterOrNumber(unicode_funcs, unichars[index])) {
+          AppendUppercase(unicode_funcs, unichars, index, CaseMapping::kTitle,
+                          tailoring, characters);
+          capitalized = true;
+        } else {
+          characters.push_back({unichars[index], index});
+        }
       }
+      break;
     }
+    default:
+      return false;
+  }
 
-    constexpr float kWidthScoreMultiplier = 1e7;
-    constexpr float kItalicScoreMultiplier = 1e4;
-    float score = width_score * kWidthScoreMultiplier +
-                  italic_score * kItalicScoreMultiplier + weight_score;
-    if (max_score < score) {
-      max_score = score;
-      max_index = index;
-    }
+  for (const auto& character : characters) {
+    callback(character.unichar, character.offset, context);
   }
+  return true;
+}
 
-  if (max_score == 0.0) {
+bool subset_normalize(const uint32_t* unichars, size_t unichar_count,
+                      SubsetNormalization normalization,
+                      SubsetCompositionFilter filter,
+                      SubsetNormalizedCallback callback, void* context) {
+  if (unichars == nullptr || callback == nullptr) {
     return false;
   }
 
-  if (best_index != nullptr) {
-    *best_index = max_index;
+  auto* unicode_funcs = hb_unicode_funcs_get_default();
+  std::vector<MappedCharacter> characters{};
+  characters.reserve(unichar_count);
+  for (std::size_t offset = 0; offset < unichar_count; ++offset) {
+    Decompose(unicode_funcs, unichars[offset], offset, characters);
+  }
+  ReorderMarks(unicode_funcs, characters);
+  if (normalization == SUBSET_NORMALIZATION_NFC) {
+    Compose(unicode_funcs, characters, filter, context);
   }
 
+  for (auto it = characters.rbegin(); it != characters.rend(); ++it) {
+    if (it != characters.rbegin()) {
+      it->offset = std::min(it->offset, std::prev(it)->offset);
+    }
+  }
+  for (const auto& character : characters) {
+    callback(character.unichar, character.offset, context);
+  }
   return true;
 }
 
+void subset_unicode_properties(uint32_t unichar,
+                               SubsetUnicodeProperties* properties) {
+  if (properties == nullptr) {
+    return;
+  }
+
+  auto* unicode_funcs = hb_unicode_funcs_get_default();
+  // N.B. SheenBidi reserves 0 for no type, and classes unassigned characters
+  // by their default, so it never comes up.
+  auto bidi_type = SBCodepointGetBidiType(unichar);
+  *properties = SubsetUnicodeProperties{
+      .general_category = static_cast<SubsetGeneralCategory>(
+          hb_unicode_general_category(unicode_funcs, unichar)),
+      .script =
+          static_cast<uint32_t>(hb_unicode_script(unicode_funcs, unichar)),
+      .mirroring = hb_unicode_mirroring(unicode_funcs, unichar),
+      .combining_class = static_cast<uint8_t>(
+          hb_unicode_combining_class(unicode_funcs, unichar)),
+      .bidi_class = bidi_type == SBBidiTypeNil
+                        ? SUBSET_BIDI_CLASS_ON
+                        : static_cast<SubsetBidiClass>(bidi_type - SBBidiTypeL),
+  };
+}
+
+bool subset_is_variation_selector(uint32_t unichar) {
+  return IsVariationSelector(unichar);
+}
+
+bool subset_is_word_separator(uint32_t unichar) {
+  return IsWordSeparator(unichar);
+}
+
+bool subset_find_best_font_match(uint32_t unichar, SubsetFontStyle font_style,
+                                 size_t font_count,
+                                 SubsetFontProvider font_provider,
+                                 void* font_provider_context,
+                                 size_t* best_index) {
+  return FindBestFontMatch(
+      font_style, SUBSET_EMOJI_PRESENTATION_NONE, font_count, font_provider,
+      font_provider_context, best_index,
+      [unichar](SubsetFont* font) -> int {
+        return subset_font_has_glyph(font, unichar) ? 1 : 0;
+      });
+}
+
+// NOLINTNEXTLINE
+bool subset_find_best_font_match_for_sequence(
+    const uint32_t* unichars, size_t unichar_count,
+    SubsetEmojiPresentation emoji_presentation, SubsetFontStyle font_style,
+    size_t font_count, SubsetFontProvider font_provider,
+    void* font_provider_context, size_t* best_index) {
+  if (unichars == nullptr || unichar_count == 0) {
+    return false;
+  }
+
+  return FindBestFontMatch(
+      font_style, emoji_presentation, font_count, font_provider,
+      font_provider_context, best_index,
+      [unichars, unichar_count](SubsetFont* font) -> int {
+        if (!subset_font_has_glyph(font, unichars[0])) {
+          return 0;
+        }
+
+        for (size_t index = 1; index < unichar_count; ++index) {
+          auto unichar = unichars[index];
+          if (IsSequenceIgnorable(unichar)) {
+            continue;
+          }
+
+          auto covered = IsVariationSelector(unichar)
+                             ? subset_font_has_variation_glyph(
+                                   font, unichars[index - 1], unichar)
+                             : subset_font_has_glyph(font, unichar);
+          if (!covered) {
+            return 1;
+          }
+        }
+
+        return 2;
+      });
+}
+
 bool subset_shape(SubsetFont* font, const SubsetShapeParams* params,
                   SubsetShapeCallback callback, void* context) {
   if (font == nullptr || params == nullptr || callback == nullptr) {
@@ -498,10 +2585,27 @@ bool subset_shape(SubsetFont* font, const SubsetShapeParams* params,
   hb_buffer_add_utf32(buf, params->unichars,
                       static_cast<int>(params->unichar_count), params->offset,
                       static_cast<int>(params->length));
-  hb_buffer_set_direction(buf, (params->bidi_level & 1U) != 0
-                                   ? HB_DIRECTION_RTL
-                                   : HB_DIRECTION_LTR);
-  hb_buffer_set_script(buf, static_cast<hb_script_t>(params->script));
+  unsigned int unichar_count{0};
+  auto* unichar_info = hb_buffer_get_glyph_infos(buf, &unichar_count);
+  for (unsigned int index = 0; index < unichar_count; ++index) {
+    unichar_info[index].codepoint =
+        ControlCharacterReplacement(unichar_info[index].codepoint);
+  }
+
+  if (params->vertical) {
+    hb_buffer_set_direction(buf, HB_DIRECTION_TTB);
+  } else {
+    hb_buffer_set_direction(buf, (params->bidi_level & 1U) != 0
+                                     ? HB_DIRECTION_RTL
+                                     : HB_DIRECTION_LTR);
+  }
+
+  auto script = static_cast<hb_script_t>(params->script);
+  hb_buffer_set_script(buf, script);
+  hb_buffer_set_flags(
+      buf, static_cast<hb_buffer_flags_t>(
+               HB_BUFFER_FLAG_PRODUCE_UNSAFE_TO_CONCAT |
+               HB_BUFFER_FLAG_PRODUCE_SAFE_TO_INSERT_TATWEEL));
   if (params->language == nullptr) {
     hb_buffer_set_language(buf, DefaultLanguage());
   } else {
@@ -514,7 +2618,29 @@ bool subset_shape(SubsetFont* font, const SubsetShapeParams* params,
     }
   }
 
-  hb_shape(FONT(font), buf, nullptr, 0);
+  auto letter_spacing =
+      IsCursiveScript(script) ? 0 : static_cast<int>(params->letter_spacing);
+  std::vector<hb_feature_t> features;
+  if (params->vertical) {
+    auto vertical_features = VerticalFeatures(FONT(font));
+    features.insert(features.end(), vertical_features.begin(),
+                    vertical_features.end());
+  }
+
+  if (letter_spacing != 0) {
+    auto no_ligature_features = NoLigatureFeatures();
+    features.insert(features.end(), no_ligature_features.begin(),
+                    no_ligature_features.end());
+  }
+
+  if (params->small_caps) {
+    features.push_back(hb_feature_t{HB_TAG('s', 'm', 'c', 'p'), 1U,
+                                    HB_FEATURE_GLOBAL_START,
+                                    HB_FEATURE_GLOBAL_END});
+  }
+
+  hb_shape(FONT(font), buf, features.data(),
+           static_cast<unsigned int>(features.size()));
 
   unsigned int glyph_count{0};
   auto* glyph_info = hb_buffer_get_glyph_infos(buf, &glyph_count);
@@ -522,11 +2648,66 @@ bool subset_shape(SubsetFont* font, const SubsetShapeParams* params,
   for (unsigned int index = 0; index < glyph_count; ++index) {
     SubsetGlyph glyph{
         .glyph_id = glyph_info[index].codepoint,
+        .cluster = glyph_info[index].cluster,
         .x_offset = glyph_pos[index].x_offset,
         .y_offset = glyph_pos[index].y_offset,
         .x_advance = glyph_pos[index].x_advance,
         .y_advance = glyph_pos[index].y_advance,
+        .x_origin = 0,
+        .y_origin = 0,
+        .glyph_class = SUBSET_GLYPH_CLASS_UNCLASSIFIED,
+        .flags = static_cast<uint32_t>(
+            hb_glyph_info_get_glyph_flags(&glyph_info[index]) &
+            HB_GLYPH_FLAG_DEFINED),
     };
+    if (params->vertical) {
+      hb_font_get_glyph_v_origin(FONT(font), glyph.glyph_id, &glyph.x_origin,
+                                 &glyph.y_origin);
+    }
+
+    if (params->glyph_classes) {
+      glyph.glyph_class = subset_font_glyph_class(font, glyph.glyph_id);
+    }
+
+    auto cluster = glyph_info[index].cluster;
+    if (params->show_invisibles &&
+        (index == 0 || glyph_info[index - 1].cluster != cluster)) {
+      auto unichar = params->unichars[cluster];
+      auto substitute = InvisibleCharacterSubstitute(unichar);
+      hb_codepoint_t glyph_id{0};
+      if (substitute != 0 &&
+          hb_font_get_nominal_glyph(FONT(font), substitute, &glyph_id) != 0) {
+        glyph.glyph_id = glyph_id;
+        // N.B. Characters shaped as zero width spaces take the advance of
+        // their substitute, so it doesn't overlap the next glyph.
+        auto hidden = ControlCharacterReplacement(unichar) == 0x200B;
+        if (params->vertical) {
+          hb_font_get_glyph_v_origin(FONT(font), glyph_id, &glyph.x_origin,
+                                     &glyph.y_origin);
+          if (hidden) {
+            glyph.y_advance = hb_font_get_glyph_v_advance(FONT(font), glyph_id);
+          }
+        } else if (hidden) {
+          glyph.x_advance = hb_font_get_glyph_h_advance(FONT(font), glyph_id);
+        }
+      }
+    }
+
+    // N.B. Spacing goes after the last glyph of a cluster, so marks stay on
+    // their base.
+    if (index + 1 == glyph_count || glyph_info[index + 1].cluster != cluster) {
+      auto spacing = letter_spacing;
+      if (IsWordSeparator(params->unichars[cluster])) {
+        spacing += params->word_spacing;
+      }
+
+      if (params->vertical) {
+        glyph.y_advance -= spacing;
+      } else {
+        glyph.x_advance += spacing;
+      }
+    }
+
     callback(glyph, context);
   }
 
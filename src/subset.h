@@ -37,6 +37,18 @@ void subset_font_destroy(SubsetFont* font);
 
 bool subset_font_has_glyph(SubsetFont* font, uint32_t unichar);
 
+bool subset_font_has_variation_glyph(SubsetFont* font, uint32_t unichar,
+                                     uint32_t variation_selector);
+
+bool subset_font_get_nominal_glyph(SubsetFont* font, uint32_t unichar,
+                                   uint32_t* glyph_id);
+
+bool subset_font_get_variation_glyph(SubsetFont* font, uint32_t unichar,
+                                     uint32_t variation_selector,
+                                     uint32_t* glyph_id);
+
+bool subset_font_has_color_glyphs(SubsetFont* font);
+
 bool subset_font_is_italic(SubsetFont* font);
 
 float subset_font_weight(SubsetFont* font);
@@ -48,6 +60,85 @@ unsigned int subset_font_upem(SubsetFont* font);
 bool subset_font_extents(SubsetFont* font, bool horizontal, int32_t* ascender,
                          int32_t* descender, int32_t* line_gap);
 
+// N.B. `y_bearing` is the top of the glyph and `height` is negative.
+bool subset_font_glyph_extents(SubsetFont* font, uint32_t glyph_id,
+                               int32_t* x_bearing, int32_t* y_bearing,
+                               int32_t* width, int32_t* height);
+
+// N.B. The advance is negative, i.e. downwards, when `vertical`.
+int32_t subset_font_glyph_advance(SubsetFont* font, uint32_t glyph_id,
+                                  bool vertical);
+
+// N.B. The typo, win and hhea values are read as is from the OS/2 and hhea
+// tables, i.e. `win_descent` is positive below the baseline.
+struct SubsetFontMetrics {
+  int32_t underline_offset;
+  int32_t underline_size;
+  int32_t strikeout_offset;
+  int32_t strikeout_size;
+  int32_t x_height;
+  int32_t cap_height;
+  int32_t typo_ascender;
+  int32_t typo_descender;
+  int32_t typo_line_gap;
+  int32_t win_ascent;
+  int32_t win_descent;
+  int32_t hhea_ascender;
+  int32_t hhea_descender;
+  int32_t hhea_line_gap;
+  int32_t subscript_x_offset;
+  int32_t subscript_y_offset;
+  int32_t subscript_x_size;
+  int32_t subscript_y_size;
+  int32_t superscript_x_offset;
+  int32_t superscript_y_offset;
+  int32_t superscript_x_size;
+  int32_t superscript_y_size;
+  bool use_typo_metrics;
+};
+
+bool subset_font_metrics(SubsetFont* font, struct SubsetFontMetrics* metrics);
+
+// Gets the position of the baseline identified by the OpenType baseline tag
+// from the BASE table. Returns `false` if the font lacks the baseline, in which
+// case `coord` is set to a position synthesized from other font metrics.
+// `script` is a HarfBuzz script and `language` may be `NULL`.
+bool subset_font_get_baseline(SubsetFont* font, uint32_t baseline_tag,
+                              bool vertical, uint32_t script,
+                              const char* language, int32_t* coord);
+
+// Returns whether the GSUB or GPOS table of the font has the feature.
+bool subset_font_has_feature(SubsetFont* font, uint32_t feature_tag);
+
+enum SubsetGlyphClass {
+  SUBSET_GLYPH_CLASS_UNCLASSIFIED = 0,
+  SUBSET_GLYPH_CLASS_BASE = 1,
+  SUBSET_GLYPH_CLASS_LIGATURE = 2,
+  SUBSET_GLYPH_CLASS_MARK = 3,
+  SUBSET_GLYPH_CLASS_COMPONENT = 4,
+};
+
+// Gets the GDEF class of the glyph, or unclassified if the font has none.
+enum SubsetGlyphClass subset_font_glyph_class(SubsetFont* font,
+                                              uint32_t glyph_id);
+
+enum SubsetDirection {
+  SUBSET_DIRECTION_LTR = 0,
+  SUBSET_DIRECTION_RTL = 1,
+  SUBSET_DIRECTION_TTB = 2,
+  SUBSET_DIRECTION_BTT = 3,
+};
+
+// Gets the GDEF ligature caret positions of the glyph, starting at
+// `start_offset`. On input `caret_count` is the capacity of `carets` and on
+// output the number of positions written. Returns the total number of carets.
+unsigned int subset_font_ligature_carets(SubsetFont* font,
+                                         enum SubsetDirection direction,
+                                         uint32_t glyph_id,
+                                         unsigned int start_offset,
+                                         unsigned int* caret_count,
+                                         int32_t* carets);
+
 enum SubsetPathVerb {
   SUBSET_PATH_VERB_MOVE_TO = 0,
   SUBSET_PATH_VERB_LINE_TO = 1,
@@ -66,21 +157,207 @@ void subset_font_draw_glyph(SubsetFont* font, uint32_t glyph_id,
                             SubsetGlyphDrawer* drawer,
                             SubsetPathCommandCallback callback, void* context);
 
+enum SubsetEmojiPresentation {
+  SUBSET_EMOJI_PRESENTATION_NONE = 0,
+  SUBSET_EMOJI_PRESENTATION_TEXT = 1,
+  SUBSET_EMOJI_PRESENTATION_EMOJI = 2,
+};
+
+// N.B. Every emoji sequence gets a run of its own with the presentation
+// set to something other than `SUBSET_EMOJI_PRESENTATION_NONE`. In vertical
+// text runs are also split on their UAX #50 orientation. The `upright` runs
+// should be shaped vertically while the others are shaped horizontally and
+// rotated sideways. Runs of Tr characters are `upright` and `transformed`, and
+// should be rotated instead if the font has neither the `vert` nor the `vrt2`
+// feature to transform them with. `paragraph_level` is the base level of the
+// paragraph the run is in.
 struct SubsetTextRun {
   size_t offset;
   size_t length;
   uint8_t bidi_level;
+  uint8_t paragraph_level;
   uint32_t script;
+  enum SubsetEmojiPresentation emoji_presentation;
+  bool upright;
+  bool transformed;
 };
 
 // NOLINTNEXTLINE
 typedef void (*SubsetTextRunCallback)(struct SubsetTextRun text_run,
                                       void* context);
 
+enum SubsetBidiControl {
+  SUBSET_BIDI_CONTROL_ISOLATE_LTR = 0,
+  SUBSET_BIDI_CONTROL_ISOLATE_RTL = 1,
+  SUBSET_BIDI_CONTROL_ISOLATE_FIRST_STRONG = 2,
+  SUBSET_BIDI_CONTROL_EMBED_LTR = 3,
+  SUBSET_BIDI_CONTROL_EMBED_RTL = 4,
+  SUBSET_BIDI_CONTROL_OVERRIDE_LTR = 5,
+  SUBSET_BIDI_CONTROL_OVERRIDE_RTL = 6,
+};
+
+// N.B. A span acts as if its text was enclosed in the matching LRI, RLI, FSI,
+// LRE, RLE, LRO or RLO control character and PDI or PDF. Spans should nest
+// and overlapping ones are cut at the end of the span they start in.
+struct SubsetBidiSpan {
+  size_t offset;
+  size_t length;
+  enum SubsetBidiControl control;
+};
+
+// N.B. Every paragraph, i.e. the text up to and including a hard line break, is
+// resolved on its own. The runs tell the base level of their paragraph, while
+// `paragraph_base_level` is the one of the first.
+// `bidi_spans` may be `NULL` if `bidi_span_count` is 0.
 int subset_text_runs(const uint32_t* unichars, size_t unichar_count,
+                     const struct SubsetBidiSpan* bidi_spans,
+                     size_t bidi_span_count, bool vertical,
                      uint8_t* paragraph_base_level,
                      SubsetTextRunCallback callback, void* context);
 
+enum SubsetSegmentation {
+  SUBSET_SEGMENTATION_GRAPHEME = 0,
+  SUBSET_SEGMENTATION_WORD = 1,
+  SUBSET_SEGMENTATION_SENTENCE = 2,
+  SUBSET_SEGMENTATION_HARD_LINE_BREAK = 3,
+};
+
+// NOLINTNEXTLINE
+typedef void (*SubsetBoundaryCallback)(size_t offset, void* context);
+
+// Calls `callback` with each UAX #29 boundary offset in order, or each UAX #14
+// hard line break boundary, including the start and the end of the text.
+// `language` may be `NULL` and tailors word boundaries, e.g. to break after
+// elided articles in French and Italian.
+bool subset_segment(const uint32_t* unichars, size_t unichar_count,
+                    enum SubsetSegmentation segmentation, const char* language,
+                    SubsetBoundaryCallback callback, void* context);
+
+// N.B. The values match `hb_unicode_general_category_t`.
+enum SubsetGeneralCategory {
+  SUBSET_GENERAL_CATEGORY_CONTROL = 0,
+  SUBSET_GENERAL_CATEGORY_FORMAT = 1,
+  SUBSET_GENERAL_CATEGORY_UNASSIGNED = 2,
+  SUBSET_GENERAL_CATEGORY_PRIVATE_USE = 3,
+  SUBSET_GENERAL_CATEGORY_SURROGATE = 4,
+  SUBSET_GENERAL_CATEGORY_LOWERCASE_LETTER = 5,
+  SUBSET_GENERAL_CATEGORY_MODIFIER_LETTER = 6,
+  SUBSET_GENERAL_CATEGORY_OTHER_LETTER = 7,
+  SUBSET_GENERAL_CATEGORY_TITLECASE_LETTER = 8,
+  SUBSET_GENERAL_CATEGORY_UPPERCASE_LETTER = 9,
+  SUBSET_GENERAL_CATEGORY_SPACING_MARK = 10,
+  SUBSET_GENERAL_CATEGORY_ENCLOSING_MARK = 11,
+  SUBSET_GENERAL_CATEGORY_NON_SPACING_MARK = 12,
+  SUBSET_GENERAL_CATEGORY_DECIMAL_NUMBER = 13,
+  SUBSET_GENERAL_CATEGORY_LETTER_NUMBER = 14,
+  SUBSET_GENERAL_CATEGORY_OTHER_NUMBER = 15,
+  SUBSET_GENERAL_CATEGORY_CONNECT_PUNCTUATION = 16,
+  SUBSET_GENERAL_CATEGORY_DASH_PUNCTUATION = 17,
+  SUBSET_GENERAL_CATEGORY_CLOSE_PUNCTUATION = 18,
+  SUBSET_GENERAL_CATEGORY_FINAL_PUNCTUATION = 19,
+  SUBSET_GENERAL_CATEGORY_INITIAL_PUNCTUATION = 20,
+  SUBSET_GENERAL_CATEGORY_OTHER_PUNCTUATION = 21,
+  SUBSET_GENERAL_CATEGORY_OPEN_PUNCTUATION = 22,
+  SUBSET_GENERAL_CATEGORY_CURRENCY_SYMBOL = 23,
+  SUBSET_GENERAL_CATEGORY_MODIFIER_SYMBOL = 24,
+  SUBSET_GENERAL_CATEGORY_MATH_SYMBOL = 25,
+  SUBSET_GENERAL_CATEGORY_OTHER_SYMBOL = 26,
+  SUBSET_GENERAL_CATEGORY_LINE_SEPARATOR = 27,
+  SUBSET_GENERAL_CATEGORY_PARAGRAPH_SEPARATOR = 28,
+  SUBSET_GENERAL_CATEGORY_SPACE_SEPARATOR = 29,
+};
+
+enum SubsetBidiClass {
+  SUBSET_BIDI_CLASS_L = 0,
+  SUBSET_BIDI_CLASS_R = 1,
+  SUBSET_BIDI_CLASS_AL = 2,
+  SUBSET_BIDI_CLASS_EN = 3,
+  SUBSET_BIDI_CLASS_ES = 4,
+  SUBSET_BIDI_CLASS_ET = 5,
+  SUBSET_BIDI_CLASS_AN = 6,
+  SUBSET_BIDI_CLASS_CS = 7,
+  SUBSET_BIDI_CLASS_NSM = 8,
+  SUBSET_BIDI_CLASS_BN = 9,
+  SUBSET_BIDI_CLASS_B = 10,
+  SUBSET_BIDI_CLASS_S = 11,
+  SUBSET_BIDI_CLASS_WS = 12,
+  SUBSET_BIDI_CLASS_ON = 13,
+  SUBSET_BIDI_CLASS_LRE = 14,
+  SUBSET_BIDI_CLASS_RLE = 15,
+  SUBSET_BIDI_CLASS_LRO = 16,
+  SUBSET_BIDI_CLASS_RLO = 17,
+  SUBSET_BIDI_CLASS_PDF = 18,
+  SUBSET_BIDI_CLASS_LRI = 19,
+  SUBSET_BIDI_CLASS_RLI = 20,
+  SUBSET_BIDI_CLASS_FSI = 21,
+  SUBSET_BIDI_CLASS_PDI = 22,
+};
+
+// N.B. `mirroring` is the Bidi_Mirroring_Glyph of the character, or the
+// character itself if it has none, and `script` an ISO 15924 tag like the one
+// of text runs.
+struct SubsetUnicodeProperties {
+  enum SubsetGeneralCategory general_category;
+  uint32_t script;
+  uint32_t mirroring;
+  uint8_t combining_class;
+  enum SubsetBidiClass bidi_class;
+};
+
+void subset_unicode_properties(uint32_t unichar,
+                               struct SubsetUnicodeProperties* properties);
+
+// Returns whether the character has the Variation_Selector property, i.e.
+// selects a variant of the base character before it.
+bool subset_is_variation_selector(uint32_t unichar);
+
+// Returns whether the character is a word separator, i.e. one that
+// `word_spacing` is added to.
+bool subset_is_word_separator(uint32_t unichar);
+
+enum SubsetNormalization {
+  SUBSET_NORMALIZATION_NFC = 0,
+  SUBSET_NORMALIZATION_NFD = 1,
+};
+
+// NOLINTNEXTLINE
+typedef bool (*SubsetCompositionFilter)(uint32_t starter, uint32_t composed,
+                                        void* context);
+
+// NOLINTNEXTLINE
+typedef void (*SubsetNormalizedCallback)(uint32_t unichar, size_t offset,
+                                         void* context);
+
+// Calls `callback` with each character of the NFC or NFD form of the text and
+// the offset of the character it comes from. Composed characters come from
+// their starter, and offsets never decrease, so marks reordered before others
+// come from the first of them. `filter` may be `NULL` and otherwise decides
+// whether `starter` composes to `composed`, for compositions that suit a font.
+bool subset_normalize(const uint32_t* unichars, size_t unichar_count,
+                      enum SubsetNormalization normalization,
+                      SubsetCompositionFilter filter,
+                      SubsetNormalizedCallback callback, void* context);
+
+enum SubsetTextTransform {
+  SUBSET_TEXT_TRANSFORM_UPPERCASE = 0,
+  SUBSET_TEXT_TRANSFORM_LOWERCASE = 1,
+  SUBSET_TEXT_TRANSFORM_CAPITALIZE = 2,
+};
+
+// NOLINTNEXTLINE
+typedef void (*SubsetTransformedCallback)(uint32_t unichar, size_t offset,
+                                          void* context);
+
+// Calls `callback` with each character of the text case mapped like the CSS
+// `text-transform` property and the offset of the character it comes from.
+// Capitalizing titlecases the first letter of every word. `language` may be
+// `NULL` and tailors the mappings for Turkish, Azerbaijani, Lithuanian and
+// Greek, where uppercase drops accents.
+bool subset_transform_text(const uint32_t* unichars, size_t unichar_count,
+                           enum SubsetTextTransform transform,
+                           const char* language,
+                           SubsetTransformedCallback callback, void* context);
+
 struct SubsetFontStyle {
   bool italic;
   float weight;
@@ -97,6 +374,23 @@ bool subset_find_best_font_match(uint32_t unichar,
                                  void* font_provider_context,
                                  size_t* best_index);
 
+// Like `subset_find_best_font_match` but prefers fonts covering the whole
+// sequence, including the exact variation sequences but ignoring joiners and
+// emoji presentation selectors, and fonts with or without color glyphs
+// depending on the emoji presentation.
+bool subset_find_best_font_match_for_sequence(
+    const uint32_t* unichars, size_t unichar_count,
+    enum SubsetEmojiPresentation emoji_presentation,
+    struct SubsetFontStyle font_style, size_t font_count,
+    SubsetFontProvider font_provider, void* font_provider_context,
+    size_t* best_index);
+
+// N.B. `letter_spacing` and `word_spacing` are in font units and added to the
+// advance of every cluster, and every cluster of a word separator,
+// respectively. Letter spacing is not applied to cursive scripts, and disables
+// optional ligatures otherwise. Control characters are shaped as blank glyphs,
+// a space for tabs and zero width otherwise, unless `show_invisibles` is set
+// and the font has a visible substitute, which also applies to spaces.
 struct SubsetShapeParams {
   const uint32_t* unichars;
   size_t unichar_count;
@@ -105,14 +399,37 @@ struct SubsetShapeParams {
   uint8_t bidi_level;
   uint32_t script;
   const char* language;
+  bool vertical;
+  bool glyph_classes;
+  int32_t letter_spacing;
+  int32_t word_spacing;
+  bool show_invisibles;
+  bool small_caps;
+};
+
+enum SubsetGlyphFlags {
+  SUBSET_GLYPH_FLAG_UNSAFE_TO_BREAK = 1,
+  SUBSET_GLYPH_FLAG_UNSAFE_TO_CONCAT = 2,
+  SUBSET_GLYPH_FLAG_SAFE_TO_INSERT_TATWEEL = 4,
 };
 
+// N.B. `cluster` is the index into `unichars` of the first character the glyph
+// was shaped from. `x_origin` and `y_origin` is the vertical origin of the
+// glyph, relative to its horizontal origin, when shaped vertically and zero
+// otherwise. `glyph_class` is only set when `glyph_classes` is requested.
+// `flags` is a combination of `SubsetGlyphFlags`, where inserting a tatweel is
+// only ever safe before clusters of scripts that join.
 struct SubsetGlyph {
   uint32_t glyph_id;
+  uint32_t cluster;
   int32_t x_offset;
   int32_t y_offset;
   int32_t x_advance;
   int32_t y_advance;
+  int32_t x_origin;
+  int32_t y_origin;
+  enum SubsetGlyphClass glyph_class;
+  uint32_t flags;
 };
 
 // NOLINTNEXTLINE
@@ -0,0 +1,209 @@
+// Generated by gen_unicode_tables.py from unofficial Unicode 15.1.0
+// VerticalOrientation.txt (UAX #50).
+// N.B. These tables were generated from files rebuilt offline, not the ones of
+// the UCD: segmentation and vertical orientation from ICU 72 (Unicode 15.0)
+// plus the 15.1 changes, Indic_Conjunct_Break inferred from the GB9c rules,
+// emoji from the unicode-properties crate, Script_Extensions from the
+// unicode-script crate and case mappings from Python 3.13. Regenerate them from
+// unicode.org.
+
+#pragma once
+
+#include <array>
+#include <cstdint>
+
+namespace subset {
+enum VerticalOrientationValue : uint8_t {
+  kVerticalRotated = 0,
+  kVerticalUpright = 1,
+  kVerticalTransformedOrUpright = 2,
+  kVerticalTransformedOrRotated = 3,
+};
+
+// N.B. Characters not covered by any range are rotated.
+struct VerticalOrientationRange {
+  uint32_t first;
+  uint32_t last;
+  VerticalOrientationValue value;
+};
+
+inline constexpr std::array<VerticalOrientationRange, 176>
+    kVerticalOrientationRanges{{
+        {0x00A7, 0x00A7, kVerticalUpright},
+        {0x00A9, 0x00A9, kVerticalUpright},
+        {0x00AE, 0x00AE, kVerticalUpright},
+        {0x00B1, 0x00B1, kVerticalUpright},
+        {0x00BC, 0x00BE, kVerticalUpright},
+        {0x00D7, 0x00D7, kVerticalUpright},
+        {0x00F7, 0x00F7, kVerticalUpright},
+        {0x02EA, 0x02EB, kVerticalUpright},
+        {0x1100, 0x11FF, kVerticalUpright},
+        {0x1401, 0x167F, kVerticalUpright},
+        {0x18B0, 0x18FF, kVerticalUpright},
+        {0x2016, 0x2016, kVerticalUpright},
+        {0x2020, 0x2021, kVerticalUpright},
+        {0x2030, 0x2031, kVerticalUpright},
+        {0x203B, 0x203C, kVerticalUpright},
+        {0x2042, 0x2042, kVerticalUpright},
+        {0x2047, 0x2049, kVerticalUpright},
+        {0x2051, 0x2051, kVerticalUpright},
+        {0x2065, 0x2065, kVerticalUpright},
+        {0x20DD, 0x20E0, kVerticalUpright},
+        {0x20E2, 0x20E4, kVerticalUpright},
+        {0x2100, 0x2101, kVerticalUpright},
+        {0x2103, 0x2109, kVerticalUpright},
+        {0x210F, 0x210F, kVerticalUpright},
+        {0x2113, 0x2114, kVerticalUpright},
+        {0x2116, 0x2117, kVerticalUpright},
+        {0x211E, 0x2123, kVerticalUpright},
+        {0x2125, 0x2125, kVerticalUpright},
+        {0x2127, 0x2127, kVerticalUpright},
+        {0x2129, 0x2129, kVerticalUpright},
+        {0x212E, 0x212E, kVerticalUpright},
+        {0x2135, 0x213F, kVerticalUpright},
+        {0x2145, 0x214A, kVerticalUpright},
+        {0x214C, 0x214D, kVerticalUpright},
+        {0x214F, 0x2189, kVerticalUpright},
+        {0x218C, 0x218F, kVerticalUpright},
+        {0x221E, 0x221E, kVerticalUpright},
+        {0x2234, 0x2235, kVerticalUpright},
+        {0x2300, 0x2307, kVerticalUpright},
+        {0x230C, 0x231F, kVerticalUpright},
+        {0x2324, 0x2328, kVerticalUpright},
+        {0x2329, 0x232A, kVerticalTransformedOrRotated},
+        {0x232B, 0x232B, kVerticalUpright},
+        {0x237D, 0x239A, kVerticalUpright},
+        {0x23BE, 0x23CD, kVerticalUpright},
+        {0x23CF, 0x23CF, kVerticalUpright},
+        {0x23D1, 0x23DB, kVerticalUpright},
+        {0x23E2, 0x2422, kVerticalUpright},
+        {0x2424, 0x24FF, kVerticalUpright},
+        {0x25A0, 0x2619, kVerticalUpright},
+        {0x2620, 0x2767, kVerticalUpright},
+        {0x2776, 0x2793, kVerticalUpright},
+        {0x2B12, 0x2B2F, kVerticalUpright},
+        {0x2B50, 0x2B59, kVerticalUpright},
+        {0x2B97, 0x2B97, kVerticalUpright},
+        {0x2BB8, 0x2BD1, kVerticalUpright},
+        {0x2BD3, 0x2BEB, kVerticalUpright},
+        {0x2BF0, 0x2BFF, kVerticalUpright},
+        {0x2E50, 0x2E51, kVerticalUpright},
+        {0x2E80, 0x3000, kVerticalUpright},
+        {0x3001, 0x3002, kVerticalTransformedOrUpright},
+        {0x3003, 0x3007, kVerticalUpright},
+        {0x3008, 0x3011, kVerticalTransformedOrRotated},
+        {0x3012, 0x3013, kVerticalUpright},
+        {0x3014, 0x301F, kVerticalTransformedOrRotated},
+        {0x3020, 0x302F, kVerticalUpright},
+        {0x3030, 0x3030, kVerticalTransformedOrRotated},
+        {0x3031, 0x3040, kVerticalUpright},
+        {0x3041, 0x3041, kVerticalTransformedOrUpright},
+        {0x3042, 0x3042, kVerticalUpright},
+        {0x3043, 0x3043, kVerticalTransformedOrUpright},
+        {0x3044, 0x3044, kVerticalUpright},
+        {0x3045, 0x3045, kVerticalTransformedOrUpright},
+        {0x3046, 0x3046, kVerticalUpright},
+        {0x3047, 0x3047, kVerticalTransformedOrUpright},
+        {0x3048, 0x3048, kVerticalUpright},
+        {0x3049, 0x3049, kVerticalTransformedOrUpright},
+        {0x304A, 0x3062, kVerticalUpright},
+        {0x3063, 0x3063, kVerticalTransformedOrUpright},
+        {0x3064, 0x3082, kVerticalUpright},
+        {0x3083, 0x3083, kVerticalTransformedOrUpright},
+        {0x3084, 0x3084, kVerticalUpright},
+        {0x3085, 0x3085, kVerticalTransformedOrUpright},
+        {0x3086, 0x3086, kVerticalUpright},
+        {0x3087, 0x3087, kVerticalTransformedOrUpright},
+        {0x3088, 0x308D, kVerticalUpright},
+        {0x308E, 0x308E, kVerticalTransformedOrUpright},
+        {0x308F, 0x3094, kVerticalUpright},
+        {0x3095, 0x3096, kVerticalTransformedOrUpright},
+        {0x3097, 0x309A, kVerticalUpright},
+        {0x309B, 0x309C, kVerticalTransformedOrUpright},
+        {0x309D, 0x309F, kVerticalUpright},
+        {0x30A0, 0x30A0, kVerticalTransformedOrRotated},
+        {0x30A1, 0x30A1, kVerticalTransformedOrUpright},
+        {0x30A2, 0x30A2, kVerticalUpright},
+        {0x30A3, 0x30A3, kVerticalTransformedOrUpright},
+        {0x30A4, 0x30A4, kVerticalUpright},
+        {0x30A5, 0x30A5, kVerticalTransformedOrUpright},
+        {0x30A6, 0x30A6, kVerticalUpright},
+        {0x30A7, 0x30A7, kVerticalTransformedOrUpright},
+        {0x30A8, 0x30A8, kVerticalUpright},
+        {0x30A9, 0x30A9, kVerticalTransformedOrUpright},
+        {0x30AA, 0x30C2, kVerticalUpright},
+        {0x30C3, 0x30C3, kVerticalTransformedOrUpright},
+        {0x30C4, 0x30E2, kVerticalUpright},
+        {0x30E3, 0x30E3, kVerticalTransformedOrUpright},
+        {0x30E4, 0x30E4, kVerticalUpright},
+        {0x30E5, 0x30E5, kVerticalTransformedOrUpright},
+        {0x30E6, 0x30E6, kVerticalUpright},
+        {0x30E7, 0x30E7, kVerticalTransformedOrUpright},
+        {0x30E8, 0x30ED, kVerticalUpright},
+        {0x30EE, 0x30EE, kVerticalTransformedOrUpright},
+        {0x30EF, 0x30F4, kVerticalUpright},
+        {0x30F5, 0x30F6, kVerticalTransformedOrUpright},
+        {0x30F7, 0x30FB, kVerticalUpright},
+        {0x30FC, 0x30FC, kVerticalTransformedOrRotated},
+        {0x30FD, 0x3126, kVerticalUpright},
+        {0x3127, 0x3127, kVerticalTransformedOrUpright},
+        {0x3128, 0x31EF, kVerticalUpright},
+        {0x31F0, 0x31FF, kVerticalTransformedOrUpright},
+        {0x3200, 0x32FE, kVerticalUpright},
+        {0x32FF, 0x3357, kVerticalTransformedOrUpright},
+        {0x3358, 0x337A, kVerticalUpright},
+        {0x337B, 0x337F, kVerticalTransformedOrUpright},
+        {0x3380, 0xA4CF, kVerticalUpright},
+        {0xA960, 0xA97F, kVerticalUpright},
+        {0xAC00, 0xD7FF, kVerticalUpright},
+        {0xE000, 0xFAFF, kVerticalUpright},
+        {0xFE10, 0xFE1F, kVerticalUpright},
+        {0xFE30, 0xFE48, kVerticalUpright},
+        {0xFE50, 0xFE52, kVerticalTransformedOrUpright},
+        {0xFE53, 0xFE57, kVerticalUpright},
+        {0xFE59, 0xFE5E, kVerticalTransformedOrRotated},
+        {0xFE5F, 0xFE62, kVerticalUpright},
+        {0xFE67, 0xFE6F, kVerticalUpright},
+        {0xFF01, 0xFF01, kVerticalTransformedOrUpright},
+        {0xFF02, 0xFF07, kVerticalUpright},
+        {0xFF08, 0xFF09, kVerticalTransformedOrRotated},
+        {0xFF0A, 0xFF0B, kVerticalUpright},
+        {0xFF0C, 0xFF0C, kVerticalTransformedOrUpright},
+        {0xFF0E, 0xFF0E, kVerticalTransformedOrUpright},
+        {0xFF0F, 0xFF19, kVerticalUpright},
+        {0xFF1A, 0xFF1B, kVerticalTransformedOrRotated},
+        {0xFF1F, 0xFF1F, kVerticalTransformedOrUpright},
+        {0xFF20, 0xFF3A, kVerticalUpright},
+        {0xFF3B, 0xFF3B, kVerticalTransformedOrRotated},
+        {0xFF3C, 0xFF3C, kVerticalUpright},
+        {0xFF3D, 0xFF3D, kVerticalTransformedOrRotated},
+        {0xFF3E, 0xFF3E, kVerticalUpright},
+        {0xFF3F, 0xFF3F, kVerticalTransformedOrRotated},
+        {0xFF40, 0xFF5A, kVerticalUpright},
+        {0xFF5B, 0xFF60, kVerticalTransformedOrRotated},
+        {0xFFE0, 0xFFE2, kVerticalUpright},
+        {0xFFE3, 0xFFE3, kVerticalTransformedOrRotated},
+        {0xFFE4, 0xFFE7, kVerticalUpright},
+        {0xFFF0, 0xFFF8, kVerticalUpright},
+        {0xFFFC, 0xFFFD, kVerticalUpright},
+        {0x10980, 0x1099F, kVerticalUpright},
+        {0x11580, 0x115FF, kVerticalUpright},
+        {0x11A00, 0x11ABF, kVerticalUpright},
+        {0x13000, 0x1345F, kVerticalUpright},
+        {0x14400, 0x1467F, kVerticalUpright},
+        {0x16FE0, 0x18D7F, kVerticalUpright},
+        {0x1AFF0, 0x1B2FF, kVerticalUpright},
+        {0x1CF00, 0x1CFCF, kVerticalUpright},
+        {0x1D000, 0x1D1FF, kVerticalUpright},
+        {0x1D2E0, 0x1D37F, kVerticalUpright},
+        {0x1D800, 0x1DAAF, kVerticalUpright},
+        {0x1F000, 0x1F1FF, kVerticalUpright},
+        {0x1F200, 0x1F201, kVerticalTransformedOrUpright},
+        {0x1F202, 0x1F7FF, kVerticalUpright},
+        {0x1F900, 0x1FAFF, kVerticalUpright},
+        {0x20000, 0x2FFFD, kVerticalUpright},
+        {0x30000, 0x3FFFD, kVerticalUpright},
+        {0xF0000, 0xFFFFD, kVerticalUpright},
+        {0x100000, 0x10FFFD, kVerticalUpright},
+    }};
+}  // namespace subset